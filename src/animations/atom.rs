@@ -1,6 +1,10 @@
 use super::Animation;
+use crate::generators::ViewSpin;
 use crate::render::Canvas;
 
+/// How fast the orbit planes turn about the view axis, in radians per second.
+const VIEW_SPEED: f64 = 0.15;
+
 struct Electron {
     orbit_radius_x: f64,
     orbit_radius_y: f64,
@@ -13,6 +17,8 @@ struct Electron {
 /// Electrons orbiting a nucleus in 3D perspective
 pub struct Atom {
     electrons: Vec<Electron>,
+    /// Orbit-plane rotation; electrons keep orbiting while it is frozen
+    spin: ViewSpin,
 }
 
 impl Atom {
@@ -44,7 +50,10 @@ impl Atom {
                 hue: 0.66,
             },
         ];
-        Atom { electrons: orbits }
+        Atom {
+            electrons: orbits,
+            spin: ViewSpin::default(),
+        }
     }
 }
 
@@ -53,19 +62,30 @@ impl Animation for Atom {
         "atom"
     }
 
-    fn update(&mut self, canvas: &mut Canvas, _dt: f64, time: f64) {
+    fn toggle_freeze(&mut self) -> bool {
+        self.spin.toggle();
+        true
+    }
+
+    fn update(&mut self, canvas: &mut Canvas, dt: f64, time: f64) {
+        self.spin.tick(dt);
+        let view = self.spin.time(time) * VIEW_SPEED;
         let w = canvas.width as f64;
         let h = canvas.height as f64;
         let cx = w * 0.5;
         let cy = h * 0.5;
+        // Orbits are laid out in horizontal pixels and tilted there; y
+        // offsets divide by the pixel aspect on the way to the canvas, so
+        // the shapes match in every render mode
+        let aspect = canvas.aspect;
 
         canvas.clear();
 
         // Draw orbit paths and electrons
         for electron in &self.electrons {
             let rx = w * electron.orbit_radius_x;
-            let ry = h * electron.orbit_radius_y;
-            let tilt = electron.tilt;
+            let ry = h * aspect * electron.orbit_radius_y;
+            let tilt = electron.tilt + view;
             let cos_tilt = tilt.cos();
             let sin_tilt = tilt.sin();
 
@@ -85,7 +105,7 @@ impl Animation for Atom {
                 let brightness = 0.1 + depth * 0.1;
 
                 let px = (cx + rotated_x) as usize;
-                let py = (cy + rotated_y) as usize;
+                let py = (cy + rotated_y / aspect) as usize;
                 if px < canvas.width && py < canvas.height {
                     let (r, g, b) = hsv_to_rgb(electron.hue, 0.3, 0.5);
                     canvas.set_colored(px, py, brightness, r, g, b);
@@ -100,46 +120,51 @@ impl Animation for Atom {
             let rotated_y = ox * sin_tilt + oy * cos_tilt;
 
             let ex = cx + rotated_x;
-            let ey = cy + rotated_y;
+            let ey = cy + rotated_y / aspect;
 
             // Electron glow
-            let glow_r = 3.0;
-            for dy in -(glow_r as i32)..=(glow_r as i32) {
-                for dx in -(glow_r as i32)..=(glow_r as i32) {
-                    let dist = ((dx * dx + dy * dy) as f64).sqrt();
-                    if dist <= glow_r {
-                        let px = (ex + dx as f64) as usize;
-                        let py = (ey + dy as f64) as usize;
-                        if px < canvas.width && py < canvas.height {
-                            let brightness = (1.0 - dist / glow_r).powi(2);
-                            let (r, g, b) = hsv_to_rgb(electron.hue, 0.8, 1.0);
-                            canvas.set_colored(px, py, brightness, r, g, b);
-                        }
-                    }
-                }
-            }
+            let (r, g, b) = hsv_to_rgb(electron.hue, 0.8, 1.0);
+            glow(canvas, ex, ey, 3.0, |dist| (1.0 - dist).powi(2), (r, g, b));
         }
 
         // Draw nucleus at center
-        let nuc_r = (w.min(h) * 0.03).max(2.0);
-        for dy in -(nuc_r as i32)..=(nuc_r as i32) {
-            for dx in -(nuc_r as i32)..=(nuc_r as i32) {
-                let dist = ((dx * dx + dy * dy) as f64).sqrt();
-                if dist <= nuc_r {
-                    let px = (cx + dx as f64) as usize;
-                    let py = (cy + dy as f64) as usize;
-                    if px < canvas.width && py < canvas.height {
-                        let brightness = (1.0 - dist / nuc_r).powi(2) * 0.9;
-                        // Nucleus pulses
-                        let pulse = (time * 3.0).sin() * 0.1 + 0.9;
-                        let r = (255.0 * pulse) as u8;
-                        let g = (100.0 * pulse) as u8;
-                        let b = (80.0 * pulse) as u8;
-                        canvas.set_colored(px, py, brightness, r, g, b);
-                    }
-                }
-            }
-        }
+        let nuc_r = (w.min(h * aspect) * 0.03).max(2.0);
+        // Nucleus pulses
+        let pulse = (time * 3.0).sin() * 0.1 + 0.9;
+        let color = (
+            (255.0 * pulse) as u8,
+            (100.0 * pulse) as u8,
+            (80.0 * pulse) as u8,
+        );
+        glow(
+            canvas,
+            cx,
+            cy,
+            nuc_r,
+            |dist| (1.0 - dist).powi(2) * 0.9,
+            color,
+        );
+    }
+}
+
+/// Round glow of horizontal radius `radius` around `(cx, cy)`, drawn as
+/// nested `fill_circle` discs from the rim inward. `falloff` maps distance as
+/// a fraction of the radius (0 at the center, 1 at the edge) to brightness.
+fn glow(
+    canvas: &mut Canvas,
+    cx: f64,
+    cy: f64,
+    radius: f64,
+    falloff: impl Fn(f64) -> f64,
+    (r, g, b): (u8, u8, u8),
+) {
+    // About one disc per pixel of radius; each ring takes the falloff at
+    // its middle
+    let rings = radius.ceil().max(1.0) as usize;
+    for ring in (1..=rings).rev() {
+        let outer = ring as f64 / rings as f64;
+        let mid = (ring as f64 - 0.5) / rings as f64;
+        canvas.fill_circle(cx, cy, radius * outer, falloff(mid), r, g, b);
     }
 }
 
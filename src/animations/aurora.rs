@@ -1,10 +1,96 @@
 use super::Animation;
+use crate::generators::{ColorGradient, ColorStop, NamedParam, named_param};
 use crate::render::Canvas;
 use noise::{NoiseFn, Perlin};
 
+/// Most curtain layers `aurora_layers` accepts.
+const MAX_LAYERS: usize = 8;
+
+/// (faint, full-intensity) endpoint colors of one curtain layer.
+type LayerColors = ((u8, u8, u8), (u8, u8, u8));
+
+/// Curtain color scheme; each layer takes the next gradient in turn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuroraPalette {
+    Green,
+    Purple,
+    Red,
+    /// Original green / teal / magenta / pink layers
+    Mixed,
+}
+
+named_param!(AuroraPalette {
+    Green => "green",
+    Purple => "purple",
+    Red => "red",
+    Mixed => "mixed",
+});
+
+impl AuroraPalette {
+    fn layer_colors(self) -> &'static [LayerColors] {
+        match self {
+            AuroraPalette::Green => &[
+                ((20, 180, 40), (20, 255, 100)),
+                ((60, 160, 30), (140, 255, 80)),
+                ((10, 130, 80), (40, 230, 160)),
+                ((10, 100, 30), (60, 200, 70)),
+            ],
+            AuroraPalette::Purple => &[
+                ((120, 20, 160), (220, 70, 255)),
+                ((70, 30, 150), (150, 110, 255)),
+                ((160, 20, 130), (255, 90, 220)),
+                ((100, 60, 170), (200, 170, 255)),
+            ],
+            AuroraPalette::Red => &[
+                ((180, 30, 50), (255, 70, 90)),
+                ((170, 60, 20), (255, 140, 60)),
+                ((180, 30, 80), (255, 70, 160)),
+                ((120, 10, 20), (220, 40, 50)),
+            ],
+            AuroraPalette::Mixed => &[
+                // Bright green (classic aurora)
+                ((0, 180, 40), (20, 255, 100)),
+                // Cyan-teal
+                ((20, 140, 160), (60, 255, 255)),
+                // Purple-magenta
+                ((120, 20, 160), (220, 70, 255)),
+                // Pink-red (rare top layer)
+                ((180, 30, 80), (255, 70, 160)),
+            ],
+        }
+    }
+}
+
+/// Build one gradient per curtain layer, cycling through the palette.
+fn layer_gradients(palette: AuroraPalette, layers: usize) -> Vec<ColorGradient> {
+    let colors = palette.layer_colors();
+    (0..layers)
+        .map(|i| {
+            let ((r0, g0, b0), (r1, g1, b1)) = colors[i % colors.len()];
+            ColorGradient::new(vec![
+                ColorStop {
+                    t: 0.0,
+                    r: r0,
+                    g: g0,
+                    b: b0,
+                },
+                ColorStop {
+                    t: 1.0,
+                    r: r1,
+                    g: g1,
+                    b: b1,
+                },
+            ])
+        })
+        .collect()
+}
+
 /// Aurora borealis effect
 pub struct Aurora {
     noise: Perlin,
+    palette: AuroraPalette,
+    /// One gradient per curtain layer, sampled by curtain intensity
+    layers: Vec<ColorGradient>,
 }
 
 impl Aurora {
@@ -12,6 +98,15 @@ impl Aurora {
     pub fn new(_width: usize, _height: usize, _scale: f64) -> Self {
         Aurora {
             noise: Perlin::new(42),
+            palette: AuroraPalette::Mixed,
+            layers: layer_gradients(AuroraPalette::Mixed, 4),
+        }
+    }
+
+    fn configure(&mut self, palette: AuroraPalette, layers: usize) {
+        if palette != self.palette || layers != self.layers.len() {
+            self.palette = palette;
+            self.layers = layer_gradients(palette, layers);
         }
     }
 }
@@ -21,6 +116,22 @@ impl Animation for Aurora {
         "aurora"
     }
 
+    fn set_params(&mut self, params: &crate::external::ExternalParams) {
+        let palette = AuroraPalette::from_params(params, "aurora_palette").unwrap_or(self.palette);
+        let layers = params
+            .get("aurora_layers")
+            .map(|v| v.round().clamp(1.0, MAX_LAYERS as f64) as usize)
+            .unwrap_or(self.layers.len());
+        self.configure(palette, layers);
+    }
+
+    fn supported_params(&self) -> &'static [(&'static str, f64, f64)] {
+        &[
+            ("aurora_palette", 0.0, 3.0),
+            ("aurora_layers", 1.0, MAX_LAYERS as f64),
+        ]
+    }
+
     fn update(&mut self, canvas: &mut Canvas, _dt: f64, time: f64) {
         let w = canvas.width as f64;
         let h = canvas.height as f64;
@@ -43,8 +154,8 @@ impl Animation for Aurora {
         for x in 0..canvas.width {
             let fx = x as f64 / w;
 
-            // Multiple curtain layers with distinct colors
-            for layer in 0..4 {
+            // Multiple curtain layers, each with its own gradient
+            for (layer, gradient) in self.layers.iter().enumerate() {
                 let offset = layer as f64 * 0.8;
                 let speed = 0.8 + layer as f64 * 0.2;
 
@@ -74,41 +185,7 @@ impl Animation for Aurora {
                         continue;
                     }
 
-                    // Vivid aurora palette — each layer a different hue
-                    let (r, g, b) = match layer {
-                        0 => {
-                            // Bright green (classic aurora)
-                            (
-                                (20.0 * intensity) as u8,
-                                (180.0 + 75.0 * intensity) as u8,
-                                (40.0 + 60.0 * intensity) as u8,
-                            )
-                        }
-                        1 => {
-                            // Cyan-teal
-                            (
-                                (20.0 + 40.0 * intensity) as u8,
-                                (140.0 + 115.0 * intensity) as u8,
-                                (160.0 + 95.0 * intensity) as u8,
-                            )
-                        }
-                        2 => {
-                            // Purple-magenta
-                            (
-                                (120.0 + 100.0 * intensity) as u8,
-                                (20.0 + 50.0 * intensity) as u8,
-                                (160.0 + 95.0 * intensity) as u8,
-                            )
-                        }
-                        _ => {
-                            // Pink-red (rare top layer)
-                            (
-                                (180.0 + 75.0 * intensity) as u8,
-                                (30.0 + 40.0 * intensity) as u8,
-                                (80.0 + 80.0 * intensity) as u8,
-                            )
-                        }
-                    };
+                    let (r, g, b) = gradient.sample(intensity);
 
                     // Layer blend — brighter layer wins, colors mix via max
                     let idx = y * canvas.width + x;
@@ -129,3 +206,41 @@ impl Animation for Aurora {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::external::ExternalParams;
+
+    #[test]
+    fn mixed_palette_matches_original_layer_colors() {
+        let layers = layer_gradients(AuroraPalette::Mixed, 4);
+        let i = 0.6;
+        assert_eq!(
+            layers[0].sample(i),
+            (
+                (20.0 * i) as u8,
+                (180.0 + 75.0 * i) as u8,
+                (40.0 + 60.0 * i) as u8
+            )
+        );
+        assert_eq!(
+            layers[3].sample(i),
+            (
+                (180.0 + 75.0 * i) as u8,
+                (30.0 + 40.0 * i) as u8,
+                (80.0 + 80.0 * i) as u8
+            )
+        );
+    }
+
+    #[test]
+    fn params_select_palette_and_layer_count() {
+        let mut aurora = Aurora::new(40, 20, 1.0);
+        let params: ExternalParams =
+            serde_json::from_str(r#"{"aurora_palette": "purple", "aurora_layers": 20}"#).unwrap();
+        aurora.set_params(&params);
+        assert_eq!(aurora.palette, AuroraPalette::Purple);
+        assert_eq!(aurora.layers.len(), MAX_LAYERS);
+    }
+}
@@ -90,7 +90,7 @@ pub struct Automata {
     cycle_duration: f64,
     /// Generation counter for current ruleset
     generation: u64,
-    rng: rand::rngs::ThreadRng,
+    rng: rand::rngs::StdRng,
 }
 
 fn hsv_to_rgb(h: f64, s: f64, v: f64) -> (u8, u8, u8) {
@@ -125,6 +125,12 @@ fn age_to_color(age: u16) -> (u8, u8, u8) {
 
 impl Automata {
     pub fn new(width: usize, height: usize, _scale: f64) -> Self {
+        Self::with_rng(width, height, super::rng())
+    }
+
+    /// A fresh grid drawing from `rng`; resizing seeds it from the old
+    /// stream so a `--seed` run stays reproducible.
+    fn with_rng(width: usize, height: usize, rng: rand::rngs::StdRng) -> Self {
         let size = width * height;
         let mut automata = Automata {
             width,
@@ -138,7 +144,7 @@ impl Automata {
             cycle_timer: 0.0,
             cycle_duration: 17.0,
             generation: 0,
-            rng: rand::rng(),
+            rng,
         };
         automata.seed_grid();
         automata
@@ -224,7 +230,11 @@ impl Animation for Automata {
     }
 
     fn on_resize(&mut self, width: usize, height: usize) {
-        *self = Automata::new(width, height, 1.0);
+        *self = Automata::with_rng(width, height, rand::SeedableRng::from_rng(&mut self.rng));
+    }
+
+    fn on_time_gap(&mut self) {
+        self.step_timer = 0.0;
     }
 
     fn update(&mut self, canvas: &mut Canvas, dt: f64, _time: f64) {
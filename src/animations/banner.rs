@@ -0,0 +1,141 @@
+use super::Animation;
+use crate::generators::{color_cycle_period, cycle_t};
+use crate::render::Canvas;
+use crate::render::font::{self, Font};
+
+const DEFAULT_TEXT: &str = "TERMFLIX";
+/// Scroll speed in glyphs per second.
+const SCROLL_GLYPHS: f64 = 3.0;
+/// Tallest the text may be, as a fraction of canvas height.
+const MAX_TEXT_HEIGHT: f64 = 0.6;
+/// Seconds per rainbow loop unless `color_cycle_period` overrides it.
+const DEFAULT_COLOR_PERIOD: f64 = 20.0 / 3.0;
+
+/// Large bitmap-font text scrolling right to left with a rainbow sweep
+pub struct Banner {
+    text: String,
+    font: &'static Font,
+    /// Pixels scrolled since the text last entered from the right edge
+    offset: f64,
+    /// Seconds per rainbow loop
+    color_period: f64,
+}
+
+impl Banner {
+    pub fn new(_width: usize, _height: usize, _scale: f64) -> Self {
+        Banner {
+            text: DEFAULT_TEXT.to_string(),
+            font: &font::COMPACT,
+            offset: 0.0,
+            color_period: DEFAULT_COLOR_PERIOD,
+        }
+    }
+
+    /// Largest integer glyph scale that keeps the text within
+    /// `MAX_TEXT_HEIGHT` of the canvas.
+    fn glyph_scale(&self, height: usize) -> usize {
+        ((height as f64 * MAX_TEXT_HEIGHT) as usize / self.font.height).max(1)
+    }
+}
+
+impl Animation for Banner {
+    fn name(&self) -> &str {
+        "banner"
+    }
+
+    fn preferred_render(&self) -> crate::render::RenderMode {
+        crate::render::RenderMode::HalfBlock
+    }
+
+    fn set_params(&mut self, params: &crate::external::ExternalParams) {
+        if let Some(text) = params.get_str("banner_text")
+            && text != self.text
+        {
+            self.text = text.to_string();
+            self.offset = 0.0;
+        }
+        if let Some(f) = params.get_str("banner_font").and_then(Font::from_name) {
+            self.font = f;
+        } else if let Some(v) = params.get("banner_font") {
+            let i = (v.round().max(0.0) as usize).min(font::FONTS.len() - 1);
+            self.font = font::FONTS[i];
+        }
+        if let Some(p) = color_cycle_period(params) {
+            self.color_period = p;
+        }
+    }
+
+    fn supported_params(&self) -> &'static [(&'static str, f64, f64)] {
+        &[
+            ("banner_font", 0.0, 2.0),
+            ("color_cycle_period", 0.5, 600.0),
+        ]
+    }
+
+    fn update(&mut self, canvas: &mut Canvas, dt: f64, time: f64) {
+        canvas.clear();
+
+        let scale = self.glyph_scale(canvas.height);
+        let advance = self.font.advance(scale);
+        let text_width = self.font.text_width(&self.text, scale);
+
+        // Enter from the right edge, leave fully past the left, then repeat.
+        self.offset += dt * SCROLL_GLYPHS * advance as f64;
+        let span = (canvas.width + text_width) as f64;
+        if self.offset > span {
+            self.offset %= span;
+        }
+
+        let x0 = canvas.width as isize - self.offset as isize;
+        let y = (canvas.height.saturating_sub(self.font.height * scale) / 2) as isize;
+        for (i, ch) in self.text.chars().enumerate() {
+            let x = x0 + (i * advance) as isize;
+            if x + advance as isize <= 0 || x >= canvas.width as isize {
+                continue;
+            }
+            let hue = (i as f64 * 0.08 + cycle_t(time, self.color_period)).fract();
+            let (r, g, b) = hsv_to_rgb(hue, 0.8, 1.0);
+            canvas.draw_glyph(x, y, ch, self.font, scale, r, g, b);
+        }
+    }
+}
+
+fn hsv_to_rgb(h: f64, s: f64, v: f64) -> (u8, u8, u8) {
+    let c = v * s;
+    let x = c * (1.0 - ((h * 6.0) % 2.0 - 1.0).abs());
+    let m = v - c;
+    let (r, g, b) = match (h * 6.0) as u32 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    (
+        ((r + m) * 255.0) as u8,
+        ((g + m) * 255.0) as u8,
+        ((b + m) * 255.0) as u8,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::{ColorMode, RenderMode};
+
+    #[test]
+    fn text_scrolls_in_from_the_right_edge() {
+        let mut banner = Banner::new(80, 40, 1.0);
+        let mut canvas = Canvas::new(80, 20, RenderMode::HalfBlock, ColorMode::TrueColor);
+        banner.update(&mut canvas, 0.5, 0.0);
+        let lit_columns: Vec<usize> = (0..canvas.width)
+            .filter(|&x| (0..canvas.height).any(|y| canvas.pixels[y * canvas.width + x] > 0.0))
+            .collect();
+        assert!(!lit_columns.is_empty());
+        assert!(
+            lit_columns[0] > canvas.width / 2,
+            "text starts at the right"
+        );
+    }
+}
@@ -1,15 +1,19 @@
 use super::Animation;
+use crate::generators::ViewSpin;
 use crate::render::Canvas;
 
 /// Accretion disk with gravitational lensing distortion, M87-inspired
 pub struct Blackhole {
-    _unused: (),
+    /// Disk rotation; star twinkle keeps the real time
+    spin: ViewSpin,
 }
 
 impl Blackhole {
     #[allow(unused_variables)]
     pub fn new(_width: usize, _height: usize, _scale: f64) -> Self {
-        Blackhole { _unused: () }
+        Blackhole {
+            spin: ViewSpin::default(),
+        }
     }
 }
 
@@ -18,7 +22,14 @@ impl Animation for Blackhole {
         "blackhole"
     }
 
-    fn update(&mut self, canvas: &mut Canvas, _dt: f64, time: f64) {
+    fn toggle_freeze(&mut self) -> bool {
+        self.spin.toggle();
+        true
+    }
+
+    fn update(&mut self, canvas: &mut Canvas, dt: f64, time: f64) {
+        self.spin.tick(dt);
+        let disk_time = self.spin.time(time);
         let w = canvas.width as f64;
         let h = canvas.height as f64;
         let cx = w * 0.5;
@@ -60,11 +71,11 @@ impl Animation for Blackhole {
                     let disk_frac = (dist - disk_inner) / (disk_outer - disk_inner);
 
                     // Doppler beaming - one side brighter than the other
-                    let doppler = (angle - time * 0.3).cos() * 0.4 + 0.6;
+                    let doppler = (angle - disk_time * 0.3).cos() * 0.4 + 0.6;
 
                     // Spiral structure in the disk
                     let spiral =
-                        ((angle * 3.0 - dist * 0.2 + time * 0.8).sin() * 0.3 + 0.7).max(0.0);
+                        ((angle * 3.0 - dist * 0.2 + disk_time * 0.8).sin() * 0.3 + 0.7).max(0.0);
 
                     // Temperature gradient: hotter near center
                     let temperature = 1.0 - disk_frac * 0.7;
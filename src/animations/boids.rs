@@ -63,6 +63,82 @@ struct Boid {
     hue: f64,
 }
 
+/// Static circle the flock steers around, in fractions of the canvas size so
+/// it keeps its place across resizes.
+struct Obstacle {
+    fx: f64,
+    fy: f64,
+    /// Radius in fractions of the shorter canvas side
+    fr: f64,
+}
+
+/// Lone hunter that chases the flock centroid.
+struct Predator {
+    x: f64,
+    y: f64,
+    vx: f64,
+    vy: f64,
+}
+
+/// Distance past an obstacle's rim at which boids start turning away.
+const OBSTACLE_MARGIN: f64 = 6.0;
+/// Inverse-square strength of the flock's fear of the predator.
+const PREDATOR_FEAR: f64 = 300.0;
+/// Distance at which boids notice the predator.
+const PREDATOR_RANGE: f64 = 40.0;
+/// Predator speed limits; its top speed stays below the flock's so boids
+/// can outrun it.
+const PREDATOR_MAX_SPEED: f64 = 28.0;
+const PREDATOR_MIN_SPEED: f64 = 12.0;
+
+/// Velocity nudge steering `(x, y)` away from edges: the edge-avoidance
+/// turn applied within `margin` of each side.
+fn edge_push(x: f64, y: f64, width: f64, height: f64) -> (f64, f64) {
+    let margin = 10.0;
+    let turn_force = 3.0;
+    let mut push = (0.0, 0.0);
+    if x < margin {
+        push.0 += turn_force;
+    }
+    if x > width - margin {
+        push.0 -= turn_force;
+    }
+    if y < margin {
+        push.1 += turn_force;
+    }
+    if y > height - margin {
+        push.1 -= turn_force;
+    }
+    push
+}
+
+/// The same turn as [`edge_push`], away from each obstacle's center when
+/// within [`OBSTACLE_MARGIN`] of its rim.
+fn obstacle_push(x: f64, y: f64, obstacles: &[(f64, f64, f64)]) -> (f64, f64) {
+    let turn_force = 3.0;
+    let mut push = (0.0, 0.0);
+    for &(ox, oy, r) in obstacles {
+        let (dx, dy) = (x - ox, y - oy);
+        let dist = (dx * dx + dy * dy).sqrt();
+        if dist < r + OBSTACLE_MARGIN {
+            // Harder the deeper in, so nothing tunnels through
+            let force = turn_force * (1.0 + (r - dist).max(0.0));
+            let dist = dist.max(0.1);
+            push.0 += dx / dist * force;
+            push.1 += dy / dist * force;
+        }
+    }
+    push
+}
+
+/// Largest `[animations.boids] count` honored.
+const MAX_CONFIGURED_COUNT: usize = 1000;
+
+/// Boids for a `width` x `height` canvas at scale 1, before density capping.
+fn natural_count(width: usize, height: usize) -> f64 {
+    (width * height) as f64 / 150.0
+}
+
 /// Boids flocking simulation
 pub struct Boids {
     width: usize,
@@ -71,12 +147,21 @@ pub struct Boids {
     grid: SpatialGrid,
     cohes_factor: f64,
     sep_factor: f64,
+    obstacles: Vec<Obstacle>,
+    show_obstacles: bool,
+    predator: Option<Predator>,
 }
 
 impl Boids {
     pub fn new(width: usize, height: usize, scale: f64) -> Self {
-        let mut rng = rand::rng();
-        let count = (((width * height) as f64 / 150.0 * scale) as usize).clamp(20, 300);
+        let mut rng = super::rng();
+        // `[animations.boids] count` fixes the flock size regardless of scale
+        let count = super::setting::<usize>("count")
+            .map_or_else(
+                || (natural_count(width, height) * scale) as usize,
+                |n| n.min(MAX_CONFIGURED_COUNT),
+            )
+            .max(1);
         let boids = (0..count)
             .map(|_| {
                 let angle = rng.random_range(0.0..std::f64::consts::TAU);
@@ -90,6 +175,14 @@ impl Boids {
                 }
             })
             .collect();
+        // Seeded after the flock so it starts the same with or without them
+        let obstacles = (0..rng.random_range(3..=5))
+            .map(|_| Obstacle {
+                fx: rng.random_range(0.15..0.85),
+                fy: rng.random_range(0.15..0.85),
+                fr: rng.random_range(0.05..0.1),
+            })
+            .collect();
 
         Boids {
             width,
@@ -98,7 +191,53 @@ impl Boids {
             grid: SpatialGrid::new(width as f64, height as f64, 25.0),
             cohes_factor: 0.005,
             sep_factor: 2.0,
+            obstacles,
+            show_obstacles: false,
+            predator: None,
+        }
+    }
+
+    /// Obstacles as `(x, y, radius)` in pixels, or none when switched off.
+    fn obstacle_circles(&self) -> Vec<(f64, f64, f64)> {
+        if !self.show_obstacles {
+            return Vec::new();
+        }
+        let (w, h) = (self.width as f64, self.height as f64);
+        let side = w.min(h);
+        self.obstacles
+            .iter()
+            .map(|o| (o.fx * w, o.fy * h, (o.fr * side).max(2.0)))
+            .collect()
+    }
+
+    /// Steer the predator toward the flock centroid, keeping it off the
+    /// edges and obstacles.
+    fn move_predator(&mut self, obstacles: &[(f64, f64, f64)], dt: f64) {
+        let (w, h) = (self.width as f64, self.height as f64);
+        let n = self.boids.len().max(1) as f64;
+        let cx = self.boids.iter().map(|b| b.x).sum::<f64>() / n;
+        let cy = self.boids.iter().map(|b| b.y).sum::<f64>() / n;
+        let Some(p) = &mut self.predator else {
+            return;
+        };
+        let seek = 0.04;
+        p.vx += (cx - p.x) * seek;
+        p.vy += (cy - p.y) * seek;
+        let (ex, ey) = edge_push(p.x, p.y, w, h);
+        let (ox, oy) = obstacle_push(p.x, p.y, obstacles);
+        p.vx += ex + ox;
+        p.vy += ey + oy;
+
+        let speed = (p.vx * p.vx + p.vy * p.vy).sqrt();
+        if speed > PREDATOR_MAX_SPEED {
+            p.vx = p.vx / speed * PREDATOR_MAX_SPEED;
+            p.vy = p.vy / speed * PREDATOR_MAX_SPEED;
+        } else if speed < PREDATOR_MIN_SPEED && speed > 0.01 {
+            p.vx = p.vx / speed * PREDATOR_MIN_SPEED;
+            p.vy = p.vy / speed * PREDATOR_MIN_SPEED;
         }
+        p.x = (p.x + p.vx * dt).rem_euclid(w.max(1.0));
+        p.y = (p.y + p.vy * dt).rem_euclid(h.max(1.0));
     }
 }
 
@@ -107,6 +246,10 @@ impl Animation for Boids {
         "boids"
     }
 
+    fn default_scale_for(&self, width: usize, height: usize) -> f64 {
+        super::density_scale(natural_count(width, height), 20.0, 300.0)
+    }
+
     fn on_resize(&mut self, width: usize, height: usize) {
         self.width = width;
         self.height = height;
@@ -120,10 +263,31 @@ impl Animation for Boids {
         if let Some(cs) = params.color_shift {
             self.sep_factor = cs.clamp(0.5, 5.0);
         }
+        if let Some(v) = params.get("boids_predator") {
+            if v < 0.5 {
+                self.predator = None;
+            } else if self.predator.is_none() {
+                // Enter from a corner, heading inward
+                self.predator = Some(Predator {
+                    x: 1.0,
+                    y: 1.0,
+                    vx: PREDATOR_MIN_SPEED,
+                    vy: PREDATOR_MIN_SPEED,
+                });
+            }
+        }
+        if let Some(v) = params.get("boids_obstacles") {
+            self.show_obstacles = v >= 0.5;
+        }
     }
 
     fn supported_params(&self) -> &'static [(&'static str, f64, f64)] {
-        &[("intensity", 0.001, 0.05), ("color_shift", 0.5, 5.0)]
+        &[
+            ("intensity", 0.001, 0.05),
+            ("color_shift", 0.5, 5.0),
+            ("boids_predator", 0.0, 1.0),
+            ("boids_obstacles", 0.0, 1.0),
+        ]
     }
 
     fn update(&mut self, canvas: &mut Canvas, dt: f64, _time: f64) {
@@ -138,6 +302,10 @@ impl Animation for Boids {
             self.grid.insert(i, boid.x, boid.y);
         }
 
+        let obstacles = self.obstacle_circles();
+        self.move_predator(&obstacles, dt);
+        let predator = self.predator.as_ref().map(|p| (p.x, p.y));
+
         // Take a snapshot for reading while mutating boids
         let snapshot: Vec<(f64, f64, f64, f64)> =
             self.boids.iter().map(|b| (b.x, b.y, b.vx, b.vy)).collect();
@@ -191,20 +359,22 @@ impl Animation for Boids {
             boid.vx += sep_x * sep_factor + align_x * align_factor + cohes_x * cohes_factor;
             boid.vy += sep_y * sep_factor + align_y * align_factor + cohes_y * cohes_factor;
 
-            // Edge avoidance
-            let margin = 10.0;
-            let turn_force = 3.0;
-            if boid.x < margin {
-                boid.vx += turn_force;
-            }
-            if boid.x > self.width as f64 - margin {
-                boid.vx -= turn_force;
-            }
-            if boid.y < margin {
-                boid.vy += turn_force;
-            }
-            if boid.y > self.height as f64 - margin {
-                boid.vy -= turn_force;
+            // Edge and obstacle avoidance
+            let (ex, ey) = edge_push(boid.x, boid.y, self.width as f64, self.height as f64);
+            let (ox, oy) = obstacle_push(boid.x, boid.y, &obstacles);
+            boid.vx += ex + ox;
+            boid.vy += ey + oy;
+
+            // Flee the predator: inverse-square repulsion
+            if let Some((px, py)) = predator {
+                let (dx, dy) = (boid.x - px, boid.y - py);
+                let dist = (dx * dx + dy * dy).sqrt();
+                if dist < PREDATOR_RANGE {
+                    let dist = dist.max(2.0);
+                    let force = PREDATOR_FEAR / (dist * dist);
+                    boid.vx += dx / dist * force;
+                    boid.vy += dy / dist * force;
+                }
             }
 
             // Speed limits
@@ -241,6 +411,18 @@ impl Animation for Boids {
 
         // Draw
         canvas.clear();
+        for &(ox, oy, r) in &obstacles {
+            let (x0, x1) = ((ox - r).max(0.0) as usize, (ox + r) as usize);
+            let (y0, y1) = ((oy - r).max(0.0) as usize, (oy + r) as usize);
+            for y in y0..=y1.min(canvas.height.saturating_sub(1)) {
+                for x in x0..=x1.min(canvas.width.saturating_sub(1)) {
+                    let (dx, dy) = (x as f64 + 0.5 - ox, y as f64 + 0.5 - oy);
+                    if dx * dx + dy * dy <= r * r {
+                        canvas.set_colored(x, y, 0.35, 90, 90, 110);
+                    }
+                }
+            }
+        }
         for boid in &self.boids {
             let ix = boid.x as usize;
             let iy = boid.y as usize;
@@ -262,6 +444,17 @@ impl Animation for Boids {
                 }
             }
         }
+        if let Some((px, py)) = predator {
+            // 3x3 body, larger than any boid
+            for dy in -1..=1 {
+                for dx in -1..=1 {
+                    let (x, y) = ((px as i32 + dx) as usize, (py as i32 + dy) as usize);
+                    if x < canvas.width && y < canvas.height {
+                        canvas.set_colored(x, y, 1.0, 255, 40, 40);
+                    }
+                }
+            }
+        }
     }
 }
 
@@ -283,3 +476,69 @@ fn hsv_to_rgb(h: f64, s: f64, v: f64) -> (u8, u8, u8) {
         ((b + m) * 255.0) as u8,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::{ColorMode, RenderMode};
+
+    #[test]
+    fn configured_count_overrides_scale() {
+        let table: toml::Table = toml::from_str("count = 12").unwrap();
+        let b = crate::animations::with_settings(Some(&table), || Boids::new(160, 96, 2.0));
+        assert_eq!(b.boids.len(), 12);
+        assert!(Boids::new(160, 96, 2.0).boids.len() > 12);
+    }
+
+    fn lone_boid(x: f64, y: f64, vx: f64, vy: f64) -> Boids {
+        let mut boids = Boids::new(100, 100, 1.0);
+        boids.boids = vec![Boid {
+            x,
+            y,
+            vx,
+            vy,
+            hue: 0.0,
+        }];
+        boids
+    }
+
+    #[test]
+    fn boids_flee_the_predator() {
+        let mut boids = lone_boid(50.0, 50.0, 0.0, 10.0);
+        boids.predator = Some(Predator {
+            x: 45.0,
+            y: 50.0,
+            vx: 0.0,
+            vy: 0.0,
+        });
+        let mut canvas = Canvas::new(100, 100, RenderMode::Braille, ColorMode::TrueColor);
+        boids.update(&mut canvas, 0.0, 0.0);
+        assert!(
+            boids.boids[0].vx > 5.0,
+            "pushed directly away from the predator"
+        );
+    }
+
+    #[test]
+    fn boids_steer_around_obstacles() {
+        let mut boids = lone_boid(20.0, 50.0, 20.0, 0.0);
+        boids.obstacles = vec![Obstacle {
+            fx: 0.5,
+            fy: 0.52,
+            fr: 0.1,
+        }];
+        boids.show_obstacles = true;
+        let mut canvas = Canvas::new(100, 100, RenderMode::Braille, ColorMode::TrueColor);
+        for _ in 0..60 {
+            boids.update(&mut canvas, 1.0 / 30.0, 0.0);
+            let b = &boids.boids[0];
+            let dist = ((b.x - 50.0).powi(2) + (b.y - 52.0).powi(2)).sqrt();
+            assert!(
+                dist > 10.0,
+                "boid entered the obstacle at ({}, {})",
+                b.x,
+                b.y
+            );
+        }
+    }
+}
@@ -1,15 +1,26 @@
 use super::Animation;
-use crate::generators::{ColorGradient, ColorStop, EmitterConfig, ParticleSystem};
+use crate::generators::NamedParam;
+use crate::generators::{
+    BlendMode, ColorGradient, ColorStop, EmitterConfig, MAX_WIND, ParticleParams, ParticleSystem,
+};
+use crate::palettes::Palette;
 use crate::render::Canvas;
 use rand::RngExt;
 
+/// Negative: embers float up
+const EMBER_GRAVITY: f64 = -3.0;
+
 /// Realistic campfire with rising ember sparks
 pub struct Campfire {
     width: usize,
     height: usize,
     fire_buf: Vec<f64>,
     embers: ParticleSystem,
-    rng: rand::rngs::ThreadRng,
+    palette: Palette,
+    /// Flame color by heat
+    gradient: ColorGradient,
+    physics: ParticleParams,
+    rng: rand::rngs::StdRng,
 }
 
 impl Campfire {
@@ -23,43 +34,22 @@ impl Campfire {
             speed_max: 25.0,
             life_min: 1.0,
             life_max: 3.5,
-            gravity: -3.0,
+            gravity: EMBER_GRAVITY,
             drag: 0.98,
             wind: 0.0,
-            gradient: ColorGradient::new(vec![
-                ColorStop {
-                    t: 0.0,
-                    r: 255,
-                    g: 200,
-                    b: 50,
-                },
-                ColorStop {
-                    t: 0.3,
-                    r: 255,
-                    g: 120,
-                    b: 0,
-                },
-                ColorStop {
-                    t: 0.7,
-                    r: 200,
-                    g: 50,
-                    b: 0,
-                },
-                ColorStop {
-                    t: 1.0,
-                    r: 80,
-                    g: 20,
-                    b: 0,
-                },
-            ]),
+            gradient: ember_gradient(Palette::Classic),
         };
 
         Campfire {
             width,
             height,
             fire_buf: vec![0.0; width * height],
-            embers: ParticleSystem::new(ember_config, (500.0 * scale) as usize),
-            rng: rand::rng(),
+            embers: ParticleSystem::new(ember_config, (500.0 * scale) as usize)
+                .with_blend(BlendMode::Add),
+            palette: Palette::Classic,
+            gradient: flame_gradient(Palette::Classic),
+            physics: ParticleParams::default(),
+            rng: super::rng(),
         }
     }
 }
@@ -69,6 +59,27 @@ impl Animation for Campfire {
         "campfire"
     }
 
+    fn set_params(&mut self, params: &crate::external::ExternalParams) {
+        if let Some(palette) = Palette::from_params(params, "fire_palette")
+            && palette != self.palette
+        {
+            self.palette = palette;
+            self.gradient = flame_gradient(palette);
+            self.embers.config.gradient = ember_gradient(palette);
+        }
+        self.physics.set(params);
+        self.embers.config.gravity = EMBER_GRAVITY * self.physics.gravity;
+    }
+
+    fn supported_params(&self) -> &'static [(&'static str, f64, f64)] {
+        &[
+            ("fire_palette", 0.0, 4.0),
+            ("wind", -MAX_WIND, MAX_WIND),
+            ("gravity", 0.0, 3.0),
+            ("emit_rate", 0.0, 4.0),
+        ]
+    }
+
     fn on_resize(&mut self, width: usize, height: usize) {
         self.width = width;
         self.height = height;
@@ -112,7 +123,7 @@ impl Animation for Campfire {
             for x in 0..w.min(canvas.width) {
                 let v = self.fire_buf[y * w + x];
                 if v > 0.01 {
-                    let (r, g, b) = campfire_color(v);
+                    let (r, g, b) = self.gradient.sample(v);
                     canvas.set_colored(x, y, v, r, g, b);
                 }
             }
@@ -142,9 +153,10 @@ impl Animation for Campfire {
         // Emit embers
         self.embers.config.x = cx + self.rng.random_range(-3.0..3.0);
         self.embers.config.y = base_y - 5.0;
-        self.embers.config.wind = (time * 0.5).sin() * 2.0;
+        self.embers.config.wind = self.physics.wind + (time * 0.5).sin() * 2.0;
         if self.rng.random_range(0.0..1.0) < 0.3 {
-            self.embers.emit(self.rng.random_range(1..4));
+            let count = self.rng.random_range(1..4);
+            self.embers.emit(self.physics.scale_count(count));
         }
 
         self.embers.update(dt);
@@ -152,18 +164,90 @@ impl Animation for Campfire {
     }
 }
 
-fn campfire_color(v: f64) -> (u8, u8, u8) {
-    if v > 0.8 {
-        let f = (v - 0.8) / 0.2;
-        (255, (230.0 + 25.0 * f) as u8, (100.0 + 155.0 * f) as u8)
-    } else if v > 0.5 {
-        let f = (v - 0.5) / 0.3;
-        (255, (120.0 + 110.0 * f) as u8, (10.0 + 90.0 * f) as u8)
-    } else if v > 0.2 {
-        let f = (v - 0.2) / 0.3;
-        ((150.0 + 105.0 * f) as u8, (20.0 + 100.0 * f) as u8, 0)
-    } else {
-        let f = v / 0.2;
-        ((50.0 + 100.0 * f) as u8, (5.0 + 15.0 * f) as u8, 0)
+/// Flame color by heat. `classic` keeps the campfire's own warmer ramp,
+/// whose repeated `t` is a small hard step in blue.
+fn flame_gradient(palette: Palette) -> ColorGradient {
+    if palette != Palette::Classic {
+        return palette.gradient();
+    }
+    let stops: &[(f64, u8, u8, u8)] = &[
+        (0.0, 50, 5, 0),
+        (0.2, 150, 20, 0),
+        (0.5, 255, 120, 0),
+        (0.5, 255, 120, 10),
+        (0.8, 255, 230, 100),
+        (1.0, 255, 255, 255),
+    ];
+    ColorGradient::new(
+        stops
+            .iter()
+            .map(|&(t, r, g, b)| ColorStop { t, r, g, b })
+            .collect(),
+    )
+}
+
+/// Ember color by age.
+fn ember_gradient(palette: Palette) -> ColorGradient {
+    if palette != Palette::Classic {
+        return palette.cooling();
+    }
+    let stops: &[(f64, u8, u8, u8)] = &[
+        (0.0, 255, 200, 50),
+        (0.3, 255, 120, 0),
+        (0.7, 200, 50, 0),
+        (1.0, 80, 20, 0),
+    ];
+    ColorGradient::new(
+        stops
+            .iter()
+            .map(|&(t, r, g, b)| ColorStop { t, r, g, b })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::external::ExternalParams;
+
+    /// The hand-written ramp the classic flame gradient replaced.
+    fn original_color(v: f64) -> (u8, u8, u8) {
+        if v > 0.8 {
+            let f = (v - 0.8) / 0.2;
+            (255, (230.0 + 25.0 * f) as u8, (100.0 + 155.0 * f) as u8)
+        } else if v > 0.5 {
+            let f = (v - 0.5) / 0.3;
+            (255, (120.0 + 110.0 * f) as u8, (10.0 + 90.0 * f) as u8)
+        } else if v > 0.2 {
+            let f = (v - 0.2) / 0.3;
+            ((150.0 + 105.0 * f) as u8, (20.0 + 100.0 * f) as u8, 0)
+        } else {
+            let f = v / 0.2;
+            ((50.0 + 100.0 * f) as u8, (5.0 + 15.0 * f) as u8, 0)
+        }
+    }
+
+    #[test]
+    fn classic_flame_matches_original_ramp() {
+        let gradient = flame_gradient(Palette::Classic);
+        for i in 0..=200 {
+            let v = i as f64 / 200.0;
+            let (a, b) = (gradient.sample(v), original_color(v));
+            let close = |x: u8, y: u8| x.abs_diff(y) <= 1;
+            assert!(
+                close(a.0, b.0) && close(a.1, b.1) && close(a.2, b.2),
+                "v={v}: {a:?} vs {b:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn palette_param_recolors_flames_and_embers() {
+        let mut campfire = Campfire::new(40, 40, 1.0);
+        let params: ExternalParams = serde_json::from_str(r#"{"fire_palette": "ice"}"#).unwrap();
+        campfire.set_params(&params);
+        assert_eq!(campfire.palette, Palette::Ice);
+        assert_eq!(campfire.gradient.sample(1.0), (255, 255, 255));
+        assert_eq!(campfire.embers.config.gradient.sample(0.0), (255, 255, 255));
     }
 }
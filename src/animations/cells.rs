@@ -1,7 +1,12 @@
 use super::Animation;
+use crate::generators::jitter;
 use crate::render::Canvas;
 use rand::RngExt;
 
+/// Brownian drift per second (pixels, 1σ); matches the old per-frame noise at 24 fps.
+const BROWNIAN_X: f64 = 0.118;
+const BROWNIAN_Y: f64 = 0.094;
+
 struct Organelle {
     angle: f64,
     dist: f64, // fraction of cell radius
@@ -32,12 +37,12 @@ pub struct Cells {
     cells: Vec<Cell>,
     max_cells: usize,
     fluid_time: f64,
-    rng: rand::rngs::ThreadRng,
+    rng: rand::rngs::StdRng,
 }
 
 impl Cells {
     pub fn new(width: usize, height: usize, scale: f64) -> Self {
-        let mut rng = rand::rng();
+        let mut rng = super::rng();
         let initial = 5;
         let cells = (0..initial)
             .map(|_| make_cell(&mut rng, width, height))
@@ -49,7 +54,7 @@ impl Cells {
             cells,
             max_cells: (35.0 * scale) as usize,
             fluid_time: 0.0,
-            rng: rand::rng(),
+            rng: super::rng(),
         }
     }
 }
@@ -128,8 +133,8 @@ impl Animation for Cells {
                 (time * 0.3 + cell.phase).cos() * 3.0 + (time * 0.7 + cell.phase * 2.3).sin() * 1.5;
             let drift_y =
                 (time * 0.4 + cell.phase).sin() * 2.5 + (time * 0.6 + cell.phase * 1.7).cos() * 1.2;
-            cell.x += (drift_x + self.rng.random_range(-1.0..1.0)) * dt;
-            cell.y += (drift_y + self.rng.random_range(-0.8..0.8)) * dt;
+            cell.x += drift_x * dt + jitter(&mut self.rng, BROWNIAN_X, dt);
+            cell.y += drift_y * dt + jitter(&mut self.rng, BROWNIAN_Y, dt);
             cell.phase += dt * 0.5;
             cell.elong_angle += dt * 0.2;
 
@@ -315,20 +320,7 @@ impl Animation for Cells {
                     let oy = cell.y + (org.angle.sin() * org.dist * base_r);
                     let (or, og, ob) =
                         hsv_to_rgb((cell.hue + org.hue_shift).fract().abs(), 0.6, 0.9);
-                    let orad = org.size;
-                    let min_x = (ox - orad).max(0.0) as usize;
-                    let max_x = (ox + orad + 1.0).min(canvas.width as f64) as usize;
-                    let min_y = (oy - orad).max(0.0) as usize;
-                    let max_y = (oy + orad + 1.0).min(canvas.height as f64) as usize;
-                    for py in min_y..max_y {
-                        for px in min_x..max_x {
-                            let dx = px as f64 - ox;
-                            let dy = py as f64 - oy;
-                            if dx * dx + dy * dy < orad * orad {
-                                canvas.set_colored(px, py, 0.7, or, og, ob);
-                            }
-                        }
-                    }
+                    canvas.fill_circle(ox, oy, org.size, 0.7, or, og, ob);
                 }
 
                 // Nucleus — darker center blob
@@ -356,7 +348,7 @@ impl Animation for Cells {
         for y in 0..canvas.height {
             for x in 0..canvas.width {
                 let dx = x as f64 - vcx;
-                let dy = (y as f64 - vcy) * 1.5;
+                let dy = (y as f64 - vcy) * canvas.aspect;
                 let dist = (dx * dx + dy * dy).sqrt() / vmax;
 
                 // Dish rim — faint circular edge
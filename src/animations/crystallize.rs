@@ -1,6 +1,14 @@
 use super::Animation;
+use crate::generators::{color_cycle_period, cycle_t, jitter};
 use crate::render::Canvas;
 use rand::RngExt;
+use rand::rngs::StdRng;
+
+/// Walker step variance per sub-step at the 24 fps reference (uniform ±1.5 px).
+const STEP_VARIANCE: f64 = 0.75;
+const REFERENCE_FPS: f64 = 24.0;
+/// Seconds per palette loop unless `color_cycle_period` overrides it.
+const DEFAULT_COLOR_PERIOD: f64 = 20.0;
 
 /// Diffusion-limited aggregation crystal growth from center seed
 pub struct Crystallize {
@@ -11,7 +19,9 @@ pub struct Crystallize {
     growth_timer: f64,
     steps_per_frame: usize,
     color_cycle: f64,
-    rng: rand::rngs::ThreadRng,
+    /// Seconds per palette loop
+    color_period: f64,
+    rng: StdRng,
 }
 
 impl Crystallize {
@@ -25,7 +35,7 @@ impl Crystallize {
         }
 
         let walker_count = (200.0 * scale) as usize;
-        let mut rng = rand::rng();
+        let mut rng = super::rng();
         let walkers = (0..walker_count)
             .map(|_| {
                 let angle = rng.random_range(0.0..std::f64::consts::TAU);
@@ -44,7 +54,8 @@ impl Crystallize {
             growth_timer: 0.0,
             steps_per_frame: (50.0 * scale) as usize,
             color_cycle: 0.0,
-            rng: rand::rng(),
+            color_period: DEFAULT_COLOR_PERIOD,
+            rng: super::rng(),
         }
     }
 }
@@ -89,6 +100,16 @@ impl Animation for Crystallize {
         "crystallize"
     }
 
+    fn set_params(&mut self, params: &crate::external::ExternalParams) {
+        if let Some(p) = color_cycle_period(params) {
+            self.color_period = p;
+        }
+    }
+
+    fn supported_params(&self) -> &'static [(&'static str, f64, f64)] {
+        &[("color_cycle_period", 0.5, 600.0)]
+    }
+
     fn preferred_render(&self) -> crate::render::RenderMode {
         crate::render::RenderMode::Braille
     }
@@ -125,10 +146,15 @@ impl Animation for Crystallize {
         }
 
         // Random walk steps
+        // Spread this frame's diffusion across its sub-steps so walkers cover the
+        // same ground per second at any frame rate.
+        let steps = self.steps_per_frame.max(1) as f64;
+        let step_dt = dt / steps;
+        let diffusion = (STEP_VARIANCE * REFERENCE_FPS * steps).sqrt();
         for _ in 0..self.steps_per_frame {
             for walker in &mut self.walkers {
-                walker.0 += self.rng.random_range(-1.5..1.5);
-                walker.1 += self.rng.random_range(-1.5..1.5);
+                walker.0 += jitter(&mut self.rng, diffusion, step_dt);
+                walker.1 += jitter(&mut self.rng, diffusion, step_dt);
 
                 let ix = walker.0 as usize;
                 let iy = walker.1 as usize;
@@ -191,7 +217,7 @@ impl Animation for Crystallize {
                 let v = self.grid[y * w + x];
                 if v > 0 {
                     let t = v as f64 / 255.0;
-                    let hue = (t + time * 0.05).fract();
+                    let hue = (t + cycle_t(time, self.color_period)).fract();
                     let (r, g, b) = hsv_to_rgb(hue, 0.7, 0.9);
                     canvas.set_colored(x, y, 0.8, r, g, b);
                 }
@@ -1,13 +1,18 @@
 use super::Animation;
+use crate::generators::ViewSpin;
 use crate::render::Canvas;
 
 /// Rotating DNA double helix
-pub struct Dna;
+pub struct Dna {
+    spin: ViewSpin,
+}
 
 impl Dna {
     #[allow(unused_variables)]
     pub fn new(_width: usize, _height: usize, _scale: f64) -> Self {
-        Dna
+        Dna {
+            spin: ViewSpin::default(),
+        }
     }
 }
 
@@ -16,15 +21,26 @@ impl Animation for Dna {
         "dna"
     }
 
+    /// The helix phase advances 2 radians per second.
+    fn loop_period(&self) -> Option<f64> {
+        Some(std::f64::consts::PI)
+    }
+
+    fn toggle_freeze(&mut self) -> bool {
+        self.spin.toggle();
+        true
+    }
+
     fn preferred_render(&self) -> crate::render::RenderMode {
         crate::render::RenderMode::Ascii
     }
 
-    fn update(&mut self, canvas: &mut Canvas, _dt: f64, time: f64) {
+    fn update(&mut self, canvas: &mut Canvas, dt: f64, time: f64) {
         let w = canvas.width as f64;
         let h = canvas.height as f64;
         let cx = w / 2.0;
-        let t = time * 2.0;
+        self.spin.tick(dt);
+        let t = self.spin.time(time) * 2.0;
 
         canvas.clear();
 
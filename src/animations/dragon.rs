@@ -1,6 +1,10 @@
 use super::Animation;
+use crate::generators::{color_cycle_period, cycle_t};
 use crate::render::Canvas;
 
+/// Seconds per palette loop unless `color_cycle_period` overrides it.
+const DEFAULT_COLOR_PERIOD: f64 = 10.0;
+
 /// Dragon curve fractal iteratively drawn and colored
 pub struct Dragon {
     points: Vec<(f64, f64)>,
@@ -8,6 +12,8 @@ pub struct Dragon {
     max_iteration: usize,
     rebuild_timer: f64,
     draw_progress: f64,
+    /// Seconds per palette loop
+    color_period: f64,
 }
 
 impl Dragon {
@@ -19,6 +25,7 @@ impl Dragon {
             max_iteration: 14,
             rebuild_timer: 0.0,
             draw_progress: 0.0,
+            color_period: DEFAULT_COLOR_PERIOD,
         }
     }
 
@@ -60,6 +67,16 @@ impl Animation for Dragon {
         "dragon"
     }
 
+    fn set_params(&mut self, params: &crate::external::ExternalParams) {
+        if let Some(p) = color_cycle_period(params) {
+            self.color_period = p;
+        }
+    }
+
+    fn supported_params(&self) -> &'static [(&'static str, f64, f64)] {
+        &[("color_cycle_period", 0.5, 600.0)]
+    }
+
     fn preferred_render(&self) -> crate::render::RenderMode {
         crate::render::RenderMode::Braille
     }
@@ -120,7 +137,7 @@ impl Animation for Dragon {
 
             // Color based on position in curve
             let t = i as f64 / self.points.len() as f64;
-            let hue = (t + time * 0.1).fract();
+            let hue = (t + cycle_t(time, self.color_period)).fract();
             let (r, g, b) = hsv_to_rgb(hue, 0.8, 0.9);
 
             // Draw line segment using Bresenham-like stepping
@@ -1,15 +1,60 @@
 use super::Animation;
 use crate::render::Canvas;
+use std::f64::consts::TAU;
 
 /// Moon crossing sun with corona rays radiating outward
 pub struct Eclipse {
     phase: f64,
+    /// Transit speed multiplier (1.0 = default crossing pace)
+    transit_speed: f64,
+    /// Corona brightness multiplier
+    corona_intensity: f64,
+    /// Corona ray reach in sun radii
+    corona_length: f64,
+    /// Seconds to hold at totality each transit (0 = no pause)
+    totality_dwell: f64,
+    dwell_remaining: f64,
 }
 
 impl Eclipse {
     #[allow(unused_variables)]
     pub fn new(_width: usize, _height: usize, _scale: f64) -> Self {
-        Eclipse { phase: 0.0 }
+        Eclipse {
+            phase: 0.0,
+            transit_speed: 1.0,
+            corona_intensity: 1.0,
+            corona_length: 1.5,
+            totality_dwell: 0.0,
+            dwell_remaining: 0.0,
+        }
+    }
+
+    /// Advance the transit phase, holding at totality for `totality_dwell` seconds.
+    fn advance(&mut self, dt: f64) {
+        if self.dwell_remaining > 0.0 {
+            self.dwell_remaining -= dt;
+            return;
+        }
+        // Totality is where `sin(phase / 2)` crosses zero, i.e. at every
+        // multiple of 2π; `moon_offset` puts the moon dead center there.
+        let totality_index = |phase: f64| (phase / TAU).floor();
+        let before = totality_index(self.phase);
+        self.phase += dt * 0.15 * self.transit_speed;
+        let after = totality_index(self.phase);
+        if self.totality_dwell > 0.0 && after > before {
+            // Snap just past the crossing so the hold is centered and can't re-trigger.
+            self.phase = after * TAU + 1e-9;
+            self.dwell_remaining = self.totality_dwell;
+        }
+    }
+
+    /// Moon center relative to the sun's, in sun radii. The moon's track is a
+    /// line through the sun's center whose tilt drifts from transit to
+    /// transit, so every crossing is total and the closest approach is
+    /// exactly where `advance` holds.
+    fn moon_offset(&self) -> (f64, f64) {
+        let cycle = (self.phase * 0.5).sin();
+        (cycle * 2.5, cycle * (self.phase * 0.3).sin() * 0.3)
     }
 }
 
@@ -18,24 +63,49 @@ impl Animation for Eclipse {
         "eclipse"
     }
 
+    fn set_params(&mut self, params: &crate::external::ExternalParams) {
+        if let Some(v) = params.get("transit_speed") {
+            self.transit_speed = v.clamp(0.1, 5.0);
+        }
+        if let Some(v) = params.get("corona_intensity") {
+            self.corona_intensity = v.clamp(0.0, 2.0);
+        }
+        if let Some(v) = params.get("corona_length") {
+            self.corona_length = v.clamp(0.5, 4.0);
+        }
+        if let Some(v) = params.get("totality_dwell") {
+            self.totality_dwell = v.clamp(0.0, 30.0);
+        }
+    }
+
+    fn supported_params(&self) -> &'static [(&'static str, f64, f64)] {
+        &[
+            ("transit_speed", 0.1, 5.0),
+            ("corona_intensity", 0.0, 2.0),
+            ("corona_length", 0.5, 4.0),
+            ("totality_dwell", 0.0, 30.0),
+        ]
+    }
+
     fn update(&mut self, canvas: &mut Canvas, dt: f64, time: f64) {
         let w = canvas.width as f64;
         let h = canvas.height as f64;
         let cx = w * 0.5;
         let cy = h * 0.5;
-        let sun_r = (w.min(h) * 0.18).max(8.0);
+        // Work in horizontal pixels: vertical offsets are scaled by the pixel
+        // aspect so the discs and corona stay round in every render mode
+        let aspect = canvas.aspect;
+        let sun_r = (w.min(h * aspect) * 0.18).max(8.0);
         let moon_r = sun_r * 0.95;
 
         // Moon moves across the sun in a slow cycle
-        self.phase += dt * 0.15;
-        let cycle = (self.phase * 0.5).sin();
-        let moon_offset_x = cycle * sun_r * 2.5;
-        let moon_offset_y = (self.phase * 0.3).sin() * sun_r * 0.3;
-        let moon_cx = cx + moon_offset_x;
-        let moon_cy = cy + moon_offset_y;
+        self.advance(dt);
+        let (offset_x, offset_y) = self.moon_offset();
+        let moon_offset_x = offset_x * sun_r;
+        let moon_offset_y = offset_y * sun_r;
 
         // How much of the sun is covered
-        let moon_dist = ((moon_cx - cx).powi(2) + (moon_cy - cy).powi(2)).sqrt();
+        let moon_dist = (moon_offset_x.powi(2) + moon_offset_y.powi(2)).sqrt();
         let coverage = (1.0 - moon_dist / (sun_r + moon_r)).clamp(0.0, 1.0);
 
         canvas.clear();
@@ -46,11 +116,11 @@ impl Animation for Eclipse {
                 let fy = y as f64;
 
                 let dx_sun = fx - cx;
-                let dy_sun = fy - cy;
+                let dy_sun = (fy - cy) * aspect;
                 let dist_sun = (dx_sun * dx_sun + dy_sun * dy_sun).sqrt();
 
-                let dx_moon = fx - moon_cx;
-                let dy_moon = fy - moon_cy;
+                let dx_moon = dx_sun - moon_offset_x;
+                let dy_moon = dy_sun - moon_offset_y;
                 let dist_moon = (dx_moon * dx_moon + dy_moon * dy_moon).sqrt();
 
                 let in_sun = dist_sun < sun_r;
@@ -80,7 +150,7 @@ impl Animation for Eclipse {
                 } else {
                     // Corona and rays
                     let corona_dist = dist_sun - sun_r;
-                    let max_corona = sun_r * 1.5;
+                    let max_corona = sun_r * self.corona_length;
 
                     if corona_dist < max_corona {
                         let angle = dy_sun.atan2(dx_sun);
@@ -90,8 +160,10 @@ impl Animation for Eclipse {
                             ((angle * ray_count * 0.5 - time * 0.3).sin() * 0.5 + 0.5).powi(3);
 
                         let falloff = (1.0 - corona_dist / max_corona).powi(2);
-                        let corona_intensity =
-                            falloff * (0.3 + ray * 0.5 + ray2 * 0.3) * coverage.max(0.2);
+                        let corona_intensity = falloff
+                            * (0.3 + ray * 0.5 + ray2 * 0.3)
+                            * coverage.max(0.2)
+                            * self.corona_intensity;
 
                         if corona_intensity > 0.01 {
                             let r = (255.0 * corona_intensity.min(1.0)) as u8;
@@ -112,3 +184,26 @@ impl Animation for Eclipse {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn totality_dwell_holds_the_moon_centered() {
+        let mut e = Eclipse::new(80, 48, 1.0);
+        e.totality_dwell = 2.0;
+        // Start just before the first totality (phase = 2π).
+        e.phase = TAU - 0.01;
+        e.advance(0.1);
+        assert!((e.phase - TAU).abs() < 1e-6);
+        let (x, y) = e.moon_offset();
+        assert!(x.hypot(y) < 1e-6, "moon off center at totality: ({x}, {y})");
+        let held = e.phase;
+        e.advance(1.0);
+        assert_eq!(e.phase, held, "phase frozen during dwell");
+        e.advance(1.5);
+        e.advance(0.1);
+        assert!(e.phase > held, "transit resumes after the dwell");
+    }
+}
@@ -1,4 +1,7 @@
 use super::Animation;
+use crate::generators::ColorGradient;
+use crate::generators::NamedParam;
+use crate::palettes::Palette;
 use crate::render::Canvas;
 use rand::RngExt;
 
@@ -9,7 +12,9 @@ pub struct Fire {
     buffer: Vec<f64>,
     /// Heat rate: controls how hot the bottom row burns (0.0 = cold, 1.0 = normal, 2.0 = intense)
     heat_rate: f64,
-    rng: rand::rngs::ThreadRng,
+    palette: Palette,
+    gradient: ColorGradient,
+    rng: rand::rngs::StdRng,
 }
 
 impl Fire {
@@ -27,7 +32,9 @@ impl Fire {
             height,
             buffer,
             heat_rate: 0.8,
-            rng: rand::rng(),
+            palette: Palette::Classic,
+            gradient: Palette::Classic.gradient(),
+            rng: super::rng(),
         }
     }
 }
@@ -41,10 +48,16 @@ impl Animation for Fire {
         if let Some(intensity) = params.intensity {
             self.heat_rate = intensity.clamp(0.0, 2.0);
         }
+        if let Some(palette) = Palette::from_params(params, "fire_palette")
+            && palette != self.palette
+        {
+            self.palette = palette;
+            self.gradient = palette.gradient();
+        }
     }
 
     fn supported_params(&self) -> &'static [(&'static str, f64, f64)] {
-        &[("intensity", 0.0, 2.0)]
+        &[("intensity", 0.0, 2.0), ("fire_palette", 0.0, 4.0)]
     }
 
     fn on_resize(&mut self, width: usize, height: usize) {
@@ -100,26 +113,10 @@ impl Animation for Fire {
             for x in 0..w {
                 let v = self.buffer[y * w + x];
                 if v > 0.01 {
-                    let (r, g, b) = fire_color(v);
+                    let (r, g, b) = self.gradient.sample(v);
                     canvas.set_colored(x, y, v, r, g, b);
                 }
             }
         }
     }
 }
-
-fn fire_color(v: f64) -> (u8, u8, u8) {
-    if v > 0.85 {
-        let t = (v - 0.85) / 0.15;
-        (255, (200.0 + 55.0 * t) as u8, (t * 200.0) as u8)
-    } else if v > 0.6 {
-        let t = (v - 0.6) / 0.25;
-        (255, (t * 200.0) as u8, 0)
-    } else if v > 0.3 {
-        let t = (v - 0.3) / 0.3;
-        ((100.0 + 155.0 * t) as u8, 0, 0)
-    } else {
-        let t = v / 0.3;
-        ((t * 100.0) as u8, 0, 0)
-    }
-}
@@ -32,7 +32,7 @@ pub struct FlappyBird {
     pipe_spacing: f64,
     gap_size: f64,
 
-    rng: rand::rngs::ThreadRng,
+    rng: rand::rngs::StdRng,
 }
 
 impl FlappyBird {
@@ -52,7 +52,7 @@ impl FlappyBird {
             pipe_speed: 0.0,
             pipe_spacing: 0.0,
             gap_size: 0.0,
-            rng: rand::rng(),
+            rng: super::rng(),
         };
         fb.tune_params();
         fb
@@ -118,6 +118,11 @@ impl Animation for FlappyBird {
         "flappy_bird"
     }
 
+    /// The pipe gap (at least 6 px) plus a 2 px margin each side must fit.
+    fn min_size(&self) -> (usize, usize) {
+        (10, 11)
+    }
+
     fn on_resize(&mut self, width: usize, height: usize) {
         self.width = width;
         self.height = height;
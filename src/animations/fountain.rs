@@ -1,8 +1,16 @@
 use super::Animation;
-use crate::generators::{ColorGradient, ColorStop, EmitterConfig, ParticleSystem};
+use crate::generators::{
+    BlendMode, ColorGradient, ColorStop, EmitterConfig, GRADIENT_LUT_SIZE, ParticleParams,
+    ParticleSystem,
+};
 use crate::render::Canvas;
 use rand::RngExt;
 
+const JET_GRAVITY: f64 = 20.0;
+const SPLASH_GRAVITY: f64 = 30.0;
+/// Negative: mist floats up
+const MIST_GRAVITY: f64 = -2.0;
+
 /// Water fountain shooting up from center bottom
 pub struct Fountain {
     width: usize,
@@ -11,7 +19,8 @@ pub struct Fountain {
     splashes: ParticleSystem,
     mist: ParticleSystem,
     emit_accum: f64,
-    rng: rand::rngs::ThreadRng,
+    physics: ParticleParams,
+    rng: rand::rngs::StdRng,
 }
 
 impl Fountain {
@@ -28,35 +37,38 @@ impl Fountain {
             speed_max: 55.0,
             life_min: 1.5,
             life_max: 3.0,
-            gravity: 20.0,
+            gravity: JET_GRAVITY,
             drag: 0.995,
             wind: 0.0,
-            gradient: ColorGradient::new(vec![
-                ColorStop {
-                    t: 0.0,
-                    r: 200,
-                    g: 220,
-                    b: 255,
-                },
-                ColorStop {
-                    t: 0.3,
-                    r: 100,
-                    g: 160,
-                    b: 255,
-                },
-                ColorStop {
-                    t: 0.7,
-                    r: 60,
-                    g: 120,
-                    b: 220,
-                },
-                ColorStop {
-                    t: 1.0,
-                    r: 30,
-                    g: 60,
-                    b: 140,
-                },
-            ]),
+            gradient: ColorGradient::baked(
+                vec![
+                    ColorStop {
+                        t: 0.0,
+                        r: 200,
+                        g: 220,
+                        b: 255,
+                    },
+                    ColorStop {
+                        t: 0.3,
+                        r: 100,
+                        g: 160,
+                        b: 255,
+                    },
+                    ColorStop {
+                        t: 0.7,
+                        r: 60,
+                        g: 120,
+                        b: 220,
+                    },
+                    ColorStop {
+                        t: 1.0,
+                        r: 30,
+                        g: 60,
+                        b: 140,
+                    },
+                ],
+                GRADIENT_LUT_SIZE,
+            ),
         };
 
         let splash_config = EmitterConfig {
@@ -68,23 +80,26 @@ impl Fountain {
             speed_max: 20.0,
             life_min: 0.2,
             life_max: 0.6,
-            gravity: 30.0,
+            gravity: SPLASH_GRAVITY,
             drag: 0.96,
             wind: 0.0,
-            gradient: ColorGradient::new(vec![
-                ColorStop {
-                    t: 0.0,
-                    r: 180,
-                    g: 210,
-                    b: 255,
-                },
-                ColorStop {
-                    t: 1.0,
-                    r: 80,
-                    g: 100,
-                    b: 180,
-                },
-            ]),
+            gradient: ColorGradient::baked(
+                vec![
+                    ColorStop {
+                        t: 0.0,
+                        r: 180,
+                        g: 210,
+                        b: 255,
+                    },
+                    ColorStop {
+                        t: 1.0,
+                        r: 80,
+                        g: 100,
+                        b: 180,
+                    },
+                ],
+                GRADIENT_LUT_SIZE,
+            ),
         };
 
         let mist_config = EmitterConfig {
@@ -96,33 +111,40 @@ impl Fountain {
             speed_max: 5.0,
             life_min: 0.5,
             life_max: 1.5,
-            gravity: -2.0, // mist floats up
+            gravity: MIST_GRAVITY,
             drag: 0.98,
             wind: 0.0,
-            gradient: ColorGradient::new(vec![
-                ColorStop {
-                    t: 0.0,
-                    r: 150,
-                    g: 180,
-                    b: 220,
-                },
-                ColorStop {
-                    t: 1.0,
-                    r: 80,
-                    g: 100,
-                    b: 140,
-                },
-            ]),
+            gradient: ColorGradient::baked(
+                vec![
+                    ColorStop {
+                        t: 0.0,
+                        r: 150,
+                        g: 180,
+                        b: 220,
+                    },
+                    ColorStop {
+                        t: 1.0,
+                        r: 80,
+                        g: 100,
+                        b: 140,
+                    },
+                ],
+                GRADIENT_LUT_SIZE,
+            ),
         };
 
         Fountain {
             width,
             height,
-            main_jet: ParticleSystem::new(jet_config, (3000.0 * scale) as usize),
-            splashes: ParticleSystem::new(splash_config, (2000.0 * scale) as usize),
-            mist: ParticleSystem::new(mist_config, (500.0 * scale) as usize),
+            main_jet: ParticleSystem::new(jet_config, (3000.0 * scale) as usize)
+                .with_blend(BlendMode::Add),
+            splashes: ParticleSystem::new(splash_config, (2000.0 * scale) as usize)
+                .with_blend(BlendMode::Add),
+            mist: ParticleSystem::new(mist_config, (500.0 * scale) as usize)
+                .with_blend(BlendMode::Add),
             emit_accum: 0.0,
-            rng: rand::rng(),
+            physics: ParticleParams::default(),
+            rng: super::rng(),
         }
     }
 }
@@ -137,6 +159,25 @@ impl Animation for Fountain {
         self.height = height;
     }
 
+    fn set_params(&mut self, params: &crate::external::ExternalParams) {
+        self.physics.set(params);
+        let (wind, gravity) = (self.physics.wind, self.physics.gravity);
+        self.main_jet.config.wind = wind;
+        self.splashes.config.wind = wind;
+        self.mist.config.wind = wind;
+        self.main_jet.config.gravity = JET_GRAVITY * gravity;
+        self.splashes.config.gravity = SPLASH_GRAVITY * gravity;
+        self.mist.config.gravity = MIST_GRAVITY * gravity;
+    }
+
+    fn supported_params(&self) -> &'static [(&'static str, f64, f64)] {
+        &ParticleParams::SUPPORTED
+    }
+
+    fn on_time_gap(&mut self) {
+        self.emit_accum = 0.0;
+    }
+
     fn update(&mut self, canvas: &mut Canvas, dt: f64, time: f64) {
         let cx = self.width as f64 / 2.0;
         let bottom = self.height as f64 - 1.0;
@@ -155,7 +196,7 @@ impl Animation for Fountain {
         self.main_jet.config.speed_max = 55.0 * power;
 
         // Emit water particles continuously
-        self.emit_accum += dt;
+        self.emit_accum += dt * self.physics.emit_rate;
         let emit_interval = 0.01;
         while self.emit_accum >= emit_interval {
             self.main_jet.emit(3);
@@ -165,7 +206,7 @@ impl Animation for Fountain {
         // Emit mist near the base
         self.mist.config.x = cx + self.rng.random_range(-5.0..5.0);
         self.mist.config.y = bottom - self.rng.random_range(0.0..10.0);
-        if self.rng.random_range(0.0..1.0) < 0.3 {
+        if self.rng.random_range(0.0..1.0) < 0.3 * self.physics.emit_rate {
             self.mist.emit(1);
         }
 
@@ -30,6 +30,7 @@ pub struct Galton {
     n_rows: i32,
     spawn_timer: f64,
     total_collected: usize,
+    rng: rand::rngs::StdRng,
 }
 
 impl Galton {
@@ -42,6 +43,7 @@ impl Galton {
             n_rows: 0,
             spawn_timer: 0.0,
             total_collected: 0,
+            rng: super::rng(),
         }
     }
 
@@ -84,7 +86,7 @@ impl Animation for Galton {
         self.n_rows = n_rows;
 
         canvas.clear();
-        let mut rng = rand::rng();
+        let rng = &mut self.rng;
 
         // Spawn new balls from the hopper.
         self.spawn_timer += dt;
@@ -90,7 +90,8 @@ const GROUND_COLOR: (u8, u8, u8) = (120, 80, 40);
 const SUN_COLOR: (u8, u8, u8) = (255, 220, 50);
 const CLOUD_COLOR: (u8, u8, u8) = (200, 200, 220);
 const RAIN_COLOR: (u8, u8, u8) = (150, 200, 255);
-const SKY_DIM: (u8, u8, u8) = (15, 25, 50);
+const SKY_TOP: (u8, u8, u8) = (8, 14, 40);
+const SKY_HORIZON: (u8, u8, u8) = (25, 38, 60);
 
 // Static rows used to build rose shapes dynamically at spawn time
 static ROSE_STEM: &[(i32, char, bool)] = &[(0, '|', false)];
@@ -136,10 +137,10 @@ pub struct Garden {
     height: usize,
     scale: f64,
     bloom_timer: Option<f64>, // Some(t) = seconds since all plants fully bloomed
-    rng: rand::rngs::ThreadRng,
+    rng: rand::rngs::StdRng,
 }
 
-fn gen_plants(rng: &mut rand::rngs::ThreadRng, width: usize, scale: f64) -> Vec<Plant> {
+fn gen_plants(rng: &mut rand::rngs::StdRng, width: usize, scale: f64) -> Vec<Plant> {
     let num_plants = ((width as f64 / 8.0) * scale).clamp(3.0, 20.0) as usize;
     let slots = (num_plants + 1) as f64;
     (0..num_plants)
@@ -175,7 +176,7 @@ fn gen_plants(rng: &mut rand::rngs::ThreadRng, width: usize, scale: f64) -> Vec<
 
 impl Garden {
     pub fn new(width: usize, height: usize, scale: f64) -> Self {
-        let mut rng = rand::rng();
+        let mut rng = super::rng();
 
         let plants = gen_plants(&mut rng, width, scale);
 
@@ -200,7 +201,7 @@ impl Garden {
             height,
             scale,
             bloom_timer: None,
-            rng: rand::rng(),
+            rng: super::rng(),
         }
     }
 }
@@ -220,10 +221,6 @@ impl Animation for Garden {
     }
 
     fn update(&mut self, canvas: &mut Canvas, dt: f64, _time: f64) {
-        if self.height < 5 {
-            return;
-        }
-
         let ground_y = self.height - 1;
         let cloud_y: usize = 3;
 
@@ -245,12 +242,9 @@ impl Animation for Garden {
 
         canvas.clear();
 
-        // Sky background (faint tint so chars on black are visible)
-        for y in 0..ground_y {
-            for x in 0..self.width {
-                canvas.set_colored(x, y, 0.1, SKY_DIM.0, SKY_DIM.1, SKY_DIM.2);
-            }
-        }
+        // Sky background (faint tint so chars on black are visible); the
+        // ground row below overwrites the bottom of it.
+        canvas.fill_vertical_gradient(0.1, SKY_TOP, SKY_HORIZON);
 
         // Ground row
         for x in 0..self.width {
@@ -1,15 +1,21 @@
 use super::Animation;
+use crate::generators::ViewSpin;
 use crate::render::Canvas;
 
+/// Spin rate in radians per second.
+const ROTATION_SPEED: f64 = 0.5;
+
 /// Rotating wireframe Earth with simplified continents
 pub struct Globe {
-    rotation: f64,
+    spin: ViewSpin,
 }
 
 impl Globe {
     #[allow(unused_variables)]
     pub fn new(_width: usize, _height: usize, _scale: f64) -> Self {
-        Globe { rotation: 0.0 }
+        Globe {
+            spin: ViewSpin::default(),
+        }
     }
 }
 
@@ -51,14 +57,24 @@ impl Animation for Globe {
         "globe"
     }
 
-    fn update(&mut self, canvas: &mut Canvas, dt: f64, _time: f64) {
+    fn loop_period(&self) -> Option<f64> {
+        Some(std::f64::consts::TAU / ROTATION_SPEED)
+    }
+
+    fn toggle_freeze(&mut self) -> bool {
+        self.spin.toggle();
+        true
+    }
+
+    fn update(&mut self, canvas: &mut Canvas, dt: f64, time: f64) {
         let w = canvas.width as f64;
         let h = canvas.height as f64;
         let cx = w * 0.5;
         let cy = h * 0.5;
         let radius = (w.min(h) * 0.4).max(10.0);
 
-        self.rotation += dt * 0.5; // radians per second
+        self.spin.tick(dt);
+        let rotation = self.spin.time(time) * ROTATION_SPEED;
 
         canvas.clear();
 
@@ -80,7 +96,7 @@ impl Animation for Globe {
 
                 // Convert to lat/lon
                 let lat = ny.asin();
-                let lon = nx.atan2(nz) + self.rotation;
+                let lon = nx.atan2(nz) + rotation;
 
                 let lat_deg = lat.to_degrees();
                 let mut lon_deg = lon.to_degrees() % 360.0;
@@ -1,4 +1,5 @@
 use super::Animation;
+use crate::generators::{NamedParam, named_param};
 use crate::render::Canvas;
 use rand::RngExt;
 
@@ -28,57 +29,378 @@ struct NetworkNode {
 pub struct Hackerman {
     width: usize,
     height: usize,
-    // Scrolling log (bottom-left panel)
+    language: HackerLang,
+    lang: &'static Strings,
+    // Scrolling log
     log_lines: Vec<LogLine>,
     log_timer: f64,
-    // Progress bars (bottom-right panel)
+    // Progress bars
     bars: Vec<ProgressBar>,
     bar_reset_timer: f64,
-    // Network map nodes (top-right panel)
+    // Network map nodes
     nodes: Vec<NetworkNode>,
     node_timer: f64,
     active_connection: Option<(usize, usize)>,
     conn_timer: f64,
-    // Top-left stats
+    // System stats
     stats_flicker: f64,
     packets_count: u64,
     bytes_count: u64,
     threats_count: u32,
     uptime_secs: f64,
-    rng: rand::rngs::ThreadRng,
+    rng: rand::rngs::StdRng,
 }
 
-const LOG_MSGS: &[&str] = &[
-    "[+] Session opened ({})",
-    "[*] Scanning port {}...",
-    "PORT {}/tcp open",
-    "[+] Credentials: admin:{}",
-    "[!] Firewall rule bypassed",
-    "$ cat /etc/shadow",
-    "root:$6$rX9:18291:0:99999:::",
-    "[*] Sending payload ({}b)",
-    "[+] Shell spawned on {}",
-    ">>> Pivoting to {}",
-    "[!] IDS alert suppressed",
-    "$ chmod +x exploit",
-    "[*] Extracting database...",
-    "[+] {} rows dumped",
-    "DNS: {} → 93.184.216.34",
-    "[+] Tunnel established",
-    "$ ssh -D 9050 root@{}",
-    "[*] ARP spoofing gateway",
-    "[+] MITM active on {}",
-    "TLS intercepted (RSA-2048)",
-    "[!] CVE-2024-21762 found",
-    "[+] Exploit successful!",
-    "uid=0(root) gid=0(root)",
-    "$ wget http://{}/shell.php",
-    "[*] Migrating to PID 1284",
-];
-
-const BAR_LABELS: &[&str] = &[
-    "DECRYPT", "CRACK", "UPLOAD", "EXFIL", "SCAN", "INJECT", "COMPILE", "BREACH",
-];
+/// HUD text language, selected with `--hacker-lang` or the `hacker_lang`
+/// param.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HackerLang {
+    #[default]
+    En,
+    Es,
+    De,
+}
+
+named_param!(HackerLang {
+    En => "en",
+    Es => "es",
+    De => "de",
+});
+
+impl HackerLang {
+    fn strings(self) -> &'static Strings {
+        match self {
+            HackerLang::En => &EN,
+            HackerLang::Es => &ES,
+            HackerLang::De => &DE,
+        }
+    }
+}
+
+/// All user-visible HUD text for one language. Log templates use `{ip}`,
+/// `{n}` and `{word}` placeholders.
+struct Strings {
+    /// Panel titles in `Panel` order.
+    titles: [&'static str; 4],
+    /// Uptime, packets, bytes, threats, status, CPU, memory.
+    stat_labels: [&'static str; 7],
+    active: &'static str,
+    log_msgs: &'static [&'static str],
+    bar_labels: &'static [&'static str],
+    node_names: &'static [&'static str],
+}
+
+const EN: Strings = Strings {
+    titles: [
+        "[ SYSTEM STATUS ]",
+        "[ NETWORK MAP ]",
+        "[ ACTIVITY LOG ]",
+        "[ OPERATIONS ]",
+    ],
+    stat_labels: [
+        "UPTIME:",
+        "PACKETS:",
+        "BYTES TX:",
+        "THREATS:",
+        "STATUS:",
+        "CPU:",
+        "MEM:",
+    ],
+    active: "● ACTIVE",
+    log_msgs: &[
+        "[+] Session opened ({ip})",
+        "[*] Scanning port {n}...",
+        "PORT {n}/tcp open",
+        "[+] Credentials: admin:{word}",
+        "[!] Firewall rule bypassed",
+        "$ cat /etc/shadow",
+        "root:$6$rX9:18291:0:99999:::",
+        "[*] Sending payload ({n}b)",
+        "[+] Shell spawned on {ip}",
+        ">>> Pivoting to {ip}",
+        "[!] IDS alert suppressed",
+        "$ chmod +x exploit",
+        "[*] Extracting database...",
+        "[+] {n} rows dumped",
+        "DNS: {word}.net → 93.184.216.34",
+        "[+] Tunnel established",
+        "$ ssh -D 9050 root@{ip}",
+        "[*] ARP spoofing gateway",
+        "[+] MITM active on {ip}",
+        "TLS intercepted (RSA-2048)",
+        "[!] CVE-2024-21762 found",
+        "[+] Exploit successful!",
+        "uid=0(root) gid=0(root)",
+        "$ wget http://{ip}/shell.php",
+        "[*] Migrating to PID 1284",
+    ],
+    bar_labels: &[
+        "DECRYPT", "CRACK", "UPLOAD", "EXFIL", "SCAN", "INJECT", "COMPILE", "BREACH",
+    ],
+    node_names: &[
+        "GATEWAY", "FIREWALL", "DB-01", "APP-SRV", "DNS", "PROXY", "TARGET", "C2",
+    ],
+};
+
+const ES: Strings = Strings {
+    titles: [
+        "[ ESTADO DEL SISTEMA ]",
+        "[ MAPA DE RED ]",
+        "[ REGISTRO ]",
+        "[ OPERACIONES ]",
+    ],
+    stat_labels: [
+        "ACTIVO:",
+        "PAQUETES:",
+        "BYTES TX:",
+        "AMENAZAS:",
+        "ESTADO:",
+        "CPU:",
+        "MEM:",
+    ],
+    active: "● EN LÍNEA",
+    log_msgs: &[
+        "[+] Sesión abierta ({ip})",
+        "[*] Escaneando puerto {n}...",
+        "PORT {n}/tcp open",
+        "[+] Credenciales: admin:{word}",
+        "[!] Regla del cortafuegos evadida",
+        "$ cat /etc/shadow",
+        "root:$6$rX9:18291:0:99999:::",
+        "[*] Enviando carga ({n}b)",
+        "[+] Shell abierta en {ip}",
+        ">>> Pivotando a {ip}",
+        "[!] Alerta IDS suprimida",
+        "$ chmod +x exploit",
+        "[*] Extrayendo base de datos...",
+        "[+] {n} filas volcadas",
+        "DNS: {word}.net → 93.184.216.34",
+        "[+] Túnel establecido",
+        "$ ssh -D 9050 root@{ip}",
+        "[*] Suplantando ARP de la pasarela",
+        "[+] MITM activo en {ip}",
+        "TLS interceptado (RSA-2048)",
+        "[!] CVE-2024-21762 encontrado",
+        "[+] ¡Exploit exitoso!",
+        "uid=0(root) gid=0(root)",
+        "$ wget http://{ip}/shell.php",
+        "[*] Migrando al PID 1284",
+    ],
+    bar_labels: &[
+        "DESCIFRAR",
+        "CRACKEAR",
+        "SUBIR",
+        "EXFILTRAR",
+        "ESCANEAR",
+        "INYECTAR",
+        "COMPILAR",
+        "BRECHA",
+    ],
+    node_names: &[
+        "PASARELA",
+        "CORTAFUEGOS",
+        "BD-01",
+        "SRV-APP",
+        "DNS",
+        "PROXY",
+        "OBJETIVO",
+        "C2",
+    ],
+};
+
+const DE: Strings = Strings {
+    titles: [
+        "[ SYSTEMSTATUS ]",
+        "[ NETZWERKKARTE ]",
+        "[ PROTOKOLL ]",
+        "[ OPERATIONEN ]",
+    ],
+    stat_labels: [
+        "LAUFZEIT:",
+        "PAKETE:",
+        "BYTES TX:",
+        "BEDROHUNGEN:",
+        "STATUS:",
+        "CPU:",
+        "RAM:",
+    ],
+    active: "● AKTIV",
+    log_msgs: &[
+        "[+] Sitzung geöffnet ({ip})",
+        "[*] Scanne Port {n}...",
+        "PORT {n}/tcp open",
+        "[+] Zugangsdaten: admin:{word}",
+        "[!] Firewall-Regel umgangen",
+        "$ cat /etc/shadow",
+        "root:$6$rX9:18291:0:99999:::",
+        "[*] Sende Payload ({n}b)",
+        "[+] Shell gestartet auf {ip}",
+        ">>> Pivot nach {ip}",
+        "[!] IDS-Alarm unterdrückt",
+        "$ chmod +x exploit",
+        "[*] Extrahiere Datenbank...",
+        "[+] {n} Zeilen ausgelesen",
+        "DNS: {word}.net → 93.184.216.34",
+        "[+] Tunnel aufgebaut",
+        "$ ssh -D 9050 root@{ip}",
+        "[*] ARP-Spoofing am Gateway",
+        "[+] MITM aktiv auf {ip}",
+        "TLS abgefangen (RSA-2048)",
+        "[!] CVE-2024-21762 gefunden",
+        "[+] Exploit erfolgreich!",
+        "uid=0(root) gid=0(root)",
+        "$ wget http://{ip}/shell.php",
+        "[*] Migriere zu PID 1284",
+    ],
+    bar_labels: &[
+        "ENTSCHLÜSSELN",
+        "KNACKEN",
+        "UPLOAD",
+        "EXFIL",
+        "SCAN",
+        "INJEKTION",
+        "KOMPILIEREN",
+        "EINBRUCH",
+    ],
+    node_names: &[
+        "GATEWAY", "FIREWALL", "DB-01", "APP-SRV", "DNS", "PROXY", "ZIEL", "C2",
+    ],
+};
+
+/// Minimum terminal width for the side-by-side 2x2 layout.
+const WIDE_LAYOUT_MIN_WIDTH: usize = 60;
+/// Minimum rows per panel including its top and (shared) bottom border.
+const MIN_PANEL_HEIGHT: usize = 4;
+const MIN_PANEL_WIDTH: usize = 12;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Panel {
+    Status,
+    Network,
+    Log,
+    Operations,
+}
+
+/// Display order of panels.
+const PANELS: [Panel; 4] = [Panel::Status, Panel::Network, Panel::Log, Panel::Operations];
+/// Which panels survive first when the terminal is too short for all four.
+const PANEL_PRIORITY: [Panel; 4] = [Panel::Log, Panel::Status, Panel::Operations, Panel::Network];
+
+/// Panel box in cells; borders are the outermost rows/columns and are shared
+/// with neighboring panels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Rect {
+    x: usize,
+    y: usize,
+    w: usize,
+    h: usize,
+}
+
+impl Rect {
+    /// Content area inside the border, with one column of padding on each
+    /// side and a blank row under the title when there is room for it.
+    fn inner(&self) -> Rect {
+        let pad_top = if self.h >= 10 { 2 } else { 1 };
+        Rect {
+            x: self.x + 2,
+            y: self.y + pad_top,
+            w: self.w.saturating_sub(4),
+            h: self.h.saturating_sub(pad_top + 1),
+        }
+    }
+
+    fn right(&self) -> usize {
+        self.x + self.w
+    }
+}
+
+/// Tile as many panels as fit into a `width` x `height` terminal: a 2x2 grid
+/// when wide enough, otherwise a single stacked column. When the terminal is
+/// too short for every panel, the lowest-priority panels are dropped; the
+/// last row of a grid with an odd panel count spans the full width.
+fn layout(width: usize, height: usize) -> Vec<(Panel, Rect)> {
+    if width < MIN_PANEL_WIDTH || height < MIN_PANEL_HEIGHT {
+        return Vec::new();
+    }
+    let cols = if width >= WIDE_LAYOUT_MIN_WIDTH { 2 } else { 1 };
+    let max_rows = (height - 1) / (MIN_PANEL_HEIGHT - 1);
+    let count = (cols * max_rows).min(PANELS.len());
+    let shown: Vec<Panel> = PANELS
+        .iter()
+        .copied()
+        .filter(|p| PANEL_PRIORITY[..count].contains(p))
+        .collect();
+
+    let rows = shown.len().div_ceil(cols);
+    let mut rects = Vec::with_capacity(shown.len());
+    for (row, chunk) in shown.chunks(cols).enumerate() {
+        let y0 = row * (height - 1) / rows;
+        let y1 = (row + 1) * (height - 1) / rows;
+        for (col, &panel) in chunk.iter().enumerate() {
+            let x0 = col * (width - 1) / chunk.len();
+            let x1 = (col + 1) * (width - 1) / chunk.len();
+            rects.push((
+                panel,
+                Rect {
+                    x: x0,
+                    y: y0,
+                    w: x1 - x0 + 1,
+                    h: y1 - y0 + 1,
+                },
+            ));
+        }
+    }
+    rects
+}
+
+const UP: u8 = 1;
+const DOWN: u8 = 2;
+const LEFT: u8 = 4;
+const RIGHT: u8 = 8;
+
+/// Draw every panel border, joining shared edges with the proper
+/// box-drawing junctions (`┬`, `├`, `┼`, ...).
+fn draw_borders(canvas: &mut Canvas, rects: &[Rect], color: (u8, u8, u8)) {
+    let (w, h) = (canvas.width, canvas.height);
+    let mut links = vec![0u8; w * h];
+    let mut link = |x: usize, y: usize, bits: u8| {
+        if x < w && y < h {
+            links[y * w + x] |= bits;
+        }
+    };
+    for r in rects {
+        let (x1, y1) = (r.x + r.w - 1, r.y + r.h - 1);
+        for x in r.x..x1 {
+            link(x, r.y, RIGHT);
+            link(x + 1, r.y, LEFT);
+            link(x, y1, RIGHT);
+            link(x + 1, y1, LEFT);
+        }
+        for y in r.y..y1 {
+            link(r.x, y, DOWN);
+            link(r.x, y + 1, UP);
+            link(x1, y, DOWN);
+            link(x1, y + 1, UP);
+        }
+    }
+    for (i, &bits) in links.iter().enumerate() {
+        let ch = match bits {
+            0 => continue,
+            b if b == DOWN | RIGHT => '┌',
+            b if b == DOWN | LEFT => '┐',
+            b if b == UP | RIGHT => '└',
+            b if b == UP | LEFT => '┘',
+            b if b == UP | DOWN | RIGHT => '├',
+            b if b == UP | DOWN | LEFT => '┤',
+            b if b == LEFT | RIGHT | DOWN => '┬',
+            b if b == LEFT | RIGHT | UP => '┴',
+            b if b == UP | DOWN | LEFT | RIGHT => '┼',
+            b if b & (UP | DOWN) != 0 => '│',
+            _ => '─',
+        };
+        canvas.set_char(i % w, i / w, ch, color.0, color.1, color.2);
+    }
+}
 
 fn rand_ip(rng: &mut impl rand::RngExt) -> String {
     format!(
@@ -97,13 +419,30 @@ fn rand_word(rng: &mut impl rand::RngExt) -> String {
     words[rng.random_range(0..words.len())].to_string()
 }
 
+fn rand_bar_color(rng: &mut impl rand::RngExt) -> (u8, u8, u8) {
+    match rng.random_range(0u8..3) {
+        0 => (0, 220, 180),
+        1 => (0, 180, 255),
+        _ => (0, 255, 100),
+    }
+}
+
 impl Hackerman {
     pub fn new(width: usize, height: usize, _scale: f64) -> Self {
-        let mut rng = rand::rng();
-        let node_names = [
-            "GATEWAY", "FIREWALL", "DB-01", "APP-SRV", "DNS", "PROXY", "TARGET", "C2",
-        ];
-        let nodes: Vec<NetworkNode> = node_names
+        // `[animations.hackerman] lang = "de"`; the `hacker_lang` param
+        // (from `--hacker-lang`) can still change it afterwards.
+        let language = super::setting::<String>("lang")
+            .as_deref()
+            .and_then(HackerLang::from_name)
+            .unwrap_or_default();
+        Self::with_language(width, height, language)
+    }
+
+    fn with_language(width: usize, height: usize, language: HackerLang) -> Self {
+        let lang = language.strings();
+        let mut rng = super::rng();
+        let nodes: Vec<NetworkNode> = lang
+            .node_names
             .iter()
             .enumerate()
             .map(|(i, name)| NetworkNode {
@@ -117,17 +456,13 @@ impl Hackerman {
 
         let bars = (0..4)
             .map(|_| {
-                let label = BAR_LABELS[rng.random_range(0..BAR_LABELS.len())];
+                let label = lang.bar_labels[rng.random_range(0..lang.bar_labels.len())];
                 ProgressBar {
                     label: label.to_string(),
                     value: 0.0,
                     target: rng.random_range(0.7..1.0),
                     speed: rng.random_range(0.05..0.25),
-                    color: match rng.random_range(0u8..3) {
-                        0 => (0, 220, 180),
-                        1 => (0, 180, 255),
-                        _ => (0, 255, 100),
-                    },
+                    color: rand_bar_color(&mut rng),
                 }
             })
             .collect();
@@ -135,6 +470,8 @@ impl Hackerman {
         Hackerman {
             width,
             height,
+            language,
+            lang,
             log_lines: Vec::new(),
             log_timer: 0.0,
             bars,
@@ -148,160 +485,27 @@ impl Hackerman {
             bytes_count: 1_284_019,
             threats_count: 3,
             uptime_secs: 3847.0,
-            rng: rand::rng(),
+            rng: super::rng(),
         }
     }
-}
-
-impl Animation for Hackerman {
-    fn name(&self) -> &str {
-        "hackerman"
-    }
-
-    fn preferred_render(&self) -> crate::render::RenderMode {
-        crate::render::RenderMode::Ascii
-    }
-
-    fn on_resize(&mut self, width: usize, height: usize) {
-        self.width = width;
-        self.height = height;
-    }
 
-    fn update(&mut self, canvas: &mut Canvas, dt: f64, time: f64) {
-        self.uptime_secs += dt;
-
-        if self.width < 40 || self.height < 15 {
+    /// Switch the HUD text to `language`. Node names follow at once; log
+    /// lines and task labels change as new ones appear.
+    fn set_language(&mut self, language: HackerLang) {
+        if language == self.language {
             return;
         }
-
-        canvas.clear();
-
-        // Layout: split into quadrants
-        let mid_x = self.width / 2;
-        let mid_y = self.height / 2;
-
-        // ── Draw borders ──
-        let border_color: (u8, u8, u8) = (0, 100, 60);
-        // Horizontal divider
-        for x in 0..self.width {
-            canvas.set_char(
-                x,
-                mid_y,
-                '─',
-                border_color.0,
-                border_color.1,
-                border_color.2,
-            );
-            canvas.set_char(x, 0, '─', border_color.0, border_color.1, border_color.2);
-            if self.height > 1 {
-                canvas.set_char(
-                    x,
-                    self.height - 1,
-                    '─',
-                    border_color.0,
-                    border_color.1,
-                    border_color.2,
-                );
-            }
-        }
-        // Vertical divider
-        for y in 0..self.height {
-            canvas.set_char(
-                mid_x,
-                y,
-                '│',
-                border_color.0,
-                border_color.1,
-                border_color.2,
-            );
-            canvas.set_char(0, y, '│', border_color.0, border_color.1, border_color.2);
-            if self.width > 1 {
-                canvas.set_char(
-                    self.width - 1,
-                    y,
-                    '│',
-                    border_color.0,
-                    border_color.1,
-                    border_color.2,
-                );
-            }
+        self.language = language;
+        self.lang = language.strings();
+        for (node, name) in self.nodes.iter_mut().zip(self.lang.node_names) {
+            node.label = name.to_string();
         }
-        // Corners and intersections
-        canvas.set_char(0, 0, '┌', border_color.0, border_color.1, border_color.2);
-        canvas.set_char(
-            self.width - 1,
-            0,
-            '┐',
-            border_color.0,
-            border_color.1,
-            border_color.2,
-        );
-        canvas.set_char(
-            0,
-            self.height - 1,
-            '└',
-            border_color.0,
-            border_color.1,
-            border_color.2,
-        );
-        canvas.set_char(
-            self.width - 1,
-            self.height - 1,
-            '┘',
-            border_color.0,
-            border_color.1,
-            border_color.2,
-        );
-        canvas.set_char(
-            mid_x,
-            0,
-            '┬',
-            border_color.0,
-            border_color.1,
-            border_color.2,
-        );
-        canvas.set_char(
-            mid_x,
-            self.height - 1,
-            '┴',
-            border_color.0,
-            border_color.1,
-            border_color.2,
-        );
-        canvas.set_char(
-            0,
-            mid_y,
-            '├',
-            border_color.0,
-            border_color.1,
-            border_color.2,
-        );
-        canvas.set_char(
-            self.width - 1,
-            mid_y,
-            '┤',
-            border_color.0,
-            border_color.1,
-            border_color.2,
-        );
-        canvas.set_char(
-            mid_x,
-            mid_y,
-            '┼',
-            border_color.0,
-            border_color.1,
-            border_color.2,
-        );
-
-        // ── Panel titles ──
-        draw_text(canvas, 2, 0, "[ SYSTEM STATUS ]", (0, 200, 100));
-        draw_text(canvas, mid_x + 2, 0, "[ NETWORK MAP ]", (0, 200, 100));
-        draw_text(canvas, 2, mid_y, "[ ACTIVITY LOG ]", (0, 200, 100));
-        draw_text(canvas, mid_x + 2, mid_y, "[ OPERATIONS ]", (0, 200, 100));
+    }
 
-        // ══════════════════════════════════════
-        // TOP-LEFT: System Status
-        // ══════════════════════════════════════
+    /// Advance counters, log, network and progress-bar state. Runs every
+    /// frame whether or not the corresponding panel is visible.
+    fn step(&mut self, dt: f64) {
+        self.uptime_secs += dt;
         self.stats_flicker += dt;
         self.packets_count += self.rng.random_range(10..200) as u64;
         self.bytes_count += self.rng.random_range(500..50000) as u64;
@@ -309,103 +513,11 @@ impl Animation for Hackerman {
             self.threats_count += 1;
         }
 
-        let uptime_h = (self.uptime_secs / 3600.0) as u32;
-        let uptime_m = ((self.uptime_secs % 3600.0) / 60.0) as u32;
-        let uptime_s = (self.uptime_secs % 60.0) as u32;
-
-        let stats_x = 2;
-        let stats_y = 2;
-        let dim_green: (u8, u8, u8) = (0, 160, 80);
-        let bright_green: (u8, u8, u8) = (0, 255, 120);
-
-        draw_text(canvas, stats_x, stats_y, "UPTIME:", dim_green);
-        draw_text(
-            canvas,
-            stats_x + 10,
-            stats_y,
-            &format!("{:02}:{:02}:{:02}", uptime_h, uptime_m, uptime_s),
-            bright_green,
-        );
-
-        draw_text(canvas, stats_x, stats_y + 2, "PACKETS:", dim_green);
-        draw_text(
-            canvas,
-            stats_x + 10,
-            stats_y + 2,
-            &format!("{}", self.packets_count),
-            bright_green,
-        );
-
-        draw_text(canvas, stats_x, stats_y + 4, "BYTES TX:", dim_green);
-        draw_text(
-            canvas,
-            stats_x + 10,
-            stats_y + 4,
-            &format!("{}", self.bytes_count),
-            bright_green,
-        );
-
-        draw_text(canvas, stats_x, stats_y + 6, "THREATS:", dim_green);
-        let threat_color = if self.threats_count > 5 {
-            (255, 50, 50)
-        } else {
-            (255, 200, 50)
-        };
-        draw_text(
-            canvas,
-            stats_x + 10,
-            stats_y + 6,
-            &format!("{}", self.threats_count),
-            threat_color,
-        );
-
-        draw_text(canvas, stats_x, stats_y + 8, "STATUS:", dim_green);
-        let blink = (time * 2.0).sin() > 0.0;
-        if blink {
-            draw_text(canvas, stats_x + 10, stats_y + 8, "● ACTIVE", (0, 255, 0));
-        } else {
-            draw_text(canvas, stats_x + 10, stats_y + 8, "● ACTIVE", (0, 120, 0));
-        }
-
-        // CPU/MEM bars
-        if stats_y + 11 < mid_y {
-            let cpu = 0.3 + (time * 0.7).sin().abs() * 0.5 + self.rng.random_range(0.0..0.1);
-            let mem = 0.6 + (time * 0.1).sin() * 0.1;
-            draw_text(canvas, stats_x, stats_y + 10, "CPU:", dim_green);
-            draw_mini_bar(
-                canvas,
-                stats_x + 6,
-                stats_y + 10,
-                20,
-                cpu.min(1.0),
-                (0, 200, 100),
-            );
-            draw_text(canvas, stats_x, stats_y + 12, "MEM:", dim_green);
-            draw_mini_bar(
-                canvas,
-                stats_x + 6,
-                stats_y + 12,
-                20,
-                mem.min(1.0),
-                (0, 180, 220),
-            );
-        }
-
-        // ══════════════════════════════════════
-        // TOP-RIGHT: Network Map
-        // ══════════════════════════════════════
-        let map_x = mid_x + 2;
-        let map_y = 2;
-        let map_w = self.width.saturating_sub(mid_x + 4);
-        let map_h = mid_y.saturating_sub(3);
-
-        // Update nodes
+        // Network nodes and active connection
         self.node_timer += dt;
         for node in &mut self.nodes {
             node.pulse += dt * 3.0;
         }
-
-        // Cycle active connections
         self.conn_timer += dt;
         if self.conn_timer > 2.0 {
             self.conn_timer = 0.0;
@@ -425,15 +537,145 @@ impl Animation for Hackerman {
             }
         }
 
+        // Add new log lines
+        self.log_timer -= dt;
+        if self.log_timer <= 0.0 {
+            self.log_timer = self.rng.random_range(0.1..0.5);
+            let msgs = self.lang.log_msgs;
+            let text = msgs[self.rng.random_range(0..msgs.len())]
+                .replace("{ip}", &rand_ip(&mut self.rng))
+                .replace("{n}", &self.rng.random_range(1000u32..65535).to_string())
+                .replace("{word}", &rand_word(&mut self.rng));
+
+            let color = if text.contains("[+]") {
+                (100, 255, 100)
+            } else if text.contains("[!]") {
+                (255, 200, 50)
+            } else if text.contains("[*]") {
+                (100, 180, 255)
+            } else if text.starts_with("$") {
+                (0, 255, 0)
+            } else {
+                (0, 180, 80)
+            };
+
+            self.log_lines.push(LogLine {
+                text,
+                color,
+                age: 0.0,
+            });
+            if self.log_lines.len() > 200 {
+                self.log_lines.drain(0..100);
+            }
+        }
+        for line in &mut self.log_lines {
+            line.age += dt;
+        }
+
+        // Progress bars
+        self.bar_reset_timer += dt;
+        for bar in &mut self.bars {
+            if bar.value < bar.target {
+                bar.value = (bar.value + bar.speed * dt).min(bar.target);
+            }
+        }
+        // Reset completed bars periodically
+        if self.bar_reset_timer > 5.0 {
+            self.bar_reset_timer = 0.0;
+            let labels = self.lang.bar_labels;
+            for bar in &mut self.bars {
+                if bar.value >= bar.target {
+                    bar.label = labels[self.rng.random_range(0..labels.len())].to_string();
+                    bar.value = 0.0;
+                    bar.target = self.rng.random_range(0.6..1.0);
+                    bar.speed = self.rng.random_range(0.05..0.3);
+                    bar.color = rand_bar_color(&mut self.rng);
+                }
+            }
+        }
+    }
+
+    fn draw_status(&mut self, canvas: &mut Canvas, area: Rect, time: f64) {
+        let dim_green: (u8, u8, u8) = (0, 160, 80);
+        let bright_green: (u8, u8, u8) = (0, 255, 120);
+        let labels = &self.lang.stat_labels;
+        let value_x = area.x + labels.iter().map(|l| l.chars().count()).max().unwrap_or(0) + 2;
+        // Double-space rows when every line fits that way.
+        let row_step = if area.h >= labels.len() * 2 - 1 { 2 } else { 1 };
+        let right = area.right();
+
+        let uptime_h = (self.uptime_secs / 3600.0) as u32;
+        let uptime_m = ((self.uptime_secs % 3600.0) / 60.0) as u32;
+        let uptime_s = (self.uptime_secs % 60.0) as u32;
+        let threat_color = if self.threats_count > 5 {
+            (255, 50, 50)
+        } else {
+            (255, 200, 50)
+        };
+        let blink = (time * 2.0).sin() > 0.0;
+        let active_color = if blink { (0, 255, 0) } else { (0, 120, 0) };
+        let values = [
+            (
+                format!("{:02}:{:02}:{:02}", uptime_h, uptime_m, uptime_s),
+                bright_green,
+            ),
+            (self.packets_count.to_string(), bright_green),
+            (self.bytes_count.to_string(), bright_green),
+            (self.threats_count.to_string(), threat_color),
+            (self.lang.active.to_string(), active_color),
+        ];
+
+        for (i, (value, color)) in values.iter().enumerate() {
+            let y = area.y + i * row_step;
+            if y >= area.y + area.h {
+                return;
+            }
+            draw_text(canvas, area.x, y, labels[i], dim_green, right);
+            draw_text(canvas, value_x, y, value, *color, right);
+        }
+
+        // CPU/MEM bars
+        let cpu = 0.3 + (time * 0.7).sin().abs() * 0.5 + self.rng.random_range(0.0..0.1);
+        let mem = 0.6 + (time * 0.1).sin() * 0.1;
+        let bar_w = 20.min(right.saturating_sub(value_x + 5));
+        for (i, (value, color)) in [(cpu, (0, 200, 100)), (mem, (0, 180, 220))]
+            .into_iter()
+            .enumerate()
+        {
+            let y = area.y + (values.len() + i) * row_step;
+            if y >= area.y + area.h || bar_w < 4 {
+                return;
+            }
+            draw_text(
+                canvas,
+                area.x,
+                y,
+                labels[values.len() + i],
+                dim_green,
+                right,
+            );
+            draw_mini_bar(canvas, value_x, y, bar_w, value.min(1.0), color);
+        }
+    }
+
+    fn draw_network(&self, canvas: &mut Canvas, area: Rect, time: f64) {
+        if area.w < 4 || area.h < 2 {
+            return;
+        }
+        let pos = |node: &NetworkNode| {
+            (
+                area.x + (node.x * area.w as f64) as usize,
+                area.y + (node.y * area.h as f64) as usize,
+            )
+        };
+
         // Draw connections (lines between nodes)
         if let Some((a, b)) = self.active_connection
             && a < self.nodes.len()
             && b < self.nodes.len()
         {
-            let ax = map_x + (self.nodes[a].x * map_w as f64) as usize;
-            let ay = map_y + (self.nodes[a].y * map_h as f64) as usize;
-            let bx = map_x + (self.nodes[b].x * map_w as f64) as usize;
-            let by = map_y + (self.nodes[b].y * map_h as f64) as usize;
+            let (ax, ay) = pos(&self.nodes[a]);
+            let (bx, by) = pos(&self.nodes[b]);
             // Simple line drawing
             let steps = ((bx as f64 - ax as f64)
                 .abs()
@@ -445,7 +687,7 @@ impl Animation for Hackerman {
                     let py = ay as f64 + (by as f64 - ay as f64) * s as f64 / steps as f64;
                     let px = px as usize;
                     let py = py as usize;
-                    if px < self.width && py < self.height {
+                    if px < area.right() && py < area.y + area.h {
                         let near_pulse = (s as i32 - pulse_pos as i32).unsigned_abs() < 3;
                         if near_pulse {
                             canvas.set_char(px, py, '●', 0, 255, 100);
@@ -459,10 +701,8 @@ impl Animation for Hackerman {
 
         // Draw nodes
         for node in &self.nodes {
-            let nx = map_x + (node.x * map_w as f64) as usize;
-            let ny = map_y + (node.y * map_h as f64) as usize;
-            if nx < self.width.saturating_sub(node.label.len() + 3) && ny < mid_y.saturating_sub(1)
-            {
+            let (nx, ny) = pos(node);
+            if nx + node.label.chars().count() + 2 <= area.right() && ny < area.y + area.h {
                 let pulse_bright = if node.active {
                     0.6 + (node.pulse.sin() * 0.4).abs()
                 } else {
@@ -479,135 +719,51 @@ impl Animation for Hackerman {
                 };
                 let icon = if node.active { '◉' } else { '○' };
                 canvas.set_char(nx, ny, icon, r, g, b);
-                draw_text(canvas, nx + 2, ny, &node.label, (r, g, b));
-            }
-        }
-
-        // ══════════════════════════════════════
-        // BOTTOM-LEFT: Activity Log (scrolling)
-        // ══════════════════════════════════════
-        let log_x = 2;
-        let log_y_start = mid_y + 2;
-        let log_h = self.height.saturating_sub(mid_y + 3);
-        let log_w = mid_x.saturating_sub(3);
-
-        // Add new log lines
-        self.log_timer -= dt;
-        if self.log_timer <= 0.0 {
-            self.log_timer = self.rng.random_range(0.1..0.5);
-            let tmpl = LOG_MSGS[self.rng.random_range(0..LOG_MSGS.len())];
-            let ip = rand_ip(&mut self.rng);
-            let num = self.rng.random_range(1000u32..65535);
-            let word = rand_word(&mut self.rng);
-            let text = tmpl.replace(
-                "{}",
-                &if tmpl.contains("port") || tmpl.contains("bytes") || tmpl.contains("rows") {
-                    num.to_string()
-                } else if tmpl.contains("admin:{}") {
-                    word
-                } else {
-                    ip
-                },
-            );
-
-            let color = if text.contains("[+]") {
-                (100, 255, 100)
-            } else if text.contains("[!]") {
-                (255, 200, 50)
-            } else if text.contains("[*]") {
-                (100, 180, 255)
-            } else if text.starts_with("$") {
-                (0, 255, 0)
-            } else {
-                (0, 180, 80)
-            };
-
-            self.log_lines.push(LogLine {
-                text,
-                color,
-                age: 0.0,
-            });
-            if self.log_lines.len() > 200 {
-                self.log_lines.drain(0..100);
+                draw_text(canvas, nx + 2, ny, &node.label, (r, g, b), area.right());
             }
         }
+    }
 
-        for line in &mut self.log_lines {
-            line.age += dt;
-        }
-
-        // Render log (most recent at bottom)
-        let visible = log_h.min(self.log_lines.len());
-        let start = self.log_lines.len().saturating_sub(visible);
+    fn draw_log(&self, canvas: &mut Canvas, area: Rect, time: f64) {
+        // Render log (most recent at bottom), leaving a row for the cursor
+        let visible = area.h.saturating_sub(1).min(self.log_lines.len());
+        let start = self.log_lines.len() - visible;
         for (i, line) in self.log_lines[start..].iter().enumerate() {
-            let sy = log_y_start + i;
-            if sy >= self.height.saturating_sub(1) {
-                break;
-            }
             let fade = (1.0 - (line.age * 0.08).min(0.4)).max(0.6);
             let (cr, cg, cb) = line.color;
-            for (cx, ch) in line.text.chars().enumerate() {
-                if cx >= log_w {
-                    break;
-                }
-                canvas.set_char(
-                    log_x + cx,
-                    sy,
-                    ch,
-                    (cr as f64 * fade) as u8,
-                    (cg as f64 * fade) as u8,
-                    (cb as f64 * fade) as u8,
-                );
-            }
-        }
-
-        // ══════════════════════════════════════
-        // BOTTOM-RIGHT: Operations (progress bars + status)
-        // ══════════════════════════════════════
-        let ops_x = mid_x + 2;
-        let ops_y = mid_y + 2;
-        let ops_w = self.width.saturating_sub(mid_x + 4);
-
-        // Update progress bars
-        self.bar_reset_timer += dt;
-        for bar in &mut self.bars {
-            if bar.value < bar.target {
-                bar.value += bar.speed * dt;
-                if bar.value > bar.target {
-                    bar.value = bar.target;
-                }
-            }
+            let color = (
+                (cr as f64 * fade) as u8,
+                (cg as f64 * fade) as u8,
+                (cb as f64 * fade) as u8,
+            );
+            draw_text(canvas, area.x, area.y + i, &line.text, color, area.right());
         }
 
-        // Reset completed bars periodically
-        if self.bar_reset_timer > 5.0 {
-            self.bar_reset_timer = 0.0;
-            for bar in &mut self.bars {
-                if bar.value >= bar.target {
-                    bar.label = BAR_LABELS[self.rng.random_range(0..BAR_LABELS.len())].to_string();
-                    bar.value = 0.0;
-                    bar.target = self.rng.random_range(0.6..1.0);
-                    bar.speed = self.rng.random_range(0.05..0.3);
-                    bar.color = match self.rng.random_range(0u8..3) {
-                        0 => (0, 220, 180),
-                        1 => (0, 180, 255),
-                        _ => (0, 255, 100),
-                    };
-                }
-            }
+        // Cursor pulses in and out rather than hard-blinking
+        if visible < area.h {
+            let alpha = (time * 3.0).sin() * 0.5 + 0.5;
+            canvas.set_char_alpha(area.x, area.y + visible, '█', alpha, 0, 255, 0);
         }
+    }
 
-        // Draw progress bars
+    fn draw_operations(&self, canvas: &mut Canvas, area: Rect) {
+        // Spread bars out when there is room for a gap row between them.
+        let row_step = if area.h >= self.bars.len() * 3 - 1 {
+            3
+        } else {
+            2
+        };
+        let right = area.right();
         for (i, bar) in self.bars.iter().enumerate() {
-            let by = ops_y + i * 3;
-            if by + 1 >= self.height.saturating_sub(1) {
+            let by = area.y + i * row_step;
+            if by + 1 >= area.y + area.h {
                 break;
             }
 
             // Label + percentage
             let pct = format!(" {:.0}%", bar.value * 100.0);
             let status = if bar.value >= bar.target { " ✓" } else { "" };
-            draw_text(canvas, ops_x, by, &bar.label, bar.color);
+            draw_text(canvas, area.x, by, &bar.label, bar.color, right);
             let status_color = if bar.value >= bar.target {
                 (0, 255, 0)
             } else {
@@ -615,44 +771,105 @@ impl Animation for Hackerman {
             };
             draw_text(
                 canvas,
-                ops_x + bar.label.len(),
+                area.x + bar.label.chars().count(),
                 by,
                 &format!("{}{}", pct, status),
                 status_color,
+                right,
             );
 
             // Bar
-            let bar_w = ops_w.saturating_sub(2);
-            let filled = (bar.value * bar_w as f64) as usize;
-            for bx in 0..bar_w {
-                let px = ops_x + bx;
-                if px < self.width.saturating_sub(1) {
-                    if bx < filled {
-                        canvas.set_char(px, by + 1, '█', bar.color.0, bar.color.1, bar.color.2);
-                    } else {
-                        canvas.set_char(px, by + 1, '░', 30, 30, 30);
-                    }
+            let filled = (bar.value * area.w as f64) as usize;
+            for bx in 0..area.w {
+                if bx < filled {
+                    canvas.set_char(
+                        area.x + bx,
+                        by + 1,
+                        '█',
+                        bar.color.0,
+                        bar.color.1,
+                        bar.color.2,
+                    );
+                } else {
+                    canvas.set_char(area.x + bx, by + 1, '░', 30, 30, 30);
                 }
             }
         }
+    }
+}
+
+impl Animation for Hackerman {
+    fn name(&self) -> &str {
+        "hackerman"
+    }
+
+    fn preferred_render(&self) -> crate::render::RenderMode {
+        crate::render::RenderMode::Ascii
+    }
+
+    fn min_size(&self) -> (usize, usize) {
+        (MIN_PANEL_WIDTH, MIN_PANEL_HEIGHT)
+    }
+
+    fn on_resize(&mut self, width: usize, height: usize) {
+        self.width = width;
+        self.height = height;
+    }
+
+    fn set_params(&mut self, params: &crate::external::ExternalParams) {
+        let language = HackerLang::from_params(params, "hacker_lang").unwrap_or(self.language);
+        self.set_language(language);
+    }
+
+    fn supported_params(&self) -> &'static [(&'static str, f64, f64)] {
+        &[("hacker_lang", 0.0, 2.0)]
+    }
+
+    fn update(&mut self, canvas: &mut Canvas, dt: f64, time: f64) {
+        self.step(dt);
+
+        canvas.clear();
+
+        let panels = layout(self.width, self.height);
+        let rects: Vec<Rect> = panels.iter().map(|&(_, r)| r).collect();
+        draw_borders(canvas, &rects, (0, 100, 60));
 
-        // Blinking cursor in log panel
-        let blink = (time * 3.0).sin() > 0.0;
-        if blink {
-            let cy = log_y_start + visible.min(log_h.saturating_sub(1));
-            if cy < self.height.saturating_sub(1) {
-                canvas.set_char(log_x, cy, '█', 0, 255, 0);
+        for (panel, rect) in panels {
+            let title = self.lang.titles[PANELS.iter().position(|&p| p == panel).unwrap_or(0)];
+            draw_text(
+                canvas,
+                rect.x + 2,
+                rect.y,
+                title,
+                (0, 200, 100),
+                rect.right().saturating_sub(1),
+            );
+            let area = rect.inner();
+            match panel {
+                Panel::Status => self.draw_status(canvas, area, time),
+                Panel::Network => self.draw_network(canvas, area, time),
+                Panel::Log => self.draw_log(canvas, area, time),
+                Panel::Operations => self.draw_operations(canvas, area),
             }
         }
     }
 }
 
-fn draw_text(canvas: &mut Canvas, x: usize, y: usize, text: &str, color: (u8, u8, u8)) {
+/// Draw `text` starting at `(x, y)`, clipped before column `right`.
+fn draw_text(
+    canvas: &mut Canvas,
+    x: usize,
+    y: usize,
+    text: &str,
+    color: (u8, u8, u8),
+    right: usize,
+) {
     for (i, ch) in text.chars().enumerate() {
         let px = x + i;
-        if px < canvas.width && y < canvas.height {
-            canvas.set_char(px, y, ch, color.0, color.1, color.2);
+        if px >= right {
+            break;
         }
+        canvas.set_char(px, y, ch, color.0, color.1, color.2);
     }
 }
 
@@ -677,5 +894,80 @@ fn draw_mini_bar(
     }
     // Percentage
     let pct = format!(" {:.0}%", value * 100.0);
-    draw_text(canvas, x + width, y, &pct, color);
+    draw_text(canvas, x + width, y, &pct, color, canvas.width);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::{ColorMode, RenderMode};
+
+    fn in_bounds(panels: &[(Panel, Rect)], w: usize, h: usize) -> bool {
+        panels.iter().all(|(_, r)| r.right() <= w && r.y + r.h <= h)
+    }
+
+    #[test]
+    fn wide_terminal_uses_two_by_two_grid() {
+        let panels = layout(80, 24);
+        assert_eq!(panels.len(), 4);
+        assert!(in_bounds(&panels, 80, 24));
+        assert_eq!(
+            panels[0].1.y, panels[1].1.y,
+            "status and network share a row"
+        );
+        assert!(panels[1].1.x > 0);
+    }
+
+    #[test]
+    fn narrow_terminal_stacks_panels_full_width() {
+        let panels = layout(40, 24);
+        assert_eq!(panels.len(), 4);
+        assert!(in_bounds(&panels, 40, 24));
+        assert!(panels.iter().all(|(_, r)| r.x == 0 && r.w == 40));
+        assert!(panels.windows(2).all(|p| p[1].1.y > p[0].1.y));
+    }
+
+    #[test]
+    fn short_terminal_keeps_highest_priority_panels() {
+        let panels = layout(30, 8);
+        let shown: Vec<Panel> = panels.iter().map(|&(p, _)| p).collect();
+        assert_eq!(shown, vec![Panel::Status, Panel::Log]);
+        assert!(in_bounds(&panels, 30, 8));
+        assert!(layout(8, 8).is_empty());
+    }
+
+    #[test]
+    fn small_terminal_still_draws_log() {
+        let mut hm = Hackerman::with_language(30, 6, HackerLang::De);
+        let mut canvas = Canvas::new(30, 6, RenderMode::Ascii, ColorMode::TrueColor);
+        for i in 0..30 {
+            hm.update(&mut canvas, 0.1, i as f64 * 0.1);
+        }
+        let text: String = canvas.char_override.iter().collect();
+        assert!(text.contains("PROTOKOLL"));
+        assert!(
+            canvas.char_override[canvas.width * 2 + 2] != '\0',
+            "log text drawn"
+        );
+    }
+
+    #[test]
+    fn from_name_parses_known_languages() {
+        assert_eq!(HackerLang::from_name("es"), Some(HackerLang::Es));
+        assert_eq!(HackerLang::from_name("xx"), None);
+    }
+
+    #[test]
+    fn language_follows_the_param() {
+        let mut hm = Hackerman::new(80, 24, 1.0);
+        let params = |json: &str| serde_json::from_str(json).unwrap();
+        hm.set_params(&params(r#"{"hacker_lang": "de"}"#));
+        assert_eq!(hm.language, HackerLang::De);
+        assert_eq!(hm.nodes[0].label, DE.node_names[0]);
+        // Unknown names keep the current language
+        hm.set_params(&params(r#"{"hacker_lang": "xx"}"#));
+        assert_eq!(hm.language, HackerLang::De);
+        hm.set_params(&params(r#"{"hacker_lang": 1}"#));
+        assert_eq!(hm.language, HackerLang::Es);
+    }
 }
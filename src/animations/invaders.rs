@@ -1,4 +1,4 @@
-use super::Animation;
+use super::{Animation, GameEvent};
 use crate::render::Canvas;
 use rand::RngExt;
 
@@ -30,7 +30,8 @@ pub struct Invaders {
     shoot_timer: f64,
     alien_shoot_timer: f64,
     wave: usize,
-    rng: rand::rngs::ThreadRng,
+    events: Vec<GameEvent>,
+    rng: rand::rngs::StdRng,
 }
 
 impl Invaders {
@@ -48,7 +49,8 @@ impl Invaders {
             shoot_timer: 0.0,
             alien_shoot_timer: 0.0,
             wave: 0,
-            rng: rand::rng(),
+            events: Vec::new(),
+            rng: super::rng(),
         };
         inv.spawn_wave();
         inv
@@ -87,6 +89,16 @@ impl Animation for Invaders {
         self.height = height;
     }
 
+    fn on_time_gap(&mut self) {
+        self.move_timer = 0.0;
+        self.shoot_timer = 0.0;
+        self.alien_shoot_timer = 0.0;
+    }
+
+    fn take_events(&mut self) -> Vec<GameEvent> {
+        std::mem::take(&mut self.events)
+    }
+
     fn update(&mut self, canvas: &mut Canvas, dt: f64, _time: f64) {
         let w = self.width as f64;
         let h = self.height as f64;
@@ -142,6 +154,7 @@ impl Animation for Invaders {
         self.shoot_timer += dt;
         if self.shoot_timer >= 0.4 {
             self.shoot_timer = 0.0;
+            self.events.push(GameEvent::Shot);
             self.bullets.push(Bullet {
                 x: self.player_x,
                 y: player_y - 1.0,
@@ -188,6 +201,7 @@ impl Animation for Invaders {
         }
         for ai in kill_list {
             self.aliens[ai].alive = false;
+            self.events.push(GameEvent::Kill);
         }
 
         // Remove off-screen bullets
@@ -195,6 +209,7 @@ impl Animation for Invaders {
 
         // Check if wave is cleared
         if self.aliens.iter().all(|a| !a.alive) {
+            self.events.push(GameEvent::Complete);
             self.spawn_wave();
         }
 
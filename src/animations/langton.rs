@@ -46,64 +46,145 @@ impl Direction {
     }
 }
 
-/// Langton's Ant cellular automaton showing emergent highway
+/// Most ants `--langton-ants` will spawn.
+pub const MAX_ANTS: usize = 16;
+
+/// Longest turmite rule (one turn per cell state).
+const MAX_RULE_LEN: usize = 16;
+
+/// Turn taken on leaving a cell in a given state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Turn {
+    Left,
+    Right,
+    /// Keep heading
+    None,
+    /// About-face
+    Back,
+}
+
+/// Parse a turmite rule like `RL` or `LLRR`: one of L/R/N/U per cell state.
+fn parse_rule(s: &str) -> Option<Vec<Turn>> {
+    let rule = s
+        .chars()
+        .map(|c| match c.to_ascii_uppercase() {
+            'L' => Some(Turn::Left),
+            'R' => Some(Turn::Right),
+            'N' => Some(Turn::None),
+            'U' => Some(Turn::Back),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+    (2..=MAX_RULE_LEN).contains(&rule.len()).then_some(rule)
+}
+
+/// Clap value parser for `--langton-rule`.
+pub fn parse_rule_arg(s: &str) -> Result<String, String> {
+    parse_rule(s)
+        .map(|_| s.to_ascii_uppercase())
+        .ok_or_else(|| format!("expected 2-{MAX_RULE_LEN} turns from L, R, N, U (e.g. RLR)"))
+}
+
+struct Ant {
+    x: i32,
+    y: i32,
+    dir: Direction,
+    color: (u8, u8, u8),
+}
+
+/// Langton's Ant cellular automaton showing emergent highway, generalized to
+/// several ants and N-state turmite rules
 pub struct Langton {
     width: usize,
     height: usize,
-    grid: Vec<bool>,
-    ant_x: i32,
-    ant_y: i32,
-    ant_dir: Direction,
+    /// Cell states, indexes into `rule`
+    grid: Vec<u8>,
+    rule: Vec<Turn>,
+    ants: Vec<Ant>,
     steps: usize,
     steps_per_frame: usize,
     total_steps: usize,
-    rng: rand::rngs::ThreadRng,
+    rng: rand::rngs::StdRng,
 }
 
 impl Langton {
     pub fn new(width: usize, height: usize, scale: f64) -> Self {
-        let mut rng = rand::rng();
-        let grid = vec![false; width * height];
-        let dir = match rng.random_range(0u8..4) {
-            0 => Direction::Up,
-            1 => Direction::Right,
-            2 => Direction::Down,
-            _ => Direction::Left,
-        };
-        // Randomize start position within central region
-        let ant_x = rng.random_range(width as i32 / 3..width as i32 * 2 / 3);
-        let ant_y = rng.random_range(height as i32 / 3..height as i32 * 2 / 3);
-        Langton {
+        let mut langton = Langton {
             width,
             height,
-            grid,
-            ant_x,
-            ant_y,
-            ant_dir: dir,
+            grid: vec![0; width * height],
+            rule: vec![Turn::Right, Turn::Left],
+            ants: Vec::new(),
             steps: 0,
             steps_per_frame: (100.0 * scale) as usize,
             total_steps: 0,
-            rng: rand::rng(),
+            rng: super::rng(),
+        };
+        langton.spawn_ants(1);
+        langton
+    }
+
+    /// Add or drop ants until there are `count`. New ants start at a random
+    /// spot in the central region; the first is always red.
+    fn spawn_ants(&mut self, count: usize) {
+        self.ants.truncate(count);
+        while self.ants.len() < count {
+            let i = self.ants.len();
+            let dir = match self.rng.random_range(0u8..4) {
+                0 => Direction::Up,
+                1 => Direction::Right,
+                2 => Direction::Down,
+                _ => Direction::Left,
+            };
+            // Randomize start position within central region
+            let (w, h) = (self.width as i32, self.height as i32);
+            let x = self.rng.random_range(w / 3..(w * 2 / 3).max(w / 3 + 1));
+            let y = self.rng.random_range(h / 3..(h * 2 / 3).max(h / 3 + 1));
+            let color = if i == 0 {
+                (255, 50, 50)
+            } else {
+                hsv_to_rgb((i as f64 * 0.618_034).fract(), 0.8, 1.0)
+            };
+            self.ants.push(Ant { x, y, dir, color });
         }
     }
 
     fn reset(&mut self) {
-        self.grid = vec![false; self.width * self.height];
-        self.ant_x = self
-            .rng
-            .random_range(self.width as i32 / 3..self.width as i32 * 2 / 3);
-        self.ant_y = self
-            .rng
-            .random_range(self.height as i32 / 3..self.height as i32 * 2 / 3);
-        self.ant_dir = match self.rng.random_range(0u8..4) {
-            0 => Direction::Up,
-            1 => Direction::Right,
-            2 => Direction::Down,
-            _ => Direction::Left,
-        };
+        self.grid = vec![0; self.width * self.height];
+        let count = self.ants.len();
+        self.ants.clear();
+        self.spawn_ants(count);
         self.steps = 0;
         self.total_steps = 0;
     }
+
+    /// Move every ant once: turn by the rule for the cell's state, advance
+    /// the cell to the next state, step forward.
+    fn step(&mut self) {
+        let states = self.rule.len() as u8;
+        for ant in &mut self.ants {
+            let ax = ant.x as usize;
+            let ay = ant.y as usize;
+
+            if ax < self.width && ay < self.height {
+                let idx = ay * self.width + ax;
+                let state = self.grid[idx];
+                ant.dir = match self.rule[state as usize] {
+                    Turn::Left => ant.dir.turn_left(),
+                    Turn::Right => ant.dir.turn_right(),
+                    Turn::None => ant.dir,
+                    Turn::Back => ant.dir.turn_right().turn_right(),
+                };
+                self.grid[idx] = (state + 1) % states;
+            }
+
+            // Move forward, wrapping around
+            ant.x = (ant.x + ant.dir.dx()).rem_euclid(self.width as i32);
+            ant.y = (ant.y + ant.dir.dy()).rem_euclid(self.height as i32);
+        }
+        self.steps += 1;
+        self.total_steps += 1;
+    }
 }
 
 impl Animation for Langton {
@@ -117,80 +198,66 @@ impl Animation for Langton {
         self.reset();
     }
 
+    fn set_params(&mut self, params: &crate::external::ExternalParams) {
+        if let Some(v) = params.get("langton_ants") {
+            let count = (v.round() as usize).clamp(1, MAX_ANTS);
+            if count != self.ants.len() {
+                self.spawn_ants(count);
+            }
+        }
+        if let Some(rule) = params.get_str("langton_rule").and_then(parse_rule)
+            && rule != self.rule
+        {
+            self.rule = rule;
+            self.reset();
+        }
+    }
+
+    fn supported_params(&self) -> &'static [(&'static str, f64, f64)] {
+        &[("langton_ants", 1.0, MAX_ANTS as f64)]
+    }
+
     fn update(&mut self, canvas: &mut Canvas, _dt: f64, _time: f64) {
-        // Reset if ant has been going for a very long time
+        // Reset if ants have been going for a very long time
         if self.total_steps > self.width * self.height * 3 {
             self.reset();
         }
 
         // Simulate steps
         for _ in 0..self.steps_per_frame {
-            let ax = self.ant_x as usize;
-            let ay = self.ant_y as usize;
-
-            if ax < self.width && ay < self.height {
-                let idx = ay * self.width + ax;
-                let cell = self.grid[idx];
-
-                if cell {
-                    // On black: turn left, flip to white
-                    self.ant_dir = self.ant_dir.turn_left();
-                    self.grid[idx] = false;
-                } else {
-                    // On white: turn right, flip to black
-                    self.ant_dir = self.ant_dir.turn_right();
-                    self.grid[idx] = true;
-                }
-            }
-
-            // Move forward
-            self.ant_x += self.ant_dir.dx();
-            self.ant_y += self.ant_dir.dy();
-
-            // Wrap around
-            if self.ant_x < 0 {
-                self.ant_x += self.width as i32;
-            }
-            if self.ant_x >= self.width as i32 {
-                self.ant_x -= self.width as i32;
-            }
-            if self.ant_y < 0 {
-                self.ant_y += self.height as i32;
-            }
-            if self.ant_y >= self.height as i32 {
-                self.ant_y -= self.height as i32;
-            }
-
-            self.steps += 1;
-            self.total_steps += 1;
+            self.step();
         }
 
-        // Render
+        // Render: hue follows position, shifted by state for multi-state rules
         canvas.clear();
+        let shade_step = 1.0 / (self.rule.len() - 1) as f64;
         for y in 0..self.height.min(canvas.height) {
             for x in 0..self.width.min(canvas.width) {
-                if self.grid[y * self.width + x] {
-                    // Colored based on position for visual interest
+                let state = self.grid[y * self.width + x];
+                if state > 0 {
                     let hue =
                         ((x as f64 / self.width as f64) + (y as f64 / self.height as f64)) * 0.5;
+                    let hue = (hue + (state - 1) as f64 * shade_step).fract();
                     let (r, g, b) = hsv_to_rgb(hue, 0.7, 0.8);
                     canvas.set_colored(x, y, 0.7, r, g, b);
                 }
             }
         }
 
-        // Draw ant
-        let ax = self.ant_x as usize;
-        let ay = self.ant_y as usize;
-        if ax < canvas.width && ay < canvas.height {
-            canvas.set_colored(ax, ay, 1.0, 255, 50, 50);
-        }
-        // Ant glow
-        for &(ox, oy) in &[(1i32, 0i32), (-1, 0), (0, 1), (0, -1)] {
-            let gx = (self.ant_x + ox) as usize;
-            let gy = (self.ant_y + oy) as usize;
-            if gx < canvas.width && gy < canvas.height {
-                canvas.set_colored(gx, gy, 0.5, 255, 100, 50);
+        for ant in &self.ants {
+            let (r, g, b) = ant.color;
+            let ax = ant.x as usize;
+            let ay = ant.y as usize;
+            if ax < canvas.width && ay < canvas.height {
+                canvas.set_colored(ax, ay, 1.0, r, g, b);
+            }
+            // Ant glow
+            for &(ox, oy) in &[(1i32, 0i32), (-1, 0), (0, 1), (0, -1)] {
+                let gx = (ant.x + ox) as usize;
+                let gy = (ant.y + oy) as usize;
+                if gx < canvas.width && gy < canvas.height {
+                    canvas.set_colored(gx, gy, 0.5, r, g.saturating_add(50), b);
+                }
             }
         }
     }
@@ -214,3 +281,50 @@ fn hsv_to_rgb(h: f64, s: f64, v: f64) -> (u8, u8, u8) {
         ((b + m) * 255.0) as u8,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_turmite_rules() {
+        assert_eq!(parse_rule("rl"), Some(vec![Turn::Right, Turn::Left]));
+        assert_eq!(parse_rule("RLR").map(|r| r.len()), Some(3));
+        for bad in ["", "R", "RLX", &"RL".repeat(9)] {
+            assert_eq!(parse_rule(bad), None, "{bad:?}");
+        }
+    }
+
+    #[test]
+    fn cells_cycle_through_every_rule_state() {
+        let mut langton = Langton::new(20, 20, 1.0);
+        langton.rule = parse_rule("NNN").unwrap();
+        let (x, y) = (langton.ants[0].x, langton.ants[0].y);
+        langton.ants[0].dir = Direction::Up;
+        // Straight up a 20-high torus: back on the start cell every 20 steps
+        let mut seen = Vec::new();
+        for _ in 0..3 {
+            for _ in 0..20 {
+                langton.step();
+            }
+            seen.push(langton.grid[y as usize * 20 + x as usize]);
+        }
+        assert_eq!((langton.ants[0].x, langton.ants[0].y), (x, y));
+        assert_eq!(seen, vec![1, 2, 0]);
+    }
+
+    #[test]
+    fn ant_count_grows_and_shrinks_without_clearing_the_grid() {
+        let mut langton = Langton::new(20, 20, 1.0);
+        langton.step();
+        let mut params = crate::external::ExternalParams::default();
+        params.extra.insert("langton_ants".into(), 3.0.into());
+        langton.set_params(&params);
+        assert_eq!(langton.ants.len(), 3);
+        assert_eq!(langton.ants[0].color, (255, 50, 50));
+        assert_eq!(langton.grid.iter().filter(|&&s| s > 0).count(), 1);
+        params.extra.insert("langton_ants".into(), 1.0.into());
+        langton.set_params(&params);
+        assert_eq!(langton.ants.len(), 1);
+    }
+}
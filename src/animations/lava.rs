@@ -1,7 +1,82 @@
 use super::Animation;
+use crate::generators::{ColorGradient, ColorStop, NamedParam, named_param};
+use crate::palettes::Palette;
 use crate::render::Canvas;
 use rand::RngExt;
 
+/// Default metaball field iso-level.
+const DEFAULT_THRESHOLD: f64 = 0.3;
+
+/// Color ramp over blob heat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LavaTheme {
+    /// Original red/orange lamp
+    Classic,
+    Blue,
+    Green,
+    Acid,
+}
+
+named_param!(LavaTheme {
+    Classic => "classic",
+    Blue => "blue",
+    Green => "green",
+    Acid => "acid",
+});
+
+impl LavaTheme {
+    /// Heat gradient from near-black to the hot core. Repeated `t` values
+    /// are hard color steps between bands.
+    fn gradient(self) -> ColorGradient {
+        let stops: &[(f64, u8, u8, u8)] = match self {
+            LavaTheme::Classic => &[
+                (0.0, 0, 0, 0),
+                // Very dark red / maroon
+                (0.15, 20, 0, 0),
+                (0.4, 60, 0, 0),
+                // Deep red
+                (0.4, 60, 5, 0),
+                // Bright red-orange
+                (0.7, 180, 30, 0),
+                (0.92, 255, 120, 0),
+                // Tiny hot orange-yellow core (no white!)
+                (0.92, 255, 120, 20),
+                (1.0, 255, 200, 60),
+            ],
+            LavaTheme::Blue => &[
+                (0.0, 0, 0, 0),
+                (0.15, 0, 0, 25),
+                (0.4, 0, 10, 80),
+                (0.7, 20, 70, 200),
+                (0.92, 60, 170, 255),
+                (1.0, 150, 230, 255),
+            ],
+            LavaTheme::Green => &[
+                (0.0, 0, 0, 0),
+                (0.15, 0, 20, 0),
+                (0.4, 0, 70, 10),
+                (0.7, 30, 180, 30),
+                (0.92, 130, 255, 60),
+                (1.0, 210, 255, 120),
+            ],
+            LavaTheme::Acid => &[
+                (0.0, 0, 0, 0),
+                (0.15, 25, 0, 30),
+                (0.4, 90, 0, 110),
+                (0.7, 230, 20, 180),
+                (0.92, 200, 255, 40),
+                (1.0, 240, 255, 150),
+            ],
+        };
+        ColorGradient::new(
+            stops
+                .iter()
+                .map(|&(t, r, g, b)| ColorStop { t, r, g, b })
+                .collect(),
+        )
+    }
+}
+
 struct Blob {
     x: f64,
     y: f64,
@@ -15,12 +90,19 @@ pub struct Lava {
     width: usize,
     height: usize,
     blobs: Vec<Blob>,
-    rng: rand::rngs::ThreadRng,
+    theme: LavaTheme,
+    /// Replaces the theme unless `classic`
+    palette: Palette,
+    gradient: ColorGradient,
+    /// Field level where blob surfaces sit; lower values bridge blobs
+    /// from farther apart, higher values keep them separate.
+    threshold: f64,
+    rng: rand::rngs::StdRng,
 }
 
 impl Lava {
     pub fn new(width: usize, height: usize, scale: f64) -> Self {
-        let mut rng = rand::rng();
+        let mut rng = super::rng();
         let count = (8.0 * scale) as usize;
         let w = width as f64;
         let h = height as f64;
@@ -37,9 +119,29 @@ impl Lava {
             width,
             height,
             blobs,
-            rng: rand::rng(),
+            theme: LavaTheme::Classic,
+            palette: Palette::Classic,
+            gradient: LavaTheme::Classic.gradient(),
+            threshold: DEFAULT_THRESHOLD,
+            rng: super::rng(),
         }
     }
+
+    fn set_colors(&mut self, theme: LavaTheme, palette: Palette) {
+        if (theme, palette) != (self.theme, self.palette) {
+            self.theme = theme;
+            self.palette = palette;
+            self.gradient = match palette {
+                Palette::Classic => theme.gradient(),
+                _ => palette.gradient(),
+            };
+        }
+    }
+
+    fn color(&self, v: f64, time: f64, x: f64, y: f64) -> (u8, u8, u8) {
+        let shift = ((x * 0.02 + y * 0.01 + time * 0.2).sin() * 0.5 + 0.5) * 0.2;
+        self.gradient.sample(v + shift)
+    }
 }
 
 impl Animation for Lava {
@@ -47,6 +149,23 @@ impl Animation for Lava {
         "lava"
     }
 
+    fn set_params(&mut self, params: &crate::external::ExternalParams) {
+        let theme = LavaTheme::from_params(params, "lava_theme");
+        let palette = Palette::from_params(params, "fire_palette");
+        self.set_colors(theme.unwrap_or(self.theme), palette.unwrap_or(self.palette));
+        if let Some(v) = params.get("lava_threshold") {
+            self.threshold = v.clamp(0.1, 1.0);
+        }
+    }
+
+    fn supported_params(&self) -> &'static [(&'static str, f64, f64)] {
+        &[
+            ("lava_theme", 0.0, 3.0),
+            ("lava_threshold", 0.1, 1.0),
+            ("fire_palette", 0.0, 4.0),
+        ]
+    }
+
     fn on_resize(&mut self, width: usize, height: usize) {
         self.width = width;
         self.height = height;
@@ -114,9 +233,9 @@ impl Animation for Lava {
                 let bg = ((fx * 0.05 + time * 0.3).sin() * 0.1 + 0.1).max(0.0);
                 field += bg;
 
-                if field > 0.3 {
-                    let v = ((field - 0.3) / 1.5).clamp(0.0, 1.0);
-                    let (r, g, b) = lava_color(v, time, fx, fy);
+                if field > self.threshold {
+                    let v = ((field - self.threshold) / 1.5).clamp(0.0, 1.0);
+                    let (r, g, b) = self.color(v, time, fx, fy);
                     canvas.set_colored(x, y, v, r, g, b);
                 }
             }
@@ -124,28 +243,69 @@ impl Animation for Lava {
     }
 }
 
-fn lava_color(v: f64, time: f64, x: f64, y: f64) -> (u8, u8, u8) {
-    let shift = ((x * 0.02 + y * 0.01 + time * 0.2).sin() * 0.5 + 0.5) * 0.2;
-    let t = (v + shift).clamp(0.0, 1.0);
-    if t > 0.92 {
-        // Tiny hot orange-yellow core (no white!)
-        let f = (t - 0.92) / 0.08;
-        (255, (120.0 + 80.0 * f) as u8, (20.0 + 40.0 * f) as u8)
-    } else if t > 0.7 {
-        // Bright red-orange
-        let f = (t - 0.7) / 0.22;
-        ((180.0 + 75.0 * f) as u8, (30.0 + 90.0 * f) as u8, 0)
-    } else if t > 0.4 {
-        // Deep red
-        let f = (t - 0.4) / 0.3;
-        ((60.0 + 120.0 * f) as u8, (5.0 + 25.0 * f) as u8, 0)
-    } else if t > 0.15 {
-        // Very dark red / maroon
-        let f = (t - 0.15) / 0.25;
-        ((20.0 + 40.0 * f) as u8, 0, 0)
-    } else {
-        // Near black with faint red
-        let f = t / 0.15;
-        ((f * 20.0) as u8, 0, 0)
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::external::ExternalParams;
+
+    /// The hand-written ramp `classic` replaced.
+    fn original_color(t: f64) -> (u8, u8, u8) {
+        if t > 0.92 {
+            let f = (t - 0.92) / 0.08;
+            (255, (120.0 + 80.0 * f) as u8, (20.0 + 40.0 * f) as u8)
+        } else if t > 0.7 {
+            let f = (t - 0.7) / 0.22;
+            ((180.0 + 75.0 * f) as u8, (30.0 + 90.0 * f) as u8, 0)
+        } else if t > 0.4 {
+            let f = (t - 0.4) / 0.3;
+            ((60.0 + 120.0 * f) as u8, (5.0 + 25.0 * f) as u8, 0)
+        } else if t > 0.15 {
+            let f = (t - 0.15) / 0.25;
+            ((20.0 + 40.0 * f) as u8, 0, 0)
+        } else {
+            ((t / 0.15 * 20.0) as u8, 0, 0)
+        }
+    }
+
+    #[test]
+    fn classic_theme_matches_original_ramp() {
+        let gradient = LavaTheme::Classic.gradient();
+        for i in 0..=200 {
+            let t = i as f64 / 200.0;
+            let (a, b) = (gradient.sample(t), original_color(t));
+            let close = |x: u8, y: u8| x.abs_diff(y) <= 1;
+            assert!(
+                close(a.0, b.0) && close(a.1, b.1) && close(a.2, b.2),
+                "t={t}: {a:?} vs {b:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn params_set_theme_and_clamp_threshold() {
+        let mut lava = Lava::new(40, 20, 1.0);
+        let params: ExternalParams =
+            serde_json::from_str(r#"{"lava_theme": "acid", "lava_threshold": 5.0}"#).unwrap();
+        lava.set_params(&params);
+        assert_eq!(lava.theme, LavaTheme::Acid);
+        assert_eq!(lava.threshold, 1.0);
+    }
+
+    #[test]
+    fn fire_palette_overrides_theme_until_classic() {
+        let mut lava = Lava::new(40, 20, 1.0);
+        let params = |json: &str| serde_json::from_str::<ExternalParams>(json).unwrap();
+        lava.set_params(&params(
+            r#"{"lava_theme": "blue", "fire_palette": "toxic"}"#,
+        ));
+        assert_eq!(
+            lava.gradient.sample(1.0),
+            Palette::Toxic.gradient().sample(1.0)
+        );
+        lava.set_params(&params(r#"{"fire_palette": "classic"}"#));
+        assert_eq!(
+            lava.gradient.sample(1.0),
+            LavaTheme::Blue.gradient().sample(1.0)
+        );
     }
 }
@@ -1,8 +1,175 @@
 use super::Animation;
+use crate::generators::{NamedParam, named_param};
 use crate::render::Canvas;
 use rand::RngExt;
+use std::collections::VecDeque;
+use std::hash::{DefaultHasher, Hash, Hasher};
 
-/// Conway's Game of Life at sub-cell resolution
+/// Generations of population kept for the graph.
+const HISTORY_LEN: usize = 512;
+/// Longest oscillator period detected (board hashes remembered).
+const MAX_PERIOD: usize = 15;
+/// Seconds a detected steady state stays on screen before reseeding.
+const STEADY_HOLD: f64 = 2.0;
+/// Terminal rows used by the population overlay (1 text row + graph).
+const GRAPH_ROWS: usize = 4;
+
+/// Why the board was flagged for a reseed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SteadyState {
+    Extinct,
+    /// Board repeats every `n` generations (1 = still life).
+    Period(usize),
+    /// Population flat for a long time (e.g. lone gliders).
+    Stagnant,
+}
+
+/// Life-like birth/survival rule, written as a `B3/S23` rulestring.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    /// Neighbor counts that bring a dead cell to life
+    pub birth: Vec<u8>,
+    /// Neighbor counts that keep a live cell alive
+    pub survive: Vec<u8>,
+}
+
+impl Rule {
+    /// Named presets accepted wherever a rulestring is.
+    pub const PRESETS: &'static [(&'static str, &'static str)] = &[
+        ("conway", "B3/S23"),
+        ("highlife", "B36/S23"),
+        ("daynight", "B3678/S34678"),
+        ("seeds", "B2/S"),
+    ];
+
+    pub fn conway() -> Self {
+        Rule {
+            birth: vec![3],
+            survive: vec![2, 3],
+        }
+    }
+
+    /// Parse a preset name or a `B…/S…` rulestring (case-insensitive, either
+    /// half may come first). Counts must be 0-8.
+    pub fn parse(s: &str) -> Option<Self> {
+        let s = s.trim();
+        let s = Self::PRESETS
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(s))
+            .map_or(s, |(_, rule)| rule);
+        let (mut birth, mut survive) = (None, None);
+        for part in s.split('/') {
+            let mut chars = part.chars();
+            let slot = match chars.next()?.to_ascii_lowercase() {
+                'b' => &mut birth,
+                's' => &mut survive,
+                _ => return None,
+            };
+            if slot.is_some() {
+                return None;
+            }
+            let mut counts = chars
+                .map(|c| c.to_digit(10).filter(|&d| d <= 8).map(|d| d as u8))
+                .collect::<Option<Vec<u8>>>()?;
+            counts.sort_unstable();
+            counts.dedup();
+            *slot = Some(counts);
+        }
+        Some(Rule {
+            birth: birth?,
+            survive: survive?,
+        })
+    }
+
+    /// Lookup table indexed by `[alive][neighbors]`.
+    fn table(&self) -> [[bool; 9]; 2] {
+        let mut table = [[false; 9]; 2];
+        for &n in &self.birth {
+            table[0][n as usize] = true;
+        }
+        for &n in &self.survive {
+            table[1][n as usize] = true;
+        }
+        table
+    }
+}
+
+/// Clap value parser for `--life-rule`.
+pub fn parse_rule_arg(s: &str) -> Result<String, String> {
+    Rule::parse(s).map(|_| s.to_string()).ok_or_else(|| {
+        let names: Vec<_> = Rule::PRESETS.iter().map(|(n, _)| *n).collect();
+        format!(
+            "expected a rulestring like B36/S23 or one of: {}",
+            names.join(", ")
+        )
+    })
+}
+
+/// Starting board: random soup or a named pattern on a cleared grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LifePattern {
+    Random,
+    Glider,
+    GosperGun,
+    Pulsar,
+    RPentomino,
+}
+
+named_param!(LifePattern {
+    Random => "random",
+    Glider => "glider",
+    GosperGun => "gosper-gun",
+    Pulsar => "pulsar",
+    RPentomino => "rpentomino",
+});
+
+impl LifePattern {
+    /// Live cells relative to the pattern's top-left corner.
+    fn cells(self) -> &'static [(i32, i32)] {
+        match self {
+            LifePattern::Random => &[],
+            LifePattern::Glider => patterns::GLIDER,
+            LifePattern::GosperGun => patterns::GOSPER_GUN,
+            LifePattern::Pulsar => patterns::PULSAR,
+            LifePattern::RPentomino => patterns::R_PENTOMINO,
+        }
+    }
+}
+
+/// Classic patterns as live-cell offsets from their top-left corner.
+mod patterns {
+    pub const GLIDER: &[(i32, i32)] = &[(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)];
+
+    pub const R_PENTOMINO: &[(i32, i32)] = &[(1, 0), (2, 0), (0, 1), (1, 1), (1, 2)];
+
+    /// Period-3 oscillator, 13x13.
+    #[rustfmt::skip]
+    pub const PULSAR: &[(i32, i32)] = &[
+        (2, 0), (3, 0), (4, 0), (8, 0), (9, 0), (10, 0),
+        (0, 2), (5, 2), (7, 2), (12, 2),
+        (0, 3), (5, 3), (7, 3), (12, 3),
+        (0, 4), (5, 4), (7, 4), (12, 4),
+        (2, 5), (3, 5), (4, 5), (8, 5), (9, 5), (10, 5),
+        (2, 7), (3, 7), (4, 7), (8, 7), (9, 7), (10, 7),
+        (0, 8), (5, 8), (7, 8), (12, 8),
+        (0, 9), (5, 9), (7, 9), (12, 9),
+        (0, 10), (5, 10), (7, 10), (12, 10),
+        (2, 12), (3, 12), (4, 12), (8, 12), (9, 12), (10, 12),
+    ];
+
+    /// Gosper glider gun, 36x9; fires a south-east glider every 30 generations.
+    #[rustfmt::skip]
+    pub const GOSPER_GUN: &[(i32, i32)] = &[
+        (0, 4), (0, 5), (1, 4), (1, 5),
+        (10, 4), (10, 5), (10, 6), (11, 3), (11, 7), (12, 2), (12, 8), (13, 2), (13, 8),
+        (14, 5), (15, 3), (15, 7), (16, 4), (16, 5), (16, 6), (17, 5),
+        (20, 2), (20, 3), (20, 4), (21, 2), (21, 3), (21, 4), (22, 1), (22, 5),
+        (24, 0), (24, 1), (24, 5), (24, 6),
+        (34, 2), (34, 3), (35, 2), (35, 3),
+    ];
+}
+
+/// Conway's Game of Life (or another Life-like rule) at sub-cell resolution
 pub struct GameOfLife {
     width: usize,
     height: usize,
@@ -13,74 +180,150 @@ pub struct GameOfLife {
     // Stagnation detection: track population over time
     prev_pop: usize,
     stable_count: u32,
-    // Track previous state hash for oscillator detection
-    prev_hash: u64,
-    hash_stable_count: u32,
-    rng: rand::rngs::ThreadRng,
+    // Board hashes of recent generations for still-life/oscillator detection
+    recent_hashes: VecDeque<u64>,
+    /// Detected steady state and seconds left before reseeding
+    steady: Option<(SteadyState, f64)>,
+    population: VecDeque<usize>,
+    show_graph: bool,
+    /// Toroidal universe (edges wrap) vs. hard-bounded (outside is dead)
+    wrap: bool,
+    rule: Rule,
+    pattern: LifePattern,
+    rng: rand::rngs::StdRng,
 }
 
 impl GameOfLife {
     #[allow(unused_variables)]
     pub fn new(width: usize, height: usize, _scale: f64) -> Self {
-        let mut rng = rand::rng();
-        let size = width * height;
-        let density = rng.random_range(0.2..0.5); // vary initial density
-        let cells: Vec<bool> = (0..size)
-            .map(|_| rng.random_range(0.0..1.0) > (1.0 - density))
-            .collect();
-        let pop = cells.iter().filter(|&&c| c).count();
-        GameOfLife {
+        Self::with_rng(width, height, super::rng())
+    }
+
+    /// A fresh board drawing from `rng`; resizing seeds it from the old
+    /// stream so a `--seed` run stays reproducible.
+    fn with_rng(width: usize, height: usize, rng: rand::rngs::StdRng) -> Self {
+        let mut life = GameOfLife {
             width,
             height,
-            cells,
+            cells: Vec::new(),
             generation: 0,
             accumulator: 0.0,
             step_interval: 0.08,
-            prev_pop: pop,
+            prev_pop: 0,
             stable_count: 0,
-            prev_hash: 0,
-            hash_stable_count: 0,
-            rng: rand::rng(),
+            recent_hashes: VecDeque::with_capacity(MAX_PERIOD),
+            steady: None,
+            population: VecDeque::with_capacity(HISTORY_LEN),
+            show_graph: false,
+            wrap: true,
+            rule: Rule::conway(),
+            pattern: LifePattern::Random,
+            rng,
+        };
+        life.reseed();
+        life
+    }
+
+    /// Fill the board with a fresh random soup. Population history is kept
+    /// so the graph shows the reseed.
+    fn reseed(&mut self) {
+        if self.pattern == LifePattern::Random {
+            let density = self.rng.random_range(0.2..0.5); // vary initial density
+            self.cells = (0..self.width * self.height)
+                .map(|_| self.rng.random_range(0.0..1.0) > (1.0 - density))
+                .collect();
+        } else {
+            self.cells = vec![false; self.width * self.height];
+            self.place_pattern();
+        }
+        self.generation = 0;
+        self.prev_pop = self.cells.iter().filter(|&&c| c).count();
+        self.stable_count = 0;
+        self.recent_hashes.clear();
+        self.steady = None;
+    }
+
+    /// Stamp the named pattern onto the board: centered, except the gun,
+    /// which sits top-left so its gliders have the whole board to cross.
+    fn place_pattern(&mut self) {
+        let cells = self.pattern.cells();
+        let (w, h) = cells
+            .iter()
+            .fold((0, 0), |(w, h), &(x, y)| (w.max(x + 1), h.max(y + 1)));
+        let (ox, oy) = if self.pattern == LifePattern::GosperGun {
+            (2, 2)
+        } else {
+            ((self.width as i32 - w) / 2, (self.height as i32 - h) / 2)
+        };
+        for &(dx, dy) in cells {
+            let (x, y) = (ox + dx, oy + dy);
+            if x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height {
+                self.cells[y as usize * self.width + x as usize] = true;
+            }
         }
     }
 
     fn step(&mut self) {
+        let table = self.rule.table();
         let mut next = vec![false; self.width * self.height];
         for y in 0..self.height {
             for x in 0..self.width {
                 let neighbors = self.count_neighbors(x, y);
                 let alive = self.cells[y * self.width + x];
-                next[y * self.width + x] =
-                    matches!((alive, neighbors), (true, 2) | (true, 3) | (false, 3));
+                next[y * self.width + x] = table[alive as usize][neighbors as usize];
             }
         }
         self.cells = next;
         self.generation += 1;
 
-        // Check for stagnation via population stability
         let pop = self.cells.iter().filter(|&&c| c).count();
-        if pop == self.prev_pop || pop == 0 {
+        if self.population.len() == HISTORY_LEN {
+            self.population.pop_front();
+        }
+        self.population.push_back(pop);
+
+        // Check for stagnation via population stability
+        if pop == self.prev_pop {
             self.stable_count += 1;
         } else {
             self.stable_count = 0;
         }
         self.prev_pop = pop;
 
-        // Check for oscillators via simple hash
+        // Still lifes and oscillators repeat an earlier board exactly
         let hash = self.cell_hash();
-        if hash == self.prev_hash {
-            self.hash_stable_count += 1;
-        } else {
-            self.hash_stable_count = 0;
+        let period = self
+            .recent_hashes
+            .iter()
+            .rev()
+            .position(|&h| h == hash)
+            .map(|i| i + 1);
+        if self.recent_hashes.len() == MAX_PERIOD {
+            self.recent_hashes.pop_front();
         }
-        self.prev_hash = hash;
+        self.recent_hashes.push_back(hash);
 
-        // Full reset if stagnant (population unchanged for 60 steps, or oscillator, or dead)
-        if self.stable_count > 60 || self.hash_stable_count > 10 || pop == 0 {
-            *self = GameOfLife::new(self.width, self.height, 1.0);
+        // Flag for reseed when dead, repeating, or population unchanged for 60 steps
+        if self.steady.is_none() {
+            let state = if pop == 0 {
+                Some(SteadyState::Extinct)
+            } else if self.pattern != LifePattern::Random {
+                // Named patterns are there to be watched; only extinction resets them
+                None
+            } else if let Some(n) = period {
+                Some(SteadyState::Period(n))
+            } else if self.stable_count > 60 {
+                Some(SteadyState::Stagnant)
+            } else {
+                None
+            };
+            self.steady = state.map(|s| (s, STEADY_HOLD));
         }
         // Inject chaos periodically to keep things interesting
-        else if self.generation.is_multiple_of(300) {
+        if self.steady.is_none()
+            && self.pattern == LifePattern::Random
+            && self.generation.is_multiple_of(300)
+        {
             // Spawn a random pattern (glider gun, r-pentomino, etc)
             let cx = self
                 .rng
@@ -89,8 +332,7 @@ impl GameOfLife {
                 .rng
                 .random_range(10..self.height.saturating_sub(10).max(11));
             // R-pentomino — classic long-lived pattern
-            let pattern = [(0, 0), (1, 0), (-1, 1), (0, 1), (0, 2)];
-            for (dx, dy) in pattern {
+            for &(dx, dy) in patterns::R_PENTOMINO {
                 let x = (cx as i32 + dx).rem_euclid(self.width as i32) as usize;
                 let y = (cy as i32 + dy).rem_euclid(self.height as i32) as usize;
                 self.cells[y * self.width + x] = true;
@@ -99,8 +341,9 @@ impl GameOfLife {
     }
 
     fn cell_hash(&self) -> u64 {
-        // Just use population as cheap "hash" — combined with prev_pop check catches most cases
-        self.prev_pop as u64
+        let mut hasher = DefaultHasher::new();
+        self.cells.hash(&mut hasher);
+        hasher.finish()
     }
 
     fn count_neighbors(&self, x: usize, y: usize) -> u8 {
@@ -110,8 +353,17 @@ impl GameOfLife {
                 if dx == 0 && dy == 0 {
                     continue;
                 }
-                let nx = (x as i32 + dx).rem_euclid(self.width as i32) as usize;
-                let ny = (y as i32 + dy).rem_euclid(self.height as i32) as usize;
+                let (nx, ny) = (x as i32 + dx, y as i32 + dy);
+                let (nx, ny) = if self.wrap {
+                    (
+                        nx.rem_euclid(self.width as i32) as usize,
+                        ny.rem_euclid(self.height as i32) as usize,
+                    )
+                } else if nx < 0 || ny < 0 || nx >= self.width as i32 || ny >= self.height as i32 {
+                    continue;
+                } else {
+                    (nx as usize, ny as usize)
+                };
                 if self.cells[ny * self.width + nx] {
                     count += 1;
                 }
@@ -119,6 +371,62 @@ impl GameOfLife {
         }
         count
     }
+
+    /// Bottom strip: generation/population readout over a scrolling line
+    /// graph of population, one pixel column per generation.
+    fn draw_graph(&self, canvas: &mut Canvas, strip_top: usize, px_per_row: usize) {
+        for y in strip_top..canvas.height {
+            for x in 0..canvas.width {
+                canvas.set_colored(x, y, 0.0, 0, 0, 0);
+            }
+        }
+        canvas.draw_line(
+            0.0,
+            strip_top as f64,
+            canvas.width as f64 - 1.0,
+            strip_top as f64,
+            0.25,
+            40,
+            120,
+            40,
+        );
+
+        let pop = self.population.back().copied().unwrap_or(self.prev_pop);
+        let mut label = format!("GEN {}  POP {}", self.generation, pop);
+        let (flag, color) = match self.steady {
+            Some((SteadyState::Extinct, _)) => (" EXTINCT - reseeding".to_string(), (255, 80, 80)),
+            Some((SteadyState::Period(1), _)) => {
+                (" STILL LIFE - reseeding".to_string(), (255, 200, 50))
+            }
+            Some((SteadyState::Period(n), _)) => {
+                (format!(" OSCILLATOR p{n} - reseeding"), (255, 200, 50))
+            }
+            Some((SteadyState::Stagnant, _)) => {
+                (" STAGNANT - reseeding".to_string(), (255, 200, 50))
+            }
+            None => (String::new(), (120, 255, 120)),
+        };
+        label.push_str(&flag);
+        canvas.draw_text(0, strip_top + px_per_row, &label, color.0, color.1, color.2);
+
+        // Graph below the text row
+        let graph_top = strip_top + px_per_row * 2;
+        let graph_bottom = canvas.height.saturating_sub(1);
+        if graph_bottom <= graph_top {
+            return;
+        }
+        let span = (graph_bottom - graph_top) as f64;
+        let start = self.population.len().saturating_sub(canvas.width);
+        let shown: Vec<usize> = self.population.iter().skip(start).copied().collect();
+        let max_pop = shown.iter().copied().max().unwrap_or(0).max(1) as f64;
+        let point =
+            |i: usize, p: usize| (i as f64, graph_bottom as f64 - p as f64 / max_pop * span);
+        for (i, pair) in shown.windows(2).enumerate() {
+            let (x0, y0) = point(i, pair[0]);
+            let (x1, y1) = point(i + 1, pair[1]);
+            canvas.draw_line(x0, y0, x1, y1, 0.9, 80, 220, 255);
+        }
+    }
 }
 
 impl Animation for GameOfLife {
@@ -131,7 +439,50 @@ impl Animation for GameOfLife {
     }
 
     fn on_resize(&mut self, width: usize, height: usize) {
-        *self = GameOfLife::new(width, height, 1.0);
+        let (show_graph, wrap, rule) = (self.show_graph, self.wrap, self.rule.clone());
+        let pattern = self.pattern;
+        *self = GameOfLife::with_rng(width, height, rand::SeedableRng::from_rng(&mut self.rng));
+        self.show_graph = show_graph;
+        self.wrap = wrap;
+        self.rule = rule;
+        if pattern != LifePattern::Random {
+            self.pattern = pattern;
+            self.reseed();
+        }
+    }
+
+    fn on_time_gap(&mut self) {
+        self.accumulator = 0.0;
+    }
+
+    fn set_params(&mut self, params: &crate::external::ExternalParams) {
+        if let Some(v) = params.get("population_graph") {
+            self.show_graph = v >= 0.5;
+        }
+        if let Some(v) = params.get("life_wrap") {
+            self.wrap = v >= 0.5;
+        }
+        if let Some(rule) = params.get_str("life_rule").and_then(Rule::parse)
+            && rule != self.rule
+        {
+            self.rule = rule;
+            self.recent_hashes.clear();
+            self.stable_count = 0;
+        }
+        if let Some(pattern) = LifePattern::from_params(params, "life_pattern")
+            && pattern != self.pattern
+        {
+            self.pattern = pattern;
+            self.reseed();
+        }
+    }
+
+    fn supported_params(&self) -> &'static [(&'static str, f64, f64)] {
+        &[
+            ("population_graph", 0.0, 1.0),
+            ("life_wrap", 0.0, 1.0),
+            ("life_pattern", 0.0, 4.0),
+        ]
     }
 
     fn update(&mut self, canvas: &mut Canvas, dt: f64, _time: f64) {
@@ -140,6 +491,12 @@ impl Animation for GameOfLife {
             self.step();
             self.accumulator -= self.step_interval;
         }
+        if let Some((_, remaining)) = &mut self.steady {
+            *remaining -= dt;
+            if *remaining <= 0.0 {
+                self.reseed();
+            }
+        }
 
         canvas.clear();
         for y in 0..self.height.min(canvas.height) {
@@ -150,5 +507,157 @@ impl Animation for GameOfLife {
                 }
             }
         }
+
+        if self.show_graph {
+            let (_, rows) = canvas.term_size();
+            if rows > GRAPH_ROWS * 2 {
+                let px_per_row = canvas.height / rows;
+                self.draw_graph(canvas, canvas.height - GRAPH_ROWS * px_per_row, px_per_row);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn board_with(width: usize, height: usize, live: &[(usize, usize)]) -> GameOfLife {
+        let mut life = GameOfLife::new(width, height, 1.0);
+        life.cells.fill(false);
+        for &(x, y) in live {
+            life.cells[y * width + x] = true;
+        }
+        life
+    }
+
+    #[test]
+    fn detects_still_life() {
+        let mut life = board_with(20, 20, &[(5, 5), (6, 5), (5, 6), (6, 6)]);
+        life.step();
+        life.step();
+        assert_eq!(life.steady.map(|s| s.0), Some(SteadyState::Period(1)));
+    }
+
+    #[test]
+    fn detects_blinker_oscillator() {
+        let mut life = board_with(20, 20, &[(5, 5), (6, 5), (7, 5)]);
+        for _ in 0..3 {
+            life.step();
+        }
+        assert_eq!(life.steady.map(|s| s.0), Some(SteadyState::Period(2)));
+    }
+
+    /// Run a south-east glider into the right edge and report whether any
+    /// cell reappeared on the left side of the board.
+    fn glider_reaches_left_side(wrap: bool) -> bool {
+        let glider = [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)];
+        let mut life = board_with(20, 20, &glider.map(|(x, y)| (x + 14, y + 5)));
+        life.wrap = wrap;
+        for _ in 0..24 {
+            life.step();
+        }
+        (0..20).any(|y| (0..5).any(|x| life.cells[y * 20 + x]))
+    }
+
+    #[test]
+    fn glider_wraps_right_edge_only_when_wrap_enabled() {
+        assert!(glider_reaches_left_side(true));
+        assert!(!glider_reaches_left_side(false));
+    }
+
+    #[test]
+    fn reseeds_after_hold_and_keeps_history() {
+        let mut life = board_with(20, 20, &[]);
+        let mut canvas = Canvas::new(
+            20,
+            20,
+            crate::render::RenderMode::Braille,
+            crate::render::ColorMode::TrueColor,
+        );
+        life.update(&mut canvas, 0.1, 0.0);
+        assert_eq!(life.steady.map(|s| s.0), Some(SteadyState::Extinct));
+        life.update(&mut canvas, STEADY_HOLD, 0.1);
+        assert!(life.steady.is_none());
+        assert!(!life.population.is_empty());
+    }
+
+    #[test]
+    fn time_gap_drops_pending_steps() {
+        let mut life = board_with(20, 20, &[(5, 5), (6, 5), (7, 5)]);
+        life.accumulator = life.step_interval * 0.9;
+        life.on_time_gap();
+        let mut canvas = Canvas::new(
+            20,
+            20,
+            crate::render::RenderMode::Braille,
+            crate::render::ColorMode::TrueColor,
+        );
+        life.update(&mut canvas, life.step_interval * 0.5, 0.0);
+        assert_eq!(life.generation, 0);
+    }
+
+    #[test]
+    fn parses_rulestrings_and_presets() {
+        assert_eq!(Rule::parse("conway"), Some(Rule::conway()));
+        assert_eq!(Rule::parse("s23/b3"), Some(Rule::conway()));
+        let highlife = Rule::parse("HighLife").unwrap();
+        assert_eq!((highlife.birth, highlife.survive), (vec![3, 6], vec![2, 3]));
+        assert_eq!(Rule::parse("seeds").unwrap().survive, Vec::<u8>::new());
+        for bad in ["", "B3", "B39/S23", "B3/S23/S1", "X3/S23", "B3/B2"] {
+            assert_eq!(Rule::parse(bad), None, "{bad:?}");
+        }
+    }
+
+    #[test]
+    fn seeds_rule_kills_every_live_cell() {
+        // Under Seeds nothing survives, and a domino births a cell on each side
+        let mut life = board_with(20, 20, &[(10, 10), (11, 10)]);
+        life.rule = Rule::parse("seeds").unwrap();
+        life.step();
+        let live: Vec<usize> = (0..life.cells.len()).filter(|&i| life.cells[i]).collect();
+        assert_eq!(
+            live,
+            vec![9 * 20 + 10, 9 * 20 + 11, 11 * 20 + 10, 11 * 20 + 11]
+        );
+    }
+
+    #[test]
+    fn gosper_gun_fires_a_glider_every_30_generations() {
+        let mut life = GameOfLife::new(80, 40, 1.0);
+        life.pattern = LifePattern::GosperGun;
+        life.reseed();
+        assert_eq!(life.prev_pop, 36);
+        for _ in 0..30 {
+            life.step();
+        }
+        // Gun back in phase plus one glider (5 cells) in flight
+        assert_eq!(life.prev_pop, 41);
+        assert!(life.steady.is_none());
+    }
+
+    #[test]
+    fn pulsar_is_centered_and_not_reseeded() {
+        let mut life = GameOfLife::new(41, 41, 1.0);
+        life.pattern = LifePattern::Pulsar;
+        life.reseed();
+        assert!(life.cells[14 * 41 + 16], "top arm starts at (16, 14)");
+        for _ in 0..9 {
+            life.step();
+        }
+        assert_eq!(life.prev_pop, 48);
+        assert!(life.steady.is_none());
+    }
+
+    #[test]
+    fn pattern_param_takes_a_name_or_an_index() {
+        let params =
+            |json: &str| serde_json::from_str::<crate::external::ExternalParams>(json).unwrap();
+        let mut life = GameOfLife::new(80, 40, 1.0);
+        life.set_params(&params(r#"{"life_pattern": "pulsar"}"#));
+        assert_eq!(life.pattern, LifePattern::Pulsar);
+        life.set_params(&params(r#"{"life_pattern": 1}"#));
+        assert_eq!(life.pattern, LifePattern::Glider);
+        assert_eq!(life.prev_pop, patterns::GLIDER.len());
     }
 }
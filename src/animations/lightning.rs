@@ -15,7 +15,11 @@ pub struct Lightning {
     bolts: Vec<Bolt>,
     spawn_timer: f64,
     flash: f64,
-    rng: rand::rngs::ThreadRng,
+    /// Seconds between strikes, drawn uniformly from this range.
+    pub(crate) interval: (f64, f64),
+    /// Flash level set by each strike; fades at 2.0 per second.
+    pub(crate) flash_peak: f64,
+    rng: rand::rngs::StdRng,
 }
 
 impl Lightning {
@@ -27,7 +31,9 @@ impl Lightning {
             bolts: Vec::new(),
             spawn_timer: 0.0,
             flash: 0.0,
-            rng: rand::rng(),
+            interval: (0.5, 3.0),
+            flash_peak: 0.3,
+            rng: super::rng(),
         }
     }
 
@@ -64,7 +70,7 @@ impl Lightning {
         depth: u32,
         _width: usize,
         height: usize,
-        rng: &mut rand::rngs::ThreadRng,
+        rng: &mut rand::rngs::StdRng,
     ) {
         if depth > 5 || y > target_y {
             return;
@@ -118,36 +124,62 @@ impl Animation for Lightning {
     }
 
     fn update(&mut self, canvas: &mut Canvas, dt: f64, _time: f64) {
-        // Spawn new bolts
+        self.step(dt);
+        canvas.clear();
+        self.draw_flash(canvas, 0.15);
+        self.draw_bolts(canvas);
+    }
+}
+
+impl Lightning {
+    /// Spawn, age, and remove bolts and fade the flash. Returns `true` when a
+    /// new bolt struck this step.
+    pub(crate) fn step(&mut self, dt: f64) -> bool {
+        let mut struck = false;
         self.spawn_timer -= dt;
         if self.spawn_timer <= 0.0 {
             let bolt = self.generate_bolt();
             self.bolts.push(bolt);
-            self.flash = 0.3;
+            self.flash = self.flash_peak;
             // Random interval between bolts
-            self.spawn_timer = self.rng.random_range(0.5..3.0);
+            self.spawn_timer = self.rng.random_range(self.interval.0..self.interval.1);
+            struck = true;
         }
 
         // Fade flash
         self.flash = (self.flash - dt * 2.0).max(0.0);
 
-        canvas.clear();
+        for bolt in &mut self.bolts {
+            bolt.life -= dt;
+            bolt.brightness = (bolt.life * 4.0).clamp(0.0, 1.0);
+        }
+        // Remove dead bolts
+        self.bolts.retain(|b| b.life > 0.0);
+        struck
+    }
 
-        // Draw background flash
-        if self.flash > 0.0 {
-            let flash_brightness = self.flash * 0.15;
-            for y in 0..canvas.height {
-                for x in 0..canvas.width {
+    /// Current flash level (0 when no flash is showing).
+    pub(crate) fn flash(&self) -> f64 {
+        self.flash
+    }
+
+    /// Raise every pixel to at least `flash * strength` in the sky color.
+    pub(crate) fn draw_flash(&self, canvas: &mut Canvas, strength: f64) {
+        if self.flash <= 0.0 {
+            return;
+        }
+        let flash_brightness = self.flash * strength;
+        for y in 0..canvas.height {
+            for x in 0..canvas.width {
+                if canvas.pixels[y * canvas.width + x] < flash_brightness {
                     canvas.set_colored(x, y, flash_brightness, 100, 100, 130);
                 }
             }
         }
+    }
 
-        // Update and draw bolts
-        for bolt in &mut self.bolts {
-            bolt.life -= dt;
-            bolt.brightness = (bolt.life * 4.0).clamp(0.0, 1.0);
-
+    pub(crate) fn draw_bolts(&self, canvas: &mut Canvas) {
+        for bolt in &self.bolts {
             if bolt.brightness < 0.01 {
                 continue;
             }
@@ -168,6 +200,10 @@ impl Animation for Lightning {
                             if gx_pos < canvas.width && gy_pos < canvas.height {
                                 let dist = ((gx * gx + gy * gy) as f64).sqrt();
                                 let glow = bolt.brightness * (1.0 - dist * 0.4).max(0.0);
+                                // Never darken the flash or a brighter core pixel
+                                if glow <= canvas.pixels[gy_pos * canvas.width + gx_pos] {
+                                    continue;
+                                }
                                 let b_val = (200.0 + 55.0 * glow) as u8;
                                 let g_val = (180.0 + 75.0 * glow) as u8;
                                 canvas.set_colored(gx_pos, gy_pos, glow, g_val, g_val, b_val);
@@ -177,8 +213,5 @@ impl Animation for Lightning {
                 }
             }
         }
-
-        // Remove dead bolts
-        self.bolts.retain(|b| b.life > 0.0);
     }
 }
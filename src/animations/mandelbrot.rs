@@ -1,11 +1,46 @@
 use super::Animation;
+use crate::generators::{color_cycle_period, cycle_t};
 use crate::render::Canvas;
 
-/// Slowly zooming into the Mandelbrot set with color cycling
+/// Seconds per palette loop unless `color_cycle_period` overrides it.
+const DEFAULT_COLOR_PERIOD: f64 = 10.0 / 3.0;
+
+/// Default dive target: a visually interesting point near the boundary.
+const DEFAULT_TARGET: (f64, f64) = (-0.7436, 0.1319);
+/// How deep the zoom cycle dives, as a power of e over the widest zoom.
+const MANDEL_DEPTH: f64 = 12.0;
+/// Julia sets lose detail much sooner than the Mandelbrot boundary.
+const JULIA_DEPTH: f64 = 4.0;
+
+/// Parse a complex number written `re,im` (as taken by `--mandel-center`
+/// and `--mandel-julia`).
+pub fn parse_complex(s: &str) -> Result<(f64, f64), String> {
+    let (re, im) = s
+        .split_once(',')
+        .ok_or_else(|| format!("expected RE,IM, got {s:?}"))?;
+    let part = |v: &str| {
+        v.trim()
+            .parse::<f64>()
+            .ok()
+            .filter(|v| v.is_finite())
+            .ok_or_else(|| format!("bad number {v:?} in {s:?}"))
+    };
+    Ok((part(re)?, part(im)?))
+}
+
+/// Slowly zooming into the Mandelbrot set (or a Julia set) with color cycling
 pub struct Mandelbrot {
     zoom: f64,
     target_x: f64,
     target_y: f64,
+    /// Widest zoom of the dive; the cycle zooms in from here and back
+    base_zoom: f64,
+    /// Fixed `c` when drawing the Julia set instead of the Mandelbrot set
+    julia: Option<(f64, f64)>,
+    /// `target_*` came from params rather than the built-in default
+    custom_target: bool,
+    /// Seconds per palette loop
+    color_period: f64,
 }
 
 impl Mandelbrot {
@@ -13,9 +48,12 @@ impl Mandelbrot {
     pub fn new(_width: usize, _height: usize, _scale: f64) -> Self {
         Mandelbrot {
             zoom: 1.0,
-            // Zoom target: a visually interesting point near the boundary
-            target_x: -0.7436,
-            target_y: 0.1319,
+            target_x: DEFAULT_TARGET.0,
+            target_y: DEFAULT_TARGET.1,
+            base_zoom: 1.0,
+            julia: None,
+            custom_target: false,
+            color_period: DEFAULT_COLOR_PERIOD,
         }
     }
 }
@@ -25,6 +63,37 @@ impl Animation for Mandelbrot {
         "mandelbrot"
     }
 
+    fn set_params(&mut self, params: &crate::external::ExternalParams) {
+        if let Some(p) = color_cycle_period(params) {
+            self.color_period = p;
+        }
+        if let (Some(re), Some(im)) = (params.get("mandel_re"), params.get("mandel_im")) {
+            (self.target_x, self.target_y) = (re, im);
+            self.custom_target = true;
+        }
+        if let Some(zoom) = params.get("mandel_zoom") {
+            self.base_zoom = zoom.max(f64::MIN_POSITIVE);
+        }
+        if let (Some(re), Some(im)) = (params.get("julia_re"), params.get("julia_im")) {
+            self.julia = Some((re, im));
+            // The Julia set is centered on the origin, not the Mandelbrot dive point
+            if !self.custom_target {
+                (self.target_x, self.target_y) = (0.0, 0.0);
+            }
+        }
+    }
+
+    fn supported_params(&self) -> &'static [(&'static str, f64, f64)] {
+        &[
+            ("color_cycle_period", 0.5, 600.0),
+            ("mandel_re", -2.5, 1.5),
+            ("mandel_im", -1.5, 1.5),
+            ("mandel_zoom", 0.1, 1e12),
+            ("julia_re", -2.0, 2.0),
+            ("julia_im", -2.0, 2.0),
+        ]
+    }
+
     fn preferred_render(&self) -> crate::render::RenderMode {
         crate::render::RenderMode::Braille
     }
@@ -36,26 +105,36 @@ impl Animation for Mandelbrot {
 
         // Smooth zoom cycle
         let zoom_cycle = (time * 0.1).sin() * 0.5 + 0.5; // 0 to 1
-        self.zoom = (1.0 + zoom_cycle * 12.0).exp(); // exponential zoom
-        let color_offset = time * 0.3;
+        let depth = if self.julia.is_some() {
+            JULIA_DEPTH
+        } else {
+            MANDEL_DEPTH
+        };
+        self.zoom = self.base_zoom * (1.0 + zoom_cycle * depth).exp(); // exponential zoom
+        let color_offset = cycle_t(time, self.color_period);
 
         let scale = 3.0 / self.zoom;
         let aspect = w / h;
 
+        let (target_x, target_y, julia) = (self.target_x, self.target_y, self.julia);
+
         canvas.clear();
 
-        for y in 0..canvas.height {
-            for x in 0..canvas.width {
+        // Rows are independent, so with the `rayon` feature they shade in parallel
+        canvas.fill_rows(|y, pixels, colors| {
+            let fy = y as f64 / h;
+            let py = target_y + (fy - 0.5) * scale;
+            for (x, (pixel, color)) in pixels.iter_mut().zip(colors).enumerate() {
                 let fx = x as f64 / w;
-                let fy = y as f64 / h;
 
                 // Map pixel to complex plane
-                let cr = self.target_x + (fx - 0.5) * scale * aspect;
-                let ci = self.target_y + (fy - 0.5) * scale;
+                let px = target_x + (fx - 0.5) * scale * aspect;
 
-                // Mandelbrot iteration
-                let mut zr = 0.0;
-                let mut zi = 0.0;
+                // Mandelbrot: z from 0, c from the pixel. Julia: z from the pixel, c fixed.
+                let (mut zr, mut zi, cr, ci) = match julia {
+                    Some((cr, ci)) => (px, py, cr, ci),
+                    None => (0.0, 0.0, px, py),
+                };
                 let mut iter = 0;
 
                 while iter < max_iter {
@@ -82,13 +161,12 @@ impl Animation for Mandelbrot {
                     let saturation = 0.8;
                     let value = (1.0 - t * 0.3).clamp(0.5, 1.0);
 
-                    let (r, g, b) = hsv_to_rgb(hue, saturation, value);
-                    let brightness = value * 0.9;
-                    canvas.set_colored(x, y, brightness, r, g, b);
+                    *color = hsv_to_rgb(hue, saturation, value);
+                    *pixel = value * 0.9;
                 }
                 // Points inside the set stay black (cleared)
             }
-        }
+        });
     }
 }
 
@@ -110,3 +188,35 @@ fn hsv_to_rgb(h: f64, s: f64, v: f64) -> (u8, u8, u8) {
         ((b + m) * 255.0) as u8,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::{ColorMode, RenderMode};
+
+    #[test]
+    fn parses_complex_pairs() {
+        assert_eq!(parse_complex("-0.75,0.1"), Ok((-0.75, 0.1)));
+        assert_eq!(parse_complex(" 0 , -1.5 "), Ok((0.0, -1.5)));
+        assert!(parse_complex("0.3").is_err());
+        assert!(parse_complex("a,1").is_err());
+        assert!(parse_complex("inf,0").is_err());
+    }
+
+    #[test]
+    fn julia_mode_iterates_from_the_pixel() {
+        // c = 0 gives the unit disk: the center stays inside, the corners escape
+        let mut canvas = Canvas::new(40, 20, RenderMode::Braille, ColorMode::TrueColor);
+        let mut anim = Mandelbrot::new(canvas.width, canvas.height, 1.0);
+        let mut params = crate::external::ExternalParams::default();
+        params.extra.insert("julia_re".into(), 0.0.into());
+        params.extra.insert("julia_im".into(), 0.0.into());
+        params.extra.insert("mandel_zoom".into(), 0.5.into());
+        anim.set_params(&params);
+        // Widest point of the zoom cycle
+        anim.update(&mut canvas, 0.0, 15.0 * std::f64::consts::PI);
+        let (w, h) = (canvas.width, canvas.height);
+        assert_eq!(canvas.pixels[h / 2 * w + w / 2], 0.0);
+        assert!(canvas.pixels[0] > 0.0);
+    }
+}
@@ -1,6 +1,9 @@
 use super::Animation;
 use crate::render::Canvas;
+use crate::render::cell::{ATTR_BOLD, ATTR_DIM};
 use rand::RngExt;
+use rand::rngs::StdRng;
+use unicode_width::UnicodeWidthChar;
 
 struct Drop {
     x: usize,
@@ -23,7 +26,7 @@ struct Layer {
 impl Layer {
     #[allow(clippy::too_many_arguments)]
     fn create_drops(
-        rng: &mut rand::rngs::ThreadRng,
+        rng: &mut StdRng,
         count: usize,
         width: usize,
         height: usize,
@@ -43,37 +46,76 @@ impl Layer {
     }
 }
 
-/// A uniformly-random glyph for the digital rain, drawn from hiragana (U+3041–U+3096),
-/// katakana (U+30A1–U+30FA), ASCII `A–Z` / `a–z`, and digits `0–9`. Kana dominate by range
-/// size, with Latin/digits sprinkled in for the classic mixed Matrix-code look.
-fn random_glyph(rng: &mut rand::rngs::ThreadRng) -> char {
-    const RANGES: &[(u32, u32)] = &[
+/// Named glyph sets for `--matrix-charset`; anything else is taken as a
+/// literal list of glyphs.
+pub const CHARSET_NAMES: &[&str] = &["latin", "katakana", "binary", "hex"];
+
+/// Seconds a message stays fully typed out before the rain takes over again.
+const MESSAGE_HOLD: f64 = 3.0;
+/// Message glyphs typed per second.
+const MESSAGE_TYPE_RATE: f64 = 12.0;
+
+/// The default glyphs: hiragana (U+3041–U+3096), katakana (U+30A1–U+30FA),
+/// ASCII `A–Z` / `a–z`, and digits `0–9`. Kana dominate by range size, with
+/// Latin/digits sprinkled in for the classic mixed Matrix-code look.
+fn default_charset() -> Vec<char> {
+    glyph_ranges(&[
         (0x3041, 0x3096), // hiragana (86)
         (0x30A1, 0x30FA), // katakana (90)
         (0x41, 0x5A),     // A–Z (26)
         (0x61, 0x7A),     // a–z (26)
         (0x30, 0x39),     // 0–9 (10)
-    ];
-    let total: u32 = RANGES.iter().map(|&(a, b)| b - a + 1).sum();
-    let mut n = rng.random_range(0..total);
-    for &(start, end) in RANGES {
-        let len = end - start + 1;
-        if n < len {
-            return char::from_u32(start + n).unwrap_or('ア');
-        }
-        n -= len;
+    ])
+}
+
+fn glyph_ranges(ranges: &[(u32, u32)]) -> Vec<char> {
+    ranges
+        .iter()
+        .flat_map(|&(start, end)| (start..=end).filter_map(char::from_u32))
+        .collect()
+}
+
+/// Glyphs for a `--matrix-charset` value: a name from [`CHARSET_NAMES`] or a
+/// literal string. Control characters are dropped; an empty result falls
+/// back to the default set.
+pub fn charset(spec: &str) -> Vec<char> {
+    let glyphs = match spec {
+        "latin" => glyph_ranges(&[(0x41, 0x5A), (0x61, 0x7A), (0x30, 0x39)]),
+        "katakana" => glyph_ranges(&[(0x30A1, 0x30FA)]),
+        "binary" => vec!['0', '1'],
+        "hex" => "0123456789ABCDEF".chars().collect(),
+        literal => literal.chars().filter(|c| !c.is_control()).collect(),
+    };
+    if glyphs.is_empty() {
+        default_charset()
+    } else {
+        glyphs
     }
-    unreachable!("glyph ranges are exhaustive over `total`")
 }
 
+/// A uniformly-random glyph for the digital rain.
+fn random_glyph(rng: &mut StdRng, charset: &[char]) -> char {
+    charset[rng.random_range(0..charset.len() as u32) as usize]
+}
+
+/// A message typed out across one row amid the rain.
+struct Message {
+    row: usize,
+    col: usize,
+    /// Seconds since it started typing
+    age: f64,
+}
+
+#[allow(clippy::too_many_arguments)]
 fn draw_layer(
     canvas: &mut Canvas,
     layer: &mut Layer,
-    rng: &mut rand::rngs::ThreadRng,
+    rng: &mut StdRng,
     width: usize,
     height: usize,
     dt: f64,
     len_range: (usize, usize),
+    charset: &[char],
 ) {
     let speed_min = layer.speed_min;
     let speed_max = layer.speed_max;
@@ -102,16 +144,27 @@ fn draw_layer(
             if draw && py >= 0 && (py as usize) < canvas.height {
                 let fade = 1.0 - (i as f64 / drop.length as f64);
                 let g = trail_g_base + ((trail_g_range as f64 * fade) as u8);
-                canvas.set_char(drop.x, py as usize, random_glyph(rng), 0, g, 0);
+                // The fading half goes faint, which still reads on 16-color terminals.
+                let attrs = if fade < 0.5 { ATTR_DIM } else { 0 };
+                canvas.set_char_attr(
+                    drop.x,
+                    py as usize,
+                    random_glyph(rng, charset),
+                    attrs,
+                    0,
+                    g,
+                    0,
+                );
             }
         }
 
         // Bright head glyph
         if draw && head >= 0 && (head as usize) < canvas.height {
-            canvas.set_char(
+            canvas.set_char_attr(
                 drop.x,
                 head as usize,
-                random_glyph(rng),
+                random_glyph(rng, charset),
+                ATTR_BOLD,
                 head_r,
                 head_g,
                 head_b,
@@ -132,12 +185,22 @@ pub struct Matrix {
     far_len: (usize, usize),
     mid_len: (usize, usize),
     near_len: (usize, usize),
-    rng: rand::rngs::ThreadRng,
+    /// Glyphs the rain samples from, and the spec they were built from
+    charset: Vec<char>,
+    charset_spec: String,
+    message: String,
+    /// Message currently on screen
+    shown: Option<Message>,
+    /// Seconds until the message next appears
+    message_wait: f64,
+    rng: StdRng,
 }
 
 impl Matrix {
     pub fn new(width: usize, height: usize, scale: f64) -> Self {
-        let mut rng = rand::rng();
+        let mut rng = super::rng();
+        // `[animations.matrix]` charset; `--matrix-charset` still replaces it
+        let charset_spec: Option<String> = super::setting("charset");
 
         let far_count = ((width as f64 * 0.8) * scale) as usize;
         let mid_count = ((width as f64 * 0.5) * scale) as usize;
@@ -196,7 +259,59 @@ impl Matrix {
             far_len,
             mid_len,
             near_len,
-            rng: rand::rng(),
+            charset: charset_spec
+                .as_deref()
+                .map_or_else(default_charset, charset),
+            charset_spec: charset_spec.unwrap_or_default(),
+            message: super::setting("message").unwrap_or_default(),
+            shown: None,
+            message_wait: 4.0,
+            rng: super::rng(),
+        }
+    }
+
+    /// Count down to the next showing of the message and type it out in
+    /// bright glyphs over the rain, centered on a random row.
+    fn draw_message(&mut self, canvas: &mut Canvas, dt: f64) {
+        let glyphs: Vec<char> = self.message.chars().filter(|c| !c.is_control()).collect();
+        let cols: usize = glyphs
+            .iter()
+            .map(|&c| UnicodeWidthChar::width(c).unwrap_or(1).max(1))
+            .sum();
+        if glyphs.is_empty() || cols + 2 > canvas.width || canvas.height < 3 {
+            self.shown = None;
+            return;
+        }
+        if self.shown.is_none() {
+            self.message_wait -= dt;
+            if self.message_wait > 0.0 {
+                return;
+            }
+            self.shown = Some(Message {
+                row: self
+                    .rng
+                    .random_range(canvas.height / 4..canvas.height * 3 / 4),
+                col: (canvas.width - cols) / 2,
+                age: 0.0,
+            });
+        }
+        let Some(msg) = &mut self.shown else {
+            return;
+        };
+        msg.age += dt;
+        let typed = (msg.age * MESSAGE_TYPE_RATE) as usize;
+        if msg.age > glyphs.len() as f64 / MESSAGE_TYPE_RATE + MESSAGE_HOLD {
+            self.shown = None;
+            self.message_wait = self.rng.random_range(10.0..20.0);
+            return;
+        }
+        let (row, mut col) = (msg.row.min(canvas.height - 1), msg.col);
+        // A wide rain glyph just left of the message would spill into its first cell
+        canvas.set_char(col.saturating_sub(1), row, ' ', 0, 0, 0);
+        for (i, &ch) in glyphs.iter().enumerate() {
+            let ch = if i < typed { ch } else { ' ' };
+            canvas.set_char_attr(col, row, ch, ATTR_BOLD, 200, 255, 200);
+            col += UnicodeWidthChar::width(glyphs[i]).unwrap_or(1).max(1);
         }
     }
 }
@@ -228,6 +343,7 @@ impl Animation for Matrix {
             height,
             dt,
             far_len,
+            &self.charset,
         );
         draw_layer(
             canvas,
@@ -237,6 +353,7 @@ impl Animation for Matrix {
             height,
             dt,
             mid_len,
+            &self.charset,
         );
         draw_layer(
             canvas,
@@ -246,7 +363,32 @@ impl Animation for Matrix {
             height,
             dt,
             near_len,
+            &self.charset,
         );
+
+        if !self.message.is_empty() {
+            self.draw_message(canvas, dt);
+        }
+    }
+
+    fn set_params(&mut self, params: &crate::external::ExternalParams) {
+        if let Some(spec) = params.get_str("matrix_charset")
+            && spec != self.charset_spec
+        {
+            self.charset = charset(spec);
+            self.charset_spec = spec.to_string();
+        }
+        if let Some(message) = params.get_str("matrix_message")
+            && message != self.message
+        {
+            self.message = message.to_string();
+            self.shown = None;
+        }
+    }
+
+    /// Near-layer drops are 8..12 rows long and capped at half the height.
+    fn min_size(&self) -> (usize, usize) {
+        (10, 18)
     }
 
     fn on_resize(&mut self, width: usize, height: usize) {
@@ -299,6 +441,15 @@ mod tests {
     use super::*;
     use crate::render::{Canvas, ColorMode, RenderMode};
 
+    #[test]
+    fn configured_charset_and_message_apply_at_construction() {
+        let table: toml::Table = toml::from_str("charset = \"binary\"\nmessage = \"HI\"").unwrap();
+        let m = crate::animations::with_settings(Some(&table), || Matrix::new(40, 20, 1.0));
+        assert_eq!(m.charset, vec!['0', '1']);
+        assert_eq!(m.charset_spec, "binary");
+        assert_eq!(m.message, "HI");
+    }
+
     #[test]
     fn matrix_renders_kana_glyphs() {
         // Drive the real Ascii render path (ascii_build_grid -> encode_full) and confirm
@@ -316,4 +467,42 @@ mod tests {
             "matrix output should contain hiragana/katakana glyphs"
         );
     }
+
+    #[test]
+    fn charset_names_and_literals() {
+        assert_eq!(charset("binary"), vec!['0', '1']);
+        assert_eq!(charset("hex").len(), 16);
+        assert_eq!(charset("katakana").len(), 90);
+        assert_eq!(charset("latin").len(), 62);
+        assert_eq!(charset("ab\tc"), vec!['a', 'b', 'c']);
+        assert_eq!(charset(""), default_charset());
+        assert_eq!(default_charset().len(), 86 + 90 + 26 + 26 + 10);
+    }
+
+    #[test]
+    fn message_is_typed_out_over_the_rain() {
+        let mut canvas = Canvas::new(80, 25, RenderMode::Ascii, ColorMode::TrueColor);
+        let mut anim = Matrix::new(canvas.width, canvas.height, 1.0);
+        let mut params = crate::external::ExternalParams::default();
+        params
+            .extra
+            .insert("matrix_charset".into(), "binary".into());
+        params
+            .extra
+            .insert("matrix_message".into(), "WAKE UP".into());
+        anim.set_params(&params);
+        // Past the initial wait and the typing, inside the hold
+        for f in 0..120u32 {
+            anim.update(&mut canvas, 1.0 / 24.0, f as f64 / 24.0);
+        }
+        let msg = anim.shown.as_ref().expect("message on screen");
+        let row: String = (0..7)
+            .map(|i| canvas.char_override[msg.row * canvas.width + msg.col + i])
+            .collect();
+        assert_eq!(row, "WAKE UP");
+        assert!(canvas.char_override.iter().all(|&c| matches!(
+            c,
+            '\0' | '0' | '1' | ' ' | 'W' | 'A' | 'K' | 'E' | 'U' | 'P'
+        )));
+    }
 }
@@ -1,6 +1,7 @@
 pub mod atom;
 pub mod aurora;
 pub mod automata;
+pub mod banner;
 pub mod blackhole;
 pub mod boids;
 pub mod campfire;
@@ -52,6 +53,7 @@ pub mod solar_system;
 pub mod sort;
 pub mod spiral;
 pub mod starfield;
+pub mod storm;
 pub mod strange_attractor;
 pub mod tetris;
 pub mod visualizer;
@@ -60,6 +62,26 @@ pub mod waterfall;
 pub mod wave;
 
 use crate::render::{Canvas, RenderMode};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::cell::{Cell, RefCell};
+
+/// A discrete moment in a game animation, reported for `--beep`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameEvent {
+    /// A point scored (`pong`)
+    Score,
+    /// The player fired (`invaders`)
+    Shot,
+    /// A target destroyed (`invaders`)
+    Kill,
+    /// Food eaten (`snake`)
+    Eat,
+    /// Game over (`snake`)
+    Die,
+    /// A round finished (`sort` done, `invaders` wave cleared)
+    Complete,
+}
 
 /// Every animation implements this trait
 pub trait Animation {
@@ -77,6 +99,50 @@ pub trait Animation {
     /// Called once per frame before update(). Default is a no-op.
     fn set_params(&mut self, _params: &crate::external::ExternalParams) {}
 
+    /// Smallest canvas `(width, height)` in sub-cell pixels this animation
+    /// can run on. Below it the main loop shows a "terminal too small" notice
+    /// (converted to terminal cells for the current render mode) instead of
+    /// calling `update`.
+    fn min_size(&self) -> (usize, usize) {
+        (10, 5)
+    }
+
+    /// Seconds after which the animation's frames repeat exactly (for a
+    /// fixed canvas and params), so exporters can capture one seamless loop.
+    /// `None` for animations that are stateful, random, or never realign.
+    fn loop_period(&self) -> Option<f64> {
+        None
+    }
+
+    /// Scale applied before the user's `--scale` when building for a
+    /// `width` x `height` pixel canvas, so animations that spawn elements per
+    /// pixel keep a sensible count on huge and tiny terminals alike.
+    /// See [`density_scale`].
+    fn default_scale_for(&self, _width: usize, _height: usize) -> f64 {
+        1.0
+    }
+
+    /// Hold or resume the view rotation (the `freeze` key). The 3D
+    /// animations (`globe`, `atom`, `dna`, `blackhole`) implement it; true
+    /// if the animation has a view to freeze.
+    fn toggle_freeze(&mut self) -> bool {
+        false
+    }
+
+    /// Events since the last call, oldest first. Game animations queue them
+    /// during `update` until this is called, so anything that keeps calling
+    /// `update` should drain them too (the main loop plays them; the browser,
+    /// export, gallery and benchmark drop them).
+    fn take_events(&mut self) -> Vec<GameEvent> {
+        Vec::new()
+    }
+
+    /// Called before an `update` that follows a long real-time gap (see
+    /// [`crate::pacing::TIME_GAP`]), e.g. waking from suspend. Animations
+    /// that step on their own timers clear the backlog here so they resume
+    /// at normal speed instead of fast-forwarding through it.
+    fn on_time_gap(&mut self) {}
+
     /// Called when the canvas is rebuilt with new dimensions.
     /// Override to update stored dimensions and rebuild size-dependent state.
     fn on_resize(&mut self, _width: usize, _height: usize) {}
@@ -90,6 +156,38 @@ pub trait Animation {
     }
 }
 
+thread_local! {
+    /// Seed of the `create` call in progress, advanced by each `rng()`.
+    static CREATE_SEED: Cell<Option<u64>> = const { Cell::new(None) };
+    /// `[animations.<name>]` config table of the `create_for_canvas` call in
+    /// progress, read by `setting()`.
+    static CREATE_SETTINGS: RefCell<Option<toml::Table>> = const { RefCell::new(None) };
+}
+
+/// Value of `key` in the animation's `[animations.<name>]` config table, for
+/// a constructor to read while `create_for_canvas` runs. `None` when the key
+/// is unset or doesn't deserialize as a `T`; outside `create_for_canvas` it is
+/// always `None`.
+///
+/// Keys are the matching param names without the animation prefix, e.g.
+/// `charset` for `matrix_charset`.
+pub fn setting<T: serde::de::DeserializeOwned>(key: &str) -> Option<T> {
+    CREATE_SETTINGS.with_borrow(|table| table.as_ref()?.get(key)?.clone().try_into().ok())
+}
+
+/// Random number generator for an animation to own. Called from a
+/// constructor during a seeded `create`, successive calls return distinct
+/// but reproducible streams; otherwise it is seeded from OS entropy.
+pub fn rng() -> StdRng {
+    match CREATE_SEED.get() {
+        Some(seed) => {
+            CREATE_SEED.set(Some(seed.wrapping_add(0x9E37_79B9_7F4A_7C15)));
+            StdRng::seed_from_u64(seed)
+        }
+        None => rand::make_rng(),
+    }
+}
+
 macro_rules! declare_animations {
     ($(( $name:literal, $path:path, $desc:literal )),* $(,)?) => {
         pub const ANIMATIONS: &[(&str, &str)] = &[
@@ -100,15 +198,88 @@ macro_rules! declare_animations {
             $( $name, )*
         ];
 
-        pub fn create(name: &str, width: usize, height: usize, scale: f64) -> Option<Box<dyn Animation>> {
-            Some(match name {
-                $( $name => Box::new(<$path>::new(width, height, scale)), )*
-                _ => return None,
-            })
+        pub fn create(
+            name: &str,
+            width: usize,
+            height: usize,
+            scale: f64,
+            seed: Option<u64>,
+        ) -> Result<Box<dyn Animation>, CreateError> {
+            let previous = CREATE_SEED.replace(seed);
+            let anim: Result<Box<dyn Animation>, _> = match name {
+                $( $name => Ok(Box::new(<$path>::new(width, height, scale))), )*
+                _ => Err(CreateError::UnknownAnimation(name.to_string())),
+            };
+            CREATE_SEED.set(previous);
+            anim
+        }
+    }
+}
+
+/// Why `create` could not build an animation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CreateError {
+    /// No animation is registered under this name.
+    UnknownAnimation(String),
+}
+
+impl std::fmt::Display for CreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CreateError::UnknownAnimation(name) => write!(f, "unknown animation: {name:?}"),
         }
     }
 }
 
+impl std::error::Error for CreateError {}
+
+/// Scale that brings an element count which would naturally be `natural`
+/// into `min..=max`; 1.0 when it already is.
+pub fn density_scale(natural: f64, min: f64, max: f64) -> f64 {
+    if natural <= 0.0 {
+        return 1.0;
+    }
+    natural.clamp(min, max) / natural
+}
+
+/// Canvas size every animation can be constructed at, for reading properties
+/// such as `min_size` or `preferred_render` before the real canvas exists.
+pub const PROBE_SIZE: (usize, usize) = (160, 96);
+
+/// `create` plus the initial `on_resize`, but never sized below the
+/// animation's `min_size`; on a smaller canvas the caller shows the
+/// too-small notice instead of drawing it. `scale` multiplies the
+/// animation's `default_scale_for` the canvas. With a `seed` the animation
+/// draws the same random numbers on every run. `settings` is the animation's
+/// `[animations.<name>]` config table, visible to its constructor through
+/// [`setting`].
+pub fn create_for_canvas(
+    name: &str,
+    width: usize,
+    height: usize,
+    scale: f64,
+    seed: Option<u64>,
+    settings: Option<&toml::Table>,
+) -> Result<Box<dyn Animation>, CreateError> {
+    with_settings(settings, || {
+        let probe = create(name, PROBE_SIZE.0, PROBE_SIZE.1, scale, seed)?;
+        let (min_w, min_h) = probe.min_size();
+        let (width, height) = (width.max(min_w), height.max(min_h));
+        let scale = scale * probe.default_scale_for(width, height);
+        let mut anim = create(name, width, height, scale, seed)?;
+        anim.on_resize(width, height);
+        Ok(anim)
+    })
+}
+
+/// Run `f` with `settings` visible to [`setting`].
+pub(crate) fn with_settings<R>(settings: Option<&toml::Table>, f: impl FnOnce() -> R) -> R {
+    let previous = CREATE_SETTINGS.replace(settings.cloned());
+    let result = f();
+    CREATE_SETTINGS.set(previous);
+    result
+}
+
 declare_animations! {
     ("fire", fire::Fire, "Doom-style fire effect with heat propagation"),
     ("matrix", matrix::Matrix, "Matrix digital rain with trailing drops"),
@@ -170,24 +341,145 @@ declare_animations! {
     ("newtons_cradle", newtons_cradle::NewtonsCradle, "Newton's cradle with energy-conserving swings"),
     ("strange_attractor", strange_attractor::StrangeAttractor, "Lorenz strange attractor with a rotating rainbow trail"),
     ("ink_in_water", ink_in_water::InkInWater, "Colored ink puffs dispersing through a turbulent flow field"),
+    ("storm", storm::Storm, "Thunderstorm: rain with lightning strikes and full-screen flashes"),
+    ("banner", banner::Banner, "Large bitmap-font text scrolling with a rainbow sweep"),
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::render::{Canvas, ColorMode, RenderMode};
+    use std::hash::{DefaultHasher, Hash, Hasher};
+
+    /// Animations that draw no random numbers, so every run must render the
+    /// same frames.
+    const DETERMINISTIC: &[&str] = &[
+        "atom",
+        "aurora",
+        "banner",
+        "blackhole",
+        "dna",
+        "dragon",
+        "eclipse",
+        "globe",
+        "mandelbrot",
+        "newtons_cradle",
+        "ocean",
+        "pendulum",
+        "plasma",
+        "pulse",
+        "sierpinski",
+        "spiral",
+        "strange_attractor",
+        "wave",
+    ];
+
+    /// Run `name` headlessly (gallery-style fixed 24 fps steps on an 80x25
+    /// half-block canvas) and hash each rendered frame.
+    fn frame_hashes(name: &str, frames: usize, seed: Option<u64>) -> Vec<u64> {
+        let dt = 1.0 / 24.0;
+        let mut canvas = Canvas::new(80, 25, RenderMode::HalfBlock, ColorMode::TrueColor);
+        let mut anim =
+            create(name, canvas.width, canvas.height, 1.0, seed).unwrap_or_else(|e| panic!("{e}"));
+        anim.on_resize(canvas.width, canvas.height);
+        (0..frames)
+            .map(|i| {
+                canvas.clear();
+                anim.update(&mut canvas, dt, i as f64 * dt);
+                canvas.apply_effects(1.0, 0.0);
+                let mut hasher = DefaultHasher::new();
+                canvas.render().hash(&mut hasher);
+                hasher.finish()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn deterministic_animations_render_identical_frames() {
+        for &name in DETERMINISTIC {
+            let first = frame_hashes(name, 48, None);
+            let second = frame_hashes(name, 48, None);
+            if let Some(frame) = first.iter().zip(&second).position(|(a, b)| a != b) {
+                panic!("{name}: frame {frame} differs between runs");
+            }
+        }
+    }
+
+    #[test]
+    fn seeded_animations_render_identical_frames() {
+        // Everything else draws from `super::rng()`, so a seed reproduces
+        // every frame
+        for name in ANIMATION_NAMES
+            .iter()
+            .filter(|n| !DETERMINISTIC.contains(n))
+        {
+            let first = frame_hashes(name, 48, Some(7));
+            let second = frame_hashes(name, 48, Some(7));
+            if let Some(frame) = first.iter().zip(&second).position(|(a, b)| a != b) {
+                panic!("{name}: frame {frame} differs between seeded runs");
+            }
+            assert_ne!(first, frame_hashes(name, 48, Some(8)), "{name}");
+        }
+    }
+
+    #[test]
+    fn loop_period_frames_repeat() {
+        let steps = 48;
+        for &name in ANIMATION_NAMES {
+            let mut anim = create(name, 80, 25, 1.0, None).unwrap();
+            let Some(period) = anim.loop_period() else {
+                continue;
+            };
+            let dt = period / steps as f64;
+            let mut canvas = Canvas::new(80, 25, RenderMode::HalfBlock, ColorMode::TrueColor);
+            anim.on_resize(canvas.width, canvas.height);
+            let mut frames = Vec::new();
+            for i in 0..=steps {
+                canvas.clear();
+                anim.update(&mut canvas, dt, i as f64 * dt);
+                frames.push(canvas.pixels.clone());
+            }
+            // Rounding may flip the odd edge pixel; a real mismatch is broad.
+            let differing = |a: &[f64], b: &[f64]| {
+                a.iter()
+                    .zip(b)
+                    .filter(|(x, y)| (*x - *y).abs() > 0.05)
+                    .count()
+            };
+            let tolerance = frames[0].len() / 100;
+            let seam = differing(&frames[0], &frames[steps]);
+            assert!(
+                seam <= tolerance,
+                "{name}: {seam} pixels differ after {period}s"
+            );
+            let midway = differing(&frames[0], &frames[steps / 3]);
+            assert!(midway > tolerance, "{name}: static across its loop");
+        }
+    }
 
     #[test]
     fn test_create_returns_some_for_all_known_names() {
         for &name in ANIMATION_NAMES {
-            let result = create(name, 80, 24, 1.0);
-            assert!(result.is_some(), "create({name:?}) returned None");
+            let result = create(name, 80, 24, 1.0, None);
+            assert!(result.is_ok(), "create({name:?}) failed");
         }
     }
 
     #[test]
-    fn test_create_returns_none_for_unknown_name() {
-        let result = create("does_not_exist", 80, 24, 1.0);
-        assert!(result.is_none());
+    fn test_create_errors_for_unknown_name() {
+        let err = create("does_not_exist", 80, 24, 1.0, None).err().unwrap();
+        assert_eq!(err, CreateError::UnknownAnimation("does_not_exist".into()));
+        assert_eq!(err.to_string(), "unknown animation: \"does_not_exist\"");
+    }
+
+    #[test]
+    fn settings_are_visible_only_during_creation() {
+        let table: toml::Table = toml::from_str("count = 7\nname = \"x\"").unwrap();
+        let seen = with_settings(Some(&table), || {
+            (setting::<usize>("count"), setting::<usize>("name"))
+        });
+        assert_eq!(seen, (Some(7), None), "wrong types read as unset");
+        assert_eq!(setting::<usize>("count"), None);
     }
 
     #[test]
@@ -205,15 +497,51 @@ mod tests {
         }
     }
 
+    #[test]
+    fn every_animation_runs_at_its_min_size() {
+        for &name in ANIMATION_NAMES {
+            let probe = create(name, 80, 24, 1.0, None).unwrap();
+            let (width, height) = probe.min_size();
+            let mode = probe.preferred_render();
+            let (px_w, px_h) = crate::render::subcell_grid(mode);
+            let (cols, rows) = (width.div_ceil(px_w), height.div_ceil(px_h));
+            let mut canvas = Canvas::new(cols, rows, mode, ColorMode::TrueColor);
+            let mut anim = create(name, canvas.width, canvas.height, 1.0, None).unwrap();
+            anim.on_resize(canvas.width, canvas.height);
+            for i in 0..30 {
+                canvas.clear();
+                anim.update(&mut canvas, 1.0 / 24.0, i as f64 / 24.0);
+            }
+        }
+    }
+
+    #[test]
+    fn dense_animations_scale_down_on_huge_canvases() {
+        assert_eq!(density_scale(100.0, 20.0, 300.0), 1.0);
+        assert_eq!(density_scale(600.0, 20.0, 300.0), 0.5);
+        assert_eq!(density_scale(10.0, 20.0, 300.0), 2.0);
+        // A 380x100-cell braille terminal, a 160x48 half-block one and a
+        // 20x6 sliver.
+        let (huge, medium, tiny) = ((760, 400), (160, 96), (20, 12));
+        for name in ["boids", "sandstorm", "rain"] {
+            let anim = create(name, PROBE_SIZE.0, PROBE_SIZE.1, 1.0, None).unwrap();
+            assert!(anim.default_scale_for(huge.0, huge.1) < 0.5, "{name}");
+            assert_eq!(anim.default_scale_for(medium.0, medium.1), 1.0, "{name}");
+            assert!(anim.default_scale_for(tiny.0, tiny.1) > 1.0, "{name}");
+        }
+        let fire = create("fire", PROBE_SIZE.0, PROBE_SIZE.1, 1.0, None).unwrap();
+        assert_eq!(fire.default_scale_for(huge.0, huge.1), 1.0);
+    }
+
     #[test]
     fn test_created_animation_name_matches_requested() {
-        let anim = create("fire", 80, 24, 1.0).unwrap();
+        let anim = create("fire", 80, 24, 1.0, None).unwrap();
         assert_eq!(anim.name(), "fire");
     }
 
     #[test]
     fn test_fire_supported_params_includes_intensity() {
-        let anim = create("fire", 80, 24, 1.0).unwrap();
+        let anim = create("fire", 80, 24, 1.0, None).unwrap();
         let params = anim.supported_params();
         assert!(!params.is_empty(), "fire should have supported params");
         assert!(params.iter().any(|&(name, _, _)| name == "intensity"));
@@ -221,7 +549,7 @@ mod tests {
 
     #[test]
     fn test_plasma_supported_params_includes_color_shift() {
-        let anim = create("plasma", 80, 24, 1.0).unwrap();
+        let anim = create("plasma", 80, 24, 1.0, None).unwrap();
         let params = anim.supported_params();
         assert!(!params.is_empty(), "plasma should have supported params");
         assert!(params.iter().any(|&(name, _, _)| name == "color_shift"));
@@ -230,14 +558,14 @@ mod tests {
     #[test]
     fn test_unknown_animation_has_empty_params() {
         // Most animations have no declared params — verify default returns empty
-        let anim = create("matrix", 80, 24, 1.0).unwrap();
+        let anim = create("matrix", 80, 24, 1.0, None).unwrap();
         let params = anim.supported_params();
         assert!(params.is_empty(), "matrix should have no declared params");
     }
 
     #[test]
     fn test_boids_supported_params() {
-        let anim = create("boids", 80, 24, 1.0).unwrap();
+        let anim = create("boids", 80, 24, 1.0, None).unwrap();
         let params = anim.supported_params();
         assert!(!params.is_empty());
         assert!(params.iter().any(|&(name, _, _)| name == "intensity"));
@@ -246,7 +574,7 @@ mod tests {
 
     #[test]
     fn test_particles_supported_params() {
-        let anim = create("particles", 80, 24, 1.0).unwrap();
+        let anim = create("particles", 80, 24, 1.0, None).unwrap();
         let params = anim.supported_params();
         assert!(!params.is_empty());
         assert!(params.iter().any(|&(name, _, _)| name == "intensity"));
@@ -254,7 +582,7 @@ mod tests {
 
     #[test]
     fn test_wave_supported_params() {
-        let anim = create("wave", 80, 24, 1.0).unwrap();
+        let anim = create("wave", 80, 24, 1.0, None).unwrap();
         let params = anim.supported_params();
         assert!(!params.is_empty());
         assert!(params.iter().any(|&(name, _, _)| name == "intensity"));
@@ -263,7 +591,7 @@ mod tests {
 
     #[test]
     fn test_sort_supported_params() {
-        let anim = create("sort", 80, 24, 1.0).unwrap();
+        let anim = create("sort", 80, 24, 1.0, None).unwrap();
         let params = anim.supported_params();
         assert!(!params.is_empty());
         assert!(params.iter().any(|&(name, _, _)| name == "speed"));
@@ -271,15 +599,30 @@ mod tests {
 
     #[test]
     fn test_snake_supported_params() {
-        let anim = create("snake", 80, 24, 1.0).unwrap();
+        let anim = create("snake", 80, 24, 1.0, None).unwrap();
         let params = anim.supported_params();
         assert!(!params.is_empty());
         assert!(params.iter().any(|&(name, _, _)| name == "speed"));
     }
 
+    #[test]
+    fn test_eclipse_supported_params() {
+        let anim = create("eclipse", 80, 24, 1.0, None).unwrap();
+        let params = anim.supported_params();
+        assert!(params.iter().any(|&(name, _, _)| name == "corona_length"));
+        assert!(params.iter().any(|&(name, _, _)| name == "totality_dwell"));
+    }
+
+    #[test]
+    fn test_radar_supported_params() {
+        let anim = create("radar", 80, 24, 1.0, None).unwrap();
+        let params = anim.supported_params();
+        assert!(params.iter().any(|&(name, _, _)| name == "contacts"));
+    }
+
     #[test]
     fn test_pong_supported_params() {
-        let anim = create("pong", 80, 24, 1.0).unwrap();
+        let anim = create("pong", 80, 24, 1.0, None).unwrap();
         let params = anim.supported_params();
         assert!(!params.is_empty());
         assert!(params.iter().any(|&(name, _, _)| name == "speed"));
@@ -30,6 +30,7 @@ pub struct NBody {
     width: usize,
     height: usize,
     bodies: Vec<Body>,
+    rng: rand::rngs::StdRng,
 }
 
 impl NBody {
@@ -38,13 +39,14 @@ impl NBody {
             width,
             height,
             bodies: Vec::new(),
+            rng: super::rng(),
         };
         sim.spawn_initial_bodies();
         sim
     }
 
     fn spawn_initial_bodies(&mut self) {
-        let mut rng = rand::rng();
+        let rng = &mut self.rng;
         let count = rng.random_range(5..=8) as usize;
         let cx = self.width as f64 * 0.5;
         let cy = self.height as f64 * 0.5;
@@ -78,7 +80,7 @@ impl NBody {
     }
 
     fn spawn_body(&mut self) {
-        let mut rng = rand::rng();
+        let rng = &mut self.rng;
         let angle = rng.random_range(0.0..std::f64::consts::TAU);
         let dist = rng.random_range(5.0..(self.width.min(self.height) as f64 * 0.4));
         let cx = self.width as f64 * 0.5;
@@ -215,6 +217,11 @@ impl Animation for NBody {
         "nbody"
     }
 
+    /// Bodies spawn at least 5 px from the center, inside 40% of the short side.
+    fn min_size(&self) -> (usize, usize) {
+        (13, 13)
+    }
+
     fn on_resize(&mut self, width: usize, height: usize) {
         self.width = width;
         self.height = height;
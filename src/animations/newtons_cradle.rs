@@ -51,18 +51,19 @@ impl Animation for NewtonsCradle {
         // Draw the frame: top beam, two legs, base.
         let beam_left = first - spacing * 0.4;
         let beam_right = first + (n - 1) as f64 * spacing + spacing * 0.4;
-        let frame: (u8, u8, u8) = (120, 120, 135);
-        draw_line(canvas, beam_left, pivot_y, beam_right, pivot_y, 0.5, frame);
-        draw_line(canvas, beam_left, pivot_y, beam_left, base_y, 0.4, frame);
-        draw_line(canvas, beam_right, pivot_y, beam_right, base_y, 0.4, frame);
-        draw_line(
-            canvas,
+        let (fr, fg, fb) = (120, 120, 135);
+        canvas.draw_line(beam_left, pivot_y, beam_right, pivot_y, 0.5, fr, fg, fb);
+        canvas.draw_line(beam_left, pivot_y, beam_left, base_y, 0.4, fr, fg, fb);
+        canvas.draw_line(beam_right, pivot_y, beam_right, base_y, 0.4, fr, fg, fb);
+        canvas.draw_line(
             beam_left * 0.6 + beam_right * 0.4,
             base_y,
             beam_right,
             base_y,
             0.5,
-            frame,
+            fr,
+            fg,
+            fb,
         );
 
         // Draw each ball: rope + shaded steel bob.
@@ -79,47 +80,12 @@ impl Animation for NewtonsCradle {
             let bob_x = pivot_x + rope * st;
             let bob_y = pivot_y + rope * ct;
 
-            draw_line(canvas, pivot_x, pivot_y, bob_x, bob_y, 0.3, (90, 90, 105));
+            canvas.draw_line(pivot_x, pivot_y, bob_x, bob_y, 0.3, 90, 90, 105);
             draw_ball(canvas, bob_x, bob_y, radius);
         }
     }
 }
 
-/// Draw a DDA-stepped line segment with the given brightness/color.
-fn draw_line(
-    canvas: &mut Canvas,
-    x0: f64,
-    y0: f64,
-    x1: f64,
-    y1: f64,
-    brightness: f64,
-    color: (u8, u8, u8),
-) {
-    let (r, g, b) = color;
-    let dx = x1 - x0;
-    let dy = y1 - y0;
-    let steps = dx.abs().max(dy.abs()).ceil() as usize;
-    if steps == 0 {
-        let ix = x0 as usize;
-        let iy = y0 as usize;
-        if ix < canvas.width && iy < canvas.height {
-            canvas.set_colored(ix, iy, brightness, r, g, b);
-        }
-        return;
-    }
-    let sx = dx / steps as f64;
-    let sy = dy / steps as f64;
-    for s in 0..=steps {
-        let x = x0 + sx * s as f64;
-        let y = y0 + sy * s as f64;
-        let ix = x as usize;
-        let iy = y as usize;
-        if ix < canvas.width && iy < canvas.height {
-            canvas.set_colored(ix, iy, brightness, r, g, b);
-        }
-    }
-}
-
 /// Draw a shaded steel ball: light from the upper-left, shadow lower-right.
 fn draw_ball(canvas: &mut Canvas, cx: f64, cy: f64, radius: f64) {
     let ri = radius.ceil() as i32;
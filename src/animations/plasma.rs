@@ -29,15 +29,23 @@ impl Animation for Plasma {
         &[("color_shift", 0.0, 1.0)]
     }
 
+    /// Every wave and color term runs at a multiple of 0.1x the plasma
+    /// clock (0.8x real time), so it all realigns after 2π / 0.08 seconds.
+    fn loop_period(&self) -> Option<f64> {
+        Some(std::f64::consts::TAU / 0.08)
+    }
+
     fn update(&mut self, canvas: &mut Canvas, _dt: f64, time: f64) {
         let w = canvas.width as f64;
         let h = canvas.height as f64;
         let t = time * 0.8;
 
-        for y in 0..canvas.height {
-            for x in 0..canvas.width {
+        let hue_bias = self.hue_bias;
+
+        canvas.fill_rows(|y, pixels, colors| {
+            let fy = y as f64 / h * 8.0;
+            for (x, (pixel, color)) in pixels.iter_mut().zip(colors).enumerate() {
                 let fx = x as f64 / w * 8.0;
-                let fy = y as f64 / h * 8.0;
 
                 let v1 = (fx + t).sin();
                 let v2 = ((fy * 1.5 + t * 0.7).sin() + (fx * 0.7 + t * 1.3).cos()) * 0.5;
@@ -47,10 +55,10 @@ impl Animation for Plasma {
                 let v = (v1 + v2 + v3 + v4) * 0.25 + 0.5; // normalize to ~0..1
                 let v = v.clamp(0.0, 1.0);
 
-                let (r, g, b) = plasma_color(v, t, self.hue_bias);
-                canvas.set_colored(x, y, v * 0.8 + 0.2, r, g, b);
+                *color = plasma_color(v, t, hue_bias);
+                *pixel = v * 0.8 + 0.2;
             }
-        }
+        });
     }
 }
 
@@ -1,4 +1,4 @@
-use super::Animation;
+use super::{Animation, GameEvent};
 use crate::render::Canvas;
 use rand::RngExt;
 
@@ -15,12 +15,13 @@ pub struct Pong {
     right_score: u32,
     serve_timer: f64,
     speed_mult: f64,
-    rng: rand::rngs::ThreadRng,
+    events: Vec<GameEvent>,
+    rng: rand::rngs::StdRng,
 }
 
 impl Pong {
     pub fn new(width: usize, height: usize, _scale: f64) -> Self {
-        let mut rng = rand::rng();
+        let mut rng = super::rng();
         let w = width as f64;
         let h = height as f64;
         let dir: f64 = if rng.random_range(0u8..2) == 0 {
@@ -40,7 +41,8 @@ impl Pong {
             right_score: 0,
             serve_timer: 0.0,
             speed_mult: 1.0,
-            rng: rand::rng(),
+            events: Vec::new(),
+            rng: super::rng(),
         }
     }
 
@@ -73,6 +75,10 @@ impl Animation for Pong {
         &[("speed", 0.2, 3.0)]
     }
 
+    fn take_events(&mut self) -> Vec<GameEvent> {
+        std::mem::take(&mut self.events)
+    }
+
     fn update(&mut self, canvas: &mut Canvas, dt: f64, _time: f64) {
         let w = canvas.width as f64;
         let h = canvas.height as f64;
@@ -131,6 +137,7 @@ impl Animation for Pong {
 
             // Score
             if self.ball_x < 0.0 {
+                self.events.push(GameEvent::Score);
                 self.right_score += 1;
                 if self.right_score >= 11 {
                     self.left_score = 0;
@@ -139,6 +146,7 @@ impl Animation for Pong {
                 self.serve(w, h);
             }
             if self.ball_x >= w {
+                self.events.push(GameEvent::Score);
                 self.left_score += 1;
                 if self.left_score >= 11 {
                     self.left_score = 0;
@@ -195,20 +203,9 @@ impl Animation for Pong {
             }
         }
 
-        // Ball
-        let bx = self.ball_x as usize;
-        let by = self.ball_y as usize;
-        if bx < canvas.width && by < canvas.height {
-            canvas.set_colored(bx, by, 1.0, 255, 255, 255);
-        }
-        // Ball glow
-        for &(ox, oy) in &[(1i32, 0i32), (-1, 0), (0, 1), (0, -1)] {
-            let gx = (self.ball_x + ox as f64) as usize;
-            let gy = (self.ball_y + oy as f64) as usize;
-            if gx < canvas.width && gy < canvas.height {
-                canvas.set_colored(gx, gy, 0.4, 200, 200, 200);
-            }
-        }
+        // Ball, over a soft glow
+        canvas.fill_circle(self.ball_x, self.ball_y, 1.5, 0.4, 200, 200, 200);
+        canvas.fill_circle(self.ball_x, self.ball_y, 0.75, 1.0, 255, 255, 255);
 
         // Score display (simple dots in top area)
         let score_y = 2_usize;
@@ -228,3 +225,47 @@ impl Animation for Pong {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::canvas::assert_canvas_chars;
+    use crate::render::{ColorMode, RenderMode};
+
+    /// With `dt = 0` nothing moves, so the serve layout is deterministic even
+    /// though the serve direction is random.
+    #[test]
+    fn initial_layout_matches_golden() {
+        let mut canvas = Canvas::new(40, 12, RenderMode::Ascii, ColorMode::Mono);
+        Pong::new(40, 12, 1.0).update(&mut canvas, 0.0, 0.0);
+        assert_canvas_chars(
+            &canvas,
+            "
+            |                                        |
+            |                    .                   |
+            |                    .                   |
+            |                                        |
+            |   %%               .              %%   |
+            |   %%               .              %%   |
+            |   %%              =@=             %%   |
+            |   %%               .              %%   |
+            |                    .                   |
+            |                                        |
+            |                    .                   |
+            |                    .                   |
+            ",
+        );
+    }
+
+    #[test]
+    fn missed_ball_reports_a_score() {
+        let mut canvas = Canvas::new(40, 12, RenderMode::Ascii, ColorMode::Mono);
+        let mut pong = Pong::new(40, 12, 1.0);
+        pong.ball_x = 0.5;
+        pong.ball_vx = -30.0;
+        pong.left_y = 0.0;
+        pong.update(&mut canvas, 0.1, 0.0);
+        assert_eq!(pong.take_events(), vec![GameEvent::Score]);
+        assert!(pong.take_events().is_empty());
+    }
+}
@@ -1,10 +1,16 @@
 use super::Animation;
+use crate::generators::{color_cycle_period, cycle_t};
 use crate::render::Canvas;
 
+/// Seconds per palette loop unless `color_cycle_period` overrides it.
+const DEFAULT_COLOR_PERIOD: f64 = 20.0 / 3.0;
+
 /// Expanding pulse rings from center
 pub struct Pulse {
     rings: Vec<PulseRing>,
     spawn_timer: f64,
+    /// Seconds per palette loop
+    color_period: f64,
 }
 
 struct PulseRing {
@@ -20,6 +26,7 @@ impl Pulse {
         Pulse {
             rings: Vec::new(),
             spawn_timer: 0.0,
+            color_period: DEFAULT_COLOR_PERIOD,
         }
     }
 }
@@ -29,12 +36,24 @@ impl Animation for Pulse {
         "pulse"
     }
 
+    fn set_params(&mut self, params: &crate::external::ExternalParams) {
+        if let Some(p) = color_cycle_period(params) {
+            self.color_period = p;
+        }
+    }
+
+    fn supported_params(&self) -> &'static [(&'static str, f64, f64)] {
+        &[("color_cycle_period", 0.5, 600.0)]
+    }
+
     fn update(&mut self, canvas: &mut Canvas, dt: f64, time: f64) {
         let w = canvas.width as f64;
         let h = canvas.height as f64;
         let cx = w / 2.0;
         let cy = h / 2.0;
-        let max_r = (cx * cx + cy * cy).sqrt();
+        // Rings are measured in horizontal pixels so they stay round
+        let aspect = canvas.aspect;
+        let max_r = (cx * cx + (cy * aspect).powi(2)).sqrt();
 
         // Spawn new rings
         self.spawn_timer -= dt;
@@ -43,7 +62,7 @@ impl Animation for Pulse {
                 radius: 0.0,
                 max_radius: max_r,
                 speed: 30.0 + (time * 0.5).sin() * 10.0,
-                hue: (time * 0.15).fract(),
+                hue: cycle_t(time, self.color_period),
             });
             self.spawn_timer = 0.5 + (time * 0.3).sin().abs() * 0.5;
         }
@@ -59,7 +78,7 @@ impl Animation for Pulse {
         for y in 0..canvas.height {
             for x in 0..canvas.width {
                 let dx = x as f64 - cx;
-                let dy = y as f64 - cy;
+                let dy = (y as f64 - cy) * aspect;
                 let dist = (dx * dx + dy * dy).sqrt();
 
                 let mut total_brightness = 0.0f64;
@@ -116,3 +135,22 @@ fn hsv_to_rgb(h: f64, s: f64, v: f64) -> (u8, u8, u8) {
         ((b + m) * 255.0) as u8,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::{ColorMode, RenderMode};
+
+    #[test]
+    fn rings_are_round_on_screen() {
+        // ASCII pixels are 2:1, so k rows down is as far as 2k columns across
+        let mut c = Canvas::new(40, 20, RenderMode::Ascii, ColorMode::TrueColor);
+        let mut p = Pulse::new(c.width, c.height, 1.0);
+        p.update(&mut c, 0.25, 0.0);
+        let at = |x: usize, y: usize| c.pixels[y * c.width + x];
+        assert!((0..10).any(|k| at(20 + 2 * k, 10) > 0.0));
+        for k in 0..10 {
+            assert_eq!(at(20 + 2 * k, 10), at(20, 10 + k), "k = {k}");
+        }
+    }
+}
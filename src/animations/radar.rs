@@ -1,6 +1,16 @@
 use super::Animation;
 use crate::render::Canvas;
 use rand::RngExt;
+use std::collections::VecDeque;
+use std::f64::consts::TAU;
+
+/// Sweep rotation speed in radians per second (~1 revolution per pi seconds).
+const SWEEP_SPEED: f64 = 2.0;
+/// Seconds for a painted echo to fade after the sweep last crossed it.
+const ECHO_FADE: f64 = 4.0;
+/// Past paints kept per contact as a fading track.
+const TRAIL_LEN: usize = 5;
+const MAX_CONTACTS: usize = 12;
 
 struct Blip {
     x: f64,
@@ -9,11 +19,26 @@ struct Blip {
     max_life: f64,
 }
 
-/// Rotating radar sweep line with random blips that fade
+/// Persistent target moving across the scope, in scope units (radius 1,
+/// angle 0 = east, increasing clockwise on screen like the sweep).
+struct Contact {
+    id: u32,
+    x: f64,
+    y: f64,
+    vx: f64,
+    vy: f64,
+    /// Positions where the sweep painted this contact, with paint time; newest last.
+    paints: VecDeque<(f64, f64, f64)>,
+}
+
+/// Rotating radar sweep line with random blips that fade, or (with the
+/// `contacts` param) persistent tracked targets labelled with their bearing
 pub struct Radar {
     blips: Vec<Blip>,
+    contacts: Vec<Contact>,
+    next_id: u32,
     sweep_angle: f64,
-    rng: rand::rngs::ThreadRng,
+    rng: rand::rngs::StdRng,
 }
 
 impl Radar {
@@ -21,8 +46,98 @@ impl Radar {
     pub fn new(_width: usize, _height: usize, _scale: f64) -> Self {
         Radar {
             blips: Vec::new(),
+            contacts: Vec::new(),
+            next_id: 1,
             sweep_angle: 0.0,
-            rng: rand::rng(),
+            rng: super::rng(),
+        }
+    }
+
+    fn set_contact_count(&mut self, count: usize) {
+        self.contacts.truncate(count);
+        while self.contacts.len() < count {
+            let contact = self.spawn_contact(true);
+            self.contacts.push(contact);
+        }
+    }
+
+    /// New contact heading roughly inward. `anywhere` places it inside the
+    /// scope (initial fill); otherwise it enters from the rim.
+    fn spawn_contact(&mut self, anywhere: bool) -> Contact {
+        let angle = self.rng.random_range(0.0..TAU);
+        let dist = if anywhere {
+            self.rng.random_range(0.2..0.9)
+        } else {
+            0.98
+        };
+        let heading = angle + std::f64::consts::PI + self.rng.random_range(-0.6..0.6);
+        let speed = self.rng.random_range(0.03..0.08);
+        let id = self.next_id;
+        self.next_id = self.next_id % 99 + 1;
+        Contact {
+            id,
+            x: angle.cos() * dist,
+            y: angle.sin() * dist,
+            vx: heading.cos() * speed,
+            vy: heading.sin() * speed,
+            paints: VecDeque::with_capacity(TRAIL_LEN),
+        }
+    }
+
+    /// Move contacts, respawn any that left the scope, and record a paint for
+    /// every contact whose bearing the sweep crossed this frame.
+    fn update_contacts(&mut self, prev_angle: f64, swept: f64, time: f64) {
+        for i in 0..self.contacts.len() {
+            let c = &mut self.contacts[i];
+            if c.x * c.x + c.y * c.y > 1.0 {
+                self.contacts[i] = self.spawn_contact(false);
+            }
+            let c = &mut self.contacts[i];
+            let angle = c.y.atan2(c.x);
+            if (angle - prev_angle).rem_euclid(TAU) < swept {
+                if c.paints.len() == TRAIL_LEN {
+                    c.paints.pop_front();
+                }
+                c.paints.push_back((c.x, c.y, time));
+            }
+        }
+    }
+
+    fn step_contacts(&mut self, dt: f64) {
+        for c in &mut self.contacts {
+            c.x += c.vx * dt;
+            c.y += c.vy * dt;
+        }
+    }
+
+    fn draw_contacts(&self, canvas: &mut Canvas, cx: f64, cy: f64, radius: f64, time: f64) {
+        for c in &self.contacts {
+            let newest = c.paints.len().saturating_sub(1);
+            for (i, &(ux, uy, seen)) in c.paints.iter().enumerate() {
+                let fade = (1.0 - (time - seen) / ECHO_FADE).clamp(0.0, 1.0);
+                if fade <= 0.01 {
+                    continue;
+                }
+                let px = cx + ux * radius;
+                let py = cy + uy * radius / canvas.aspect;
+                if px < 0.0 || py < 0.0 {
+                    continue;
+                }
+                let (px, py) = (px as usize, py as usize);
+                if i == newest {
+                    let brightness = fade.powi(2).max(0.2);
+                    let g = (255.0 * brightness) as u8;
+                    let r = (120.0 * brightness) as u8;
+                    canvas.fill_circle(px as f64, py as f64, 1.5, brightness, r, g, 0);
+                    let bearing = (uy.atan2(ux).to_degrees() + 90.0).rem_euclid(360.0);
+                    let label = format!("TF{:02} {:03.0}°", c.id, bearing);
+                    let lg = (120.0 + 135.0 * fade) as u8;
+                    canvas.draw_text(px + 3, py, &label, 0, lg, 0);
+                } else if px < canvas.width && py < canvas.height {
+                    let brightness = fade * 0.4;
+                    canvas.set_colored(px, py, brightness, 0, (200.0 * brightness) as u8, 0);
+                }
+            }
         }
     }
 }
@@ -32,27 +147,44 @@ impl Animation for Radar {
         "radar"
     }
 
-    fn update(&mut self, canvas: &mut Canvas, dt: f64, _time: f64) {
+    fn set_params(&mut self, params: &crate::external::ExternalParams) {
+        if let Some(v) = params.get("contacts") {
+            self.set_contact_count(v.clamp(0.0, MAX_CONTACTS as f64) as usize);
+        }
+    }
+
+    fn supported_params(&self) -> &'static [(&'static str, f64, f64)] {
+        &[("contacts", 0.0, MAX_CONTACTS as f64)]
+    }
+
+    fn update(&mut self, canvas: &mut Canvas, dt: f64, time: f64) {
         let w = canvas.width as f64;
         let h = canvas.height as f64;
         let cx = w * 0.5;
         let cy = h * 0.5;
-        let radius = (w.min(h) * 0.45).max(10.0);
+        // Distances are in horizontal pixels; y offsets divide by the pixel
+        // aspect so the scope stays round in every render mode
+        let aspect = canvas.aspect;
+        let radius = (w.min(h * aspect) * 0.45).max(10.0);
 
         // Rotate sweep
         let prev_angle = self.sweep_angle;
-        self.sweep_angle += dt * 2.0; // ~1 revolution per pi seconds
-        if self.sweep_angle >= std::f64::consts::TAU {
-            self.sweep_angle -= std::f64::consts::TAU;
+        let swept = dt * SWEEP_SPEED;
+        self.sweep_angle += swept;
+        if self.sweep_angle >= TAU {
+            self.sweep_angle -= TAU;
         }
 
-        // Spawn blips along sweep line
-        if self.rng.random_range(0.0..1.0) < 0.15 {
+        self.step_contacts(dt);
+        self.update_contacts(prev_angle, swept, time);
+
+        // Spawn blips along sweep line (classic mode only)
+        if self.contacts.is_empty() && self.rng.random_range(0.0..1.0) < 0.15 {
             let dist = self.rng.random_range(radius * 0.15..radius * 0.9);
             let blip_angle = self.sweep_angle + self.rng.random_range(-0.05..0.05);
             self.blips.push(Blip {
                 x: cx + blip_angle.cos() * dist,
-                y: cy + blip_angle.sin() * dist,
+                y: cy + blip_angle.sin() * dist / aspect,
                 life: 4.0,
                 max_life: 4.0,
             });
@@ -73,7 +205,7 @@ impl Animation for Radar {
             for i in 0..steps {
                 let angle = std::f64::consts::TAU * i as f64 / steps as f64;
                 let px = (cx + angle.cos() * r) as usize;
-                let py = (cy + angle.sin() * r) as usize;
+                let py = (cy + angle.sin() * r / aspect) as usize;
                 if px < canvas.width && py < canvas.height {
                     canvas.set_colored(px, py, 0.15, 0, 100, 0);
                 }
@@ -83,11 +215,12 @@ impl Animation for Radar {
         // Draw cross hairs
         for i in 0..(radius as usize) {
             let t = i as f64;
+            let ty = t / aspect;
             let positions = [
                 ((cx + t) as usize, cy as usize),
                 ((cx - t).max(0.0) as usize, cy as usize),
-                (cx as usize, (cy + t) as usize),
-                (cx as usize, (cy - t).max(0.0) as usize),
+                (cx as usize, (cy + ty) as usize),
+                (cx as usize, (cy - ty).max(0.0) as usize),
             ];
             for (px, py) in positions {
                 if px < canvas.width && py < canvas.height {
@@ -101,7 +234,7 @@ impl Animation for Radar {
         for y in 0..canvas.height {
             for x in 0..canvas.width {
                 let dx = x as f64 - cx;
-                let dy = y as f64 - cy;
+                let dy = (y as f64 - cy) * aspect;
                 let dist = (dx * dx + dy * dy).sqrt();
 
                 if dist > radius || dist < 2.0 {
@@ -134,7 +267,7 @@ impl Animation for Radar {
         for i in 0..steps {
             let t = i as f64;
             let px = (cx + self.sweep_angle.cos() * t) as usize;
-            let py = (cy + self.sweep_angle.sin() * t) as usize;
+            let py = (cy + self.sweep_angle.sin() * t / aspect) as usize;
             if px < canvas.width && py < canvas.height {
                 canvas.set_colored(px, py, 0.9, 50, 255, 50);
             }
@@ -162,13 +295,46 @@ impl Animation for Radar {
             }
         }
 
+        self.draw_contacts(canvas, cx, cy, radius, time);
+
         // Center dot
         let icx = cx as usize;
         let icy = cy as usize;
         if icx < canvas.width && icy < canvas.height {
             canvas.set_colored(icx, icy, 1.0, 100, 255, 100);
         }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::{ColorMode, RenderMode};
 
-        let _ = prev_angle;
+    #[test]
+    fn contacts_are_repainted_once_per_sweep() {
+        let mut radar = Radar::new(80, 96, 1.0);
+        radar.set_contact_count(4);
+        for c in &mut radar.contacts {
+            (c.vx, c.vy) = (0.0, 0.0);
+        }
+        let mut canvas = Canvas::new(40, 24, RenderMode::Braille, ColorMode::TrueColor);
+        let dt = 1.0 / 30.0;
+        let revolution = TAU / SWEEP_SPEED;
+        let frames = (revolution * 2.0 / dt) as usize;
+        for f in 0..frames {
+            radar.update(&mut canvas, dt, f as f64 * dt);
+        }
+        assert!(
+            radar.blips.is_empty(),
+            "contacts mode replaces random blips"
+        );
+        for c in &radar.contacts {
+            assert!(
+                (1..=3).contains(&c.paints.len()),
+                "two sweeps paint each contact about twice, got {}",
+                c.paints.len()
+            );
+        }
     }
 }
@@ -1,5 +1,7 @@
 use super::Animation;
-use crate::generators::{ColorGradient, ColorStop, EmitterConfig, ParticleSystem};
+use crate::generators::{
+    ColorGradient, ColorStop, EmitterConfig, GRADIENT_LUT_SIZE, ParticleParams, ParticleSystem,
+};
 use crate::render::Canvas;
 use rand::RngExt;
 
@@ -15,6 +17,32 @@ struct Raindrop {
     b: u8,
 }
 
+impl Raindrop {
+    /// A drop at a random depth, somewhere in rows `y_range`.
+    fn spawn(rng: &mut impl RngExt, width: usize, y_range: std::ops::Range<f64>) -> Raindrop {
+        let depth = rng.random_range(0.0..1.0);
+        Raindrop {
+            x: rng.random_range(0.0..width.max(1) as f64),
+            y: rng.random_range(y_range),
+            speed: 15.0 + depth * 50.0, // back: 15, front: 65
+            length: 1.0 + depth * 5.0,  // back: short, front: long
+            wind_offset: rng.random_range(-0.5..0.5),
+            depth,
+            r: (60.0 + 80.0 * depth) as u8,
+            g: (80.0 + 90.0 * depth) as u8,
+            b: (120.0 + 135.0 * depth) as u8,
+        }
+    }
+}
+
+/// Splash particle gravity
+const SPLASH_GRAVITY: f64 = 25.0;
+
+/// Drops for a `width` x `height` canvas at scale 1, before density capping.
+fn natural_count(width: usize, height: usize) -> f64 {
+    (width * height) as f64 / 80.0
+}
+
 /// Rain with splash particles on impact
 pub struct Rain {
     width: usize,
@@ -24,31 +52,18 @@ pub struct Rain {
     wind: f64,
     wind_target: f64,
     wind_timer: f64,
-    rng: rand::rngs::ThreadRng,
+    /// Drop count at `emit_rate` 1
+    base_drops: usize,
+    physics: ParticleParams,
+    rng: rand::rngs::StdRng,
 }
 
 impl Rain {
     pub fn new(width: usize, height: usize, scale: f64) -> Self {
-        let mut rng = rand::rng();
-        let num_drops = ((width * height) as f64 / 80.0 * scale) as usize;
+        let mut rng = super::rng();
+        let num_drops = (natural_count(width, height) * scale) as usize;
         let drops = (0..num_drops)
-            .map(|_| {
-                let depth = rng.random_range(0.0..1.0);
-                let r = (60.0 + 80.0 * depth) as u8;
-                let g = (80.0 + 90.0 * depth) as u8;
-                let b = (120.0 + 135.0 * depth) as u8;
-                Raindrop {
-                    x: rng.random_range(0.0..width as f64),
-                    y: rng.random_range(-(height as f64)..height as f64),
-                    speed: 15.0 + depth * 50.0, // back: 15, front: 65
-                    length: 1.0 + depth * 5.0,  // back: short, front: long
-                    wind_offset: rng.random_range(-0.5..0.5),
-                    depth,
-                    r,
-                    g,
-                    b,
-                }
-            })
+            .map(|_| Raindrop::spawn(&mut rng, width, -(height as f64)..height as f64))
             .collect();
 
         let splash_config = EmitterConfig {
@@ -60,29 +75,32 @@ impl Rain {
             speed_max: 35.0,
             life_min: 0.3,
             life_max: 0.8,
-            gravity: 25.0,
+            gravity: SPLASH_GRAVITY,
             drag: 0.98,
             wind: 0.0,
-            gradient: ColorGradient::new(vec![
-                ColorStop {
-                    t: 0.0,
-                    r: 200,
-                    g: 220,
-                    b: 255,
-                },
-                ColorStop {
-                    t: 0.5,
-                    r: 150,
-                    g: 180,
-                    b: 255,
-                },
-                ColorStop {
-                    t: 1.0,
-                    r: 80,
-                    g: 120,
-                    b: 200,
-                },
-            ]),
+            gradient: ColorGradient::baked(
+                vec![
+                    ColorStop {
+                        t: 0.0,
+                        r: 200,
+                        g: 220,
+                        b: 255,
+                    },
+                    ColorStop {
+                        t: 0.5,
+                        r: 150,
+                        g: 180,
+                        b: 255,
+                    },
+                    ColorStop {
+                        t: 1.0,
+                        r: 80,
+                        g: 120,
+                        b: 200,
+                    },
+                ],
+                GRADIENT_LUT_SIZE,
+            ),
         };
 
         Rain {
@@ -93,7 +111,9 @@ impl Rain {
             wind: 0.0,
             wind_target: 0.0,
             wind_timer: 0.0,
-            rng: rand::rng(),
+            base_drops: num_drops,
+            physics: ParticleParams::default(),
+            rng: super::rng(),
         }
     }
 }
@@ -107,27 +127,58 @@ impl Animation for Rain {
         crate::render::RenderMode::HalfBlock
     }
 
+    fn default_scale_for(&self, width: usize, height: usize) -> f64 {
+        super::density_scale(natural_count(width, height), 60.0, 1500.0)
+    }
+
+    fn set_params(&mut self, params: &crate::external::ExternalParams) {
+        self.physics.set(params);
+        self.splashes.config.gravity = SPLASH_GRAVITY * self.physics.gravity;
+        let target = self.physics.scale_count(self.base_drops);
+        if target < self.drops.len() {
+            self.drops.truncate(target);
+        } else {
+            // New drops start above the top edge and fall in
+            let y_range = -(self.height as f64).max(1.0)..0.0;
+            while self.drops.len() < target {
+                let drop = Raindrop::spawn(&mut self.rng, self.width, y_range.clone());
+                self.drops.push(drop);
+            }
+        }
+    }
+
+    fn supported_params(&self) -> &'static [(&'static str, f64, f64)] {
+        &ParticleParams::SUPPORTED
+    }
+
     fn on_resize(&mut self, width: usize, height: usize) {
         self.width = width;
         self.height = height;
     }
 
     fn update(&mut self, canvas: &mut Canvas, dt: f64, _time: f64) {
+        canvas.clear();
+        self.step(canvas, dt);
+    }
+}
+
+impl Rain {
+    /// Advance drops and splashes by `dt` and draw them onto `canvas` without
+    /// clearing it, so other effects can share the frame (see `storm`).
+    pub(crate) fn step(&mut self, canvas: &mut Canvas, dt: f64) {
         // Vary wind over time
         self.wind_timer -= dt;
         if self.wind_timer <= 0.0 {
             self.wind_target = self.rng.random_range(-8.0..8.0);
             self.wind_timer = self.rng.random_range(2.0..6.0);
         }
-        self.wind += (self.wind_target - self.wind) * dt * 0.5;
-
-        canvas.clear();
+        self.wind += (self.wind_target + self.physics.wind - self.wind) * dt * 0.5;
 
         // Update and draw raindrops
         for drop in &mut self.drops {
             let effective_wind = self.wind + drop.wind_offset;
             drop.x += effective_wind * dt;
-            drop.y += drop.speed * dt;
+            drop.y += drop.speed * self.physics.gravity * dt;
 
             // Draw raindrop — depth affects brightness and color
             let depth_brightness = 0.25 + drop.depth * 0.75; // back: dim, front: bright
@@ -174,3 +225,30 @@ impl Animation for Rain {
         self.splashes.draw(canvas);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::external::ExternalParams;
+
+    fn params(json: &str) -> ExternalParams {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn particle_params_resize_drops_and_clamp() {
+        let mut rain = Rain::new(80, 40, 1.0);
+        let base = rain.drops.len();
+        rain.set_params(&params(
+            r#"{"emit_rate": 2.0, "gravity": 9.0, "wind": -100.0}"#,
+        ));
+        assert_eq!(rain.drops.len(), base * 2);
+        assert_eq!(rain.splashes.config.gravity, SPLASH_GRAVITY * 3.0);
+        assert_eq!(rain.physics.wind, -crate::generators::MAX_WIND);
+        // Repeating the same params each frame leaves the drops alone
+        rain.set_params(&params(r#"{"emit_rate": 2.0}"#));
+        assert_eq!(rain.drops.len(), base * 2);
+        rain.set_params(&params(r#"{"emit_rate": 0.5}"#));
+        assert_eq!(rain.drops.len(), (base as f64 * 0.5).round() as usize);
+    }
+}
@@ -39,11 +39,17 @@ pub struct ReactionDiffusion {
     reset_timer: f64,
     /// Seconds before auto-reset
     reset_duration: f64,
-    rng: rand::rngs::ThreadRng,
+    rng: rand::rngs::StdRng,
 }
 
 impl ReactionDiffusion {
     pub fn new(width: usize, height: usize, _scale: f64) -> Self {
+        Self::with_rng(width, height, super::rng())
+    }
+
+    /// A fresh simulation drawing from `rng`; resizing seeds it from the old
+    /// stream so a `--seed` run stays reproducible.
+    fn with_rng(width: usize, height: usize, rng: rand::rngs::StdRng) -> Self {
         let sim_w = (width / 4).max(20);
         let sim_h = (height / 4).max(12);
         let size = sim_w * sim_h;
@@ -66,7 +72,7 @@ impl ReactionDiffusion {
             steps_per_tick: 3,
             reset_timer: 0.0,
             reset_duration: 30.0,
-            rng: rand::rng(),
+            rng,
         };
         rd.seed();
         rd
@@ -162,7 +168,12 @@ impl Animation for ReactionDiffusion {
     }
 
     fn on_resize(&mut self, width: usize, height: usize) {
-        *self = ReactionDiffusion::new(width, height, 1.0);
+        *self =
+            ReactionDiffusion::with_rng(width, height, rand::SeedableRng::from_rng(&mut self.rng));
+    }
+
+    fn on_time_gap(&mut self) {
+        self.step_timer = 0.0;
     }
 
     fn update(&mut self, canvas: &mut Canvas, dt: f64, _time: f64) {
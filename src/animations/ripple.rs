@@ -1,8 +1,36 @@
 use super::Animation;
+use crate::generators::{NamedParam, named_param};
 use crate::render::Canvas;
 use rand::RngExt;
 
-struct RippleSource {
+/// Grid mode: drop points per row/column and seconds between drops.
+const GRID_COLS: usize = 4;
+const GRID_ROWS: usize = 3;
+const GRID_INTERVAL: f64 = 0.6;
+/// Beat mode: `bands` energy must rise through this level to trigger a drop.
+const BEAT_THRESHOLD: f64 = 0.5;
+
+/// Where and when new ripples start; the wave math is the same for all.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RippleSource {
+    /// Random drops at random intervals (original behavior)
+    Random,
+    /// Drops walk a regular lattice, like rain on a still pond
+    Grid,
+    /// A drop wherever `ripple_x`/`ripple_y` (canvas fractions) move to
+    Click,
+    /// A drop per rising pulse of the external `bands` energy
+    Beat,
+}
+
+named_param!(RippleSource {
+    Random => "random",
+    Grid => "grid",
+    Click => "click",
+    Beat => "beat",
+});
+
+struct Droplet {
     x: f64,
     y: f64,
     birth: f64,
@@ -11,9 +39,18 @@ struct RippleSource {
 
 /// Ripple interference pattern (like water drops)
 pub struct Ripple {
-    sources: Vec<RippleSource>,
+    sources: Vec<Droplet>,
     spawn_timer: f64,
-    rng: rand::rngs::ThreadRng,
+    source: RippleSource,
+    /// Next lattice point in grid mode
+    grid_index: usize,
+    /// Click mode: latest requested drop point and whether it is still unspent
+    click: Option<(f64, f64)>,
+    click_pending: bool,
+    /// Beat mode: latest `bands` energy and the level seen by the last update
+    energy: f64,
+    prev_energy: f64,
+    rng: rand::rngs::StdRng,
 }
 
 impl Ripple {
@@ -22,9 +59,93 @@ impl Ripple {
         Ripple {
             sources: Vec::new(),
             spawn_timer: 0.0,
-            rng: rand::rng(),
+            source: RippleSource::Random,
+            grid_index: 0,
+            click: None,
+            click_pending: false,
+            energy: 0.0,
+            prev_energy: 0.0,
+            rng: super::rng(),
+        }
+    }
+
+    fn set_source(&mut self, source: RippleSource) {
+        if source != self.source {
+            self.source = source;
+            self.spawn_timer = 0.0;
         }
     }
+
+    /// New drop for this frame as `(x, y, strength)` in canvas pixels, if the
+    /// current source mode fires one.
+    fn next_drop(&mut self, w: f64, h: f64, dt: f64) -> Option<(f64, f64, f64)> {
+        match self.source {
+            RippleSource::Random => {
+                self.spawn_timer -= dt;
+                if self.spawn_timer > 0.0 {
+                    return None;
+                }
+                self.spawn_timer = self.rng.random_range(0.3..1.5);
+                Some((
+                    self.rng.random_range(0.0..w),
+                    self.rng.random_range(0.0..h),
+                    self.rng.random_range(0.5..1.0),
+                ))
+            }
+            RippleSource::Grid => {
+                self.spawn_timer -= dt;
+                if self.spawn_timer > 0.0 {
+                    return None;
+                }
+                self.spawn_timer = GRID_INTERVAL;
+                let i = self.grid_index;
+                self.grid_index = (i + 1) % (GRID_COLS * GRID_ROWS);
+                let (x, y) = grid_point(i);
+                Some((x * w, y * h, 0.8))
+            }
+            RippleSource::Click => {
+                let (x, y) = self.click.filter(|_| self.click_pending)?;
+                self.click_pending = false;
+                Some((x * w, y * h, 1.0))
+            }
+            RippleSource::Beat => {
+                let rising = self.energy > BEAT_THRESHOLD && self.prev_energy <= BEAT_THRESHOLD;
+                self.prev_energy = self.energy;
+                rising.then(|| {
+                    (
+                        self.rng.random_range(0.0..w),
+                        self.rng.random_range(0.0..h),
+                        self.energy.min(1.0),
+                    )
+                })
+            }
+        }
+    }
+}
+
+/// Lattice point `i` (wrapping) as canvas fractions, visited in a scattered
+/// order so consecutive drops land apart.
+fn grid_point(i: usize) -> (f64, f64) {
+    let n = GRID_COLS * GRID_ROWS;
+    // 5 is coprime with 12, so stepping by it visits every point once.
+    let k = (i * 5) % n;
+    let (col, row) = (k % GRID_COLS, k / GRID_COLS);
+    (
+        (col as f64 + 0.5) / GRID_COLS as f64,
+        (row as f64 + 0.5) / GRID_ROWS as f64,
+    )
+}
+
+/// Overall level of a `bands` value: the mean of a number array, or the
+/// number itself.
+fn band_energy(value: &serde_json::Value) -> Option<f64> {
+    match value {
+        serde_json::Value::Array(bands) => {
+            let levels: Vec<f64> = bands.iter().filter_map(serde_json::Value::as_f64).collect();
+            (!levels.is_empty()).then(|| levels.iter().sum::<f64>() / levels.len() as f64)
+        }
+        v => v.as_f64(),
+    }
 }
 
 impl Animation for Ripple {
@@ -32,20 +153,43 @@ impl Animation for Ripple {
         "ripple"
     }
 
+    fn set_params(&mut self, params: &crate::external::ExternalParams) {
+        if let Some(src) = RippleSource::from_params(params, "ripple_source") {
+            self.set_source(src);
+        }
+        if let (Some(x), Some(y)) = (params.get("ripple_x"), params.get("ripple_y")) {
+            let point = (x.clamp(0.0, 1.0), y.clamp(0.0, 1.0));
+            // Params persist between frames, so only a moved point is a new click.
+            if self.click != Some(point) {
+                self.click = Some(point);
+                self.click_pending = true;
+            }
+        }
+        if let Some(e) = params.extra.get("bands").and_then(band_energy) {
+            self.energy = e.max(0.0);
+        }
+    }
+
+    fn supported_params(&self) -> &'static [(&'static str, f64, f64)] {
+        &[
+            ("ripple_source", 0.0, 3.0),
+            ("ripple_x", 0.0, 1.0),
+            ("ripple_y", 0.0, 1.0),
+        ]
+    }
+
     fn update(&mut self, canvas: &mut Canvas, dt: f64, time: f64) {
         let w = canvas.width as f64;
         let h = canvas.height as f64;
 
         // Spawn new ripple sources
-        self.spawn_timer -= dt;
-        if self.spawn_timer <= 0.0 {
-            self.sources.push(RippleSource {
-                x: self.rng.random_range(0.0..w),
-                y: self.rng.random_range(0.0..h),
+        if let Some((x, y, strength)) = self.next_drop(w, h, dt) {
+            self.sources.push(Droplet {
+                x,
+                y,
                 birth: time,
-                strength: self.rng.random_range(0.5..1.0),
+                strength,
             });
-            self.spawn_timer = self.rng.random_range(0.3..1.5);
         }
 
         // Remove old sources
@@ -88,3 +232,38 @@ impl Animation for Ripple {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid_points_cover_the_lattice_once_per_cycle() {
+        let n = GRID_COLS * GRID_ROWS;
+        let mut points: Vec<(u64, u64)> = (0..n)
+            .map(|i| {
+                let (x, y) = grid_point(i);
+                (x.to_bits(), y.to_bits())
+            })
+            .collect();
+        points.sort_unstable();
+        points.dedup();
+        assert_eq!(points.len(), n);
+        assert_eq!(grid_point(n), grid_point(0));
+    }
+
+    #[test]
+    fn beat_mode_drops_once_per_rising_pulse() {
+        let mut ripple = Ripple::new(80, 40, 1.0);
+        ripple.set_source(RippleSource::Beat);
+        let mut drops = 0;
+        for level in [0.2, 0.8, 0.9, 0.7, 0.1, 0.6, 0.6] {
+            ripple.energy = level;
+            drops += ripple.next_drop(80.0, 40.0, 0.1).is_some() as usize;
+        }
+        assert_eq!(drops, 2);
+        let bands = serde_json::json!([0.25, 0.5, 0.75]);
+        assert_eq!(band_energy(&bands), Some(0.5));
+        assert_eq!(band_energy(&serde_json::json!(0.3)), Some(0.3));
+    }
+}
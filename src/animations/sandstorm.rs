@@ -1,6 +1,13 @@
 use super::Animation;
+use crate::generators::jitter;
 use crate::render::Canvas;
 use rand::RngExt;
+use rand::rngs::StdRng;
+
+/// Per-second (1σ) gust noise on horizontal position and vertical velocity;
+/// matches the old per-frame noise at 24 fps.
+const X_JITTER: f64 = 0.236;
+const VY_JITTER: f64 = 1.18;
 
 struct SandParticle {
     x: f64,
@@ -10,6 +17,11 @@ struct SandParticle {
     size: f64,
 }
 
+/// Grains for a `width` x `height` canvas at scale 1, before density capping.
+fn natural_count(width: usize, height: usize) -> f64 {
+    (width * height) as f64 / 60.0
+}
+
 /// Blowing sand particles with dune formation at bottom
 pub struct Sandstorm {
     width: usize,
@@ -19,13 +31,13 @@ pub struct Sandstorm {
     wind: f64,
     wind_target: f64,
     wind_timer: f64,
-    rng: rand::rngs::ThreadRng,
+    rng: StdRng,
 }
 
 impl Sandstorm {
     pub fn new(width: usize, height: usize, scale: f64) -> Self {
-        let mut rng = rand::rng();
-        let count = ((width * height) as f64 / 60.0 * scale) as usize;
+        let mut rng = super::rng();
+        let count = (natural_count(width, height) * scale) as usize;
         let particles = (0..count)
             .map(|_| SandParticle {
                 x: rng.random_range(0.0..width as f64),
@@ -44,7 +56,7 @@ impl Sandstorm {
             wind: 10.0,
             wind_target: 10.0,
             wind_timer: 0.0,
-            rng: rand::rng(),
+            rng: super::rng(),
         }
     }
 }
@@ -54,6 +66,10 @@ impl Animation for Sandstorm {
         "sandstorm"
     }
 
+    fn default_scale_for(&self, width: usize, height: usize) -> f64 {
+        super::density_scale(natural_count(width, height), 150.0, 2000.0)
+    }
+
     fn on_resize(&mut self, width: usize, height: usize) {
         self.width = width;
         self.height = height;
@@ -77,11 +93,11 @@ impl Animation for Sandstorm {
         // Update particles
         for p in &mut self.particles {
             let gust = (p.y * 0.1 + p.x * 0.05).sin() * 3.0;
-            p.vx = self.wind + gust + self.rng.random_range(-2.0..2.0);
-            p.vy += self.rng.random_range(-0.5..1.5) * dt * 10.0;
+            p.vx = self.wind + gust;
+            p.vy += 5.0 * dt + jitter(&mut self.rng, VY_JITTER, dt);
             p.vy = p.vy.clamp(-2.0, 8.0);
 
-            p.x += p.vx * dt;
+            p.x += p.vx * dt + jitter(&mut self.rng, X_JITTER, dt);
             p.y += p.vy * dt;
 
             // Check dune collision
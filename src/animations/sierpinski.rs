@@ -1,15 +1,27 @@
 use super::Animation;
+use crate::generators::{color_cycle_period, cycle_t};
 use crate::render::Canvas;
 
+/// Seconds per zoom in-and-out (`|sin|` of 0.3 rad/s repeats every π / 0.3).
+const ZOOM_PERIOD: f64 = std::f64::consts::PI / 0.3;
+/// Seconds per palette loop unless `color_cycle_period` overrides it; two
+/// loops per zoom cycle so the whole frame repeats with the zoom.
+const DEFAULT_COLOR_PERIOD: f64 = ZOOM_PERIOD / 2.0;
+
 /// Animated Sierpinski triangle with zoom
 pub struct Sierpinski {
     zoom: f64,
+    /// Seconds per palette loop
+    color_period: f64,
 }
 
 impl Sierpinski {
     #[allow(unused_variables)]
     pub fn new(_width: usize, _height: usize, _scale: f64) -> Self {
-        Sierpinski { zoom: 1.0 }
+        Sierpinski {
+            zoom: 1.0,
+            color_period: DEFAULT_COLOR_PERIOD,
+        }
     }
 }
 
@@ -18,6 +30,28 @@ impl Animation for Sierpinski {
         "sierpinski"
     }
 
+    fn set_params(&mut self, params: &crate::external::ExternalParams) {
+        if let Some(p) = color_cycle_period(params) {
+            self.color_period = p;
+        }
+    }
+
+    fn supported_params(&self) -> &'static [(&'static str, f64, f64)] {
+        &[("color_cycle_period", 0.5, 600.0)]
+    }
+
+    /// The zoom cycle, or the palette loop if longer, when one is a whole
+    /// multiple of the other; other `color_cycle_period` values never realign.
+    fn loop_period(&self) -> Option<f64> {
+        let (short, long) = if self.color_period < ZOOM_PERIOD {
+            (self.color_period, ZOOM_PERIOD)
+        } else {
+            (ZOOM_PERIOD, self.color_period)
+        };
+        let ratio = long / short;
+        ((ratio - ratio.round()).abs() < 1e-6).then_some(long)
+    }
+
     fn preferred_render(&self) -> crate::render::RenderMode {
         crate::render::RenderMode::Braille
     }
@@ -30,7 +64,7 @@ impl Animation for Sierpinski {
 
         // Zoom cycles
         self.zoom = 1.0 + (time * 0.3).sin().abs() * 4.0;
-        let color_offset = time * 0.2;
+        let color_offset = cycle_t(time, self.color_period);
 
         canvas.clear();
 
@@ -266,3 +300,37 @@ fn hsv_to_rgb(h: f64, s: f64, v: f64) -> (u8, u8, u8) {
         ((b + m) * 255.0) as u8,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::canvas::assert_canvas_chars;
+    use crate::render::{ColorMode, RenderMode};
+
+    /// Golden frame at t=0 (zoom 1.0); update it deliberately when the
+    /// fractal drawing changes.
+    #[test]
+    fn first_frame_matches_golden() {
+        let mut canvas = Canvas::new(32, 14, RenderMode::Ascii, ColorMode::Mono);
+        Sierpinski::new(32, 14, 1.0).update(&mut canvas, 0.0, 0.0);
+        assert_canvas_chars(
+            &canvas,
+            "
+            |                                |
+            |                                |
+            |                                |
+            |            %       %           |
+            |                                |
+            |              %   %             |
+            |             %     %            |
+            |                                |
+            |               % %              |
+            |              %% %%             |
+            |                                |
+            |                                |
+            |                                |
+            |                                |
+            ",
+        );
+    }
+}
@@ -1,9 +1,59 @@
 use super::Animation;
-use crate::generators::{ColorGradient, ColorStop, EmitterConfig, ParticleSystem};
+use crate::generators::{
+    ColorGradient, ColorStop, EmitterConfig, NamedParam, ParticleSystem, named_param,
+};
+use crate::palettes::Palette;
 use crate::render::Canvas;
 use noise::{NoiseFn, Perlin};
 use rand::RngExt;
 
+/// Smoke tint: maps particle age (density falloff) onto a color gradient.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SmokeColor {
+    Gray,
+    Toxic,
+    Fire,
+    Steam,
+}
+
+named_param!(SmokeColor {
+    Gray => "gray",
+    Toxic => "toxic",
+    Fire => "fire",
+    Steam => "steam",
+});
+
+impl SmokeColor {
+    fn gradient(self) -> ColorGradient {
+        let stops: [(u8, u8, u8); 4] = match self {
+            SmokeColor::Gray => [
+                (200, 180, 150),
+                (150, 140, 130),
+                (100, 95, 90),
+                (50, 48, 46),
+            ],
+            SmokeColor::Toxic => [(170, 255, 90), (100, 200, 60), (55, 120, 40), (25, 55, 20)],
+            SmokeColor::Fire => [(255, 200, 90), (230, 110, 30), (120, 60, 30), (50, 40, 35)],
+            SmokeColor::Steam => [
+                (255, 255, 255),
+                (225, 230, 235),
+                (180, 185, 195),
+                (120, 125, 135),
+            ],
+        };
+        ColorGradient::new(
+            stops
+                .iter()
+                .zip([0.0, 0.3, 0.6, 1.0])
+                .map(|(&(r, g, b), t)| ColorStop { t, r, g, b })
+                .collect(),
+        )
+    }
+}
+
+/// Particles emitted per second at `smoke_density` 1.0.
+const BASE_EMIT_RATE: f64 = 100.0;
+
 /// Smoke rising with turbulence
 pub struct Smoke {
     width: usize,
@@ -11,7 +61,12 @@ pub struct Smoke {
     system: ParticleSystem,
     noise: Perlin,
     emit_accum: f64,
-    rng: rand::rngs::ThreadRng,
+    color: SmokeColor,
+    /// Replaces the smoke color unless `classic`
+    palette: Palette,
+    /// Emission-rate and opacity multiplier (1.0 = default).
+    density: f64,
+    rng: rand::rngs::StdRng,
 }
 
 impl Smoke {
@@ -31,32 +86,7 @@ impl Smoke {
             gravity: -3.0, // rises
             drag: 0.99,
             wind: 0.0,
-            gradient: ColorGradient::new(vec![
-                ColorStop {
-                    t: 0.0,
-                    r: 200,
-                    g: 180,
-                    b: 150,
-                },
-                ColorStop {
-                    t: 0.3,
-                    r: 150,
-                    g: 140,
-                    b: 130,
-                },
-                ColorStop {
-                    t: 0.6,
-                    r: 100,
-                    g: 95,
-                    b: 90,
-                },
-                ColorStop {
-                    t: 1.0,
-                    r: 50,
-                    g: 48,
-                    b: 46,
-                },
-            ]),
+            gradient: SmokeColor::Gray.gradient(),
         };
 
         Smoke {
@@ -65,7 +95,21 @@ impl Smoke {
             system: ParticleSystem::new(config, (4000.0 * scale) as usize),
             noise: Perlin::new(123),
             emit_accum: 0.0,
-            rng: rand::rng(),
+            color: SmokeColor::Gray,
+            palette: Palette::Classic,
+            density: 1.0,
+            rng: super::rng(),
+        }
+    }
+
+    fn set_colors(&mut self, color: SmokeColor, palette: Palette) {
+        if (color, palette) != (self.color, self.palette) {
+            self.color = color;
+            self.palette = palette;
+            self.system.config.gradient = match palette {
+                Palette::Classic => color.gradient(),
+                _ => palette.cooling(),
+            };
         }
     }
 }
@@ -80,6 +124,23 @@ impl Animation for Smoke {
         self.height = height;
     }
 
+    fn set_params(&mut self, params: &crate::external::ExternalParams) {
+        let color = SmokeColor::from_params(params, "smoke_color");
+        let palette = Palette::from_params(params, "fire_palette");
+        self.set_colors(color.unwrap_or(self.color), palette.unwrap_or(self.palette));
+        if let Some(v) = params.get("smoke_density") {
+            self.density = v.clamp(0.1, 3.0);
+        }
+    }
+
+    fn supported_params(&self) -> &'static [(&'static str, f64, f64)] {
+        &[
+            ("smoke_color", 0.0, 3.0),
+            ("smoke_density", 0.1, 3.0),
+            ("fire_palette", 0.0, 4.0),
+        ]
+    }
+
     fn update(&mut self, canvas: &mut Canvas, dt: f64, time: f64) {
         let cx = self.width as f64 / 2.0;
         let bottom = self.height as f64 - 1.0;
@@ -88,10 +149,11 @@ impl Animation for Smoke {
         self.system.config.y = bottom;
 
         // Emit smoke
-        self.emit_accum += dt;
-        while self.emit_accum >= 0.02 {
-            self.system.emit(2);
-            self.emit_accum -= 0.02;
+        self.emit_accum += dt * BASE_EMIT_RATE * self.density;
+        let count = self.emit_accum as usize;
+        if count > 0 {
+            self.system.emit(count);
+            self.emit_accum -= count as f64;
         }
 
         // Apply turbulence via noise before standard physics
@@ -113,7 +175,8 @@ impl Animation for Smoke {
             let age = p.age();
             let size = (1.0 + age * 3.0) as usize;
             let (r, g, b) = self.system.config.gradient.sample(age);
-            let brightness = p.life_frac() * 0.7;
+            // Denser smoke is more opaque; thinner smoke more transparent.
+            let brightness = (p.life_frac() * 0.7 * self.density.sqrt()).min(1.0);
 
             for dy in 0..size {
                 for dx in 0..size {
@@ -139,3 +202,46 @@ impl Animation for Smoke {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::external::ExternalParams;
+
+    fn params(json: &str) -> ExternalParams {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn smoke_color_accepts_name_or_index() {
+        let mut smoke = Smoke::new(40, 40, 1.0);
+        smoke.set_params(&params(r#"{"smoke_color": "toxic"}"#));
+        assert_eq!(smoke.color, SmokeColor::Toxic);
+        smoke.set_params(&params(r#"{"smoke_color": 3}"#));
+        assert_eq!(smoke.color, SmokeColor::Steam);
+        assert_eq!(smoke.system.config.gradient.sample(0.0), (255, 255, 255));
+        smoke.set_params(&params(r#"{"fire_palette": "blue-fire"}"#));
+        assert_eq!(
+            smoke.system.config.gradient.sample(0.0),
+            Palette::BlueFire.cooling().sample(0.0)
+        );
+    }
+
+    #[test]
+    fn smoke_density_scales_emission() {
+        let mut thin = Smoke::new(40, 40, 1.0);
+        let mut thick = Smoke::new(40, 40, 1.0);
+        thin.set_params(&params(r#"{"smoke_density": 0.5}"#));
+        thick.set_params(&params(r#"{"smoke_density": 2.0}"#));
+        let mut canvas = Canvas::new(
+            20,
+            10,
+            crate::render::RenderMode::Braille,
+            crate::render::ColorMode::TrueColor,
+        );
+        thin.update(&mut canvas, 0.1, 0.0);
+        thick.update(&mut canvas, 0.1, 0.0);
+        assert_eq!(thin.system.particles.len(), 5);
+        assert_eq!(thick.system.particles.len(), 20);
+    }
+}
@@ -1,4 +1,5 @@
-use super::Animation;
+use super::{Animation, GameEvent};
+use crate::generators::{NamedParam, named_param};
 use crate::render::Canvas;
 use rand::RngExt;
 
@@ -37,6 +38,54 @@ impl Dir {
     }
 }
 
+/// How the snake picks its next move.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnakeAi {
+    /// Closest step toward the food (the original AI; traps itself often)
+    Greedy,
+    /// Greedy unless that boxes the snake in, else the roomiest move
+    Safe,
+    /// Follow a Hamiltonian cycle over the whole grid
+    Hamilton,
+}
+
+named_param!(SnakeAi {
+    Greedy => "greedy",
+    Safe => "safe",
+    Hamilton => "hamilton",
+});
+
+/// A Hamiltonian cycle over a `width` x `height` grid, or `None` when both
+/// sides are odd (no such cycle exists).
+///
+/// Runs along row 0, snakes back and forth through columns `1..` on the
+/// remaining rows, and returns up column 0; a transposed walk covers grids
+/// with an odd height.
+fn hamilton_cycle(width: usize, height: usize) -> Option<Vec<(i32, i32)>> {
+    if height % 2 == 1 {
+        let transposed = hamilton_cycle_even_height(height, width)?;
+        return Some(transposed.into_iter().map(|(x, y)| (y, x)).collect());
+    }
+    hamilton_cycle_even_height(width, height)
+}
+
+fn hamilton_cycle_even_height(width: usize, height: usize) -> Option<Vec<(i32, i32)>> {
+    if height % 2 == 1 || width < 2 || height < 2 {
+        return None;
+    }
+    let (w, h) = (width as i32, height as i32);
+    let mut cycle: Vec<(i32, i32)> = (0..w).map(|x| (x, 0)).collect();
+    for y in 1..h {
+        if y % 2 == 1 {
+            cycle.extend((1..w).rev().map(|x| (x, y)));
+        } else {
+            cycle.extend((1..w).map(|x| (x, y)));
+        }
+    }
+    cycle.extend((1..h).rev().map(|y| (0, y)));
+    Some(cycle)
+}
+
 /// Self-playing Snake game AI
 pub struct Snake {
     width: usize,
@@ -48,7 +97,14 @@ pub struct Snake {
     move_interval: f64,
     score: usize,
     game_over_timer: f64,
-    rng: rand::rngs::ThreadRng,
+    events: Vec<GameEvent>,
+    ai: SnakeAi,
+    /// Hamiltonian cycle for the current grid size, and each cell's index
+    /// into it (`usize::MAX` off-cycle); rebuilt when the grid changes
+    cycle: Vec<(i32, i32)>,
+    cycle_index: Vec<usize>,
+    cycle_dims: (usize, usize),
+    rng: rand::rngs::StdRng,
 }
 
 impl Snake {
@@ -58,7 +114,7 @@ impl Snake {
         let cx = grid_w as i32 / 2;
         let cy = grid_h as i32 / 2;
 
-        let mut rng = rand::rng();
+        let mut rng = super::rng();
         let food = (
             rng.random_range(1..grid_w as i32 - 1),
             rng.random_range(1..grid_h as i32 - 1),
@@ -74,7 +130,12 @@ impl Snake {
             move_interval: 0.08,
             score: 0,
             game_over_timer: 0.0,
-            rng: rand::rng(),
+            events: Vec::new(),
+            ai: SnakeAi::Safe,
+            cycle: Vec::new(),
+            cycle_index: Vec::new(),
+            cycle_dims: (0, 0),
+            rng: super::rng(),
         }
     }
 
@@ -90,57 +151,169 @@ impl Snake {
         self.score = 0;
     }
 
-    fn ai_choose_direction(&self) -> Dir {
+    fn ai_choose_direction(&mut self) -> Dir {
+        match self.ai {
+            SnakeAi::Greedy => self.greedy_direction(),
+            SnakeAi::Safe => self.safe_direction(),
+            SnakeAi::Hamilton => self.hamilton_direction(),
+        }
+    }
+
+    /// Moves that don't immediately hit a wall or the body.
+    fn open_moves(&self) -> impl Iterator<Item = (Dir, (i32, i32))> + '_ {
         let head = self.body[0];
-        let fx = self.food.0;
-        let fy = self.food.1;
+        [Dir::Up, Dir::Down, Dir::Left, Dir::Right]
+            .into_iter()
+            .filter(move |&d| d != self.dir.opposite())
+            .map(move |d| (d, (head.0 + d.dx(), head.1 + d.dy())))
+            .filter(move |&(_, (nx, ny))| {
+                nx >= 0
+                    && nx < self.width as i32
+                    && ny >= 0
+                    && ny < self.height as i32
+                    && !self.body.contains(&(nx, ny))
+            })
+    }
+
+    fn food_distance(&self, (x, y): (i32, i32)) -> i32 {
+        (x - self.food.0).abs() + (y - self.food.1).abs()
+    }
 
-        // Possible directions (excluding reverse)
-        let dirs = [Dir::Up, Dir::Down, Dir::Left, Dir::Right];
-        let mut best_dir = self.dir;
-        let mut best_dist = i32::MAX;
+    fn greedy_direction(&self) -> Dir {
+        self.open_moves()
+            .min_by_key(|&(_, cell)| self.food_distance(cell))
+            .map_or(self.dir, |(d, _)| d)
+    }
 
-        for &d in &dirs {
-            if d == self.dir.opposite() {
-                continue;
+    /// Free cells reachable from `head` once the snake has moved there.
+    fn reachable_after(&self, head: (i32, i32)) -> usize {
+        let (w, h) = (self.width as i32, self.height as i32);
+        let mut blocked = vec![false; self.width * self.height];
+        // The tail moves off its cell unless this move eats
+        let keep = if head == self.food {
+            self.body.len()
+        } else {
+            self.body.len() - 1
+        };
+        for &(x, y) in self.body.iter().take(keep) {
+            if x >= 0 && y >= 0 && x < w && y < h {
+                blocked[(y * w + x) as usize] = true;
             }
+        }
+        let mut stack = vec![head];
+        blocked[(head.1 * w + head.0) as usize] = true;
+        let mut count = 0;
+        while let Some((x, y)) = stack.pop() {
+            count += 1;
+            for (dx, dy) in [(0, -1), (0, 1), (-1, 0), (1, 0)] {
+                let (nx, ny) = (x + dx, y + dy);
+                if nx >= 0 && ny >= 0 && nx < w && ny < h && !blocked[(ny * w + nx) as usize] {
+                    blocked[(ny * w + nx) as usize] = true;
+                    stack.push((nx, ny));
+                }
+            }
+        }
+        count
+    }
 
-            let nx = head.0 + d.dx();
-            let ny = head.1 + d.dy();
+    /// Greedy if the snake still has room to fit its body afterwards,
+    /// otherwise the move with the most reachable free space.
+    fn safe_direction(&self) -> Dir {
+        let moves: Vec<(Dir, i32, usize)> = self
+            .open_moves()
+            .map(|(d, cell)| (d, self.food_distance(cell), self.reachable_after(cell)))
+            .collect();
+        let roomy = moves
+            .iter()
+            .filter(|&&(_, _, room)| room >= self.body.len())
+            .min_by_key(|&&(_, dist, _)| dist);
+        roomy
+            .or_else(|| {
+                moves
+                    .iter()
+                    .max_by_key(|&&(_, dist, room)| (room, std::cmp::Reverse(dist)))
+            })
+            .map_or(self.dir, |&(d, _, _)| d)
+    }
 
-            // Check if move is safe
-            if nx < 0 || nx >= self.width as i32 || ny < 0 || ny >= self.height as i32 {
-                continue;
+    /// Next cell along the Hamiltonian cycle; falls back to `safe` while
+    /// that cell is blocked (e.g. just after switching AI) or when the grid
+    /// has no cycle.
+    fn hamilton_direction(&mut self) -> Dir {
+        if self.cycle_dims != (self.width, self.height) {
+            self.cycle_dims = (self.width, self.height);
+            self.cycle = hamilton_cycle(self.width, self.height).unwrap_or_default();
+            self.cycle_index = vec![usize::MAX; self.width * self.height];
+            for (i, &(x, y)) in self.cycle.iter().enumerate() {
+                self.cycle_index[y as usize * self.width + x as usize] = i;
             }
-
-            let hits_body = self.body.iter().any(|&(bx, by)| bx == nx && by == ny);
-            if hits_body {
-                continue;
+        }
+        let head = self.body[0];
+        let on_grid = head.0 >= 0
+            && head.1 >= 0
+            && (head.0 as usize) < self.width
+            && (head.1 as usize) < self.height;
+        if on_grid && !self.cycle.is_empty() {
+            let i = self.cycle_index[head.1 as usize * self.width + head.0 as usize];
+            let next = self.cycle[(i + 1) % self.cycle.len()];
+            if let Some((d, _)) = self.open_moves().find(|&(_, cell)| cell == next) {
+                return d;
             }
+        }
+        self.safe_direction()
+    }
 
-            let dist = (nx - fx).abs() + (ny - fy).abs();
-            if dist < best_dist {
-                best_dist = dist;
-                best_dir = d;
-            }
+    /// One move: pick a direction, then die, eat, or slide forward.
+    fn advance(&mut self) {
+        self.dir = self.ai_choose_direction();
+
+        let head = self.body[0];
+        let new_head = (head.0 + self.dir.dx(), head.1 + self.dir.dy());
+
+        // Check collision
+        let wall_hit = new_head.0 < 0
+            || new_head.0 >= self.width as i32
+            || new_head.1 < 0
+            || new_head.1 >= self.height as i32;
+        let body_hit = self
+            .body
+            .iter()
+            .any(|&(bx, by)| bx == new_head.0 && by == new_head.1);
+
+        if wall_hit || body_hit {
+            self.events.push(GameEvent::Die);
+            self.game_over_timer = 2.0;
+            return;
         }
 
-        best_dir
+        self.body.insert(0, new_head);
+
+        // Check food
+        if new_head == self.food {
+            self.events.push(GameEvent::Eat);
+            self.score += 1;
+            self.spawn_food();
+        } else {
+            self.body.pop();
+        }
     }
 
     fn spawn_food(&mut self) {
-        loop {
-            let fx = self.rng.random_range(1..self.width as i32 - 1);
-            let fy = self.rng.random_range(1..self.height as i32 - 1);
-            if !self.body.iter().any(|&(bx, by)| bx == fx && by == fy) {
-                self.food = (fx, fy);
-                break;
-            }
-            // Safety: if snake fills the grid, just place it anywhere
-            if self.body.len() >= self.width * self.height - 2 {
-                self.food = (fx, fy);
-                break;
-            }
+        let (w, h) = (self.width as i32, self.height as i32);
+        let body = &self.body;
+        let free = |margin: i32| -> Vec<(i32, i32)> {
+            (margin..h - margin)
+                .flat_map(|y| (margin..w - margin).map(move |x| (x, y)))
+                .filter(|cell| !body.contains(cell))
+                .collect()
+        };
+        // Off the border while there's room, then anywhere free
+        let mut cells = free(1);
+        if cells.is_empty() {
+            cells = free(0);
+        }
+        if !cells.is_empty() {
+            self.food = cells[self.rng.random_range(0..cells.len())];
         }
     }
 }
@@ -154,10 +327,21 @@ impl Animation for Snake {
         if let Some(speed) = params.speed {
             self.move_interval = speed.clamp(0.02, 0.2);
         }
+        if let Some(ai) = SnakeAi::from_params(params, "snake_ai") {
+            self.ai = ai;
+        }
+    }
+
+    fn on_time_gap(&mut self) {
+        self.move_timer = 0.0;
     }
 
     fn supported_params(&self) -> &'static [(&'static str, f64, f64)] {
-        &[("speed", 0.02, 0.2)]
+        &[("speed", 0.02, 0.2), ("snake_ai", 0.0, 2.0)]
+    }
+
+    fn take_events(&mut self) -> Vec<GameEvent> {
+        std::mem::take(&mut self.events)
     }
 
     fn update(&mut self, canvas: &mut Canvas, dt: f64, _time: f64) {
@@ -177,36 +361,9 @@ impl Animation for Snake {
             self.move_timer += dt;
             while self.move_timer >= self.move_interval {
                 self.move_timer -= self.move_interval;
-
-                // AI decides direction
-                self.dir = self.ai_choose_direction();
-
-                let head = self.body[0];
-                let new_head = (head.0 + self.dir.dx(), head.1 + self.dir.dy());
-
-                // Check collision
-                let wall_hit = new_head.0 < 0
-                    || new_head.0 >= self.width as i32
-                    || new_head.1 < 0
-                    || new_head.1 >= self.height as i32;
-                let body_hit = self
-                    .body
-                    .iter()
-                    .any(|&(bx, by)| bx == new_head.0 && by == new_head.1);
-
-                if wall_hit || body_hit {
-                    self.game_over_timer = 2.0;
-                    continue;
-                }
-
-                self.body.insert(0, new_head);
-
-                // Check food
-                if new_head == self.food {
-                    self.score += 1;
-                    self.spawn_food();
-                } else {
-                    self.body.pop();
+                self.advance();
+                if self.game_over_timer > 0.0 {
+                    break;
                 }
             }
         }
@@ -276,3 +433,65 @@ impl Animation for Snake {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Score of the first game (at most `moves` moves) on a 12x10 grid.
+    fn first_game_score(ai: SnakeAi, moves: usize) -> usize {
+        let mut snake = Snake::new(24, 20, 1.0);
+        snake.ai = ai;
+        for _ in 0..moves {
+            snake.advance();
+            if snake.game_over_timer > 0.0 {
+                break;
+            }
+        }
+        snake.score
+    }
+
+    #[test]
+    fn hamilton_cycle_visits_every_cell_once() {
+        for (w, h) in [(12, 10), (11, 10), (10, 11), (2, 2)] {
+            let cycle = hamilton_cycle(w, h).unwrap();
+            assert_eq!(cycle.len(), w * h);
+            let mut seen = std::collections::HashSet::new();
+            for (i, &(x, y)) in cycle.iter().enumerate() {
+                assert!(seen.insert((x, y)));
+                let (nx, ny) = cycle[(i + 1) % cycle.len()];
+                assert_eq!((nx - x).abs() + (ny - y).abs(), 1, "{w}x{h} step {i}");
+            }
+        }
+        assert!(hamilton_cycle(11, 9).is_none());
+    }
+
+    #[test]
+    fn hamilton_snake_fills_the_board() {
+        // 100 interior food cells on a 120-cell board
+        assert!(first_game_score(SnakeAi::Hamilton, 50_000) >= 90);
+    }
+
+    #[test]
+    fn safe_snake_outlasts_greedy() {
+        let games = 20;
+        let safe: usize = (0..games)
+            .map(|_| first_game_score(SnakeAi::Safe, 20_000))
+            .sum();
+        let greedy: usize = (0..games)
+            .map(|_| first_game_score(SnakeAi::Greedy, 20_000))
+            .sum();
+        assert!(safe * 2 > greedy * 3, "safe {safe} vs greedy {greedy}");
+    }
+
+    #[test]
+    fn ai_param_takes_a_name_or_an_index() {
+        let params =
+            |json: &str| serde_json::from_str::<crate::external::ExternalParams>(json).unwrap();
+        let mut snake = Snake::new(40, 20, 1.0);
+        snake.set_params(&params(r#"{"snake_ai": "hamilton"}"#));
+        assert_eq!(snake.ai, SnakeAi::Hamilton);
+        snake.set_params(&params(r#"{"snake_ai": 0}"#));
+        assert_eq!(snake.ai, SnakeAi::Greedy);
+    }
+}
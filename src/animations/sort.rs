@@ -1,4 +1,4 @@
-use super::Animation;
+use super::{Animation, GameEvent};
 use crate::render::Canvas;
 use rand::RngExt;
 
@@ -38,7 +38,8 @@ pub struct Sort {
     sorted: bool,
     pause_timer: f64,
     ops_per_frame: usize,
-    rng: rand::rngs::ThreadRng,
+    events: Vec<GameEvent>,
+    rng: rand::rngs::StdRng,
 }
 
 #[derive(Clone, Copy)]
@@ -50,7 +51,7 @@ enum SortOp {
 impl Sort {
     pub fn new(width: usize, _height: usize, _scale: f64) -> Self {
         let size = (width / 2).clamp(16, 200);
-        let mut rng = rand::rng();
+        let mut rng = super::rng();
         let data: Vec<f64> = (0..size).map(|_| rng.random_range(0.05..1.0)).collect();
 
         let mut sort = Sort {
@@ -62,7 +63,8 @@ impl Sort {
             sorted: false,
             pause_timer: 0.0,
             ops_per_frame: 3,
-            rng: rand::rng(),
+            events: Vec::new(),
+            rng: super::rng(),
         };
         sort.generate_ops();
         sort
@@ -225,6 +227,10 @@ impl Animation for Sort {
         &[("speed", 1.0, 20.0)]
     }
 
+    fn take_events(&mut self) -> Vec<GameEvent> {
+        std::mem::take(&mut self.events)
+    }
+
     fn update(&mut self, canvas: &mut Canvas, dt: f64, _time: f64) {
         let w = canvas.width;
         let h = canvas.height;
@@ -257,6 +263,7 @@ impl Animation for Sort {
                 } else {
                     self.sorted = true;
                     self.pause_timer = 2.0;
+                    self.events.push(GameEvent::Complete);
                     break;
                 }
             }
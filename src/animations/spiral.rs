@@ -1,13 +1,124 @@
 use super::Animation;
+use crate::generators::{ColorGradient, ColorStop, NamedParam, cycle_t, named_param};
 use crate::render::Canvas;
+use std::f64::consts::TAU;
+
+/// Arm rotation speed in radians per second.
+const ARM_SPEED: f64 = 4.5;
+/// Seconds per hue loop: five arm turns, so color and shape realign.
+const HUE_PERIOD: f64 = 5.0 * TAU / ARM_SPEED;
+const DEFAULT_ARMS: u32 = 4;
+const MAX_ARMS: u32 = 12;
+
+/// Which way the arms sweep on screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpiralDirection {
+    Clockwise,
+    CounterClockwise,
+}
+
+named_param!(SpiralDirection {
+    Clockwise => "cw",
+    CounterClockwise => "ccw",
+});
+
+impl SpiralDirection {
+    fn sign(self) -> f64 {
+        match self {
+            SpiralDirection::Clockwise => 1.0,
+            SpiralDirection::CounterClockwise => -1.0,
+        }
+    }
+}
+
+/// How the arms wind outward from the center.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpiralShape {
+    /// Evenly spaced turns (the original look)
+    Archimedean,
+    /// Turns widen with distance, like a nautilus shell
+    Logarithmic,
+}
+
+named_param!(SpiralShape {
+    Archimedean => "archimedean",
+    Logarithmic => "logarithmic",
+});
+
+impl SpiralShape {
+    /// Phase offset added at radius `r` pixels from the center.
+    fn winding(self, r: f64) -> f64 {
+        match self {
+            SpiralShape::Archimedean => r * 0.15,
+            SpiralShape::Logarithmic => (1.0 + r).ln() * 3.0,
+        }
+    }
+}
+
+/// Color scheme for the arms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpiralPalette {
+    /// Full hue wheel around the center (the original look)
+    Rainbow,
+    Fire,
+    Ice,
+    Toxic,
+}
+
+named_param!(SpiralPalette {
+    Rainbow => "rainbow",
+    Fire => "fire",
+    Ice => "ice",
+    Toxic => "toxic",
+});
+
+impl SpiralPalette {
+    /// Gradient swept around the center; `None` for the hue wheel.
+    fn gradient(self) -> Option<ColorGradient> {
+        let stops: &[(f64, u8, u8, u8)] = match self {
+            SpiralPalette::Rainbow => return None,
+            SpiralPalette::Fire => &[(0.0, 120, 10, 0), (0.5, 255, 110, 0), (1.0, 255, 230, 120)],
+            SpiralPalette::Ice => &[
+                (0.0, 20, 40, 140),
+                (0.5, 60, 160, 255),
+                (1.0, 220, 245, 255),
+            ],
+            SpiralPalette::Toxic => &[(0.0, 20, 90, 0), (0.5, 120, 255, 40), (1.0, 230, 255, 160)],
+        };
+        Some(ColorGradient::new(
+            stops
+                .iter()
+                .map(|&(t, r, g, b)| ColorStop { t, r, g, b })
+                .collect(),
+        ))
+    }
+}
 
 /// Rotating spiral pattern
-pub struct Spiral;
+pub struct Spiral {
+    arms: u32,
+    direction: SpiralDirection,
+    shape: SpiralShape,
+    palette: SpiralPalette,
+    gradient: Option<ColorGradient>,
+}
 
 impl Spiral {
-    #[allow(unused_variables)]
     pub fn new(_width: usize, _height: usize, _scale: f64) -> Self {
-        Spiral
+        Spiral {
+            arms: DEFAULT_ARMS,
+            direction: SpiralDirection::Clockwise,
+            shape: SpiralShape::Archimedean,
+            palette: SpiralPalette::Rainbow,
+            gradient: None,
+        }
+    }
+
+    fn set_palette(&mut self, palette: SpiralPalette) {
+        if palette != self.palette {
+            self.palette = palette;
+            self.gradient = palette.gradient();
+        }
     }
 }
 
@@ -16,13 +127,43 @@ impl Animation for Spiral {
         "spiral"
     }
 
+    fn set_params(&mut self, params: &crate::external::ExternalParams) {
+        if let Some(v) = params.get("spiral_arms") {
+            self.arms = (v.round() as u32).clamp(1, MAX_ARMS);
+        }
+        if let Some(d) = SpiralDirection::from_params(params, "spiral_direction") {
+            self.direction = d;
+        }
+        if let Some(s) = SpiralShape::from_params(params, "spiral_shape") {
+            self.shape = s;
+        }
+        if let Some(p) = SpiralPalette::from_params(params, "spiral_palette") {
+            self.set_palette(p);
+        }
+    }
+
+    fn supported_params(&self) -> &'static [(&'static str, f64, f64)] {
+        &[
+            ("spiral_arms", 1.0, MAX_ARMS as f64),
+            ("spiral_direction", 0.0, 1.0),
+            ("spiral_shape", 0.0, 1.0),
+            ("spiral_palette", 0.0, 3.0),
+        ]
+    }
+
+    fn loop_period(&self) -> Option<f64> {
+        Some(HUE_PERIOD)
+    }
+
     fn update(&mut self, canvas: &mut Canvas, _dt: f64, time: f64) {
         let w = canvas.width as f64;
         let h = canvas.height as f64;
         let cx = w / 2.0;
         let cy = h / 2.0;
         let max_r = (cx * cx + cy * cy).sqrt();
-        let t = time * 1.5;
+        let sign = self.direction.sign();
+        let hue_shift = cycle_t(sign * time, HUE_PERIOD);
+        let arms = self.arms as f64;
 
         for y in 0..canvas.height {
             for x in 0..canvas.width {
@@ -32,14 +173,24 @@ impl Animation for Spiral {
                 let angle = dy.atan2(dx);
 
                 // Spiral formula: brightness based on angle + radius offset
-                let arms = 4.0;
-                let spiral = (angle * arms + r * 0.15 - t * 3.0).sin();
+                let spiral = (angle * arms + self.shape.winding(r) - sign * time * ARM_SPEED).sin();
                 let fade = 1.0 - (r / max_r).clamp(0.0, 1.0);
                 let v = ((spiral + 1.0) * 0.5 * fade).clamp(0.0, 1.0);
 
                 if v > 0.05 {
-                    let hue = (angle / std::f64::consts::TAU + 0.5 + t * 0.1).fract();
-                    let (cr, cg, cb) = hsv_to_rgb(hue, 0.8, v);
+                    let hue = (angle / TAU + 0.5 + hue_shift).fract();
+                    let (cr, cg, cb) = match &self.gradient {
+                        // Mirror the sweep so the gradient has no seam.
+                        Some(gradient) => {
+                            let (r, g, b) = gradient.sample(1.0 - (hue * 2.0 - 1.0).abs());
+                            (
+                                (r as f64 * v) as u8,
+                                (g as f64 * v) as u8,
+                                (b as f64 * v) as u8,
+                            )
+                        }
+                        None => hsv_to_rgb(hue, 0.8, v),
+                    };
                     canvas.set_colored(x, y, v, cr, cg, cb);
                 } else {
                     canvas.set_colored(x, y, 0.0, 0, 0, 0);
@@ -67,3 +218,43 @@ fn hsv_to_rgb(h: f64, s: f64, v: f64) -> (u8, u8, u8) {
         ((b + m) * 255.0) as u8,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::external::ExternalParams;
+    use crate::render::{ColorMode, RenderMode};
+
+    /// Lit runs met walking a ring around the center of a fresh frame.
+    fn arms_on_ring(spiral: &mut Spiral) -> usize {
+        let mut canvas = Canvas::new(60, 30, RenderMode::HalfBlock, ColorMode::TrueColor);
+        spiral.update(&mut canvas, 0.0, 0.0);
+        let (cx, cy) = (canvas.width as f64 / 2.0, canvas.height as f64 / 2.0);
+        let lit: Vec<bool> = (0..360)
+            .map(|deg| {
+                let a = (deg as f64).to_radians();
+                let x = (cx + a.cos() * 12.0) as usize;
+                let y = (cy + a.sin() * 12.0) as usize;
+                canvas.pixels[y * canvas.width + x] > 0.3
+            })
+            .collect();
+        (0..lit.len())
+            .filter(|&i| lit[i] && !lit[(i + lit.len() - 1) % lit.len()])
+            .count()
+    }
+
+    #[test]
+    fn arm_count_follows_param() {
+        let mut spiral = Spiral::new(60, 60, 1.0);
+        assert_eq!(arms_on_ring(&mut spiral), DEFAULT_ARMS as usize);
+
+        let mut params = ExternalParams::default();
+        params.extra.insert("spiral_arms".into(), 7.into());
+        params
+            .extra
+            .insert("spiral_shape".into(), "logarithmic".into());
+        spiral.set_params(&params);
+        assert_eq!(spiral.shape, SpiralShape::Logarithmic);
+        assert_eq!(arms_on_ring(&mut spiral), 7);
+    }
+}
@@ -0,0 +1,91 @@
+use super::Animation;
+use super::lightning::Lightning;
+use super::rain::Rain;
+use crate::render::Canvas;
+use rand::RngExt;
+
+/// Flash brightness multiplier; much stronger than bare `lightning` so the
+/// whole scene lights up.
+const FLASH_STRENGTH: f64 = 0.35;
+/// How far the scene dims right after a flash (fraction of brightness).
+const THUNDER_DARKEN: f64 = 0.5;
+
+/// Thunderstorm: `rain` with occasional `lightning` strikes, a full-screen
+/// flash, and the scene dimming while the thunder rolls in
+pub struct Storm {
+    rain: Rain,
+    lightning: Lightning,
+    /// Seconds until the thunder of the last strike arrives.
+    thunder: f64,
+    thunder_total: f64,
+    rng: rand::rngs::StdRng,
+}
+
+impl Storm {
+    pub fn new(width: usize, height: usize, scale: f64) -> Self {
+        let mut lightning = Lightning::new(width, height, scale);
+        lightning.interval = (2.5, 7.0);
+        lightning.flash_peak = 1.0;
+        Storm {
+            rain: Rain::new(width, height, scale * 1.5),
+            lightning,
+            thunder: 0.0,
+            thunder_total: 0.0,
+            rng: super::rng(),
+        }
+    }
+}
+
+impl Animation for Storm {
+    fn name(&self) -> &str {
+        "storm"
+    }
+
+    fn preferred_render(&self) -> crate::render::RenderMode {
+        crate::render::RenderMode::HalfBlock
+    }
+
+    fn on_resize(&mut self, width: usize, height: usize) {
+        self.rain.on_resize(width, height);
+        self.lightning.on_resize(width, height);
+    }
+
+    fn update(&mut self, canvas: &mut Canvas, dt: f64, _time: f64) {
+        if self.lightning.step(dt) {
+            self.thunder_total = self.rng.random_range(0.8..2.5);
+            self.thunder = self.thunder_total;
+        }
+        self.thunder = (self.thunder - dt).max(0.0);
+
+        canvas.clear();
+        self.rain.step(canvas, dt);
+
+        // Once the flash fades the eye needs time to readjust: dim the scene,
+        // recovering until the thunder arrives.
+        if self.lightning.flash() <= 0.0 && self.thunder > 0.0 {
+            let dim = 1.0 - THUNDER_DARKEN * self.thunder / self.thunder_total;
+            for p in canvas.pixels.iter_mut() {
+                *p *= dim;
+            }
+        }
+
+        self.lightning.draw_flash(canvas, FLASH_STRENGTH);
+        self.lightning.draw_bolts(canvas);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::{ColorMode, RenderMode};
+
+    #[test]
+    fn first_strike_flashes_whole_canvas_and_starts_thunder() {
+        let mut storm = Storm::new(40, 40, 1.0);
+        let mut canvas = Canvas::new(40, 20, RenderMode::HalfBlock, ColorMode::TrueColor);
+        storm.update(&mut canvas, 0.01, 0.0);
+        assert!(storm.thunder > 0.0);
+        let floor = storm.lightning.flash() * FLASH_STRENGTH;
+        assert!(canvas.pixels.iter().all(|&p| p >= floor));
+    }
+}
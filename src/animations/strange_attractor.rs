@@ -1,4 +1,5 @@
 use super::Animation;
+use crate::generators::{color_cycle_period, cycle_t};
 use crate::render::Canvas;
 
 const SIGMA: f64 = 10.0;
@@ -7,6 +8,8 @@ const BETA: f64 = 8.0 / 3.0;
 const TRAIL_MAX: usize = 1500;
 const STEPS_PER_FRAME: usize = 16;
 const ODE_DT: f64 = 0.005;
+/// Seconds per palette loop unless `color_cycle_period` overrides it.
+const DEFAULT_COLOR_PERIOD: f64 = 100.0 / 3.0;
 
 /// Strange attractor: a Lorenz system integrated with RK4 and rendered as a
 /// slowly rotating, rainbow-fading trajectory drawn with soft glowing points.
@@ -15,6 +18,8 @@ pub struct StrangeAttractor {
     y: f64,
     z: f64,
     trail: Vec<(f64, f64, f64)>,
+    /// Seconds per palette loop
+    color_period: f64,
 }
 
 impl StrangeAttractor {
@@ -26,6 +31,7 @@ impl StrangeAttractor {
             y: 0.0,
             z: 0.0,
             trail: Vec::with_capacity(TRAIL_MAX),
+            color_period: DEFAULT_COLOR_PERIOD,
         }
     }
 
@@ -53,6 +59,16 @@ impl Animation for StrangeAttractor {
         "strange_attractor"
     }
 
+    fn set_params(&mut self, params: &crate::external::ExternalParams) {
+        if let Some(p) = color_cycle_period(params) {
+            self.color_period = p;
+        }
+    }
+
+    fn supported_params(&self) -> &'static [(&'static str, f64, f64)] {
+        &[("color_cycle_period", 0.5, 600.0)]
+    }
+
     fn update(&mut self, canvas: &mut Canvas, _dt: f64, time: f64) {
         for _ in 0..STEPS_PER_FRAME {
             self.rk4_step(ODE_DT);
@@ -85,7 +101,7 @@ impl Animation for StrangeAttractor {
 
             let depth = ((rz + 25.0) / 50.0).clamp(0.2, 1.0);
             let frac = i as f64 / n as f64;
-            let hue = (frac * 0.7 + time * 0.03).rem_euclid(1.0);
+            let hue = (frac * 0.7 + cycle_t(time, self.color_period)).rem_euclid(1.0);
             let (r, g, b) = hsv_to_rgb(hue, 0.95, 0.6 + 0.4 * frac);
             let bright = ((0.15 + 0.85 * frac) * depth).clamp(0.0, 1.0);
             plot_soft(canvas, sx, sy, bright, (r, g, b));
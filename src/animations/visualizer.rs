@@ -2,7 +2,8 @@ use super::Animation;
 use crate::render::Canvas;
 use rand::RngExt;
 
-/// Fake audio spectrum analyzer bars bouncing to imaginary music
+/// Audio spectrum analyzer: live input with `--audio`, otherwise bars
+/// bouncing to imaginary music
 pub struct Visualizer {
     bars: Vec<f64>,
     targets: Vec<f64>,
@@ -11,7 +12,7 @@ pub struct Visualizer {
     beat_timer: f64,
     beat_interval: f64,
     energy: f64,
-    rng: rand::rngs::ThreadRng,
+    rng: rand::rngs::StdRng,
 }
 
 impl Visualizer {
@@ -25,7 +26,7 @@ impl Visualizer {
             beat_timer: 0.0,
             beat_interval: 0.5,
             energy: 0.5,
-            rng: rand::rng(),
+            rng: super::rng(),
         }
     }
 }
@@ -46,9 +47,12 @@ impl Animation for Visualizer {
         self.peaks.resize(bar_count, 0.0);
         self.peak_vel.resize(bar_count, 0.0);
 
-        // Simulate music beats
+        // Live spectrum when capturing, simulated music beats otherwise
+        if let Some(spectrum) = crate::audio::latest() {
+            self.targets = crate::audio::log_bands(&spectrum, bar_count);
+        }
         self.beat_timer -= dt;
-        if self.beat_timer <= 0.0 {
+        if !crate::audio::is_capturing() && self.beat_timer <= 0.0 {
             self.beat_interval = self.rng.random_range(0.3..0.8);
             self.beat_timer = self.beat_interval;
             self.energy = self.rng.random_range(0.3..1.0);
@@ -90,20 +94,23 @@ impl Animation for Visualizer {
 
         canvas.clear();
 
-        // Draw bars
+        // Bars stand on a floor strip that holds their reflection
+        let reflect_h = reflection_rows(h);
+        let bar_area = h - reflect_h;
         let bar_width = (w / bar_count).max(1);
         let gap = if bar_width > 1 { 1 } else { 0 };
 
         for i in 0..bar_count {
-            let bar_height = (self.bars[i] * h as f64) as usize;
+            let bar_height = (self.bars[i] * bar_area as f64) as usize;
             let bar_x = i * bar_width;
+            let bar_pos = i as f64 / bar_count as f64;
 
             for dy in 0..bar_height {
-                let y = h.saturating_sub(1 + dy);
-                let frac = dy as f64 / h as f64;
+                let y = bar_area.saturating_sub(1 + dy);
+                let frac = dy as f64 / bar_area as f64;
 
                 // Color gradient: green -> yellow -> red from bottom to top
-                let (r, g, b) = bar_color(frac, time, i as f64 / bar_count as f64);
+                let (r, g, b) = bar_color(frac, time, bar_pos);
 
                 for bx in 0..(bar_width.saturating_sub(gap)) {
                     let px = bar_x + bx;
@@ -114,39 +121,27 @@ impl Animation for Visualizer {
             }
 
             // Peak indicator
-            let peak_y = h.saturating_sub(1 + (self.peaks[i] * h as f64) as usize);
+            let peak_y = bar_area.saturating_sub(1 + (self.peaks[i] * bar_area as f64) as usize);
             for bx in 0..(bar_width.saturating_sub(gap)) {
                 let px = bar_x + bx;
                 if px < canvas.width && peak_y < canvas.height {
                     canvas.set_colored(px, peak_y, 1.0, 255, 255, 255);
                 }
             }
-        }
-
-        // Reflection at bottom (subtle)
-        let reflect_h = (h / 6).min(5);
-        for i in 0..bar_count {
-            let bar_height = (self.bars[i] * h as f64) as usize;
-            let bar_x = i * bar_width;
 
+            // Reflection (subtle): the bar's lowest rows mirrored below the
+            // baseline, fading with distance from it
             for dy in 0..reflect_h.min(bar_height) {
-                let y = h.saturating_sub(1).wrapping_sub(0).min(canvas.height - 1);
-                let src_y = h.saturating_sub(1 + dy);
-                if src_y >= canvas.height || y >= canvas.height {
-                    continue;
+                let ry = bar_area + dy;
+                if ry >= canvas.height {
+                    break;
                 }
                 let fade = 0.15 * (1.0 - dy as f64 / reflect_h as f64);
-
+                let (r, g, b) = bar_color(dy as f64 / bar_area as f64, time, bar_pos);
                 for bx in 0..(bar_width.saturating_sub(gap)) {
                     let px = bar_x + bx;
                     if px < canvas.width {
-                        let frac = dy as f64 / h as f64;
-                        let (r, g, b) = bar_color(frac, time, i as f64 / bar_count as f64);
-                        // Reflection goes below the bottom
-                        let ry = (h - 1).wrapping_add(dy + 1);
-                        if ry < canvas.height {
-                            canvas.set_colored(px, ry, fade, r / 3, g / 3, b / 3);
-                        }
+                        canvas.set_colored(px, ry, fade, r / 3, g / 3, b / 3);
                     }
                 }
             }
@@ -154,6 +149,11 @@ impl Animation for Visualizer {
     }
 }
 
+/// Rows under the bars reserved for their reflection on an `h`-pixel canvas.
+fn reflection_rows(h: usize) -> usize {
+    (h / 6).min(5)
+}
+
 fn bar_color(height_frac: f64, _time: f64, _bar_pos: f64) -> (u8, u8, u8) {
     if height_frac > 0.8 {
         // Red zone
@@ -168,3 +168,33 @@ fn bar_color(height_frac: f64, _time: f64, _bar_pos: f64) -> (u8, u8, u8) {
         (0, (120.0 + 135.0 * f) as u8, (50.0 * (1.0 - f)) as u8)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::{ColorMode, RenderMode};
+
+    #[test]
+    fn reflection_fills_the_floor_under_full_bars() {
+        let mut canvas = Canvas::new(20, 10, RenderMode::HalfBlock, ColorMode::TrueColor);
+        let (w, h) = (canvas.width, canvas.height);
+        let mut vis = Visualizer::new(w, h, 1.0);
+        let count = vis.bars.len();
+        vis.bars = vec![1.0; count];
+        vis.targets = vec![1.0; count];
+        vis.peaks = vec![1.0; count];
+        // Hold the simulated beat so the targets stay put
+        vis.beat_timer = f64::INFINITY;
+        vis.update(&mut canvas, 0.0, 0.0);
+
+        let floor = reflection_rows(h);
+        assert!(floor > 0);
+        let at = |y: usize| canvas.pixels[y * w];
+        // Bars end at the baseline; every floor row holds a faint mirror
+        assert!(at(h - floor - 1) >= 0.7);
+        for y in h - floor..h {
+            assert!(at(y) > 0.0 && at(y) <= 0.15, "row {y}: {}", at(y));
+        }
+        assert!(at(h - floor) > at(h - 1), "reflection fades downward");
+    }
+}
@@ -1,8 +1,15 @@
 use super::Animation;
-use crate::generators::{ColorGradient, ColorStop, EmitterConfig, ParticleSystem};
+use crate::generators::{
+    BlendMode, ColorGradient, ColorStop, EmitterConfig, ParticleParams, ParticleSystem,
+};
 use crate::render::Canvas;
 use rand::RngExt;
 
+/// Downward acceleration of falling water
+const DROP_GRAVITY: f64 = 20.0;
+/// Negative: mist floats up
+const MIST_GRAVITY: f64 = -2.0;
+
 struct WaterDrop {
     x: f64,
     y: f64,
@@ -18,7 +25,8 @@ pub struct Waterfall {
     mist: ParticleSystem,
     fall_x: f64,
     fall_width: f64,
-    rng: rand::rngs::ThreadRng,
+    physics: ParticleParams,
+    rng: rand::rngs::StdRng,
 }
 
 impl Waterfall {
@@ -26,7 +34,7 @@ impl Waterfall {
         let fall_x = width as f64 * 0.5;
         let fall_width = (width as f64 * 0.25).max(8.0);
         let count = ((width * height) as f64 / 40.0 * scale) as usize;
-        let mut rng = rand::rng();
+        let mut rng = super::rng();
 
         let drops = (0..count)
             .map(|_| WaterDrop {
@@ -46,7 +54,7 @@ impl Waterfall {
             speed_max: 25.0,
             life_min: 0.8,
             life_max: 3.0,
-            gravity: -2.0,
+            gravity: MIST_GRAVITY,
             drag: 0.95,
             wind: 0.0,
             gradient: ColorGradient::new(vec![
@@ -75,10 +83,12 @@ impl Waterfall {
             width,
             height,
             drops,
-            mist: ParticleSystem::new(mist_config, (1500.0 * scale) as usize),
+            mist: ParticleSystem::new(mist_config, (1500.0 * scale) as usize)
+                .with_blend(BlendMode::Add),
             fall_x,
             fall_width,
-            rng: rand::rng(),
+            physics: ParticleParams::default(),
+            rng: super::rng(),
         }
     }
 }
@@ -88,6 +98,15 @@ impl Animation for Waterfall {
         "waterfall"
     }
 
+    fn set_params(&mut self, params: &crate::external::ExternalParams) {
+        self.physics.set(params);
+        self.mist.config.gravity = MIST_GRAVITY * self.physics.gravity;
+    }
+
+    fn supported_params(&self) -> &'static [(&'static str, f64, f64)] {
+        &ParticleParams::SUPPORTED
+    }
+
     fn on_resize(&mut self, width: usize, height: usize) {
         self.width = width;
         self.height = height;
@@ -118,7 +137,7 @@ impl Animation for Waterfall {
 
         // Update and draw water drops
         for drop in &mut self.drops {
-            drop.vy += 20.0 * dt; // gravity acceleration
+            drop.vy += DROP_GRAVITY * self.physics.gravity * dt;
             drop.y += drop.vy * dt;
 
             // Add slight horizontal drift
@@ -183,8 +202,9 @@ impl Animation for Waterfall {
                 .rng
                 .random_range(-self.fall_width * 0.5..self.fall_width * 0.5);
         self.mist.config.y = pool_y;
-        self.mist.config.wind = (time * 0.3).sin() * 5.0;
-        self.mist.emit(self.rng.random_range(4..10));
+        self.mist.config.wind = self.physics.wind + (time * 0.3).sin() * 5.0;
+        let count = self.rng.random_range(4..10);
+        self.mist.emit(self.physics.scale_count(count));
         self.mist.update(dt);
         self.mist.draw(canvas);
     }
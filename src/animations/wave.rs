@@ -1,18 +1,150 @@
 use super::Animation;
+use crate::generators::{ColorGradient, ColorStop, NamedParam, named_param};
 use crate::render::Canvas;
+use std::f64::consts::FRAC_PI_2;
+
+/// Color mapping for the summed wave amplitude.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WavePalette {
+    /// Original per-channel sine mapping
+    Classic,
+    Ocean,
+    Sunset,
+    Neon,
+}
+
+named_param!(WavePalette {
+    Classic => "classic",
+    Ocean => "ocean",
+    Sunset => "sunset",
+    Neon => "neon",
+});
+
+impl WavePalette {
+    /// Trough-to-crest gradient; `None` for the classic sine mapping.
+    fn gradient(self) -> Option<ColorGradient> {
+        let stops: &[(f64, u8, u8, u8)] = match self {
+            WavePalette::Classic => return None,
+            WavePalette::Ocean => &[
+                (0.0, 0, 10, 40),
+                (0.4, 0, 70, 140),
+                (0.75, 40, 170, 200),
+                (1.0, 220, 250, 255),
+            ],
+            WavePalette::Sunset => &[
+                (0.0, 30, 0, 60),
+                (0.35, 150, 30, 90),
+                (0.7, 255, 120, 40),
+                (1.0, 255, 230, 140),
+            ],
+            WavePalette::Neon => &[
+                (0.0, 10, 0, 30),
+                (0.3, 255, 0, 200),
+                (0.65, 0, 220, 255),
+                (1.0, 200, 255, 120),
+            ],
+        };
+        Some(ColorGradient::new(
+            stops
+                .iter()
+                .map(|&(t, r, g, b)| ColorStop { t, r, g, b })
+                .collect(),
+        ))
+    }
+}
+
+/// A point emitter orbiting its anchor; positions are canvas fractions.
+struct WaveSource {
+    anchor_x: f64,
+    anchor_y: f64,
+    /// Orbit angular speeds (x uses cos, y uses sin, like a Lissajous path)
+    orbit_x: f64,
+    orbit_y: f64,
+    phase_x: f64,
+    phase_y: f64,
+    /// Phase speed of the emitted ripples
+    ripple_speed: f64,
+}
+
+impl WaveSource {
+    fn position(&self, w: f64, h: f64, t: f64) -> (f64, f64) {
+        (
+            w * self.anchor_x + (t * self.orbit_x + self.phase_x).cos() * w * 0.2,
+            h * self.anchor_y + (t * self.orbit_y + self.phase_y).sin() * h * 0.3,
+        )
+    }
+}
+
+/// The original two sources; extra sources are spread on a golden-angle
+/// spiral so higher counts fill the screen without clumping.
+fn make_sources(count: usize) -> Vec<WaveSource> {
+    let mut sources = vec![
+        WaveSource {
+            anchor_x: 0.3,
+            anchor_y: 0.5,
+            orbit_x: 0.5,
+            orbit_y: 0.7,
+            phase_x: 0.0,
+            phase_y: 0.0,
+            ripple_speed: 4.0,
+        },
+        WaveSource {
+            anchor_x: 0.7,
+            anchor_y: 0.5,
+            orbit_x: 0.3,
+            orbit_y: 0.4,
+            phase_x: -FRAC_PI_2,
+            phase_y: FRAC_PI_2,
+            ripple_speed: 3.5,
+        },
+    ];
+    const GOLDEN_ANGLE: f64 = 2.399_963_229_728_653;
+    for i in 2..count {
+        let angle = i as f64 * GOLDEN_ANGLE;
+        let radius = 0.15 + 0.2 * ((i % 3) as f64 / 2.0);
+        sources.push(WaveSource {
+            anchor_x: 0.5 + angle.cos() * radius,
+            anchor_y: 0.5 + angle.sin() * radius,
+            orbit_x: 0.2 + 0.1 * (i % 4) as f64,
+            orbit_y: 0.3 + 0.1 * (i % 5) as f64,
+            phase_x: angle,
+            phase_y: -angle,
+            ripple_speed: 3.0 + 0.4 * (i % 4) as f64,
+        });
+    }
+    sources.truncate(count.max(1));
+    sources
+}
 
 /// Sine wave interference pattern
 pub struct Wave {
     amplitude: f64,
     frequency: f64,
+    sources: Vec<WaveSource>,
+    /// Multiplier on source orbit and ripple speeds
+    speed: f64,
+    palette: WavePalette,
+    gradient: Option<ColorGradient>,
 }
 
 impl Wave {
-    #[allow(unused_variables)]
-    pub fn new(_width: usize, _height: usize, _scale: f64) -> Self {
+    /// Source count follows `scale`: 2 at 1.0 and below, up to 8 at 2.0.
+    pub fn new(_width: usize, _height: usize, scale: f64) -> Self {
+        let count = (2.0 * scale * scale).round().clamp(2.0, 8.0) as usize;
         Wave {
             amplitude: 0.5,
             frequency: 0.3,
+            sources: make_sources(count),
+            speed: 1.0,
+            palette: WavePalette::Classic,
+            gradient: None,
+        }
+    }
+
+    fn set_palette(&mut self, palette: WavePalette) {
+        if palette != self.palette {
+            self.palette = palette;
+            self.gradient = palette.gradient();
         }
     }
 }
@@ -29,43 +161,81 @@ impl Animation for Wave {
         if let Some(cs) = params.color_shift {
             self.frequency = cs.clamp(0.05, 0.8);
         }
+        if let Some(v) = params.get("wave_speed") {
+            self.speed = v.clamp(0.1, 3.0);
+        }
+        if let Some(p) = WavePalette::from_params(params, "wave_palette") {
+            self.set_palette(p);
+        }
     }
 
     fn supported_params(&self) -> &'static [(&'static str, f64, f64)] {
-        &[("intensity", 0.1, 1.0), ("color_shift", 0.05, 0.8)]
+        &[
+            ("intensity", 0.1, 1.0),
+            ("color_shift", 0.05, 0.8),
+            ("wave_speed", 0.1, 3.0),
+            ("wave_palette", 0.0, 3.0),
+        ]
     }
 
     fn update(&mut self, canvas: &mut Canvas, _dt: f64, time: f64) {
         let w = canvas.width as f64;
         let h = canvas.height as f64;
-        let t = time;
+        let t = time * self.speed;
 
-        // Two wave sources
-        let s1x = w * 0.3 + (t * 0.5).cos() * w * 0.2;
-        let s1y = h * 0.5 + (t * 0.7).sin() * h * 0.3;
-        let s2x = w * 0.7 + (t * 0.3).sin() * w * 0.2;
-        let s2y = h * 0.5 + (t * 0.4).cos() * h * 0.3;
+        let positions: Vec<(f64, f64)> = self.sources.iter().map(|s| s.position(w, h, t)).collect();
+        // Two sources at amplitude 0.5 span exactly -1..1; keep that for any count.
+        let gain = self.amplitude * 2.0 / self.sources.len() as f64;
 
         for y in 0..canvas.height {
             for x in 0..canvas.width {
                 let fx = x as f64;
                 let fy = y as f64;
 
-                let d1 = ((fx - s1x).powi(2) + (fy - s1y).powi(2)).sqrt();
-                let d2 = ((fx - s2x).powi(2) + (fy - s2y).powi(2)).sqrt();
-
-                let wave1 = (d1 * self.frequency - t * 4.0).sin();
-                let wave2 = (d2 * self.frequency - t * 3.5).sin();
-                let combined = (wave1 + wave2) * self.amplitude;
+                let mut sum = 0.0;
+                for (src, &(sx, sy)) in self.sources.iter().zip(&positions) {
+                    let d = ((fx - sx).powi(2) + (fy - sy).powi(2)).sqrt();
+                    sum += (d * self.frequency - t * src.ripple_speed).sin();
+                }
+                let combined = sum * gain;
 
                 let v = (combined + 1.0) * 0.5; // normalize to 0..1
 
-                let r = ((v * std::f64::consts::PI).sin() * 100.0 + 50.0) as u8;
-                let g = ((v * std::f64::consts::PI * 0.7).sin() * 150.0 + 100.0) as u8;
-                let b = ((v * std::f64::consts::PI * 1.3 + 1.0).sin() * 127.0 + 128.0) as u8;
+                let (r, g, b) = match &self.gradient {
+                    Some(gradient) => gradient.sample(v),
+                    None => (
+                        ((v * std::f64::consts::PI).sin() * 100.0 + 50.0) as u8,
+                        ((v * std::f64::consts::PI * 0.7).sin() * 150.0 + 100.0) as u8,
+                        ((v * std::f64::consts::PI * 1.3 + 1.0).sin() * 127.0 + 128.0) as u8,
+                    ),
+                };
 
                 canvas.set_colored(x, y, v, r, g, b);
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn source_count_follows_scale() {
+        assert_eq!(Wave::new(10, 10, 1.0).sources.len(), 2);
+        assert_eq!(Wave::new(10, 10, 1.5).sources.len(), 5);
+        assert_eq!(Wave::new(10, 10, 2.0).sources.len(), 8);
+    }
+
+    #[test]
+    fn default_sources_match_original_pair() {
+        let sources = make_sources(2);
+        let (w, h, t) = (100.0, 50.0, 1.7);
+        let (x1, y1) = sources[0].position(w, h, t);
+        assert!((x1 - (w * 0.3 + (t * 0.5).cos() * w * 0.2)).abs() < 1e-9);
+        assert!((y1 - (h * 0.5 + (t * 0.7).sin() * h * 0.3)).abs() < 1e-9);
+        let (x2, y2) = sources[1].position(w, h, t);
+        assert!((x2 - (w * 0.7 + (t * 0.3).sin() * w * 0.2)).abs() < 1e-9);
+        assert!((y2 - (h * 0.5 + (t * 0.4).cos() * h * 0.3)).abs() < 1e-9);
+    }
+}
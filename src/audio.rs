@@ -0,0 +1,403 @@
+//! `--audio`: a live spectrum of the default input device for `visualizer`,
+//! and `--audio-reactive`: loudness and beats driving `intensity`/`speed`.
+//! Capture and FFT need the `audio` feature; without it, or without an input
+//! device, [`start`] fails, `visualizer` keeps its simulated beats, and
+//! nothing pulses.
+
+use crate::external::ExternalParams;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::sync::mpsc::Receiver;
+
+/// Lowest and highest frequency (Hz) spread across the visualizer's bars.
+const MIN_HZ: f64 = 40.0;
+const MAX_HZ: f64 = 16_000.0;
+/// Bins below this frequency (Hz) make up the bass a beat is detected in.
+const BASS_HZ: f64 = 200.0;
+
+/// Seconds of bass history a beat must stand out from.
+const BEAT_WINDOW: f64 = 1.0;
+/// Bass level above the window's mean (about 6 dB) that counts as a beat.
+const BEAT_MARGIN: f64 = 0.1;
+/// Quietest bass level that can be a beat, so a noise floor never pulses.
+const BEAT_FLOOR: f64 = 0.3;
+/// Shortest gap between beats in seconds (300 bpm).
+const BEAT_REFRACTORY: f64 = 0.2;
+/// Time constant (s) of a beat's decay back to the loudness level.
+const ENVELOPE_DECAY: f64 = 0.15;
+
+/// One FFT frame: per-bin levels in 0.0..=1.0 (a -60..0 dB range against a
+/// full-scale sine) and the width of each bin in Hz.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Spectrum {
+    pub levels: Vec<f64>,
+    pub bin_hz: f64,
+}
+
+impl Spectrum {
+    /// Mean level of the bins below `BASS_HZ`, skipping DC.
+    pub fn bass(&self) -> f64 {
+        let bins = ((BASS_HZ / self.bin_hz).ceil() as usize).min(self.levels.len());
+        let bass = self.levels.get(1..bins).unwrap_or(&[]);
+        bass.iter().sum::<f64>() / bass.len().max(1) as f64
+    }
+
+    /// Mean level across the `MIN_HZ..MAX_HZ` range, 0.0..=1.0.
+    pub fn loudness(&self) -> f64 {
+        let lo = (MIN_HZ / self.bin_hz) as usize;
+        let hi = ((MAX_HZ / self.bin_hz) as usize).min(self.levels.len());
+        let range = &self.levels[lo.min(hi)..hi];
+        range.iter().sum::<f64>() / range.len().max(1) as f64
+    }
+}
+
+/// The live input's loudness (0.0..=1.0) and how many beats it has had.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Pulse {
+    pub energy: f64,
+    pub beats: u64,
+}
+
+/// Frames from the capture thread; `None` until [`start`] succeeds.
+static SPECTRUM: Mutex<Option<Receiver<Spectrum>>> = Mutex::new(None);
+
+/// Latest pulse from the capture thread; `None` until it analyzes a frame.
+static PULSE: Mutex<Option<Pulse>> = Mutex::new(None);
+
+/// Start capturing the default input device on a background thread; a
+/// second call keeps the running capture. Errors say why there is no live
+/// input.
+pub fn start() -> Result<(), String> {
+    #[cfg(feature = "audio")]
+    {
+        let mut spectrum = SPECTRUM.lock().unwrap_or_else(|e| e.into_inner());
+        if spectrum.is_none() {
+            *spectrum = Some(capture::start()?);
+        }
+        Ok(())
+    }
+    #[cfg(not(feature = "audio"))]
+    Err("--audio needs a build with `--features audio`".into())
+}
+
+/// True once [`start`] has succeeded.
+pub fn is_capturing() -> bool {
+    SPECTRUM.lock().unwrap_or_else(|e| e.into_inner()).is_some()
+}
+
+/// The newest frame since the last call, if any arrived.
+pub fn latest() -> Option<Spectrum> {
+    let guard = SPECTRUM.lock().unwrap_or_else(|e| e.into_inner());
+    guard.as_ref()?.try_iter().last()
+}
+
+/// The input's current loudness and beat count, once capturing.
+pub fn pulse() -> Option<Pulse> {
+    *PULSE.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Energy-based beat detection: a beat is bass that jumps `BEAT_MARGIN`
+/// above its mean over the last `BEAT_WINDOW`, at most once per
+/// `BEAT_REFRACTORY`.
+#[derive(Debug, Default)]
+pub struct BeatDetector {
+    history: VecDeque<f64>,
+    since_beat: f64,
+}
+
+impl BeatDetector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed the bass level of a frame arriving `dt` seconds after the last;
+    /// true if it is a beat.
+    pub fn push(&mut self, bass: f64, dt: f64) -> bool {
+        self.since_beat += dt;
+        let beat = !self.history.is_empty()
+            && bass > BEAT_FLOOR
+            && bass > self.history.iter().sum::<f64>() / self.history.len() as f64 + BEAT_MARGIN
+            && self.since_beat >= BEAT_REFRACTORY;
+        if beat {
+            self.since_beat = 0.0;
+        }
+        let window = (BEAT_WINDOW / dt.max(1e-3)).ceil() as usize;
+        self.history.push_back(bass);
+        while self.history.len() > window {
+            self.history.pop_front();
+        }
+        beat
+    }
+}
+
+/// Envelope follower turning [`Pulse`]s into `intensity` and `speed` params:
+/// loudness sets a base level, and each beat spikes it and decays with
+/// `ENVELOPE_DECAY`.
+#[derive(Debug, Default)]
+pub struct Reactive {
+    envelope: f64,
+    beats: u64,
+}
+
+impl Reactive {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Params for a frame `dt` seconds after the last.
+    pub fn update(&mut self, pulse: Pulse, dt: f64) -> ExternalParams {
+        if pulse.beats != self.beats {
+            self.beats = pulse.beats;
+            self.envelope = 1.0;
+        } else {
+            self.envelope *= (-dt / ENVELOPE_DECAY).exp();
+        }
+        let energy = pulse.energy.clamp(0.0, 1.0);
+        ExternalParams {
+            intensity: Some(0.6 + 0.4 * energy + 0.8 * self.envelope),
+            speed: Some(0.8 + 0.4 * energy + 0.6 * self.envelope),
+            ..ExternalParams::default()
+        }
+    }
+}
+
+/// `spectrum` folded into `bands` levels on a logarithmic frequency scale
+/// from `MIN_HZ` to `MAX_HZ` (or Nyquist), each the loudest bin it covers.
+pub fn log_bands(spectrum: &Spectrum, bands: usize) -> Vec<f64> {
+    let bins = spectrum.levels.len();
+    if bins == 0 {
+        return vec![0.0; bands];
+    }
+    let top = MAX_HZ.min(spectrum.bin_hz * bins as f64);
+    let edge = |k: usize| MIN_HZ * (top / MIN_HZ).powf(k as f64 / bands as f64);
+    (0..bands)
+        .map(|k| {
+            let lo = ((edge(k) / spectrum.bin_hz) as usize).min(bins - 1);
+            let hi = ((edge(k + 1) / spectrum.bin_hz).ceil() as usize).clamp(lo + 1, bins);
+            spectrum.levels[lo..hi].iter().copied().fold(0.0, f64::max)
+        })
+        .collect()
+}
+
+#[cfg(feature = "audio")]
+mod capture {
+    use super::{BeatDetector, PULSE, Pulse, Spectrum};
+    use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+    use cpal::{FromSample, SampleFormat, SizedSample, StreamConfig};
+    use rustfft::FftPlanner;
+    use rustfft::num_complex::Complex;
+    use std::sync::mpsc::{self, Receiver, Sender};
+
+    /// Samples per FFT; at 48 kHz, 23 Hz bins every ~21 ms (half overlap).
+    const FFT_SIZE: usize = 2048;
+    const HOP: usize = FFT_SIZE / 2;
+    /// Level floor in dB below a full-scale sine.
+    const FLOOR_DB: f64 = -60.0;
+
+    /// Open the default input and spawn the thread that owns the stream (not
+    /// `Send` on every platform) and turns its samples into `Spectrum`s.
+    pub fn start() -> Result<Receiver<Spectrum>, String> {
+        let (spectrum_tx, spectrum_rx) = mpsc::channel();
+        let (ready_tx, ready_rx) = mpsc::channel();
+        std::thread::spawn(move || {
+            let (stream, samples, sample_rate) = match open() {
+                Ok(opened) => opened,
+                Err(e) => {
+                    let _ = ready_tx.send(Err(e));
+                    return;
+                }
+            };
+            if let Err(e) = stream.play() {
+                let _ = ready_tx.send(Err(format!("audio input: {e}")));
+                return;
+            }
+            let _ = ready_tx.send(Ok(()));
+            analyze(&samples, sample_rate, &spectrum_tx);
+        });
+        ready_rx
+            .recv()
+            .unwrap_or_else(|_| Err("audio thread exited".into()))?;
+        Ok(spectrum_rx)
+    }
+
+    /// The default input stream, a channel of its mono samples, and its rate.
+    fn open() -> Result<(cpal::Stream, Receiver<Vec<f32>>, f64), String> {
+        let device = cpal::default_host()
+            .default_input_device()
+            .ok_or("no audio input device")?;
+        let supported = device
+            .default_input_config()
+            .map_err(|e| format!("audio input: {e}"))?;
+        let format = supported.sample_format();
+        let config: StreamConfig = supported.into();
+        let (tx, rx) = mpsc::channel();
+        let stream = match format {
+            SampleFormat::F32 => build::<f32>(&device, &config, tx),
+            SampleFormat::I16 => build::<i16>(&device, &config, tx),
+            SampleFormat::U16 => build::<u16>(&device, &config, tx),
+            other => return Err(format!("unsupported audio sample format {other}")),
+        }
+        .map_err(|e| format!("audio input: {e}"))?;
+        Ok((stream, rx, config.sample_rate.0 as f64))
+    }
+
+    fn build<T>(
+        device: &cpal::Device,
+        config: &StreamConfig,
+        tx: Sender<Vec<f32>>,
+    ) -> Result<cpal::Stream, cpal::BuildStreamError>
+    where
+        T: SizedSample,
+        f32: FromSample<T>,
+    {
+        let channels = config.channels.max(1) as usize;
+        device.build_input_stream(
+            config,
+            move |data: &[T], _: &cpal::InputCallbackInfo| {
+                // Downmix interleaved frames to mono.
+                let mono = data
+                    .chunks(channels)
+                    .map(|frame| {
+                        frame.iter().map(|&s| s.to_sample::<f32>()).sum::<f32>() / channels as f32
+                    })
+                    .collect();
+                let _ = tx.send(mono);
+            },
+            |_| {},
+            None,
+        )
+    }
+
+    /// Run a Hann-windowed FFT over every `HOP` new samples until either
+    /// channel closes.
+    fn analyze(samples: &Receiver<Vec<f32>>, sample_rate: f64, tx: &Sender<Spectrum>) {
+        let fft = FftPlanner::new().plan_fft_forward(FFT_SIZE);
+        let window: Vec<f32> = (0..FFT_SIZE)
+            .map(|i| {
+                let x = i as f32 / (FFT_SIZE - 1) as f32;
+                0.5 - 0.5 * (std::f32::consts::TAU * x).cos()
+            })
+            .collect();
+        // A Hann-windowed full-scale sine peaks at FFT_SIZE / 4.
+        let full_scale = FFT_SIZE as f64 / 4.0;
+        let mut pending: Vec<f32> = Vec::with_capacity(FFT_SIZE * 2);
+        let mut buf = vec![Complex::new(0.0f32, 0.0); FFT_SIZE];
+        let mut detector = BeatDetector::new();
+        let mut beats = 0;
+        let frame_secs = HOP as f64 / sample_rate;
+        for chunk in samples {
+            pending.extend(chunk);
+            while pending.len() >= FFT_SIZE {
+                for (slot, (&s, &w)) in buf.iter_mut().zip(pending.iter().zip(&window)) {
+                    *slot = Complex::new(s * w, 0.0);
+                }
+                fft.process(&mut buf);
+                let levels = buf[..FFT_SIZE / 2]
+                    .iter()
+                    .map(|c| {
+                        let db = 20.0 * (c.norm() as f64 / full_scale).max(1e-9).log10();
+                        ((db - FLOOR_DB) / -FLOOR_DB).clamp(0.0, 1.0)
+                    })
+                    .collect();
+                let spectrum = Spectrum {
+                    levels,
+                    bin_hz: sample_rate / FFT_SIZE as f64,
+                };
+                if detector.push(spectrum.bass(), frame_secs) {
+                    beats += 1;
+                }
+                *PULSE.lock().unwrap_or_else(|e| e.into_inner()) = Some(Pulse {
+                    energy: spectrum.loudness(),
+                    beats,
+                });
+                if tx.send(spectrum).is_err() {
+                    return;
+                }
+                pending.drain(..HOP);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn log_bands_give_low_notes_their_own_bars() {
+        // 1024 bins of 23.4 Hz (48 kHz, 2048-point FFT): one tone at 100 Hz.
+        let mut levels = vec![0.0; 1024];
+        levels[4] = 1.0;
+        let spectrum = Spectrum {
+            levels,
+            bin_hz: 48_000.0 / 2048.0,
+        };
+        let bands = log_bands(&spectrum, 32);
+        assert_eq!(bands.len(), 32);
+        let lit: Vec<usize> = (0..32).filter(|&k| bands[k] > 0.0).collect();
+        // Log spacing puts 100 Hz in the lowest fifth of the bars, where a
+        // linear split would lump it into the first bar with everything to 750 Hz.
+        assert!(
+            !lit.is_empty() && lit.iter().all(|&k| (2..7).contains(&k)),
+            "{lit:?}"
+        );
+    }
+
+    #[test]
+    fn not_capturing_without_start() {
+        assert!(!is_capturing());
+        assert_eq!(latest(), None);
+        assert_eq!(pulse(), None);
+    }
+
+    #[test]
+    fn beats_are_bass_jumps_above_the_recent_mean() {
+        let dt = 0.02;
+        let mut detector = BeatDetector::new();
+        assert!((0..50).all(|_| !detector.push(0.5, dt)), "steady bass");
+        assert!(detector.push(0.8, dt));
+        assert!(!detector.push(0.8, dt), "inside the refractory gap");
+        (0..20).for_each(|_| {
+            detector.push(0.5, dt);
+        });
+        assert!(detector.push(0.8, dt), "after the gap");
+
+        let mut quiet = BeatDetector::new();
+        (0..50).for_each(|_| {
+            quiet.push(0.05, dt);
+        });
+        assert!(!quiet.push(0.25, dt), "below the floor");
+    }
+
+    #[test]
+    fn beat_spikes_intensity_then_decays() {
+        let mut reactive = Reactive::new();
+        let quiet = reactive.update(
+            Pulse {
+                energy: 0.5,
+                beats: 0,
+            },
+            0.02,
+        );
+        let beat = reactive.update(
+            Pulse {
+                energy: 0.5,
+                beats: 1,
+            },
+            0.02,
+        );
+        assert!(beat.intensity > quiet.intensity && beat.speed > quiet.speed);
+        let mut later = beat.clone();
+        for _ in 0..50 {
+            later = reactive.update(
+                Pulse {
+                    energy: 0.5,
+                    beats: 1,
+                },
+                0.02,
+            );
+        }
+        let (base, after) = (quiet.intensity.unwrap(), later.intensity.unwrap());
+        assert!((after - base).abs() < 0.01, "{after} vs {base}");
+    }
+}
@@ -0,0 +1,172 @@
+//! `--benchmark`: step animations offscreen for a fixed number of frames and
+//! report how fast `update` runs, with no TTY, raw mode, or terminal output.
+
+use crate::export;
+use clap::ValueEnum;
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+use termflix::animations;
+use termflix::render::{Canvas, ColorMode, RenderMode};
+
+pub struct BenchmarkConfig {
+    /// Animations to run, in table order
+    pub names: Vec<&'static str>,
+    pub frames: usize,
+    pub fps: u32,
+    pub scale: f64,
+    pub seed: Option<u64>,
+    /// Terminal cells the canvas covers
+    pub cols: usize,
+    pub rows: usize,
+    /// `None` uses each animation's preferred mode
+    pub render_mode: Option<RenderMode>,
+    pub cell_aspect: f64,
+    /// `[animations.<name>]` config tables
+    pub settings: HashMap<String, toml::Table>,
+}
+
+/// Update timings for one animation.
+#[derive(Debug, Default, PartialEq)]
+struct Stats {
+    total: Duration,
+    peak: Duration,
+    frames: usize,
+}
+
+impl Stats {
+    fn record(&mut self, elapsed: Duration) {
+        self.total += elapsed;
+        self.peak = self.peak.max(elapsed);
+        self.frames += 1;
+    }
+
+    fn updates_per_sec(&self) -> f64 {
+        self.frames as f64 / self.total.as_secs_f64().max(1e-9)
+    }
+
+    fn avg_ms(&self) -> f64 {
+        self.total.as_secs_f64() * 1000.0 / self.frames.max(1) as f64
+    }
+}
+
+/// Run every animation in `config.names` for `config.frames` updates at a
+/// fixed `1 / fps` step and write a table row per animation to `out` as
+/// each finishes. An animation whose minimum size doesn't fit the canvas is
+/// skipped with a note in its row.
+pub fn run_benchmark(config: &BenchmarkConfig, out: &mut impl Write) -> io::Result<()> {
+    let name_width = config
+        .names
+        .iter()
+        .map(|n| n.len())
+        .chain(["animation".len()])
+        .max()
+        .unwrap_or_default();
+    writeln!(
+        out,
+        "{:<name_width$}  {:<10} {:>11} {:>9} {:>9}",
+        "animation", "render", "updates/s", "avg ms", "peak ms"
+    )?;
+    let dt = 1.0 / config.fps as f64;
+    let (probe_w, probe_h) = animations::PROBE_SIZE;
+    for &name in &config.names {
+        let invalid = |e| io::Error::new(io::ErrorKind::InvalidInput, e);
+        let probe = animations::create(name, probe_w, probe_h, 1.0, None).map_err(invalid)?;
+        let render_mode = config.render_mode.unwrap_or(probe.preferred_render());
+        let mode = render_mode
+            .to_possible_value()
+            .map(|v| v.get_name().to_string())
+            .unwrap_or_default();
+        let (need_cols, need_rows) = export::min_cells(probe.as_ref(), render_mode);
+        if config.cols < need_cols || config.rows < need_rows {
+            writeln!(
+                out,
+                "{name:<name_width$}  {mode:<10} skipped: needs at least {need_cols}x{need_rows} cells"
+            )?;
+            continue;
+        }
+        let mut canvas = Canvas::new(config.cols, config.rows, render_mode, ColorMode::TrueColor);
+        canvas.set_cell_aspect(config.cell_aspect);
+        let mut anim = animations::create_for_canvas(
+            name,
+            canvas.width,
+            canvas.height,
+            config.scale,
+            config.seed,
+            config.settings.get(name),
+        )
+        .map_err(invalid)?;
+
+        let mut stats = Stats::default();
+        let mut time = 0.0;
+        for _ in 0..config.frames {
+            time += dt;
+            let start = Instant::now();
+            anim.update(&mut canvas, dt, time);
+            stats.record(start.elapsed());
+            // Game sounds are run_loop's business; don't let them pile up
+            anim.take_events();
+        }
+        writeln!(
+            out,
+            "{name:<name_width$}  {mode:<10} {:>11.1} {:>9.3} {:>9.3}",
+            stats.updates_per_sec(),
+            stats.avg_ms(),
+            stats.peak.as_secs_f64() * 1000.0
+        )?;
+        out.flush()?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use termflix::render::DEFAULT_CELL_ASPECT;
+
+    #[test]
+    fn stats_track_average_and_peak() {
+        let mut s = Stats::default();
+        s.record(Duration::from_millis(2));
+        s.record(Duration::from_millis(6));
+        assert_eq!(s.avg_ms(), 4.0);
+        assert_eq!(s.peak, Duration::from_millis(6));
+        assert_eq!(s.updates_per_sec(), 250.0);
+    }
+
+    #[test]
+    fn writes_one_row_per_animation() {
+        let config = BenchmarkConfig {
+            names: vec!["plasma", "fire"],
+            frames: 3,
+            fps: 24,
+            scale: 1.0,
+            seed: Some(1),
+            cols: 20,
+            rows: 10,
+            render_mode: None,
+            cell_aspect: DEFAULT_CELL_ASPECT,
+            settings: HashMap::new(),
+        };
+        let mut out = Vec::new();
+        run_benchmark(&config, &mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("animation"));
+        assert!(lines[1].starts_with("plasma     half-block"));
+        assert!(lines[2].starts_with("fire"));
+
+        // Too small for aurora and pong: skipped rather than updated
+        let config = BenchmarkConfig {
+            names: vec!["aurora", "pong"],
+            cols: 2,
+            rows: 1,
+            ..config
+        };
+        let mut out = Vec::new();
+        run_benchmark(&config, &mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert_eq!(text.matches("skipped: needs at least").count(), 2, "{text}");
+    }
+}
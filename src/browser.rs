@@ -0,0 +1,413 @@
+use crate::animations::{self, Animation};
+use crate::external::ExternalParams;
+use crate::render::cell::{Cell, CellGrid};
+use crate::render::{self, Canvas, ColorMode, RenderMode};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::style::Color;
+use crossterm::terminal;
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+/// Preview size in terminal cells (the name label sits on the row below).
+const TILE_COLS: usize = 22;
+const TILE_ROWS: usize = 7;
+/// Blank columns between neighbouring tiles.
+const TILE_GAP: usize = 1;
+/// Title line at the top, selected animation's description at the bottom.
+const HEADER_ROWS: usize = 1;
+const FOOTER_ROWS: usize = 1;
+/// Target update rate of each preview.
+const PREVIEW_FPS: f64 = 8.0;
+/// Most previews stepped per frame; with many tiles on screen each one
+/// updates less often instead of the whole grid slowing down.
+const MAX_UPDATES_PER_FRAME: usize = 6;
+
+pub struct BrowserConfig {
+    pub entries: Vec<(&'static str, &'static str)>,
+    pub render_override: Option<RenderMode>,
+    pub color_mode: ColorMode,
+    /// Terminal palette for `ColorMode::Palette`, when it was read
+    pub palette: Option<[(u8, u8, u8); 16]>,
+    pub scale: f64,
+    pub cell_aspect: f64,
+    pub frame_dur: Duration,
+    pub params: ExternalParams,
+    /// `[animations.<name>]` config tables
+    pub settings: HashMap<String, toml::Table>,
+}
+
+/// How many tiles fit on screen for a terminal size.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Layout {
+    grid_cols: usize,
+    visible_rows: usize,
+}
+
+impl Layout {
+    fn new(term_cols: usize, term_rows: usize) -> Self {
+        let body = term_rows.saturating_sub(HEADER_ROWS + FOOTER_ROWS);
+        Layout {
+            grid_cols: ((term_cols + TILE_GAP) / (TILE_COLS + TILE_GAP)).max(1),
+            visible_rows: (body / (TILE_ROWS + 1)).max(1),
+        }
+    }
+
+    /// First grid row to show so that `selected` stays on screen, moving
+    /// `scroll` as little as possible.
+    fn scroll_to(&self, selected: usize, scroll: usize) -> usize {
+        let row = selected / self.grid_cols;
+        if row < scroll {
+            row
+        } else if row >= scroll + self.visible_rows {
+            row + 1 - self.visible_rows
+        } else {
+            scroll
+        }
+    }
+
+    /// Entry indices on screen for a scroll position.
+    fn visible(&self, scroll: usize, count: usize) -> std::ops::Range<usize> {
+        let start = (scroll * self.grid_cols).min(count);
+        let end = (start + self.grid_cols * self.visible_rows).min(count);
+        start..end
+    }
+}
+
+/// Selection after moving `dx` tiles across and `dy` rows down, clamped to
+/// the list.
+fn move_selection(selected: usize, count: usize, grid_cols: usize, dx: isize, dy: isize) -> usize {
+    let target = selected as isize + dx + dy * grid_cols as isize;
+    target.clamp(0, count.saturating_sub(1) as isize) as usize
+}
+
+/// Indices of the previews to step this frame: those never drawn or at least
+/// `interval` seconds stale, oldest first, at most `cap` of them. `ages` is
+/// `(index, seconds since last update)`, `None` for never updated.
+fn due_previews(ages: &[(usize, Option<f64>)], interval: f64, cap: usize) -> Vec<usize> {
+    let mut due: Vec<(usize, f64)> = ages
+        .iter()
+        .filter_map(|&(i, age)| match age {
+            None => Some((i, f64::INFINITY)),
+            Some(a) if a >= interval => Some((i, a)),
+            Some(_) => None,
+        })
+        .collect();
+    due.sort_by(|a, b| b.1.total_cmp(&a.1));
+    due.into_iter().take(cap).map(|(i, _)| i).collect()
+}
+
+/// One running animation shrunk to a tile.
+struct Preview {
+    /// `None` when the tile is smaller than the animation's `min_size`
+    anim: Option<Box<dyn Animation>>,
+    canvas: Canvas,
+    grid: Option<CellGrid>,
+    time: f64,
+    last_update: Option<Instant>,
+}
+
+impl Preview {
+    fn new(name: &str, config: &BrowserConfig) -> Self {
+        let (probe_w, probe_h) = animations::PROBE_SIZE;
+        let probe = animations::create(name, probe_w, probe_h, config.scale, None);
+        let render_mode = config.render_override.unwrap_or_else(|| {
+            probe
+                .as_ref()
+                .map_or(RenderMode::HalfBlock, |a| a.preferred_render())
+        });
+        let mut canvas = Canvas::new(TILE_COLS, TILE_ROWS, render_mode, config.color_mode);
+        if let Some(palette) = config.palette {
+            canvas.palette = palette;
+        }
+        canvas.set_cell_aspect(config.cell_aspect);
+        let fits = probe.is_ok_and(|a| {
+            let (min_w, min_h) = a.min_size();
+            canvas.width >= min_w && canvas.height >= min_h
+        });
+        let anim = if fits {
+            animations::create_for_canvas(
+                name,
+                canvas.width,
+                canvas.height,
+                config.scale,
+                None,
+                config.settings.get(name),
+            )
+            .map(|mut a| {
+                a.set_params(&config.params);
+                a
+            })
+            .ok()
+        } else {
+            None
+        };
+        Preview {
+            anim,
+            canvas,
+            grid: None,
+            time: 0.0,
+            last_update: None,
+        }
+    }
+
+    fn step(&mut self, now: Instant) {
+        let Some(anim) = self.anim.as_mut() else {
+            return;
+        };
+        // Cap dt so a tile that sat unscheduled for a while doesn't jump.
+        let gap = self.last_update.map(|t| now - t);
+        if gap.is_some_and(|gap| gap >= crate::pacing::TIME_GAP) {
+            anim.on_time_gap();
+        }
+        let dt = gap.map_or(1.0 / PREVIEW_FPS, |gap| gap.as_secs_f64().min(0.25));
+        self.time += dt;
+        self.last_update = Some(now);
+        self.canvas.clear();
+        anim.update(&mut self.canvas, dt, self.time);
+        // Previews are silent; drop game events so they don't pile up
+        anim.take_events();
+        self.grid = Some(self.canvas.render_cells());
+    }
+}
+
+/// Show the entries as a grid of live previews until the user picks one
+/// (Enter) or quits (q/Esc). Expects raw mode and the alternate screen to be
+/// active already; returns the chosen animation name.
+pub fn run_browser(config: &BrowserConfig) -> io::Result<Option<&'static str>> {
+    let count = config.entries.len();
+    let mut previews: Vec<Option<Preview>> = (0..count).map(|_| None).collect();
+    let mut selected = 0usize;
+    let mut scroll = 0usize;
+    let mut size = (0, 0);
+    let mut layout = Layout::new(0, 0);
+    let mut delta = render::encoder::DeltaEncoder::default();
+    let mut stdout = io::stdout();
+
+    loop {
+        let frame_start = Instant::now();
+
+        let (cols, rows) = terminal::size()?;
+        if (cols as usize, rows as usize) != size {
+            size = (cols as usize, rows as usize);
+            layout = Layout::new(size.0, size.1);
+            delta.invalidate();
+        }
+        scroll = layout.scroll_to(selected, scroll);
+        let visible = layout.visible(scroll, count);
+
+        // Only tiles on screen keep a running animation.
+        for (i, preview) in previews.iter_mut().enumerate() {
+            if !visible.contains(&i) {
+                *preview = None;
+            } else if preview.is_none() {
+                *preview = Some(Preview::new(config.entries[i].0, config));
+            }
+        }
+
+        let now = Instant::now();
+        let ages: Vec<(usize, Option<f64>)> = visible
+            .clone()
+            .filter(|&i| previews[i].as_ref().is_some_and(|p| p.anim.is_some()))
+            .map(|i| {
+                let last = previews[i].as_ref().and_then(|p| p.last_update);
+                (i, last.map(|t| (now - t).as_secs_f64()))
+            })
+            .collect();
+        for i in due_previews(&ages, 1.0 / PREVIEW_FPS, MAX_UPDATES_PER_FRAME) {
+            if let Some(preview) = previews[i].as_mut() {
+                preview.step(now);
+            }
+        }
+
+        let grid = compose(config, &previews, &layout, visible, selected, size);
+        let frame = delta.encode(grid, true, true);
+        let mut frame_buf: Vec<u8> = Vec::with_capacity(frame.len() + 16);
+        frame_buf.extend_from_slice(b"\x1b[?2026h\x1b[H");
+        frame_buf.extend_from_slice(frame.as_bytes());
+        frame_buf.extend_from_slice(b"\x1b[?2026l");
+        stdout.write_all(&frame_buf)?;
+        stdout.flush()?;
+
+        let timeout = config.frame_dur.saturating_sub(frame_start.elapsed());
+        if event::poll(timeout)?
+            && let Event::Key(key) = event::read()?
+            && key.kind == KeyEventKind::Press
+        {
+            let page = (layout.grid_cols * layout.visible_rows) as isize;
+            let grid_cols = layout.grid_cols;
+            let step = |dx, dy| move_selection(selected, count, grid_cols, dx, dy);
+            selected = match key.code {
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    return Ok(None);
+                }
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(None),
+                KeyCode::Enter => return Ok(Some(config.entries[selected].0)),
+                KeyCode::Left | KeyCode::Char('h') => step(-1, 0),
+                KeyCode::Right | KeyCode::Char('l') => step(1, 0),
+                KeyCode::Up | KeyCode::Char('k') => step(0, -1),
+                KeyCode::Down | KeyCode::Char('j') => step(0, 1),
+                KeyCode::PageUp => step(-page, 0),
+                KeyCode::PageDown => step(page, 0),
+                KeyCode::Home => 0,
+                KeyCode::End => count - 1,
+                _ => selected,
+            };
+        }
+    }
+}
+
+/// Lay the previews, labels, title and footer out on one terminal-sized grid.
+fn compose(
+    config: &BrowserConfig,
+    previews: &[Option<Preview>],
+    layout: &Layout,
+    visible: std::ops::Range<usize>,
+    selected: usize,
+    (cols, rows): (usize, usize),
+) -> CellGrid {
+    let mut grid = CellGrid::new(cols, rows);
+    let highlight = if config.color_mode == ColorMode::Mono {
+        Color::White
+    } else {
+        Color::Yellow
+    };
+    let count = config.entries.len();
+
+    let title = format!(
+        " termflix — {} animation(s) │ ←↑↓→ move  PgUp/PgDn page  Enter play  q quit",
+        count
+    );
+    put_text(&mut grid, 0, 0, &title, Some(Color::White), None, cols);
+
+    let first = visible.start;
+    for i in visible {
+        let slot = i - first;
+        let x = (slot % layout.grid_cols) * (TILE_COLS + TILE_GAP);
+        let y = HEADER_ROWS + (slot / layout.grid_cols) * (TILE_ROWS + 1);
+        let width = TILE_COLS.min(cols.saturating_sub(x));
+
+        match previews[i].as_ref() {
+            Some(Preview {
+                grid: Some(tile), ..
+            }) => {
+                for row in 0..tile.rows.min(rows.saturating_sub(y)) {
+                    for col in 0..tile.cols.min(width) {
+                        grid.cells[(y + row) * cols + x + col] = tile.get(row, col);
+                    }
+                }
+            }
+            Some(Preview { anim: None, .. }) => {
+                let note = "(needs more room)";
+                let nx = x + TILE_COLS.saturating_sub(note.chars().count()) / 2;
+                put_text(
+                    &mut grid,
+                    nx,
+                    y + TILE_ROWS / 2,
+                    note,
+                    Some(Color::DarkGrey),
+                    None,
+                    (x + width).saturating_sub(nx),
+                );
+            }
+            _ => {}
+        }
+
+        let name = config.entries[i].0;
+        let (label, fg, bg) = if i == selected {
+            (format!("▸ {}", name), Some(Color::Black), Some(highlight))
+        } else {
+            (format!("  {}", name), Some(Color::Grey), None)
+        };
+        put_text(&mut grid, x, y + TILE_ROWS, &label, fg, bg, width);
+    }
+
+    if rows > HEADER_ROWS {
+        let pages = count
+            .div_ceil(layout.grid_cols * layout.visible_rows)
+            .max(1);
+        let page = selected / (layout.grid_cols * layout.visible_rows) + 1;
+        let (name, desc) = config.entries[selected];
+        let footer = format!(" {} — {}  [{}/{}]", name, desc, page, pages);
+        put_text(&mut grid, 0, rows - 1, &footer, Some(highlight), None, cols);
+    }
+    grid
+}
+
+/// Write `text` into row `y` from column `x`, clipped to `max_width` cells
+/// and the grid edge.
+fn put_text(
+    grid: &mut CellGrid,
+    x: usize,
+    y: usize,
+    text: &str,
+    fg: Option<Color>,
+    bg: Option<Color>,
+    max_width: usize,
+) {
+    if y >= grid.rows {
+        return;
+    }
+    let limit = max_width.min(grid.cols.saturating_sub(x));
+    for (i, ch) in text.chars().take(limit).enumerate() {
+        grid.cells[y * grid.cols + x + i] = Cell {
+            ch,
+            fg,
+            bg,
+            attrs: 0,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layout_scrolls_to_keep_selection_visible() {
+        // 80x20: 3 tiles across, 2 rows of tiles
+        let layout = Layout::new(80, 20);
+        assert_eq!(
+            layout,
+            Layout {
+                grid_cols: 3,
+                visible_rows: 2
+            }
+        );
+        assert_eq!(layout.scroll_to(4, 0), 0);
+        assert_eq!(layout.scroll_to(6, 0), 1);
+        assert_eq!(layout.scroll_to(0, 5), 0);
+        assert_eq!(layout.visible(1, 8), 3..8);
+        assert_eq!(move_selection(7, 8, 3, 0, 1), 7);
+        assert_eq!(move_selection(1, 8, 3, 0, -1), 0);
+    }
+
+    #[test]
+    fn previews_drop_game_events() {
+        let config = BrowserConfig {
+            entries: Vec::new(),
+            render_override: None,
+            color_mode: ColorMode::TrueColor,
+            palette: None,
+            scale: 1.0,
+            cell_aspect: crate::render::DEFAULT_CELL_ASPECT,
+            frame_dur: Duration::from_millis(50),
+            params: ExternalParams::default(),
+            settings: HashMap::new(),
+        };
+        let mut preview = Preview::new("pong", &config);
+        let start = Instant::now();
+        for i in 0..2000 {
+            preview.step(start + Duration::from_millis(100 * i));
+        }
+        let anim = preview.anim.as_mut().expect("pong fits a tile");
+        assert!(anim.take_events().is_empty());
+    }
+
+    #[test]
+    fn due_previews_are_capped_and_oldest_first() {
+        let ages = [(0, Some(0.05)), (1, Some(0.5)), (2, None), (3, Some(0.2))];
+        assert_eq!(due_previews(&ages, 0.125, 2), vec![2, 1]);
+        assert_eq!(due_previews(&ages, 0.125, 10), vec![2, 1, 3]);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::render::{ColorMode, RenderMode};
+use crate::render::{ColorMode, Mirror, RenderMode};
 use serde::Deserialize;
 use std::collections::HashMap;
 use std::path::PathBuf;
@@ -14,21 +14,32 @@ pub struct Config {
     pub render: Option<RenderModeConfig>,
     /// Default color mode
     pub color: Option<ColorModeConfig>,
+    /// Read the terminal's 16-color palette and draw with it
+    pub theme_from_terminal: Option<bool>,
     /// Target FPS (1-120)
     pub fps: Option<u32>,
     /// Particle/element scale factor (0.5-2.0)
     pub scale: Option<f64>,
+    /// Terminal cell height:width ratio for aspect correction (default 2.0)
+    pub aspect: Option<f64>,
+    /// Letterbox to this on-screen width:height ratio ("16:9", "4/3", "1")
+    pub letterbox: Option<String>,
     /// Hide status bar
     pub clean: Option<bool>,
+    /// Status bar template with `{name}`, `{fps}`, ... placeholders
+    pub status_format: Option<String>,
     /// Auto-cycle interval in seconds (0 = disabled)
     pub cycle: Option<u32>,
+    /// Auto-cycle in a random order, reshuffled after each pass
+    pub shuffle: Option<bool>,
     /// Color quantization step (0 = off, 4/8/16 = coarser colors for less output)
     pub color_quant: Option<u8>,
     /// Remove FPS cap and render as fast as possible
     pub unlimited_fps: Option<bool>,
     /// Path to a file to watch for external control params (ndjson)
     pub data_file: Option<String>,
-    /// Custom keybindings (action -> key name)
+    /// Custom keybindings (action -> key name); also accepted as `[keys]`
+    #[serde(alias = "keys")]
     pub keybindings: Option<HashMap<String, String>>,
     /// Post-processing effects configuration
     pub postproc: Option<PostProcConfig>,
@@ -40,8 +51,109 @@ pub struct Config {
     /// Daltonization correction: protanopia | deuteranopia | tritanopia
     /// (mutually exclusive with `palette`).
     pub colorblind: Option<String>,
-    /// Ordered (Bayer 4x4) dithering for ANSI-256 mode (reduces banding).
+    /// Ordered (Bayer 4x4) dithering for ANSI-256 and ANSI-16 modes (reduces banding).
     pub dither: Option<bool>,
+    /// Mono mode: pixels dimmer than this (0-1) render unlit
+    pub mono_threshold: Option<f64>,
+    /// ASCII mode characters from darkest to brightest (at least two)
+    pub ascii_ramp: Option<String>,
+    /// Overall brightness factor (0-1) for long-running displays
+    pub dim: Option<f64>,
+    /// Move the picture by one cell every few minutes against burn-in
+    pub drift: Option<bool>,
+    /// Sound game events (tones with the `sound` feature, else the bell)
+    pub beep: Option<bool>,
+    /// Animation-specific params passed to the animation by name, same as
+    /// external control fields (e.g. `corona_length = 2.5`)
+    pub params: Option<HashMap<String, serde_json::Value>>,
+    /// Per-animation construction settings, one `[animations.<name>]` table
+    /// each (e.g. `[animations.boids] count = 80`)
+    pub animations: Option<HashMap<String, toml::Table>>,
+    /// Named setting bundles selected with `--preset <name>`; each accepts any
+    /// of the fields above
+    pub presets: Option<HashMap<String, Config>>,
+}
+
+impl Config {
+    /// Names of the presets defined in the config, sorted.
+    pub fn preset_names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self
+            .presets
+            .iter()
+            .flat_map(|p| p.keys().map(String::as_str))
+            .collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Overlay preset `name` on top of this config: fields the preset sets win,
+    /// everything else keeps the base config value. `params`, `keybindings`,
+    /// `postproc` and each `[animations.<name>]` table are merged key by key.
+    pub fn with_preset(mut self, name: &str) -> Result<Config, String> {
+        let preset = self
+            .presets
+            .as_mut()
+            .and_then(|p| p.remove(name))
+            .ok_or_else(|| format!("Unknown preset '{}'", name))?;
+        let params = match (self.params, preset.params) {
+            (Some(mut base), Some(over)) => {
+                base.extend(over);
+                Some(base)
+            }
+            (base, over) => over.or(base),
+        };
+        let keybindings = match (self.keybindings, preset.keybindings) {
+            (Some(mut base), Some(over)) => {
+                base.extend(over);
+                Some(base)
+            }
+            (base, over) => over.or(base),
+        };
+        let postproc = match (self.postproc, preset.postproc) {
+            (Some(base), Some(over)) => Some(over.or(base)),
+            (base, over) => over.or(base),
+        };
+        let animations = match (self.animations, preset.animations) {
+            (Some(mut base), Some(over)) => {
+                for (name, table) in over {
+                    base.entry(name).or_default().extend(table);
+                }
+                Some(base)
+            }
+            (base, over) => over.or(base),
+        };
+        Ok(Config {
+            animation: preset.animation.or(self.animation),
+            render: preset.render.or(self.render),
+            color: preset.color.or(self.color),
+            theme_from_terminal: preset.theme_from_terminal.or(self.theme_from_terminal),
+            fps: preset.fps.or(self.fps),
+            scale: preset.scale.or(self.scale),
+            aspect: preset.aspect.or(self.aspect),
+            letterbox: preset.letterbox.or(self.letterbox),
+            clean: preset.clean.or(self.clean),
+            status_format: preset.status_format.or(self.status_format),
+            cycle: preset.cycle.or(self.cycle),
+            shuffle: preset.shuffle.or(self.shuffle),
+            color_quant: preset.color_quant.or(self.color_quant),
+            unlimited_fps: preset.unlimited_fps.or(self.unlimited_fps),
+            data_file: preset.data_file.or(self.data_file),
+            keybindings,
+            postproc,
+            smoothing: preset.smoothing.or(self.smoothing),
+            palette: preset.palette.or(self.palette),
+            colorblind: preset.colorblind.or(self.colorblind),
+            dither: preset.dither.or(self.dither),
+            mono_threshold: preset.mono_threshold.or(self.mono_threshold),
+            ascii_ramp: preset.ascii_ramp.or(self.ascii_ramp),
+            dim: preset.dim.or(self.dim),
+            drift: preset.drift.or(self.drift),
+            beep: preset.beep.or(self.beep),
+            params,
+            animations,
+            presets: self.presets,
+        })
+    }
 }
 
 /// Render mode names for config file (kebab-case friendly)
@@ -50,6 +162,7 @@ pub struct Config {
 pub enum RenderModeConfig {
     Braille,
     HalfBlock,
+    Quadrant,
     Ascii,
 }
 
@@ -58,6 +171,7 @@ impl From<RenderModeConfig> for RenderMode {
         match c {
             RenderModeConfig::Braille => RenderMode::Braille,
             RenderModeConfig::HalfBlock => RenderMode::HalfBlock,
+            RenderModeConfig::Quadrant => RenderMode::Quadrant,
             RenderModeConfig::Ascii => RenderMode::Ascii,
         }
     }
@@ -71,6 +185,7 @@ pub enum ColorModeConfig {
     Ansi16,
     Ansi256,
     TrueColor,
+    Palette,
 }
 
 impl From<ColorModeConfig> for ColorMode {
@@ -80,6 +195,7 @@ impl From<ColorModeConfig> for ColorMode {
             ColorModeConfig::Ansi16 => ColorMode::Ansi16,
             ColorModeConfig::Ansi256 => ColorMode::Ansi256,
             ColorModeConfig::TrueColor => ColorMode::TrueColor,
+            ColorModeConfig::Palette => ColorMode::Palette,
         }
     }
 }
@@ -91,6 +207,45 @@ pub struct PostProcConfig {
     pub bloom_threshold: Option<f64>,
     pub vignette: Option<f64>,
     pub scanlines: Option<bool>,
+    pub pixelate: Option<u32>,
+    pub mirror: Option<MirrorConfig>,
+    pub kaleidoscope: Option<u32>,
+}
+
+impl PostProcConfig {
+    /// Each effect set here, else the one from `base`.
+    fn or(self, base: PostProcConfig) -> PostProcConfig {
+        PostProcConfig {
+            bloom: self.bloom.or(base.bloom),
+            bloom_threshold: self.bloom_threshold.or(base.bloom_threshold),
+            vignette: self.vignette.or(base.vignette),
+            scanlines: self.scanlines.or(base.scanlines),
+            pixelate: self.pixelate.or(base.pixelate),
+            mirror: self.mirror.or(base.mirror),
+            kaleidoscope: self.kaleidoscope.or(base.kaleidoscope),
+        }
+    }
+}
+
+/// `[postproc] mirror` values
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum MirrorConfig {
+    None,
+    H,
+    V,
+    Both,
+}
+
+impl From<MirrorConfig> for Mirror {
+    fn from(c: MirrorConfig) -> Self {
+        match c {
+            MirrorConfig::None => Mirror::None,
+            MirrorConfig::H => Mirror::H,
+            MirrorConfig::V => Mirror::V,
+            MirrorConfig::Both => Mirror::Both,
+        }
+    }
 }
 
 /// Get the config file path: ~/.config/termflix/config.toml
@@ -124,24 +279,44 @@ pub fn default_config_string() -> String {
 # Default animation (use --list to see all)
 # animation = "fire"
 
-# Default render mode: braille, half-block, ascii
+# Default render mode: braille, half-block, quadrant, ascii
 # render = "half-block"
 
-# Default color mode: mono, ansi16, ansi256, true-color
+# Default color mode: mono, ansi16, ansi256, true-color, palette
 # color = "true-color"
 
+# Draw with the terminal theme's 16 colors, read via OSC 4 at startup
+# (palette mode; falls back to true-color if the terminal doesn't answer)
+# theme_from_terminal = false
+
 # Target FPS (1-120)
 # fps = 24
 
 # Particle/element scale factor (0.5-2.0)
 # scale = 1.0
 
+# Terminal cell height:width ratio for aspect correction (default 2.0).
+# Adjust if circles look squashed or stretched with your font.
+# aspect = 2.0
+
+# Keep this on-screen width:height ratio, centered with blank margins
+# ("16:9", "4/3", "1"), so round and 3D animations stay proportioned
+# letterbox = "1"
+
 # Hide status bar
 # clean = false
 
+# Status bar template. Placeholders: {name} {render} {color} {fps} {rec}
+# {paused} {saved} {scale} {bloom} {smooth} {dither} {assist} {keys}
+# ({{ and }} are literal braces). Omit for the full default bar.
+# status_format = "{name} | {fps}{rec}{paused}"
+
 # Auto-cycle interval in seconds (0 = disabled)
 # cycle = 0
 
+# Auto-cycle in a random order, reshuffled after each full pass
+# shuffle = false
+
 # Color quantization step (0 = off, 4/8/16 = coarser colors, less output)
 # Useful for slow terminals or tmux
 # color_quant = 0
@@ -152,7 +327,11 @@ pub fn default_config_string() -> String {
 # Watch a file for external control params (ndjson — one JSON object per line)
 # data_file = "/tmp/termflix.json"
 
-# Custom keybindings (key names: q, n, Right, Left, Esc, Space, Tab, etc.)
+# Custom keybindings (key names: q, n, Right, Left, Esc, Space, Tab, etc.;
+# `[keys]` works too). A configured action replaces that action's default
+# keys and takes its key from any other default, e.g. vim-style
+# next = "l" / prev = "h" / status = "H". Modifiers must be held when given
+# ("ctrl+q", "alt+Left"); "+" is the plus key.
 # [keybindings]
 # next = "Right"
 # prev = "Left"
@@ -160,6 +339,21 @@ pub fn default_config_string() -> String {
 # render = "r"
 # color = "c"
 # status = "h"
+# pause = "Space"
+# freeze = "f"
+# reset = "0"              # restart the current animation (unbound by default)
+# screenshot = "S"
+# screenshot_text = "T"
+# copy = "y"               # copy the frame as text to the clipboard (OSC 52)
+# bloom = "b"
+# smooth = "s"
+# dither = "d"
+# uncap = "u"
+# reverse = ","            # flip the --cycle direction
+# faster = "+"             # `=` also speeds up by default
+# slower = "-"
+# hue_down = "["
+# hue_up = "]"
 
 # Post-processing effects
 # [postproc]
@@ -167,6 +361,9 @@ pub fn default_config_string() -> String {
 # bloom_threshold = 0.6     # Brightness threshold to trigger bloom (0.0-1.0)
 # vignette = 0.4            # Edge darkening (0.0-1.0)
 # scanlines = false         # CRT scanline effect
+# pixelate = 4              # Chunky low-res blocks, N pixels wide (2-32)
+# mirror = "h"              # Flip frames: none | h (left-right) | v (upside down) | both
+# kaleidoscope = 6          # Fold frames into N mirrored segments (2-12)
 
 # Temporal brightness smoothing time constant in seconds (0 = off).
 # Reduces flicker in fire/plasma/aurora. Best on continuous-noise animations.
@@ -179,8 +376,56 @@ pub fn default_config_string() -> String {
 # (mutually exclusive with palette)
 # colorblind = "deuteranopia"
 
-# Ordered (Bayer 4x4) dithering for ANSI-256 mode (reduces banding on 256-color terminals)
+# Ordered (Bayer 4x4) dithering for ANSI-256 and ANSI-16 modes (reduces banding)
 # dither = true
+
+# In mono color mode, treat pixels dimmer than this (0-1) as unlit — crisp
+# high-contrast output for e-ink displays and screenshots
+# mono_threshold = 0.4
+
+# ASCII mode characters from darkest to brightest (at least two); reverse the
+# ramp for dark text on a light background
+# ascii_ramp = " ░▒▓█"
+
+# Overall brightness factor (0-1) and a one-cell drift every few minutes,
+# for OLED wall displays that run all night
+# dim = 0.6
+# drift = true
+
+# Sound game events: tones when built with `--features sound`, else the bell
+# beep = true
+
+# Animation-specific params (same names as external control fields)
+# [params]
+# corona_length = 2.5
+
+# Per-animation settings, read once when the animation is created
+# (unknown keys and values of the wrong type are ignored). A key here is
+# the matching param without its animation prefix: `charset` below starts
+# where the `matrix_charset` param would, `lang` where `hacker_lang` would.
+# [animations.matrix]
+# charset = "binary"        # latin | katakana | binary | hex, or literal characters
+# message = "WAKE UP"       # Phrase spelled out in the rain now and then
+#
+# [animations.boids]
+# count = 80                # Number of boids (1-1000; default scales with the canvas)
+#
+# [animations.hackerman]
+# lang = "es"               # HUD text: en | es | de
+
+# Named presets: any setting above, applied with --preset <name>
+# (CLI flags still win). Tables merge key by key, so a preset's postproc
+# or keybindings only change the keys it lists. List them with --list-presets.
+# [presets.blue-fire]
+# animation = "fire"
+# render = "half-block"
+# scale = 1.5
+# palette = "viridis"
+# postproc = { bloom = 0.6 }
+#
+# [presets.storm-scope]
+# animation = "radar"
+# params = { contacts = 6 }
 "#
     .to_string()
 }
@@ -235,6 +480,85 @@ mod tests {
         assert_eq!(cfg.dither, Some(true));
     }
 
+    #[test]
+    fn config_parses_postproc_mirror() {
+        let cfg: Config = toml::from_str("[postproc]\nmirror = \"both\"\n").unwrap();
+        let mirror = cfg.postproc.and_then(|p| p.mirror).map(Mirror::from);
+        assert_eq!(mirror, Some(Mirror::Both));
+        assert!(toml::from_str::<Config>("[postproc]\nmirror = \"x\"\n").is_err());
+    }
+
+    #[test]
+    fn preset_overrides_base_config_and_merges_params() {
+        let toml = r#"
+            animation = "plasma"
+            fps = 30
+            [params]
+            contacts = 2
+            smoke_density = 1.5
+            [presets.scope]
+            animation = "radar"
+            params = { contacts = 6 }
+        "#;
+        let cfg: Config = toml::from_str(toml).unwrap();
+        assert_eq!(cfg.preset_names(), vec!["scope"]);
+        let cfg = cfg.with_preset("scope").unwrap();
+        assert_eq!(cfg.animation.as_deref(), Some("radar"));
+        assert_eq!(cfg.fps, Some(30), "unset preset fields keep the base value");
+        let params = cfg.params.unwrap();
+        assert_eq!(params["contacts"], serde_json::json!(6));
+        assert_eq!(params["smoke_density"], serde_json::json!(1.5));
+    }
+
+    #[test]
+    fn animation_tables_parse_and_merge_with_presets() {
+        let toml = r#"
+            [animations.matrix]
+            charset = "hex"
+            message = "HELLO"
+            [presets.bin.animations.matrix]
+            charset = "binary"
+        "#;
+        let cfg: Config = toml::from_str(toml).unwrap();
+        assert_eq!(
+            cfg.animations.as_ref().unwrap()["matrix"]["charset"].as_str(),
+            Some("hex")
+        );
+        let cfg = cfg.with_preset("bin").unwrap();
+        let matrix = &cfg.animations.unwrap()["matrix"];
+        assert_eq!(matrix["charset"].as_str(), Some("binary"));
+        assert_eq!(matrix["message"].as_str(), Some("HELLO"));
+    }
+
+    #[test]
+    fn presets_merge_keybindings_and_postproc_key_by_key() {
+        let toml = r#"
+            [keybindings]
+            quit = "x"
+            pause = "p"
+            [postproc]
+            bloom = 0.4
+            vignette = 0.5
+            [presets.glow]
+            keybindings = { quit = "z" }
+            postproc = { bloom = 0.9 }
+        "#;
+        let cfg: Config = toml::from_str(toml).unwrap();
+        let cfg = cfg.with_preset("glow").unwrap();
+        let keys = cfg.keybindings.unwrap();
+        assert_eq!(keys["quit"], "z");
+        assert_eq!(keys["pause"], "p");
+        let postproc = cfg.postproc.unwrap();
+        assert_eq!(postproc.bloom, Some(0.9));
+        assert_eq!(postproc.vignette, Some(0.5));
+    }
+
+    #[test]
+    fn unknown_preset_is_an_error() {
+        let cfg: Config = toml::from_str("fps = 30\n").unwrap();
+        assert!(cfg.with_preset("nope").is_err());
+    }
+
     #[test]
     fn test_config_parses_keybindings() {
         let toml = r#"
@@ -246,5 +570,8 @@ mod tests {
         let kb = cfg.keybindings.unwrap();
         assert_eq!(kb.get("next").unwrap(), "Right");
         assert_eq!(kb.get("quit").unwrap(), "Esc");
+
+        let cfg: Config = toml::from_str("[keys]\nnext = \"space\"\n").unwrap();
+        assert_eq!(cfg.keybindings.unwrap().get("next").unwrap(), "space");
     }
 }
@@ -0,0 +1,143 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{RngExt, SeedableRng};
+
+/// The order `--cycle` visits animations in: `ANIMATION_NAMES` order, or with
+/// `--shuffle` a random permutation that is reshuffled after each full pass.
+pub struct CycleOrder {
+    len: usize,
+    shuffle: Option<Shuffle>,
+}
+
+struct Shuffle {
+    rng: StdRng,
+    order: Vec<usize>,
+    pos: usize,
+}
+
+impl CycleOrder {
+    /// Cycle order over `len` animations starting from `start`. Shuffled
+    /// orders are reproducible when `seed` is given.
+    pub fn new(len: usize, start: usize, shuffle: bool, seed: Option<u64>) -> Self {
+        let shuffle = shuffle.then(|| {
+            let rng = seed.map_or_else(rand::make_rng, StdRng::seed_from_u64);
+            let mut s = Shuffle {
+                rng,
+                order: (0..len).collect(),
+                pos: 0,
+            };
+            // The first pass starts with the animation already on screen
+            s.order.shuffle(&mut s.rng);
+            if let Some(i) = s.order.iter().position(|&a| a == start) {
+                s.order.swap(0, i);
+            }
+            s
+        });
+        CycleOrder { len, shuffle }
+    }
+
+    /// The animation to switch to after `current`.
+    pub fn next(&mut self, current: usize) -> usize {
+        let Some(s) = self.shuffle.as_mut() else {
+            return (current + 1) % self.len;
+        };
+        s.pos += 1;
+        if s.pos == s.order.len() {
+            let last = s.order[s.order.len() - 1];
+            s.order.shuffle(&mut s.rng);
+            // Don't show the same animation twice in a row across passes
+            if s.order.len() > 1 && s.order[0] == last {
+                let end = s.order.len() - 1;
+                s.order.swap(0, end);
+            }
+            s.pos = 0;
+        }
+        s.order[s.pos]
+    }
+
+    /// The animation to switch to when the cycle runs backward from
+    /// `current`. A shuffled order retraces the pass it is in, wrapping to
+    /// that pass's end rather than reshuffling.
+    pub fn prev(&mut self, current: usize) -> usize {
+        let Some(s) = self.shuffle.as_mut() else {
+            return (current + self.len - 1) % self.len;
+        };
+        s.pos = s.pos.checked_sub(1).unwrap_or(s.order.len() - 1);
+        s.order[s.pos]
+    }
+
+    /// [`next`](Self::next) when `dir` is positive, else [`prev`](Self::prev).
+    pub fn step(&mut self, current: usize, dir: i32) -> usize {
+        if dir > 0 {
+            self.next(current)
+        } else {
+            self.prev(current)
+        }
+    }
+}
+
+/// `--random`: one animation index in `0..len`, reproducible when `seed` is
+/// given.
+pub fn random_pick(len: usize, seed: Option<u64>) -> usize {
+    let mut rng: StdRng = seed.map_or_else(rand::make_rng, StdRng::seed_from_u64);
+    rng.random_range(0..len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn random_pick_is_in_range_and_seeded() {
+        assert!((0..100).all(|_| random_pick(5, None) < 5));
+        assert_eq!(random_pick(62, Some(9)), random_pick(62, Some(9)));
+        let picks: std::collections::HashSet<usize> =
+            (0..20).map(|s| random_pick(62, Some(s))).collect();
+        assert!(picks.len() > 1, "different seeds pick different animations");
+    }
+
+    #[test]
+    fn sequential_wraps_from_the_current_animation() {
+        let mut order = CycleOrder::new(4, 0, false, None);
+        assert_eq!(order.next(0), 1);
+        assert_eq!(order.next(3), 0);
+    }
+
+    #[test]
+    fn shuffled_passes_visit_everything_once() {
+        let mut order = CycleOrder::new(10, 3, true, Some(7));
+        let mut shown = vec![3];
+        for _ in 0..29 {
+            let prev = *shown.last().unwrap();
+            shown.push(order.next(prev));
+        }
+        for pass in shown.chunks(10) {
+            let mut sorted = pass.to_vec();
+            sorted.sort_unstable();
+            assert_eq!(sorted, (0..10).collect::<Vec<_>>());
+        }
+        assert!(shown.windows(2).all(|w| w[0] != w[1]));
+        assert_ne!(&shown[..10], &[3, 4, 5, 6, 7, 8, 9, 0, 1, 2]);
+
+        let mut again = CycleOrder::new(10, 3, true, Some(7));
+        let replay: Vec<usize> = (0..29).map(|_| again.next(0)).collect();
+        assert_eq!(replay, shown[1..], "same seed, same order");
+    }
+
+    #[test]
+    fn reversing_retraces_the_order() {
+        let mut order = CycleOrder::new(4, 0, false, None);
+        assert_eq!(order.step(0, -1), 3);
+        assert_eq!(order.step(3, -1), 2);
+        assert_eq!(order.step(2, 1), 3);
+
+        let mut order = CycleOrder::new(10, 3, true, Some(7));
+        let forward: Vec<usize> = (0..4).map(|_| order.step(0, 1)).collect();
+        let back: Vec<usize> = (0..3).map(|_| order.step(0, -1)).collect();
+        assert_eq!(back, [forward[2], forward[1], forward[0]]);
+        // Backing past the pass start wraps within the same pass
+        assert_eq!(order.step(0, -1), 3);
+        let wrapped = order.step(0, -1);
+        assert!(!forward[..3].contains(&wrapped) && wrapped != 3);
+    }
+}
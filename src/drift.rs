@@ -0,0 +1,53 @@
+use std::time::Duration;
+
+/// How long the picture holds each position before moving one cell.
+pub const DRIFT_INTERVAL: Duration = Duration::from_secs(180);
+
+/// Cell offsets visited in turn, a one-cell square so nothing ever travels
+/// more than one column or row from where the animation drew it.
+const DRIFT_PATH: [(usize, usize); 4] = [(0, 0), (1, 0), (1, 1), (0, 1)];
+
+/// `(dx, dy)` cell offset for the whole frame after `elapsed` of playback,
+/// for `--drift`: moving static elements (HUD borders, radar rings) by a
+/// cell every few minutes spreads their wear on OLED panels.
+pub fn offset(elapsed: Duration) -> (usize, usize) {
+    let step = elapsed.as_secs() / DRIFT_INTERVAL.as_secs();
+    DRIFT_PATH[step as usize % DRIFT_PATH.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::cell::{Cell, CellGrid};
+
+    #[test]
+    fn offset_walks_a_one_cell_square() {
+        assert_eq!(offset(Duration::ZERO), (0, 0));
+        assert_eq!(offset(DRIFT_INTERVAL - Duration::from_secs(1)), (0, 0));
+        let visited: Vec<_> = (0..8).map(|i| offset(DRIFT_INTERVAL * i)).collect();
+        assert_eq!(&visited[..4], &DRIFT_PATH);
+        assert_eq!(&visited[4..], &DRIFT_PATH);
+    }
+
+    #[test]
+    fn shifted_grid_keeps_its_size_and_drops_the_far_edge() {
+        let mut grid = CellGrid::new(3, 2);
+        for (i, ch) in "abcdef".chars().enumerate() {
+            grid.cells[i] = Cell {
+                ch,
+                fg: None,
+                bg: None,
+                attrs: 0,
+            };
+        }
+        let moved = grid.shifted(1, 1);
+        assert_eq!((moved.cols, moved.rows), (3, 2));
+        let text: String = moved.cells.iter().map(|c| c.ch).collect();
+        assert_eq!(text, "    ab");
+
+        // A wide glyph pushed into the last column would overflow the row.
+        grid.cells[1].ch = 'ア';
+        let text: String = grid.shifted(1, 0).cells.iter().map(|c| c.ch).collect();
+        assert_eq!(text, " a  de");
+    }
+}
@@ -0,0 +1,355 @@
+//! `--export-png` / `--export-gif` / `--record-headless`: render frames
+//! offscreen, with no TTY, raw mode, or event loop, and write them as a
+//! numbered PNG sequence or one animated GIF at the canvas's sub-pixel
+//! resolution, or as a `.asciianim` / `.cast` recording of the encoded frames.
+
+use crate::{gif, png, record};
+use std::fs;
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+use termflix::animations;
+use termflix::external::{CurrentState, ExternalParams};
+use termflix::render::{Canvas, ColorMode, PostProcessConfig, RenderMode};
+
+/// Longest clip `--export-gif` will encode; every frame is held in memory
+/// until the palette is built, and the file grows with each one.
+pub const MAX_GIF_FRAMES: usize = 1000;
+
+/// Where the rendered frames go.
+pub enum Output {
+    /// `frame_00001.png`, `frame_00002.png`, ... in this directory
+    PngDir(PathBuf),
+    /// One animated GIF with a palette fitted to the whole clip
+    Gif(PathBuf),
+    /// A recording of `Canvas::render()` output, asciinema v2 if the path
+    /// ends in `.cast`
+    Recording(PathBuf),
+}
+
+pub struct ExportConfig {
+    pub output: Output,
+    pub name: String,
+    pub frames: usize,
+    pub fps: u32,
+    pub scale: f64,
+    pub seed: Option<u64>,
+    /// Terminal cells the canvas covers; the image is this times the
+    /// render mode's sub-cell grid
+    pub cols: usize,
+    pub rows: usize,
+    /// `None` uses the animation's preferred mode
+    pub render_mode: Option<RenderMode>,
+    pub color_mode: ColorMode,
+    pub color_quant: u8,
+    pub dither: bool,
+    pub mono_threshold: f64,
+    pub ascii_ramp: String,
+    pub cell_aspect: f64,
+    pub params: ExternalParams,
+    /// The animation's `[animations.<name>]` config table
+    pub settings: Option<toml::Table>,
+    pub postproc: PostProcessConfig,
+}
+
+/// Smallest canvas in terminal cells that fits `anim`'s
+/// [`min_size`](animations::Animation::min_size) in `render_mode`. The live
+/// loop shows a notice below it; offscreen paths must not call `update` there.
+pub fn min_cells(anim: &dyn animations::Animation, render_mode: RenderMode) -> (usize, usize) {
+    let (min_w, min_h) = anim.min_size();
+    let (px_per_col, px_per_row) = termflix::render::subcell_grid(render_mode);
+    (min_w.div_ceil(px_per_col), min_h.div_ceil(px_per_row))
+}
+
+/// Render `config.frames` frames at a fixed `1 / fps` step and write them to
+/// `config.output`, reporting progress on stderr.
+pub fn run_export(config: &ExportConfig) -> io::Result<()> {
+    let invalid = |e| io::Error::new(io::ErrorKind::InvalidInput, e);
+    let frames = match config.output {
+        Output::PngDir(ref dir) => {
+            fs::create_dir_all(dir)?;
+            config.frames
+        }
+        Output::Gif(_) if config.frames > MAX_GIF_FRAMES => {
+            eprintln!(
+                "--frames {} is over the GIF limit; writing {MAX_GIF_FRAMES}",
+                config.frames
+            );
+            MAX_GIF_FRAMES
+        }
+        Output::Gif(_) | Output::Recording(_) => config.frames,
+    };
+
+    let (probe_w, probe_h) = animations::PROBE_SIZE;
+    let probe = animations::create(&config.name, probe_w, probe_h, 1.0, None).map_err(invalid)?;
+    let render_mode = config.render_mode.unwrap_or(probe.preferred_render());
+    let (need_cols, need_rows) = min_cells(probe.as_ref(), render_mode);
+    if config.cols < need_cols || config.rows < need_rows {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "--size {}x{} is too small for {}; it needs at least {need_cols}x{need_rows} cells",
+                config.cols, config.rows, config.name
+            ),
+        ));
+    }
+    let mut canvas = Canvas::new(config.cols, config.rows, render_mode, config.color_mode);
+    canvas.color_quant = config.color_quant;
+    canvas.dither = config.dither;
+    canvas.mono_threshold = config.mono_threshold;
+    canvas.set_ascii_ramp(&config.ascii_ramp);
+    canvas.set_cell_aspect(config.cell_aspect);
+    let mut anim = animations::create_for_canvas(
+        &config.name,
+        canvas.width,
+        canvas.height,
+        config.scale,
+        config.seed,
+        config.settings.as_ref(),
+    )
+    .map_err(invalid)?;
+
+    let mut state = CurrentState::default();
+    state.merge(config.params.clone());
+    let dt = 1.0 / config.fps as f64;
+    let speed = state.speed();
+    // GIF delays are whole centiseconds, and browsers treat anything under 2 as slow
+    let delay_cs = (100 / config.fps as u64).max(2);
+    let mut gif_frames: Vec<gif::PixelFrame> = Vec::new();
+    let mut recorder = record::Recorder::with_metadata(record::Metadata {
+        anim: Some(config.name.clone()),
+        size: Some((config.cols as u16, config.rows as u16)),
+        fps: Some(config.fps),
+    });
+    let mut time = 0.0;
+    let mut stderr = io::stderr();
+
+    for i in 1..=frames {
+        time += dt * speed;
+        anim.set_params(state.params());
+        anim.update(&mut canvas, dt * speed, time);
+        // No sound in an export; drop game events so they don't pile up
+        anim.take_events();
+        canvas.apply_effects(
+            state.intensity().clamp(0.0, 2.0),
+            state.color_shift().clamp(0.0, 1.0),
+        );
+        canvas.post_process(&config.postproc);
+
+        match config.output {
+            Output::PngDir(ref dir) => {
+                let rgba: Vec<u8> = canvas_rgb(&canvas)
+                    .iter()
+                    .flat_map(|&(r, g, b)| [r, g, b, 255])
+                    .collect();
+                let path = dir.join(format!("frame_{i:05}.png"));
+                let mut writer = BufWriter::new(fs::File::create(&path)?);
+                png::export_png(
+                    &mut writer,
+                    &rgba,
+                    canvas.width as u32,
+                    canvas.height as u32,
+                )?;
+                writer.flush()?;
+            }
+            Output::Gif(_) => gif_frames.push(gif::PixelFrame {
+                timestamp_ms: (i as u64 - 1) * delay_cs * 10,
+                pixels: canvas_rgb(&canvas),
+            }),
+            Output::Recording(_) => {
+                let timestamp_ms = (i as u64 - 1) * 1000 / config.fps as u64;
+                recorder.capture_at(
+                    &canvas.render(),
+                    timestamp_ms,
+                    (config.cols as u16, config.rows as u16),
+                );
+            }
+        }
+        write!(stderr, "\r{}: frame {i}/{frames}", config.name)?;
+    }
+
+    let size = format!("{}x{}", canvas.width, canvas.height);
+    match config.output {
+        Output::PngDir(ref dir) => {
+            writeln!(
+                stderr,
+                "\nWrote {frames} {size} frames to {}",
+                dir.display()
+            )?;
+        }
+        Output::Gif(ref path) => {
+            write!(stderr, "\r{}: encoding GIF...", config.name)?;
+            let mut writer = BufWriter::new(fs::File::create(path)?);
+            gif::export_gif_pixels_adaptive(
+                &mut writer,
+                &gif_frames,
+                canvas.width,
+                canvas.height,
+                1,
+            )?;
+            drop(writer);
+            let bytes = fs::metadata(path)?.len();
+            writeln!(
+                stderr,
+                "\nWrote {frames} {size} frames to {} ({})",
+                path.display(),
+                human_size(bytes)
+            )?;
+        }
+        Output::Recording(ref path) => {
+            recorder.save_for_path(path, config.cols as u16, config.rows as u16)?;
+            writeln!(
+                stderr,
+                "\nSaved {frames} {}x{} frames to {}",
+                config.cols,
+                config.rows,
+                path.display()
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// `--size` value: `WxH` in terminal cells, each from 1 to 1000.
+pub fn parse_size(s: &str) -> Result<(u16, u16), String> {
+    let (w, h) = s
+        .split_once(['x', 'X'])
+        .ok_or_else(|| format!("expected WxH (e.g. 80x24), got '{s}'"))?;
+    let dim = |v: &str| match v.trim().parse::<u16>() {
+        Ok(n) if (1..=1000).contains(&n) => Ok(n),
+        _ => Err(format!("'{v}' is not a size from 1 to 1000")),
+    };
+    Ok((dim(w)?, dim(h)?))
+}
+
+/// One RGB pixel per canvas pixel: its color scaled by its brightness.
+fn canvas_rgb(canvas: &Canvas) -> Vec<(u8, u8, u8)> {
+    canvas
+        .pixels
+        .iter()
+        .zip(&canvas.colors)
+        .map(|(&v, &(r, g, b))| {
+            let v = v.clamp(0.0, 1.0);
+            (
+                (r as f64 * v) as u8,
+                (g as f64 * v) as u8,
+                (b as f64 * v) as u8,
+            )
+        })
+        .collect()
+}
+
+fn human_size(bytes: u64) -> String {
+    match bytes {
+        0..1024 => format!("{bytes} B"),
+        1024..1_048_576 => format!("{:.1} KiB", bytes as f64 / 1024.0),
+        _ => format!("{:.1} MiB", bytes as f64 / 1_048_576.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use termflix::render::{DEFAULT_ASCII_RAMP, DEFAULT_CELL_ASPECT};
+
+    fn config(output: Output, frames: usize) -> ExportConfig {
+        ExportConfig {
+            output,
+            name: "plasma".into(),
+            frames,
+            fps: 24,
+            scale: 1.0,
+            seed: Some(1),
+            cols: 20,
+            rows: 10,
+            render_mode: Some(RenderMode::Braille),
+            color_mode: ColorMode::TrueColor,
+            color_quant: 0,
+            dither: false,
+            mono_threshold: 0.0,
+            ascii_ramp: DEFAULT_ASCII_RAMP.into(),
+            cell_aspect: DEFAULT_CELL_ASPECT,
+            params: ExternalParams::default(),
+            settings: None,
+            postproc: PostProcessConfig::default(),
+        }
+    }
+
+    #[test]
+    fn writes_numbered_frames_at_subpixel_size() {
+        let dir = std::env::temp_dir().join(format!("termflix-export-{}", std::process::id()));
+        run_export(&config(Output::PngDir(dir.clone()), 3)).unwrap();
+        let first = fs::read(dir.join("frame_00001.png")).unwrap();
+        assert_eq!(&first[..8], &[137, 80, 78, 71, 13, 10, 26, 10]);
+        // IHDR width and height: 2x4 braille dots per cell
+        assert_eq!(&first[16..20], &40u32.to_be_bytes());
+        assert_eq!(&first[20..24], &40u32.to_be_bytes());
+        assert!(dir.join("frame_00003.png").exists());
+        assert!(!dir.join("frame_00004.png").exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn rejects_a_size_below_the_animation_minimum() {
+        let path = std::env::temp_dir().join(format!("termflix-export-{}.txt", std::process::id()));
+        for name in ["aurora", "pong"] {
+            let mut tiny = config(Output::Recording(path.clone()), 3);
+            tiny.name = name.into();
+            (tiny.cols, tiny.rows) = (1, 1);
+            let err = run_export(&tiny).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidInput, "{name}");
+        }
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn writes_gif_with_fps_delay() {
+        let path = std::env::temp_dir().join(format!("termflix-export-{}.gif", std::process::id()));
+        run_export(&config(Output::Gif(path.clone()), 3)).unwrap();
+        let gif = fs::read(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(&gif[..6], b"GIF89a");
+        assert_eq!(&gif[6..8], &40u16.to_le_bytes());
+        assert_eq!(&gif[8..10], &40u16.to_le_bytes());
+        // First graphic control extension: 100 / 24 fps = 4 centiseconds
+        let gce = gif.windows(2).position(|w| w == [0x21, 0xF9]).unwrap();
+        assert_eq!(&gif[gce + 4..gce + 6], &4u16.to_le_bytes());
+        assert_eq!(*gif.last().unwrap(), 0x3B);
+    }
+
+    #[test]
+    fn records_one_frame_per_step_without_a_terminal() {
+        let path =
+            std::env::temp_dir().join(format!("termflix-export-{}.cast", std::process::id()));
+        run_export(&config(Output::Recording(path.clone()), 5)).unwrap();
+        let text = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        let lines: Vec<serde_json::Value> = text
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(
+            lines[0],
+            serde_json::json!({ "version": 2, "width": 20, "height": 10 })
+        );
+        assert_eq!(lines.len(), 6);
+        // 24 fps: frame 5 lands at 4 * 1000 / 24 ms
+        assert_eq!(lines[5][0], serde_json::json!(0.166));
+    }
+
+    #[test]
+    fn parses_sizes() {
+        assert_eq!(parse_size("80x24"), Ok((80, 24)));
+        assert_eq!(parse_size("120X40"), Ok((120, 40)));
+        assert!(parse_size("80").is_err());
+        assert!(parse_size("0x24").is_err());
+        assert!(parse_size("80x-1").is_err());
+        assert!(parse_size("wide x tall").is_err());
+    }
+
+    #[test]
+    fn human_sizes() {
+        assert_eq!(human_size(512), "512 B");
+        assert_eq!(human_size(1536), "1.5 KiB");
+        assert_eq!(human_size(3 * 1_048_576), "3.0 MiB");
+    }
+}
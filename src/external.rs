@@ -1,3 +1,32 @@
+use std::collections::HashMap;
+
+/// Slowest and fastest the global `speed` multiplier may run.
+pub const MIN_SPEED: f64 = 0.1;
+pub const MAX_SPEED: f64 = 8.0;
+
+/// Speeds the `+`/`-` keys step through.
+const SPEED_STEPS: [f64; 11] = [0.1, 0.25, 0.5, 0.75, 1.0, 1.5, 2.0, 3.0, 4.0, 6.0, 8.0];
+
+/// Hue rotation the `[`/`]` keys apply per press (15°).
+pub const HUE_STEP: f64 = 1.0 / 24.0;
+
+/// `color_shift` rotated by `steps` of [`HUE_STEP`], wrapping around the
+/// color wheel.
+pub fn nudge_hue(shift: f64, steps: i32) -> f64 {
+    (shift + steps as f64 * HUE_STEP).rem_euclid(1.0)
+}
+
+/// The next speed step above (`faster`) or below `speed`, staying at the
+/// ends of the range. Speeds between steps move to the neighbouring one.
+pub fn step_speed(speed: f64, faster: bool) -> f64 {
+    let next = if faster {
+        SPEED_STEPS.iter().find(|&&s| s > speed + 1e-9)
+    } else {
+        SPEED_STEPS.iter().rev().find(|&&s| s < speed - 1e-9)
+    };
+    next.copied().unwrap_or(speed).clamp(MIN_SPEED, MAX_SPEED)
+}
+
 #[derive(Debug, Clone, Default, serde::Deserialize)]
 pub struct ExternalParams {
     pub animation: Option<String>,
@@ -7,6 +36,29 @@ pub struct ExternalParams {
     pub scale: Option<f64>,
     pub render: Option<String>,
     pub color: Option<String>,
+    /// Steady horizontal force on particles, added to an animation's own
+    /// gusts (`rain`, `fountain`, `campfire`, `waterfall`)
+    pub wind: Option<f64>,
+    /// Multiplier on a particle animation's own gravity (1.0 = default)
+    pub gravity: Option<f64>,
+    /// Multiplier on a particle animation's emission (1.0 = default)
+    pub emit_rate: Option<f64>,
+    /// Animation-specific knobs (see `Animation::supported_params`), e.g.
+    /// `{"corona_length": 2.0}` or `{"smoke_color": "toxic"}`.
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl ExternalParams {
+    /// Look up an animation-specific numeric param by name.
+    pub fn get(&self, name: &str) -> Option<f64> {
+        self.extra.get(name).and_then(serde_json::Value::as_f64)
+    }
+
+    /// Look up an animation-specific string param by name.
+    pub fn get_str(&self, name: &str) -> Option<&str> {
+        self.extra.get(name).and_then(serde_json::Value::as_str)
+    }
 }
 
 #[derive(Debug, Clone, Default)]
@@ -44,6 +96,10 @@ impl CurrentState {
         if let Some(v) = p.color_shift {
             self.color_shift = Some(v);
         }
+        self.params.wind = p.wind.or(self.params.wind);
+        self.params.gravity = p.gravity.or(self.params.gravity);
+        self.params.emit_rate = p.emit_rate.or(self.params.emit_rate);
+        self.params.extra.extend(p.extra);
 
         // Keep self.params in sync with accumulated state
         self.params.animation = self.animation_pending.clone();
@@ -87,8 +143,10 @@ impl CurrentState {
         v
     }
 
+    /// The `speed` multiplier for `dt`, clamped to
+    /// [`MIN_SPEED`]..=[`MAX_SPEED`].
     pub fn speed(&self) -> f64 {
-        self.speed.unwrap_or(1.0)
+        self.speed.unwrap_or(1.0).clamp(MIN_SPEED, MAX_SPEED)
     }
 
     pub fn intensity(&self) -> f64 {
@@ -217,6 +275,60 @@ mod tests {
         assert_eq!(state.intensity(), 0.5);
     }
 
+    #[test]
+    fn test_external_params_collects_extra_numeric_keys() {
+        let json = r#"{"speed": 1.5, "corona_length": 2.0, "label": "x"}"#;
+        let p: ExternalParams = serde_json::from_str(json).unwrap();
+        assert_eq!(p.speed, Some(1.5));
+        assert_eq!(p.get("corona_length"), Some(2.0));
+        assert_eq!(p.get("label"), None);
+        assert_eq!(p.get("missing"), None);
+    }
+
+    #[test]
+    fn test_speed_is_clamped_and_steps() {
+        let mut state = CurrentState::default();
+        state.merge(ExternalParams {
+            speed: Some(20.0),
+            ..Default::default()
+        });
+        assert_eq!(state.speed(), MAX_SPEED);
+        assert_eq!(step_speed(1.0, true), 1.5);
+        assert_eq!(step_speed(1.0, false), 0.75);
+        assert_eq!(step_speed(1.2, false), 1.0);
+        assert_eq!(step_speed(MAX_SPEED, true), MAX_SPEED);
+        assert_eq!(step_speed(MIN_SPEED, false), MIN_SPEED);
+    }
+
+    #[test]
+    fn test_nudge_hue_wraps() {
+        assert!((nudge_hue(0.0, 1) - HUE_STEP).abs() < 1e-12);
+        assert!((nudge_hue(0.0, -1) - (1.0 - HUE_STEP)).abs() < 1e-12);
+        assert!(nudge_hue(1.0 - HUE_STEP, 1).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_current_state_merge_accumulates_extra() {
+        let mut state = CurrentState::default();
+        state.merge(serde_json::from_str(r#"{"corona_length": 2.0}"#).unwrap());
+        state.merge(serde_json::from_str(r#"{"totality_dwell": 3.0}"#).unwrap());
+        assert_eq!(state.params().get("corona_length"), Some(2.0));
+        assert_eq!(state.params().get("totality_dwell"), Some(3.0));
+    }
+
+    #[test]
+    fn test_current_state_merge_keeps_particle_params() {
+        let mut state = CurrentState::default();
+        state.merge(serde_json::from_str(r#"{"wind": -5.0, "emit_rate": 2.0}"#).unwrap());
+        state.merge(serde_json::from_str(r#"{"gravity": 0.5}"#).unwrap());
+        let p = state.params();
+        assert_eq!(
+            (p.wind, p.gravity, p.emit_rate),
+            (Some(-5.0), Some(0.5), Some(2.0))
+        );
+        assert_eq!(p.get("wind"), None, "typed fields stay out of extra");
+    }
+
     #[test]
     fn test_current_state_take_animation_change() {
         let mut state = CurrentState::default();
@@ -1,20 +1,27 @@
 use crate::animations;
 use crate::gif;
 use crate::png;
-use crate::render::{Canvas, ColorMode, PostProcessConfig, RenderMode};
+use crate::render::{self, Canvas, ColorMode, Mirror, PostProcessConfig, RenderMode};
 use std::fs;
 use std::io::BufWriter;
 use std::path::PathBuf;
 
 const PNG_SCALE: usize = 8;
 const GIF_SCALE: usize = 8;
+const CAPTURE_FPS: f64 = 24.0;
+/// GIF length when no duration is given and the animation has no usable loop.
+const DEFAULT_DURATION_SECS: f64 = 5.0;
+/// Longest `loop_period` captured in full; longer loops fall back to the default.
+const MAX_LOOP_SECS: f64 = 15.0;
 
 pub struct GalleryConfig {
     pub dir: PathBuf,
     pub cols: usize,
     pub rows: usize,
     pub wait_secs: f64,
-    pub duration_secs: f64,
+    /// Seconds of GIF to record; `None` captures one loop of animations
+    /// that report a short enough `loop_period`.
+    pub duration_secs: Option<f64>,
     pub names: Option<Vec<String>>,
 }
 
@@ -71,43 +78,78 @@ fn resolve_animations(config: &GalleryConfig) -> Vec<(&'static str, &'static str
     }
 }
 
+/// Frame count and step for a capture at `fps`. Without an explicit duration
+/// a `loop_period` up to `MAX_LOOP_SECS` is covered exactly once, the step
+/// nudged so the frame after the last one would be the first again.
+fn capture_timing(duration: Option<f64>, loop_period: Option<f64>, fps: f64) -> (usize, f64) {
+    match (duration, loop_period) {
+        (None, Some(period)) if period <= MAX_LOOP_SECS => {
+            let frames = ((period * fps).round() as usize).max(1);
+            (frames, period / frames as f64)
+        }
+        _ => {
+            let secs = duration.unwrap_or(DEFAULT_DURATION_SECS);
+            ((secs * fps) as usize, 1.0 / fps)
+        }
+    }
+}
+
 fn capture_animation(name: &str, config: &GalleryConfig) -> std::io::Result<()> {
     let cols = config.cols;
     let rows = config.rows;
-    let fps = 24.0;
-    let dt = 1.0 / fps;
-    let total_frames = (config.duration_secs * fps) as usize;
-    let png_frame = (config.wait_secs * fps) as usize;
-    let png_frame = png_frame.clamp(0, total_frames.saturating_sub(1));
+    let fps = CAPTURE_FPS;
 
     let color_mode = ColorMode::TrueColor;
     let render_mode = RenderMode::HalfBlock;
     let canvas = Canvas::new(cols, rows, render_mode, color_mode);
 
-    let mut anim = animations::create(name, canvas.width, canvas.height, 1.0).ok_or_else(|| {
-        std::io::Error::new(
+    let invalid = |e| std::io::Error::new(std::io::ErrorKind::InvalidInput, e);
+    // Check the animation's minimum before building it at the capture size.
+    let (probe_w, probe_h) = animations::PROBE_SIZE;
+    let (min_w, min_h) = animations::create(name, probe_w, probe_h, 1.0, None)
+        .map_err(invalid)?
+        .min_size();
+    if canvas.width < min_w || canvas.height < min_h {
+        let (px_per_col, px_per_row) = render::subcell_grid(render_mode);
+        return Err(std::io::Error::new(
             std::io::ErrorKind::InvalidInput,
-            format!("unknown animation: {}", name),
-        )
-    })?;
+            format!(
+                "needs at least {}x{} cells, --gallery-cols/--gallery-rows give {}x{}",
+                min_w.div_ceil(px_per_col),
+                min_h.div_ceil(px_per_row),
+                cols,
+                rows
+            ),
+        ));
+    }
+    let mut anim =
+        animations::create_for_canvas(name, canvas.width, canvas.height, 1.0, None, None)
+            .map_err(invalid)?;
     let mut canvas = Canvas::new(cols, rows, render_mode, color_mode);
-    anim.on_resize(canvas.width, canvas.height);
+
+    let (total_frames, dt) = capture_timing(config.duration_secs, anim.loop_period(), fps);
+    let png_frame = (config.wait_secs * fps) as usize;
+    let png_frame = png_frame.clamp(0, total_frames.saturating_sub(1));
 
     let postproc = PostProcessConfig {
         bloom: 0.4,
         bloom_threshold: 0.6,
         vignette: 0.0,
         scanlines: false,
+        pixelate: 0,
+        mirror: Mirror::None,
+        kaleidoscope: 0,
     };
 
     let mut gif_frames: Vec<gif::PixelFrame> = Vec::with_capacity(total_frames);
     let mut png_frame_pixels: Option<Vec<(u8, u8, u8)>> = None;
     let mut time = 0.0f64;
-    let dt_ms = (dt * 1000.0) as u64;
 
     for frame_i in 0..total_frames {
         canvas.clear();
         anim.update(&mut canvas, dt, time);
+        // Stills are silent; drop game events so they don't pile up
+        anim.take_events();
         canvas.apply_effects(1.0, 0.0);
         canvas.post_process(&postproc);
 
@@ -128,7 +170,7 @@ fn capture_animation(name: &str, config: &GalleryConfig) -> std::io::Result<()>
         }
 
         gif_frames.push(gif::PixelFrame {
-            timestamp_ms: frame_i as u64 * dt_ms,
+            timestamp_ms: (frame_i as f64 * dt * 1000.0).round() as u64,
             pixels: frame_pixels,
         });
 
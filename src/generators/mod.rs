@@ -1,4 +1,14 @@
 use rand::RngExt;
+use rand::rngs::StdRng;
+
+/// Frame-rate-independent random-walk noise: a zero-mean sample scaled so the
+/// variance accumulated over one second of frames is `scale²` whatever `dt` is.
+/// Add it directly to a position (Brownian displacement); do not multiply by `dt`.
+pub fn jitter(rng: &mut impl RngExt, scale: f64, dt: f64) -> f64 {
+    // Uniform on [-√3, √3] has unit variance.
+    const SQRT_3: f64 = 1.732_050_807_568_877_2;
+    rng.random_range(-SQRT_3..SQRT_3) * scale * dt.max(0.0).sqrt()
+}
 
 /// A single particle managed by the ParticleSystem.
 pub struct Particle {
@@ -36,21 +46,49 @@ pub struct ColorStop {
     pub b: u8,
 }
 
+/// Entries in a baked gradient's lookup table; enough that neighbouring
+/// entries differ by at most one step per channel across a full 0-255 ramp.
+pub const GRADIENT_LUT_SIZE: usize = 256;
+
 /// Linear gradient between color stops. Samples by parameter t in 0.0..=1.0.
 #[derive(Clone)]
 pub struct ColorGradient {
     stops: Vec<ColorStop>,
+    /// Precomputed samples at evenly spaced t, from [`ColorGradient::baked`]
+    lut: Option<Vec<(u8, u8, u8)>>,
 }
 
 impl ColorGradient {
     /// Create a gradient from a list of color stops. Stops should be sorted by t.
     pub fn new(stops: Vec<ColorStop>) -> Self {
         assert!(stops.len() >= 2, "ColorGradient requires at least 2 stops");
-        ColorGradient { stops }
+        ColorGradient { stops, lut: None }
+    }
+
+    /// Like `new`, but precomputes `resolution` samples so `sample` is a
+    /// table lookup instead of a scan over the stops. For gradients sampled
+    /// thousands of times per frame, e.g. particle colors by age.
+    pub fn baked(stops: Vec<ColorStop>, resolution: usize) -> Self {
+        let mut gradient = Self::new(stops);
+        let last = resolution.max(2) - 1;
+        let lut = (0..=last)
+            .map(|i| gradient.interpolate(i as f64 / last as f64))
+            .collect();
+        gradient.lut = Some(lut);
+        gradient
     }
 
     /// Sample the gradient at parameter t (0.0..=1.0).
+    #[inline]
     pub fn sample(&self, t: f64) -> (u8, u8, u8) {
+        match self.lut {
+            Some(ref lut) => lut[(t.clamp(0.0, 1.0) * (lut.len() - 1) as f64).round() as usize],
+            None => self.interpolate(t),
+        }
+    }
+
+    /// Exact interpolation between the stops around `t`.
+    fn interpolate(&self, t: f64) -> (u8, u8, u8) {
         let t = t.clamp(0.0, 1.0);
         if t <= self.stops[0].t {
             return (self.stops[0].r, self.stops[0].g, self.stops[0].b);
@@ -74,6 +112,148 @@ impl ColorGradient {
     }
 }
 
+/// Position within a repeating cycle of `period` seconds as a 0..1 fraction,
+/// so a palette driven by it completes exactly one loop per period.
+pub fn cycle_t(time: f64, period: f64) -> f64 {
+    (time / period).rem_euclid(1.0)
+}
+
+/// The shared `color_cycle_period` param (seconds per palette loop, clamped
+/// to 0.5-600), read by every animation that cycles its colors over time.
+pub fn color_cycle_period(params: &crate::external::ExternalParams) -> Option<f64> {
+    params
+        .get("color_cycle_period")
+        .map(|p| p.clamp(0.5, 600.0))
+}
+
+/// An enum knob that a flag or config sets by name and an external param sets
+/// by name or index (`"smoke_color": "fire"` or `"smoke_color": 2`).
+/// Implemented with [`named_param!`].
+pub trait NamedParam: Copy + 'static {
+    /// Accepted names in variant order, also the flag's possible values.
+    const NAMES: &'static [&'static str];
+    /// The variants, in the same order as `NAMES`.
+    const VARIANTS: &'static [Self];
+
+    fn from_name(s: &str) -> Option<Self> {
+        let i = Self::NAMES.iter().position(|&n| n == s)?;
+        Some(Self::VARIANTS[i])
+    }
+
+    /// Rounds `i` and clamps it to the variants.
+    fn from_index(i: f64) -> Self {
+        let last = Self::VARIANTS.len() - 1;
+        Self::VARIANTS[(i.round().max(0.0) as usize).min(last)]
+    }
+
+    /// The `key` param as a name or an index; `None` when it is unset or an
+    /// unknown name.
+    fn from_params(params: &crate::external::ExternalParams, key: &str) -> Option<Self> {
+        match params.get_str(key) {
+            Some(name) => Self::from_name(name),
+            None => params.get(key).map(Self::from_index),
+        }
+    }
+}
+
+/// Implements [`NamedParam`] from one `Variant => "name"` list, so names and
+/// variants can't drift apart.
+macro_rules! named_param {
+    ($ty:ident { $($variant:ident => $name:literal),+ $(,)? }) => {
+        impl $crate::generators::NamedParam for $ty {
+            const NAMES: &'static [&'static str] = &[$($name),+];
+            const VARIANTS: &'static [Self] = &[$($ty::$variant),+];
+        }
+    };
+}
+pub(crate) use named_param;
+
+/// Largest `wind` param magnitude, in canvas pixels per second².
+pub const MAX_WIND: f64 = 40.0;
+
+/// Live physics knobs shared by the particle animations, from the `wind`,
+/// `gravity`, and `emit_rate` params.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParticleParams {
+    /// Steady force added to the animation's own gusts
+    pub wind: f64,
+    /// Multiplier on the animation's own gravity
+    pub gravity: f64,
+    /// Multiplier on emission
+    pub emit_rate: f64,
+}
+
+impl Default for ParticleParams {
+    fn default() -> Self {
+        ParticleParams {
+            wind: 0.0,
+            gravity: 1.0,
+            emit_rate: 1.0,
+        }
+    }
+}
+
+impl ParticleParams {
+    /// The entries to list in `Animation::supported_params`.
+    pub const SUPPORTED: [(&'static str, f64, f64); 3] = [
+        ("wind", -MAX_WIND, MAX_WIND),
+        ("gravity", 0.0, 3.0),
+        ("emit_rate", 0.0, 4.0),
+    ];
+
+    /// Take whichever of the three params are set, clamped to the
+    /// [`SUPPORTED`](Self::SUPPORTED) ranges.
+    pub fn set(&mut self, params: &crate::external::ExternalParams) {
+        if let Some(w) = params.wind {
+            self.wind = w.clamp(-MAX_WIND, MAX_WIND);
+        }
+        if let Some(g) = params.gravity {
+            self.gravity = g.clamp(0.0, 3.0);
+        }
+        if let Some(r) = params.emit_rate {
+            self.emit_rate = r.clamp(0.0, 4.0);
+        }
+    }
+
+    /// `count` particles scaled by `emit_rate`.
+    pub fn scale_count(&self, count: usize) -> usize {
+        (count as f64 * self.emit_rate).round() as usize
+    }
+}
+
+/// The view-rotation clock of a 3D animation, which the `freeze` key can
+/// hold still while everything else keeps moving with the real `time`.
+#[derive(Default)]
+pub struct ViewSpin {
+    frozen: bool,
+    /// Seconds spent frozen, subtracted from `time`
+    held: f64,
+}
+
+impl ViewSpin {
+    /// Freeze or resume the rotation.
+    pub fn toggle(&mut self) {
+        self.frozen = !self.frozen;
+    }
+
+    pub fn frozen(&self) -> bool {
+        self.frozen
+    }
+
+    /// Call once per `update` with its `dt`.
+    pub fn tick(&mut self, dt: f64) {
+        if self.frozen {
+            self.held += dt;
+        }
+    }
+
+    /// Time to drive the rotation with: `time` minus the time spent frozen,
+    /// so it stops while frozen and resumes without a jump.
+    pub fn time(&self, time: f64) -> f64 {
+        time - self.held
+    }
+}
+
 /// Configuration for a particle emitter.
 #[derive(Clone)]
 pub struct EmitterConfig {
@@ -100,11 +280,24 @@ pub struct EmitterConfig {
     pub gradient: ColorGradient,
 }
 
+/// How a particle combines with the pixel already under it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BlendMode {
+    /// Overwrite the pixel (`Canvas::set_colored`).
+    #[default]
+    Replace,
+    /// Accumulate brightness and blend color (`Canvas::add_colored`), so
+    /// dense clouds glow where particles pile up.
+    Add,
+}
+
 /// A reusable particle system with configurable emitter.
 pub struct ParticleSystem {
     pub particles: Vec<Particle>,
     pub config: EmitterConfig,
+    pub blend: BlendMode,
     capacity: usize,
+    rng: StdRng,
 }
 
 impl ParticleSystem {
@@ -113,13 +306,36 @@ impl ParticleSystem {
         ParticleSystem {
             particles: Vec::with_capacity(capacity),
             config,
+            blend: BlendMode::default(),
             capacity,
+            rng: crate::animations::rng(),
+        }
+    }
+
+    /// The same system drawing with `blend`.
+    pub fn with_blend(mut self, blend: BlendMode) -> Self {
+        self.blend = blend;
+        self
+    }
+
+    fn plot(
+        &self,
+        canvas: &mut crate::render::Canvas,
+        x: usize,
+        y: usize,
+        v: f64,
+        rgb: (u8, u8, u8),
+    ) {
+        let (r, g, b) = rgb;
+        match self.blend {
+            BlendMode::Replace => canvas.set_colored(x, y, v, r, g, b),
+            BlendMode::Add => canvas.add_colored(x, y, v, r, g, b),
         }
     }
 
     /// Emit `count` particles from the emitter.
     pub fn emit(&mut self, count: usize) {
-        let mut rng = rand::rng();
+        let rng = &mut self.rng;
         for _ in 0..count {
             if self.particles.len() >= self.capacity {
                 break;
@@ -168,7 +384,7 @@ impl ParticleSystem {
         g_range: (u8, u8),
         b_range: (u8, u8),
     ) {
-        let mut rng = rand::rng();
+        let rng = &mut self.rng;
         for _ in 0..count {
             if self.particles.len() >= self.capacity {
                 break;
@@ -204,7 +420,7 @@ impl ParticleSystem {
                 let r = (p.r as f64 * fade) as u8;
                 let g = (p.g as f64 * fade) as u8;
                 let b = (p.b as f64 * fade) as u8;
-                canvas.set_colored(ix, iy, fade, r, g, b);
+                self.plot(canvas, ix, iy, fade, (r, g, b));
             }
         }
     }
@@ -230,9 +446,9 @@ impl ParticleSystem {
             let iy = p.y as usize;
             if ix < canvas.width && iy < canvas.height {
                 let age = p.age();
-                let (r, g, b) = self.config.gradient.sample(age);
+                let color = self.config.gradient.sample(age);
                 let brightness = p.life_frac();
-                canvas.set_colored(ix, iy, brightness, r, g, b);
+                self.plot(canvas, ix, iy, brightness, color);
             }
         }
     }
@@ -249,3 +465,171 @@ impl ParticleSystem {
         self.particles.clear();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    /// The `fountain` jet's stops.
+    fn jet_stops() -> Vec<ColorStop> {
+        [
+            (0.0, 200, 220, 255),
+            (0.3, 100, 160, 255),
+            (0.7, 60, 120, 220),
+            (1.0, 30, 60, 140),
+        ]
+        .into_iter()
+        .map(|(t, r, g, b)| ColorStop { t, r, g, b })
+        .collect()
+    }
+
+    fn jet_system(gradient: ColorGradient) -> ParticleSystem {
+        let config = EmitterConfig {
+            x: 120.0,
+            y: 159.0,
+            spread: 0.4,
+            angle: -std::f64::consts::FRAC_PI_2,
+            speed_min: 35.0,
+            speed_max: 55.0,
+            life_min: 1.5,
+            life_max: 3.0,
+            gravity: 20.0,
+            drag: 0.995,
+            wind: 0.0,
+            gradient,
+        };
+        let mut system = ParticleSystem::new(config, 3000);
+        for _ in 0..30 {
+            system.emit(100);
+            system.update(1.0 / 24.0);
+        }
+        system
+    }
+
+    #[test]
+    fn baked_gradient_matches_exact_samples() {
+        let exact = ColorGradient::new(jet_stops());
+        let baked = ColorGradient::baked(jet_stops(), GRADIENT_LUT_SIZE);
+        for i in 0..=1000 {
+            let t = i as f64 / 1000.0;
+            let (a, b) = (exact.sample(t), baked.sample(t));
+            let off = |x: u8, y: u8| x.abs_diff(y);
+            assert!(
+                off(a.0, b.0) <= 1 && off(a.1, b.1) <= 1 && off(a.2, b.2) <= 1,
+                "t={t}: {a:?} vs {b:?}"
+            );
+        }
+        assert_eq!(baked.sample(-1.0), (200, 220, 255));
+        assert_eq!(baked.sample(2.0), (30, 60, 140));
+    }
+
+    /// Times `ParticleSystem::draw` on a full `fountain` jet with exact and
+    /// baked gradients.
+    /// Run: cargo test bench_gradient -- --ignored --nocapture
+    #[test]
+    #[ignore = "benchmark; run with --ignored --nocapture"]
+    fn bench_gradient() {
+        use crate::render::{Canvas, ColorMode, RenderMode};
+        let mut canvas = Canvas::new(120, 40, RenderMode::Braille, ColorMode::TrueColor);
+        let n = 500;
+        for (label, gradient) in [
+            ("exact", ColorGradient::new(jet_stops())),
+            (
+                "baked",
+                ColorGradient::baked(jet_stops(), GRADIENT_LUT_SIZE),
+            ),
+        ] {
+            let system = jet_system(gradient);
+            let start = std::time::Instant::now();
+            for _ in 0..n {
+                system.draw(&mut canvas);
+            }
+            let per_frame = start.elapsed() / n;
+            println!(
+                "{label}: {} particles, {per_frame:?} per draw",
+                system.count()
+            );
+        }
+    }
+
+    /// Variance of the total displacement after one simulated second at `fps`.
+    fn displacement_variance_per_second(fps: usize) -> f64 {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+        let dt = 1.0 / fps as f64;
+        let trials = 4000;
+        let mut sum_sq = 0.0;
+        for _ in 0..trials {
+            let x: f64 = (0..fps).map(|_| jitter(&mut rng, 2.0, dt)).sum();
+            sum_sq += x * x;
+        }
+        sum_sq / trials as f64
+    }
+
+    #[test]
+    fn view_spin_holds_while_frozen_and_resumes_smoothly() {
+        let mut spin = ViewSpin::default();
+        spin.tick(1.0);
+        assert_eq!(spin.time(1.0), 1.0);
+        spin.toggle();
+        spin.tick(0.5);
+        spin.tick(0.5);
+        assert_eq!(spin.time(2.0), 1.0);
+        spin.toggle();
+        spin.tick(0.25);
+        assert_eq!(spin.time(2.25), 1.25);
+    }
+
+    #[test]
+    fn jitter_variance_per_second_is_frame_rate_independent() {
+        let slow = displacement_variance_per_second(10);
+        let fast = displacement_variance_per_second(60);
+        assert!((slow - 4.0).abs() < 0.4, "10 fps variance {slow}");
+        assert!((fast - 4.0).abs() < 0.4, "60 fps variance {fast}");
+    }
+
+    #[test]
+    fn cycle_t_wraps_once_per_period() {
+        assert_eq!(cycle_t(0.0, 4.0), 0.0);
+        assert_eq!(cycle_t(1.0, 4.0), 0.25);
+        assert_eq!(cycle_t(5.0, 4.0), 0.25);
+        assert_eq!(cycle_t(-1.0, 4.0), 0.75);
+    }
+
+    #[test]
+    fn jitter_is_zero_for_zero_dt() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1);
+        assert_eq!(jitter(&mut rng, 5.0, 0.0), 0.0);
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Tint {
+        Red,
+        Green,
+        Blue,
+    }
+
+    named_param!(Tint {
+        Red => "red",
+        Green => "green",
+        Blue => "blue",
+    });
+
+    #[test]
+    fn named_param_reads_a_name_or_an_index() {
+        let params =
+            |json: &str| serde_json::from_str::<crate::external::ExternalParams>(json).unwrap();
+        assert_eq!(Tint::NAMES, ["red", "green", "blue"]);
+        assert_eq!(Tint::from_name("green"), Some(Tint::Green));
+        assert_eq!(Tint::from_name("Green"), None);
+        // Indices round and clamp to the variants
+        assert_eq!(Tint::from_index(1.4), Tint::Green);
+        assert_eq!(Tint::from_index(-3.0), Tint::Red);
+        assert_eq!(Tint::from_index(9.0), Tint::Blue);
+        let from = |json| Tint::from_params(&params(json), "tint");
+        assert_eq!(from(r#"{"tint": "blue"}"#), Some(Tint::Blue));
+        assert_eq!(from(r#"{"tint": 1}"#), Some(Tint::Green));
+        assert_eq!(from(r#"{"tint": "teal"}"#), None);
+        assert_eq!(from("{}"), None);
+    }
+}
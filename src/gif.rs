@@ -3,6 +3,9 @@
 //! No external crate dependencies. Converts `.asciianim` frame data into an animated GIF
 //! by decoding ANSI escape sequences, quantizing truecolor to a 6x7x6 palette (252 colors
 //! + 4 reserved), and writing GIF frames with variable-width LZW.
+//!
+//! Offscreen pixel frames can instead use a median-cut palette fitted to the clip
+//! (`--export-gif`).
 
 use std::io::Write;
 
@@ -324,6 +327,95 @@ impl Palette {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Adaptive palette — median cut over a 5-bit-per-channel histogram
+// ---------------------------------------------------------------------------
+
+const BIN_COUNT: usize = 1 << 15;
+
+/// 15-bit histogram bin for an RGB color (top 5 bits of each channel).
+fn color_bin(r: u8, g: u8, b: u8) -> usize {
+    ((r as usize >> 3) << 10) | ((g as usize >> 3) << 5) | (b as usize >> 3)
+}
+
+/// Channel `ch` (0 = R, 1 = G, 2 = B) of a bin, 0-31.
+fn bin_channel(bin: usize, ch: usize) -> usize {
+    (bin >> (10 - ch * 5)) & 0x1F
+}
+
+impl Palette {
+    /// Median-cut palette over every pixel in `frames`: repeatedly split the
+    /// box with the widest (population-weighted) channel range at its
+    /// weighted median until there are 256 boxes, then use each box's mean
+    /// color. Also returns the bin -> palette index table for encoding.
+    fn median_cut(frames: &[PixelFrame]) -> (Self, Vec<u8>) {
+        let mut counts = vec![0u64; BIN_COUNT];
+        let mut sums = vec![[0u64; 3]; BIN_COUNT];
+        for &(r, g, b) in frames.iter().flat_map(|f| &f.pixels) {
+            let bin = color_bin(r, g, b);
+            counts[bin] += 1;
+            sums[bin][0] += r as u64;
+            sums[bin][1] += g as u64;
+            sums[bin][2] += b as u64;
+        }
+
+        let mut boxes: Vec<Vec<usize>> = vec![(0..BIN_COUNT).filter(|&b| counts[b] > 0).collect()];
+        while boxes.len() < PALETTE_SIZE {
+            let widest = boxes
+                .iter()
+                .enumerate()
+                .filter(|(_, bins)| bins.len() > 1)
+                .map(|(i, bins)| {
+                    let (ch, range) = (0..3)
+                        .map(|ch| {
+                            let lo = bins.iter().map(|&b| bin_channel(b, ch)).min().unwrap();
+                            let hi = bins.iter().map(|&b| bin_channel(b, ch)).max().unwrap();
+                            (ch, hi - lo)
+                        })
+                        .max_by_key(|&(_, range)| range)
+                        .unwrap();
+                    let population: u64 = bins.iter().map(|&b| counts[b]).sum();
+                    (i, ch, range as u64 * population)
+                })
+                .max_by_key(|&(_, _, weight)| weight);
+            let Some((i, ch, _)) = widest else {
+                break;
+            };
+
+            let mut bins = boxes.swap_remove(i);
+            bins.sort_unstable_by_key(|&b| bin_channel(b, ch));
+            let total: u64 = bins.iter().map(|&b| counts[b]).sum();
+            let mut seen = 0;
+            let mut cut = bins.len() / 2;
+            for (j, &b) in bins.iter().enumerate() {
+                seen += counts[b];
+                if seen * 2 >= total {
+                    cut = (j + 1).clamp(1, bins.len() - 1);
+                    break;
+                }
+            }
+            let upper = bins.split_off(cut);
+            boxes.push(bins);
+            boxes.push(upper);
+        }
+
+        let mut entries = [(0u8, 0u8, 0u8); PALETTE_SIZE];
+        let mut lookup = vec![0u8; BIN_COUNT];
+        for (i, bins) in boxes.iter().enumerate() {
+            let population: u64 = bins.iter().map(|&b| counts[b]).sum();
+            let mean = |ch: usize| {
+                let sum: u64 = bins.iter().map(|&b| sums[b][ch]).sum();
+                (sum / population.max(1)) as u8
+            };
+            entries[i] = (mean(0), mean(1), mean(2));
+            for &b in bins {
+                lookup[b] = i as u8;
+            }
+        }
+        (Palette { entries }, lookup)
+    }
+}
+
 // ---------------------------------------------------------------------------
 // LZW compressor — variable-width, LSB-first packing
 // ---------------------------------------------------------------------------
@@ -674,8 +766,38 @@ pub fn export_gif_pixels<W: Write>(
     height: usize,
     scale: usize,
 ) -> std::io::Result<()> {
-    assert!(scale >= 1, "scale must be >= 1");
     let palette = Palette::new();
+    write_pixel_gif(writer, frames, width, height, scale, &palette, |r, g, b| {
+        palette.find_nearest(r, g, b)
+    })
+}
+
+/// Like [`export_gif_pixels`], but with a median-cut palette fitted to the
+/// colors that actually appear across `frames` instead of the fixed 6x7x6
+/// cube, so smooth gradients keep their detail.
+pub fn export_gif_pixels_adaptive<W: Write>(
+    writer: &mut W,
+    frames: &[PixelFrame],
+    width: usize,
+    height: usize,
+    scale: usize,
+) -> std::io::Result<()> {
+    let (palette, lookup) = Palette::median_cut(frames);
+    write_pixel_gif(writer, frames, width, height, scale, &palette, |r, g, b| {
+        lookup[color_bin(r, g, b)]
+    })
+}
+
+fn write_pixel_gif<W: Write>(
+    writer: &mut W,
+    frames: &[PixelFrame],
+    width: usize,
+    height: usize,
+    scale: usize,
+    palette: &Palette,
+    index_of: impl Fn(u8, u8, u8) -> u8,
+) -> std::io::Result<()> {
+    assert!(scale >= 1, "scale must be >= 1");
     let out_w = (width * scale) as u16;
     let out_h = (height * scale) as u16;
     let native_count = width * height;
@@ -719,7 +841,7 @@ pub fn export_gif_pixels<W: Write>(
         // Compute palette indices at native resolution (cheap dedup key).
         let mut native = vec![0u8; native_count];
         for (i, &(r, g, b)) in frame.pixels.iter().enumerate() {
-            native[i] = palette.find_nearest(r, g, b);
+            native[i] = index_of(r, g, b);
         }
 
         let delay_cs = if fi + 1 < frames.len() {
@@ -843,6 +965,36 @@ mod tests {
         assert_eq!((r, g, b), (255, 255, 255));
     }
 
+    #[test]
+    fn test_median_cut_keeps_sparse_colors_exact() {
+        let colors = [(0, 0, 0), (255, 0, 0), (10, 200, 30), (90, 90, 250)];
+        let frames = vec![PixelFrame {
+            timestamp_ms: 0,
+            pixels: colors.iter().cycle().take(64).copied().collect(),
+        }];
+        let (palette, lookup) = Palette::median_cut(&frames);
+        for &(r, g, b) in &colors {
+            let idx = lookup[color_bin(r, g, b)];
+            assert_eq!(palette.entries[idx as usize], (r, g, b));
+        }
+    }
+
+    #[test]
+    fn test_median_cut_fills_palette_for_gradients() {
+        let pixels: Vec<(u8, u8, u8)> = (0..4096)
+            .map(|i| ((i % 64 * 4) as u8, (i / 64 * 4) as u8, 128))
+            .collect();
+        let frames = vec![PixelFrame {
+            timestamp_ms: 0,
+            pixels,
+        }];
+        let (palette, _) = Palette::median_cut(&frames);
+        let mut distinct = palette.entries.to_vec();
+        distinct.sort_unstable();
+        distinct.dedup();
+        assert_eq!(distinct.len(), PALETTE_SIZE);
+    }
+
     #[test]
     fn test_virtual_terminal_cursor_position() {
         let mut vt = VirtualTerminal::new(10, 5);
@@ -1055,6 +1207,7 @@ mod tests {
         let frames = vec![crate::record::Frame {
             timestamp_ms: 0,
             content: "Hello".to_string(),
+            size: None,
         }];
         let mut buf = Vec::new();
         let result = export_gif(&mut buf, &frames, 10, 5);
@@ -1074,14 +1227,17 @@ mod tests {
             crate::record::Frame {
                 timestamp_ms: 0,
                 content: content.to_string(),
+                size: None,
             },
             crate::record::Frame {
                 timestamp_ms: 100,
                 content: content.to_string(),
+                size: None,
             },
             crate::record::Frame {
                 timestamp_ms: 200,
                 content: content.to_string(),
+                size: None,
             },
         ];
         let mut buf = Vec::new();
@@ -0,0 +1,412 @@
+use crossterm::event::{KeyCode, KeyModifiers};
+use std::collections::HashMap;
+
+/// Something a key does in the main loop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    Next,
+    Prev,
+    Quit,
+    CycleRender,
+    CycleColor,
+    ToggleStatus,
+    Pause,
+    /// Hold or resume the view rotation of the 3D animations
+    Freeze,
+    /// Restart the current animation from a fresh state
+    Reset,
+    Screenshot,
+    ScreenshotText,
+    /// Copy the frame as plain text to the clipboard (OSC 52)
+    CopyFrame,
+    ToggleBloom,
+    ToggleSmoothing,
+    ToggleDither,
+    ToggleUnlimited,
+    /// Flip the direction `--cycle` advances in
+    ReverseCycle,
+    /// Step the global speed multiplier up
+    Faster,
+    /// Step the global speed multiplier down
+    Slower,
+    /// Rotate the global hue shift backward
+    HueDown,
+    /// Rotate the global hue shift forward
+    HueUp,
+}
+
+/// Default quit keys, also polled by the write path mid-frame.
+const DEFAULT_QUIT: &[KeyCode] = &[KeyCode::Char('q'), KeyCode::Esc];
+
+/// A key plus the modifiers that must be held with it, as produced by
+/// [`normalize`].
+pub type KeyBinding = (KeyCode, KeyModifiers);
+
+/// The binding a key event matches. Only Ctrl and Alt count for characters,
+/// whose case already says whether Shift was held (terminals disagree on
+/// reporting it for `S` or `+`); other keys keep Shift too.
+pub fn normalize(code: KeyCode, modifiers: KeyModifiers) -> KeyBinding {
+    let relevant = match code {
+        KeyCode::Char(_) => KeyModifiers::CONTROL | KeyModifiers::ALT,
+        _ => KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SHIFT,
+    };
+    (code, modifiers & relevant)
+}
+
+impl Action {
+    /// Config name and default keys for every action, in binding order.
+    const ALL: [(&'static str, Action, &'static [KeyCode]); 21] = [
+        ("next", Action::Next, &[KeyCode::Right, KeyCode::Char('n')]),
+        ("prev", Action::Prev, &[KeyCode::Left, KeyCode::Char('p')]),
+        ("quit", Action::Quit, DEFAULT_QUIT),
+        ("render", Action::CycleRender, &[KeyCode::Char('r')]),
+        ("color", Action::CycleColor, &[KeyCode::Char('c')]),
+        ("status", Action::ToggleStatus, &[KeyCode::Char('h')]),
+        ("pause", Action::Pause, &[KeyCode::Char(' ')]),
+        ("freeze", Action::Freeze, &[KeyCode::Char('f')]),
+        // Unbound unless configured
+        ("reset", Action::Reset, &[]),
+        ("screenshot", Action::Screenshot, &[KeyCode::Char('S')]),
+        (
+            "screenshot_text",
+            Action::ScreenshotText,
+            &[KeyCode::Char('T')],
+        ),
+        ("copy", Action::CopyFrame, &[KeyCode::Char('y')]),
+        ("bloom", Action::ToggleBloom, &[KeyCode::Char('b')]),
+        ("smooth", Action::ToggleSmoothing, &[KeyCode::Char('s')]),
+        ("dither", Action::ToggleDither, &[KeyCode::Char('d')]),
+        ("uncap", Action::ToggleUnlimited, &[KeyCode::Char('u')]),
+        ("reverse", Action::ReverseCycle, &[KeyCode::Char(',')]),
+        // `=` is `+` without shift on most layouts
+        (
+            "faster",
+            Action::Faster,
+            &[KeyCode::Char('+'), KeyCode::Char('=')],
+        ),
+        ("slower", Action::Slower, &[KeyCode::Char('-')]),
+        ("hue_down", Action::HueDown, &[KeyCode::Char('[')]),
+        ("hue_up", Action::HueUp, &[KeyCode::Char(']')]),
+    ];
+}
+
+/// Which [`Action`] each key triggers, from the defaults overlaid with the
+/// config's `[keybindings]` (or `[keys]`) table.
+pub struct KeyMap {
+    actions: HashMap<KeyBinding, Action>,
+    /// Kept separately for the write path's quit polling
+    quit: Vec<KeyBinding>,
+    /// Status bar hints for the bound keys
+    hints: String,
+}
+
+/// The status bar's key hints: a label after the first key of each action.
+/// A group whose actions are all unbound is left out.
+const HINTS: &[(&[Action], &str)] = &[
+    (&[Action::Prev, Action::Next], "anim"),
+    (&[Action::Pause], "pause"),
+    (&[Action::Screenshot, Action::ScreenshotText], "shot"),
+    (&[Action::ToggleBloom], "bloom"),
+    (&[Action::ToggleSmoothing], "smooth"),
+    (&[Action::ToggleDither], "dither"),
+    (&[Action::ToggleUnlimited], "uncap"),
+    (&[Action::CycleRender], "render"),
+    (&[Action::CycleColor], "color"),
+    (&[Action::ToggleStatus], "hide"),
+    (&[Action::Quit], "quit"),
+];
+
+impl Default for KeyMap {
+    fn default() -> Self {
+        KeyMap::from_config(None)
+    }
+}
+
+impl KeyMap {
+    /// Build the map from `bindings` (action name -> key name). A configured
+    /// action replaces all of that action's default keys, and takes its key
+    /// away from any default binding (so `prev = "h"` frees `h` from
+    /// `status`). Keys may carry modifiers (`ctrl+q`, `alt+Left`), which
+    /// must then be held. Unknown actions and key names are ignored.
+    pub fn from_config(bindings: Option<&HashMap<String, String>>) -> Self {
+        let configured = |name: &str| {
+            bindings
+                .and_then(|m| m.get(name))
+                .and_then(|s| parse_key_binding(s))
+        };
+        let mut actions = HashMap::new();
+        for (name, action, defaults) in Action::ALL {
+            if configured(name).is_none() {
+                for &code in defaults {
+                    actions.insert((code, KeyModifiers::NONE), action);
+                }
+            }
+        }
+        for (name, action, _) in Action::ALL {
+            if let Some(binding) = configured(name) {
+                actions.insert(binding, action);
+            }
+        }
+        // Each action's keys in binding order, minus any taken by another
+        let keys = |name: &str, action: Action, defaults: &[KeyCode]| -> Vec<KeyBinding> {
+            configured(name)
+                .map_or_else(
+                    || {
+                        defaults
+                            .iter()
+                            .map(|&code| (code, KeyModifiers::NONE))
+                            .collect()
+                    },
+                    |binding| vec![binding],
+                )
+                .into_iter()
+                .filter(|binding| actions.get(binding) == Some(&action))
+                .collect()
+        };
+        let first_key = |action: Action| {
+            let (name, _, defaults) = Action::ALL.iter().find(|(_, a, _)| *a == action)?;
+            keys(name, action, defaults).first().map(|&b| key_label(b))
+        };
+        let hints = HINTS
+            .iter()
+            .filter_map(|(group, label)| {
+                let bound: Vec<String> = group.iter().filter_map(|&a| first_key(a)).collect();
+                (!bound.is_empty()).then(|| format!("[{}] {label}", bound.join("/")))
+            })
+            .collect::<Vec<_>>()
+            .join("  ");
+        let quit = keys("quit", Action::Quit, DEFAULT_QUIT);
+        KeyMap {
+            actions,
+            quit,
+            hints,
+        }
+    }
+
+    /// The action bound to a key event, if any.
+    pub fn action(&self, code: KeyCode, modifiers: KeyModifiers) -> Option<Action> {
+        self.actions.get(&normalize(code, modifiers)).copied()
+    }
+
+    /// Every key bound to [`Action::Quit`].
+    pub fn quit_keys(&self) -> &[KeyBinding] {
+        &self.quit
+    }
+
+    /// Key hints for the status bar's `{keys}`, e.g. `[←/→] anim  [space] pause`.
+    pub fn hints(&self) -> &str {
+        &self.hints
+    }
+}
+
+/// How a binding is shown in the key hints: `space`, `←`, `ctrl+q`.
+fn key_label((code, modifiers): KeyBinding) -> String {
+    let mut label = String::new();
+    for (m, name) in [
+        (KeyModifiers::CONTROL, "ctrl+"),
+        (KeyModifiers::ALT, "alt+"),
+        (KeyModifiers::SHIFT, "shift+"),
+    ] {
+        if modifiers.contains(m) {
+            label.push_str(name);
+        }
+    }
+    match code {
+        KeyCode::Char(' ') => label.push_str("space"),
+        KeyCode::Char(c) => label.push(c),
+        KeyCode::Left => label.push('←'),
+        KeyCode::Right => label.push('→'),
+        KeyCode::Up => label.push('↑'),
+        KeyCode::Down => label.push('↓'),
+        KeyCode::Esc => label.push_str("esc"),
+        KeyCode::Enter => label.push_str("enter"),
+        KeyCode::Tab => label.push_str("tab"),
+        other => label.push_str(&format!("{other:?}").to_lowercase()),
+    }
+    label
+}
+
+/// A key name, optionally after `+`-joined modifiers (`ctrl`, `alt`,
+/// `shift`): `q`, `ctrl+q`, `ctrl+alt+Left`. A lone `+` is the plus key, as
+/// is a trailing one (`ctrl++`). `shift+` a character means its upper case.
+fn parse_key_binding(s: &str) -> Option<KeyBinding> {
+    let s = s.trim();
+    let (mods, key) = match s.strip_suffix("++") {
+        Some(mods) => (mods, "+"),
+        None => match s.rsplit_once('+') {
+            Some((mods, key)) if !mods.is_empty() => (mods, key),
+            _ => ("", s),
+        },
+    };
+    let mut code = parse_key_code(key.trim())?;
+    let mut modifiers = KeyModifiers::NONE;
+    for m in mods.split('+').filter(|_| !mods.is_empty()) {
+        modifiers |= match m.trim().to_ascii_lowercase().as_str() {
+            "ctrl" | "control" => KeyModifiers::CONTROL,
+            "alt" => KeyModifiers::ALT,
+            "shift" => KeyModifiers::SHIFT,
+            _ => return None,
+        };
+    }
+    if let KeyCode::Char(c) = code
+        && modifiers.contains(KeyModifiers::SHIFT)
+    {
+        code = KeyCode::Char(c.to_ascii_uppercase());
+    }
+    Some(normalize(code, modifiers))
+}
+
+/// A single character (case-sensitive, so `S` is shift+s) or a key name
+/// (`Space`, `Left`, `esc`, ...; any case).
+fn parse_key_code(s: &str) -> Option<KeyCode> {
+    if s.chars().count() == 1 {
+        return s.chars().next().map(KeyCode::Char);
+    }
+    match s.to_ascii_lowercase().as_str() {
+        "left" => Some(KeyCode::Left),
+        "right" => Some(KeyCode::Right),
+        "up" => Some(KeyCode::Up),
+        "down" => Some(KeyCode::Down),
+        "esc" => Some(KeyCode::Esc),
+        "enter" => Some(KeyCode::Enter),
+        "space" => Some(KeyCode::Char(' ')),
+        "tab" => Some(KeyCode::Tab),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bindings(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|&(a, k)| (a.to_string(), k.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn defaults_match_the_classic_keys() {
+        let map = KeyMap::default();
+        assert_eq!(
+            map.action(KeyCode::Right, KeyModifiers::NONE),
+            Some(Action::Next)
+        );
+        assert_eq!(
+            map.action(KeyCode::Char('p'), KeyModifiers::NONE),
+            Some(Action::Prev)
+        );
+        assert_eq!(
+            map.action(KeyCode::Char(' '), KeyModifiers::NONE),
+            Some(Action::Pause)
+        );
+        assert_eq!(
+            map.action(KeyCode::Char('f'), KeyModifiers::NONE),
+            Some(Action::Freeze)
+        );
+        assert_eq!(
+            map.action(KeyCode::Char('u'), KeyModifiers::NONE),
+            Some(Action::ToggleUnlimited)
+        );
+        assert_eq!(
+            map.action(KeyCode::Char(','), KeyModifiers::NONE),
+            Some(Action::ReverseCycle)
+        );
+        assert_eq!(
+            map.action(KeyCode::Char('='), KeyModifiers::NONE),
+            Some(Action::Faster)
+        );
+        assert_eq!(map.action(KeyCode::Char('x'), KeyModifiers::NONE), None);
+        assert_eq!(
+            map.quit_keys(),
+            &[
+                (KeyCode::Char('q'), KeyModifiers::NONE),
+                (KeyCode::Esc, KeyModifiers::NONE)
+            ]
+        );
+    }
+
+    #[test]
+    fn configured_keys_replace_defaults_and_win_conflicts() {
+        let b = bindings(&[
+            ("next", "l"),
+            ("status", "space"),
+            ("prev", "h"),
+            ("quit", "x"),
+            ("reset", "0"),
+            ("bogus", "z"),
+            ("pause", "NotAKey"),
+        ]);
+        let map = KeyMap::from_config(Some(&b));
+        assert_eq!(
+            map.action(KeyCode::Char('l'), KeyModifiers::NONE),
+            Some(Action::Next)
+        );
+        assert_eq!(map.action(KeyCode::Right, KeyModifiers::NONE), None);
+        assert_eq!(
+            map.action(KeyCode::Char('h'), KeyModifiers::NONE),
+            Some(Action::Prev)
+        );
+        assert_eq!(
+            map.action(KeyCode::Char('0'), KeyModifiers::NONE),
+            Some(Action::Reset)
+        );
+        assert_eq!(map.action(KeyCode::Char('z'), KeyModifiers::NONE), None);
+        assert_eq!(
+            map.action(KeyCode::Char(' '), KeyModifiers::NONE),
+            Some(Action::ToggleStatus)
+        );
+        assert_eq!(map.quit_keys(), &[(KeyCode::Char('x'), KeyModifiers::NONE)]);
+    }
+
+    #[test]
+    fn modifiers_must_be_held_and_plus_is_bindable() {
+        let b = bindings(&[("quit", "ctrl+q"), ("faster", "+"), ("slower", "alt++")]);
+        let map = KeyMap::from_config(Some(&b));
+        let (ctrl, alt) = (KeyModifiers::CONTROL, KeyModifiers::ALT);
+        assert_eq!(map.action(KeyCode::Char('q'), ctrl), Some(Action::Quit));
+        assert_eq!(map.action(KeyCode::Char('q'), KeyModifiers::NONE), None);
+        assert_eq!(map.quit_keys(), &[(KeyCode::Char('q'), ctrl)]);
+        // Shift is implied by the character, however the terminal reports it
+        assert_eq!(
+            map.action(KeyCode::Char('+'), KeyModifiers::SHIFT),
+            Some(Action::Faster)
+        );
+        assert_eq!(map.action(KeyCode::Char('+'), alt), Some(Action::Slower));
+        assert_eq!(
+            map.action(KeyCode::Char('S'), KeyModifiers::SHIFT),
+            Some(Action::Screenshot)
+        );
+
+        assert_eq!(
+            parse_key_binding("Ctrl+Alt+Left"),
+            Some((KeyCode::Left, ctrl | alt))
+        );
+        assert_eq!(
+            parse_key_binding("shift+s"),
+            Some((KeyCode::Char('S'), KeyModifiers::NONE))
+        );
+        assert_eq!(parse_key_binding("hyper+x"), None);
+    }
+
+    #[test]
+    fn hints_follow_the_bindings() {
+        assert_eq!(
+            KeyMap::default().hints(),
+            "[←/→] anim  [space] pause  [S/T] shot  [b] bloom  [s] smooth  [d] dither  \
+             [u] uncap  [r] render  [c] color  [h] hide  [q] quit"
+        );
+        // `prev = "h"` takes h from `status`, whose hint then goes
+        let map = KeyMap::from_config(Some(&bindings(&[
+            ("prev", "h"),
+            ("quit", "ctrl+x"),
+            ("pause", "Enter"),
+        ])));
+        assert_eq!(
+            map.hints(),
+            "[h/→] anim  [enter] pause  [S/T] shot  [b] bloom  [s] smooth  [d] dither  \
+             [u] uncap  [r] render  [c] color  [ctrl+x] quit"
+        );
+    }
+}
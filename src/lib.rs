@@ -0,0 +1,67 @@
+//! termflix's procedural animations and the canvas they draw on, for
+//! embedding in other terminal programs. Create an animation, call
+//! [`Animation::update`] on a [`Canvas`] each frame, then write
+//! [`Canvas::render`] (or encode [`Canvas::render_cells`]) to the terminal.
+
+pub mod animations;
+pub mod audio;
+pub mod external;
+pub mod generators;
+pub mod pacing;
+pub mod palettes;
+pub mod render;
+
+pub use animations::Animation;
+pub use render::{Canvas, ColorMode, RenderMode};
+
+/// Every animation as `(name, description)`, in catalog order.
+pub fn animations() -> &'static [(&'static str, &'static str)] {
+    animations::ANIMATIONS
+}
+
+/// Animation `name` sized for a canvas of `width` x `height` pixels
+/// ([`Canvas::width`]/[`Canvas::height`]), with `scale` multiplying its
+/// element counts. `None` if no animation has that name, or if the canvas is
+/// smaller than its [`Animation::min_size`].
+pub fn create_animation(
+    name: &str,
+    width: usize,
+    height: usize,
+    scale: f64,
+) -> Option<Box<dyn Animation>> {
+    let anim = animations::create_for_canvas(name, width, height, scale, None, None).ok()?;
+    let (min_w, min_h) = anim.min_size();
+    (width >= min_w && height >= min_h).then_some(anim)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn catalog_names_all_create() {
+        for &(name, _) in animations() {
+            let anim = create_animation(name, 80, 48, 1.0).expect(name);
+            assert_eq!(anim.name(), name);
+        }
+        assert!(create_animation("nope", 80, 48, 1.0).is_none());
+
+        // Below `min_size` there is no animation; at or above it, updating
+        // a tiny canvas must not panic
+        for &(name, _) in animations() {
+            for (cols, rows) in [(1, 1), (2, 1), (2, 2), (4, 4), (6, 3)] {
+                for mode in [RenderMode::Braille, RenderMode::HalfBlock] {
+                    let mut canvas = Canvas::new(cols, rows, mode, ColorMode::TrueColor);
+                    let Some(mut anim) = create_animation(name, canvas.width, canvas.height, 1.0)
+                    else {
+                        continue;
+                    };
+                    for i in 0..5 {
+                        anim.update(&mut canvas, 0.05, i as f64 * 0.05);
+                    }
+                }
+            }
+        }
+        assert!(create_animation("matrix", 1, 1, 1.0).is_none());
+    }
+}
@@ -1,37 +1,59 @@
-mod animations;
+mod benchmark;
+mod browser;
 mod config;
-mod external;
+mod cycle;
+mod drift;
+mod export;
 mod gallery;
-pub mod generators;
 mod gif;
+mod keymap;
 mod png;
 mod record;
-mod render;
+mod sound;
+mod status;
 // The writer thread uses raw fd + libc::write (deliberately unbuffered, to
 // bypass Stdout's LineWriter). That's unix-only; on Windows main.rs writes
 // frames inline via stdout.write_all() (see the cfg(not(unix)) branches).
 #[cfg(unix)]
 mod render_sink;
+mod screenshot;
+mod term_query;
+mod term_restore;
+
+use termflix::generators::NamedParam;
+use termflix::{animations, external, pacing, render};
 
 use animations::Animation;
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use crossterm::{
     cursor,
-    event::{
-        self, DisableFocusChange, EnableFocusChange, Event, KeyCode, KeyEvent, KeyEventKind,
-        KeyModifiers,
-    },
-    execute, terminal,
+    event::{self, EnableFocusChange, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    execute,
+    style::Print,
+    terminal,
 };
 use external::{CurrentState, ExternalParams, ParamsSource, spawn_reader};
-use render::{Canvas, ColorAssist, ColorMode, PostProcessConfig, RenderMode, smoothing_alpha};
+use render::letterbox::viewport;
+use render::{
+    Canvas, ColorAssist, ColorMode, DEFAULT_ASCII_RAMP, DEFAULT_CELL_ASPECT, Mirror,
+    PostProcessConfig, RenderMode, smoothing_alpha,
+};
+use std::collections::HashMap;
 use std::io;
 use std::io::IsTerminal;
+use std::path::Path;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc;
 use std::time::{Duration, Instant};
 
+/// `--graphics` protocols.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Graphics {
+    /// kitty graphics protocol (kitty, WezTerm, Ghostty)
+    Kitty,
+}
+
 #[derive(Parser)]
 #[command(name = "termflix", about = "Terminal animation player")]
 struct Cli {
@@ -46,6 +68,12 @@ struct Cli {
     #[arg(short, long, value_enum)]
     color: Option<ColorMode>,
 
+    /// Read the terminal's 16-color palette (OSC 4) and draw with it, so
+    /// animations follow your color scheme; ignored if the terminal doesn't
+    /// answer
+    #[arg(long)]
+    theme_from_terminal: bool,
+
     /// Target FPS (1-120)
     #[arg(short, long)]
     fps: Option<u32>,
@@ -54,11 +82,31 @@ struct Cli {
     #[arg(short, long)]
     list: Option<Option<String>>,
 
+    /// Print the animations as a JSON array of {name, description,
+    /// preferred_render} objects and exit (optional: filter by substring)
+    #[arg(long, value_name = "FILTER", conflicts_with = "list")]
+    list_json: Option<Option<String>>,
+
+    /// With --list, browse animations in an interactive grid of live previews
+    #[arg(long, requires = "list")]
+    live: bool,
+
     /// Cycle through all animations (seconds per animation, 0 = disabled)
     #[arg(long)]
     cycle: Option<u32>,
 
-    /// Record animation to .asciianim file
+    /// Start with a random animation when none is named (reproducible with
+    /// --seed); unlike --cycle it stays on that one
+    #[arg(long)]
+    random: bool,
+
+    /// With --cycle, visit the animations in a random order, reshuffled
+    /// after each full pass (reproducible with --seed)
+    #[arg(long)]
+    shuffle: bool,
+
+    /// Record animation to a .asciianim file, or an asciinema v2 recording
+    /// if the path ends in .cast
     #[arg(long)]
     record: Option<String>,
 
@@ -66,22 +114,112 @@ struct Cli {
     #[arg(long)]
     play: Option<String>,
 
-    /// Export recording to GIF (requires --play)
-    #[arg(long, value_name = "PATH")]
+    /// Loop --play playback: forever, or N times in all with `--loop N`
+    #[arg(
+        long = "loop",
+        value_name = "N",
+        num_args = 0..=1,
+        default_missing_value = "0",
+        requires = "play"
+    )]
+    loop_count: Option<u32>,
+
+    /// --play speed multiplier (0.1-10, e.g. 2 = twice as fast); `+`/`-`
+    /// adjust it during playback
+    #[arg(long, value_name = "SPEED", requires = "play")]
+    play_speed: Option<f64>,
+
+    /// Export to an animated GIF: the --play recording, or otherwise --frames
+    /// frames of the animation rendered offscreen at sub-pixel resolution
+    #[arg(long, value_name = "PATH", group = "export")]
     export_gif: Option<String>,
 
+    /// Render frames offscreen to DIR/frame_00001.png, ... at the canvas's
+    /// sub-pixel resolution instead of animating in the terminal
+    #[arg(long, value_name = "DIR", group = "export")]
+    export_png: Option<String>,
+
+    /// Record FILE (.asciianim, or .cast for asciinema) offscreen: --frames
+    /// updates at a fixed 1/fps step, with no TTY, raw mode, or key handling
+    #[arg(long, value_name = "FILE", group = "export", conflicts_with = "record")]
+    record_headless: Option<String>,
+
+    /// Time --frames offscreen updates of every animation (or just the one
+    /// named) and print updates/sec and average/peak frame time as a table
+    #[arg(long, group = "export", conflicts_with_all = ["record", "random"])]
+    benchmark: bool,
+
+    /// Number of frames --export-png / --export-gif / --record-headless
+    /// render, or --benchmark times (GIFs are capped at 1000)
+    #[arg(
+        long,
+        value_name = "N",
+        default_value_t = 120,
+        requires = "export",
+        conflicts_with = "play"
+    )]
+    frames: usize,
+
+    /// Canvas size in terminal cells for --export-png / --export-gif /
+    /// --record-headless / --benchmark (default: the terminal's size, or
+    /// 80x24 for --record-headless and --benchmark)
+    #[arg(long, value_name = "WxH", value_parser = export::parse_size, requires = "export")]
+    size: Option<(u16, u16)>,
+
     /// Scale factor for particle/element counts (0.5-2.0)
     #[arg(short, long)]
     scale: Option<f64>,
 
+    /// Seed the random number generators so runs are reproducible; the
+    /// animation then advances a fixed 1/fps per frame
+    #[arg(long, value_name = "N")]
+    seed: Option<u64>,
+
+    /// Terminal cell height:width ratio used for aspect correction (default 2.0)
+    #[arg(long)]
+    aspect: Option<f64>,
+
+    /// Draw into a centered region of this on-screen width:height ratio
+    /// (e.g. 16:9, 4/3, 1) with blank margins, so round and 3D animations
+    /// keep their proportions on very wide or tall terminals
+    #[arg(long, value_name = "W:H", value_parser = render::letterbox::parse_ratio)]
+    letterbox: Option<f64>,
+
+    /// Seconds for one full palette loop in color-cycling animations
+    /// (mandelbrot, dragon, sierpinski, ...); set it to the loop length of a
+    /// recording so the colors wrap seamlessly
+    #[arg(long, value_name = "SECS")]
+    color_cycle_period: Option<f64>,
+
+    /// Animation speed multiplier (0.1-8, e.g. 0.5 = slow motion); `+`/`-`
+    /// adjust it live
+    #[arg(long, value_name = "SPEED")]
+    speed: Option<f64>,
+
     /// Remove FPS cap and render as fast as possible (overrides --fps)
     #[arg(long)]
     unlimited: bool,
 
+    /// Draw each frame as one true-color image with a terminal graphics
+    /// protocol instead of characters, at the render mode's pixel resolution
+    #[arg(long, value_enum, value_name = "PROTOCOL")]
+    graphics: Option<Graphics>,
+
     /// Hide the status bar for pure animation mode
     #[arg(long)]
     clean: bool,
 
+    /// Draw in the normal screen buffer instead of the alternate screen, and
+    /// leave the last frame visible above the prompt on exit
+    #[arg(long)]
+    no_alt_screen: bool,
+
+    /// Status bar template, e.g. "{name} | {fps}". Placeholders: {name},
+    /// {render}, {color}, {fps}, {rec}, {paused}, {saved}, {scale}, {bloom},
+    /// {smooth}, {dither}, {assist}, {keys}; `{{` and `}}` are literal braces
+    #[arg(long, value_name = "FMT", value_parser = status::StatusFormat::parse)]
+    status_format: Option<status::StatusFormat>,
+
     /// Generate default config file at ~/.config/termflix/config.toml
     #[arg(long)]
     init_config: bool,
@@ -89,6 +227,18 @@ struct Cli {
     /// Show config file path and current settings
     #[arg(long)]
     show_config: bool,
+
+    /// Print detected terminal capabilities and chosen modes to stderr at startup
+    #[arg(long)]
+    verbose: bool,
+
+    /// Apply a named preset from the config file (CLI flags still override it)
+    #[arg(long, value_name = "NAME")]
+    preset: Option<String>,
+
+    /// List presets defined in the config file and exit
+    #[arg(long)]
+    list_presets: bool,
     /// Exit on first keypress or focus when running as a screensaver
     #[arg(long)]
     screensaver: bool,
@@ -97,10 +247,51 @@ struct Cli {
     #[arg(long, requires = "screensaver")]
     screensaver_keys: bool,
 
+    /// Scale overall brightness by this factor (0-1), e.g. for a wall display
+    /// running all night
+    #[arg(long, value_name = "0..1")]
+    dim: Option<f64>,
+
+    /// Brightness multiplier for every animation (0-2); seeds the
+    /// `intensity` external param
+    #[arg(long, value_name = "0..2")]
+    intensity: Option<f64>,
+
+    /// Rotate every animation's hues by this fraction of the color wheel
+    /// (0-1, e.g. 0.75 turns fire purple); `[`/`]` nudge it live
+    #[arg(long, value_name = "0..1")]
+    hue_shift: Option<f64>,
+
+    /// Move the whole picture by one cell every few minutes to spread
+    /// burn-in from static elements on OLED screens
+    #[arg(long)]
+    drift: bool,
+
+    /// Sound game events (pong scores, invaders shots and kills, snake
+    /// eating and dying, sort finishing): short tones when built with the
+    /// `sound` feature, otherwise the terminal bell
+    #[arg(long)]
+    beep: bool,
+
+    /// Drive `visualizer` from the default audio input (needs the `audio`
+    /// feature; falls back to simulated music without an input device)
+    #[arg(long)]
+    audio: bool,
+
+    /// Pulse any animation's intensity and speed with the loudness and beats
+    /// of the default audio input (needs the `audio` feature)
+    #[arg(long)]
+    audio_reactive: bool,
+
     /// Watch a file for external control params (ndjson — one JSON object per line)
     #[arg(long, value_name = "PATH")]
     data_file: Option<String>,
 
+    /// External control source: `stdin` for piped ndjson, or a file path to
+    /// watch (same as --data-file). Piped stdin is also picked up without it.
+    #[arg(long, value_name = "stdin|PATH", conflicts_with = "data_file")]
+    control: Option<String>,
+
     /// Bloom/glow post-processing effect intensity (0.0-1.0)
     #[arg(long)]
     bloom_intensity: Option<f64>,
@@ -117,6 +308,20 @@ struct Cli {
     #[arg(long)]
     scanlines: bool,
 
+    /// Average the canvas over N-pixel blocks for a chunky low-res look
+    /// (2-32; blocks are square on screen in every render mode)
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(2..=MAX_PIXELATE as i64))]
+    pixelate: Option<u32>,
+
+    /// Fold every frame into N mirrored segments around the center, like a
+    /// kaleidoscope (2-12)
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(2..=MAX_KALEIDOSCOPE as i64))]
+    kaleidoscope: Option<u32>,
+
+    /// Mirror every frame: h (left-right), v (upside down), or both
+    #[arg(long, value_enum, value_name = "AXES")]
+    mirror: Option<Mirror>,
+
     /// Temporal brightness smoothing time constant in seconds (0 = off).
     /// Reduces flicker in fire/plasma/aurora. Toggle live with `s`.
     #[arg(long)]
@@ -130,11 +335,147 @@ struct Cli {
     #[arg(long)]
     colorblind: Option<String>,
 
-    /// Enable 4x4 Bayer ordered dithering in ANSI-256 mode (reduces banding).
+    /// Language for hackerman HUD text: en | es | de
+    #[arg(long, value_name = "LANG", value_parser = clap::builder::PossibleValuesParser::new(animations::hackerman::HackerLang::NAMES))]
+    hacker_lang: Option<String>,
+
+    /// Aurora curtain colors for the aurora animation: green | purple | red | mixed
+    #[arg(long, value_parser = clap::builder::PossibleValuesParser::new(animations::aurora::AuroraPalette::NAMES))]
+    aurora_palette: Option<String>,
+
+    /// Number of aurora curtain layers (1-8, default 4)
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=8))]
+    aurora_layers: Option<u32>,
+
+    /// Bitmap font for the banner animation: compact | bold | tiny
+    #[arg(long, value_parser = clap::builder::PossibleValuesParser::new(render::font::FONT_NAMES))]
+    banner_font: Option<String>,
+
+    /// Text scrolled by the banner animation (default: TERMFLIX)
+    #[arg(long, value_name = "TEXT")]
+    banner_text: Option<String>,
+
+    /// Heat colors for fire, campfire, lava, and smoke: classic | blue-fire |
+    /// green-fire | ice | toxic (classic keeps each animation's own look)
+    #[arg(long, value_parser = clap::builder::PossibleValuesParser::new(termflix::palettes::Palette::NAMES))]
+    fire_palette: Option<String>,
+
+    /// Lava lamp color theme: classic | blue | green | acid
+    #[arg(long, value_parser = clap::builder::PossibleValuesParser::new(animations::lava::LavaTheme::NAMES))]
+    lava_theme: Option<String>,
+
+    /// Lava metaball merge threshold (0.1-1.0, default 0.3); lower merges blobs sooner
+    #[arg(long)]
+    lava_threshold: Option<f64>,
+
+    /// Where ripples start: random | grid | click | beat (click and beat are
+    /// driven by the ripple_x/ripple_y and bands control params)
+    #[arg(long, value_parser = clap::builder::PossibleValuesParser::new(animations::ripple::RippleSource::NAMES))]
+    ripple_source: Option<String>,
+
+    /// Smoke tint for the smoke animation: gray | toxic | fire | steam
+    #[arg(long, value_parser = clap::builder::PossibleValuesParser::new(animations::smoke::SmokeColor::NAMES))]
+    smoke_color: Option<String>,
+
+    /// Smoke emission rate and opacity multiplier (0.1-3.0, default 1.0)
+    #[arg(long)]
+    smoke_density: Option<f64>,
+
+    /// Number of arms in the spiral animation (1-12, default 4)
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=12))]
+    spiral_arms: Option<u32>,
+
+    /// Spiral rotation direction: cw | ccw
+    #[arg(long, value_parser = clap::builder::PossibleValuesParser::new(animations::spiral::SpiralDirection::NAMES))]
+    spiral_direction: Option<String>,
+
+    /// Spiral arm winding: archimedean (even turns) | logarithmic (widening turns)
+    #[arg(long, value_parser = clap::builder::PossibleValuesParser::new(animations::spiral::SpiralShape::NAMES))]
+    spiral_shape: Option<String>,
+
+    /// Spiral color palette: rainbow | fire | ice | toxic
+    #[arg(long, value_parser = clap::builder::PossibleValuesParser::new(animations::spiral::SpiralPalette::NAMES))]
+    spiral_palette: Option<String>,
+
+    /// Show a population graph with generation/population readout in `life`
+    #[arg(long)]
+    life_graph: bool,
+
+    /// Game of Life edges: true = toroidal wrap (default), false = hard-bounded
+    #[arg(long, value_name = "BOOL")]
+    life_wrap: Option<bool>,
+
+    /// Game of Life rule: a rulestring like B36/S23 or conway | highlife | daynight | seeds
+    #[arg(long, value_name = "RULE", value_parser = animations::life::parse_rule_arg)]
+    life_rule: Option<String>,
+
+    /// Game of Life starting board: random | glider | gosper-gun | pulsar | rpentomino
+    #[arg(long, value_parser = clap::builder::PossibleValuesParser::new(animations::life::LifePattern::NAMES))]
+    life_pattern: Option<String>,
+
+    /// Number of Langton ants sharing the grid
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..=animations::langton::MAX_ANTS as i64))]
+    langton_ants: Option<u32>,
+
+    /// Langton turmite rule: one turn (L, R, N, U) per cell state, e.g. RLR (default RL)
+    #[arg(long, value_name = "RULE", value_parser = animations::langton::parse_rule_arg)]
+    langton_rule: Option<String>,
+
+    /// Glyphs for the matrix rain: latin | katakana | binary | hex, or a literal string of glyphs
+    #[arg(long, value_name = "CHARSET")]
+    matrix_charset: Option<String>,
+
+    /// Text the matrix rain occasionally types out in bright glyphs
+    #[arg(long, value_name = "TEXT")]
+    matrix_message: Option<String>,
+
+    /// Mandelbrot dive target as RE,IM (e.g. Seahorse Valley: -0.75,0.1)
+    #[arg(long, value_name = "RE,IM", allow_hyphen_values = true, value_parser = animations::mandelbrot::parse_complex)]
+    mandel_center: Option<(f64, f64)>,
+
+    /// Widest Mandelbrot zoom; the animated dive zooms in from here and back
+    #[arg(long, value_name = "F")]
+    mandel_zoom: Option<f64>,
+
+    /// Draw the Julia set for the fixed c = RE,IM instead of the Mandelbrot set
+    #[arg(long, value_name = "RE,IM", allow_hyphen_values = true, value_parser = animations::mandelbrot::parse_complex)]
+    mandel_julia: Option<(f64, f64)>,
+
+    /// Add a predator to `boids` that chases the flock
+    #[arg(long)]
+    boids_predator: bool,
+
+    /// Scatter a few static obstacles for `boids` to steer around
+    #[arg(long)]
+    boids_obstacles: bool,
+
+    /// Snake AI: greedy | safe (default) | hamilton
+    #[arg(long, value_parser = clap::builder::PossibleValuesParser::new(animations::snake::SnakeAi::NAMES))]
+    snake_ai: Option<String>,
+
+    /// Color palette for the wave animation: classic | ocean | sunset | neon
+    #[arg(long, value_parser = clap::builder::PossibleValuesParser::new(animations::wave::WavePalette::NAMES))]
+    wave_palette: Option<String>,
+
+    /// Wave source orbit and ripple speed multiplier (0.1-3.0, default 1.0)
+    #[arg(long)]
+    wave_speed: Option<f64>,
+
+    /// Enable 4x4 Bayer ordered dithering in ANSI-256 and ANSI-16 modes (reduces banding).
     /// Toggle live with `d`.
     #[arg(long)]
     dither: bool,
 
+    /// In mono color mode, treat pixels dimmer than this (0-1) as unlit for
+    /// crisp high-contrast output
+    #[arg(long, value_name = "0..1")]
+    mono_threshold: Option<f64>,
+
+    /// Characters ASCII mode maps brightness onto, darkest first
+    /// (e.g. " ░▒▓█"; default " .:-=+*#%@")
+    #[arg(long, value_name = "CHARS")]
+    ascii_ramp: Option<String>,
+
     /// Profile per-frame timing and print summary on exit
     #[arg(long)]
     profile: bool,
@@ -148,6 +489,11 @@ struct Cli {
     #[arg(long)]
     full_frames: bool,
 
+    /// Rewrite the status bar every frame, even when its text is unchanged
+    /// (implied by --full-frames)
+    #[arg(long)]
+    status_every_frame: bool,
+
     /// Capture animations as PNG+GIF gallery (optional: comma-separated animation names)
     #[arg(long)]
     gallery: Option<Option<String>>,
@@ -168,7 +514,8 @@ struct Cli {
     #[arg(long)]
     gallery_wait: Option<f64>,
 
-    /// Total seconds of GIF recording for gallery captures (default: 5.0)
+    /// Total seconds of GIF recording for gallery captures (default: one
+    /// seamless loop for animations that repeat, else 5.0)
     #[arg(long)]
     gallery_duration: Option<f64>,
 }
@@ -193,10 +540,55 @@ fn main() -> io::Result<()> {
     }
 
     // Load config file (defaults if not found)
-    let cfg = config::load_config();
-    let keybindings = build_keybindings(&cfg);
+    let mut cfg = config::load_config();
 
-    let data_file = cli.data_file.clone().or(cfg.data_file.clone());
+    if cli.list_presets {
+        let names = cfg.preset_names();
+        if names.is_empty() {
+            println!("No presets defined. Add [presets.<name>] sections to the config file.");
+        } else {
+            println!("Available presets:");
+            for name in names {
+                let preset = &cfg.presets.as_ref().expect("names come from presets")[name];
+                println!(
+                    "  {:<16} {}",
+                    name,
+                    preset.animation.as_deref().unwrap_or("(default animation)")
+                );
+            }
+        }
+        return Ok(());
+    }
+
+    // Merge order: CLI flags > preset > config file > defaults
+    if let Some(ref name) = cli.preset {
+        cfg = match cfg.with_preset(name) {
+            Ok(cfg) => cfg,
+            Err(e) => {
+                eprintln!("{}. Use --list-presets to see available presets.", e);
+                std::process::exit(1);
+            }
+        };
+    }
+    let keymap = keymap::KeyMap::from_config(cfg.keybindings.as_ref());
+
+    let control = match cli.control.as_deref() {
+        Some("stdin") => {
+            if std::io::stdin().is_terminal() {
+                eprintln!(
+                    "--control stdin needs ndjson piped into termflix, e.g. `producer | termflix --control stdin`."
+                );
+                std::process::exit(1);
+            }
+            Some(ParamsSource::Stdin)
+        }
+        Some(path) => Some(ParamsSource::File(path.into())),
+        None => cli
+            .data_file
+            .clone()
+            .or(cfg.data_file.clone())
+            .map(|path| ParamsSource::File(path.into())),
+    };
 
     // --show-config: display current settings
     if cli.show_config {
@@ -224,7 +616,7 @@ fn main() -> io::Result<()> {
             cols: cli.gallery_cols.unwrap_or(80),
             rows: cli.gallery_rows.unwrap_or(25),
             wait_secs: cli.gallery_wait.unwrap_or(3.0),
-            duration_secs: cli.gallery_duration.unwrap_or(5.0),
+            duration_secs: cli.gallery_duration,
             names,
         };
         return gallery::run_gallery(&config);
@@ -237,7 +629,11 @@ fn main() -> io::Result<()> {
                 eprintln!("No frames to export.");
                 std::process::exit(1);
             }
-            let (cols, rows) = detect_recording_size(player.frames());
+            let (cols, rows) = player
+                .metadata
+                .size
+                .map(|(c, r)| (c as usize, r as usize))
+                .unwrap_or_else(|| detect_recording_size(player.frames()));
             let file = std::fs::File::create(gif_path)?;
             let mut writer = std::io::BufWriter::new(file);
             match gif::export_gif(&mut writer, player.frames(), cols, rows) {
@@ -251,45 +647,64 @@ fn main() -> io::Result<()> {
             }
             return Ok(());
         }
-        let player = record::Player::load(play_path)?;
-        return player.play();
+        let mut player = record::Player::load(play_path)?;
+        if let Some(summary) = player.metadata.summary() {
+            println!("{play_path}: {summary}");
+        }
+        if let (Some((rec_cols, rec_rows)), Ok((cols, rows))) =
+            (player.metadata.size, terminal::size())
+            && (cols < rec_cols || rows < rec_rows)
+        {
+            eprintln!(
+                "Warning: recorded at {rec_cols}x{rec_rows} but this terminal is {cols}x{rows}; \
+                 frames may wrap or be cut off"
+            );
+        }
+        if let Some(speed) = cli.play_speed {
+            player.set_speed(speed);
+        }
+        return player.play(cli.loop_count.unwrap_or(1));
     }
 
-    if let Some(filter) = cli.list {
-        println!("Available animations:");
-        let filter = filter.as_deref().map(|s| s.to_lowercase());
-        let mut count = 0;
-        for &(name, desc) in animations::ANIMATIONS {
-            if let Some(ref f) = filter
-                && !name.to_lowercase().contains(f)
-                && !desc.to_lowercase().contains(f)
-            {
-                continue;
+    if let Some(filter) = cli.list_json.as_ref() {
+        return print_list_json(&filter_animations(filter.as_deref()));
+    }
+
+    // --list --live keeps the filtered entries for the preview browser below
+    let mut browse_entries = None;
+    if let Some(filter) = cli.list.as_ref() {
+        let entries = filter_animations(filter.as_deref());
+        if cli.live {
+            if entries.is_empty() {
+                eprintln!(
+                    "No animations match '{}'.",
+                    filter.as_deref().unwrap_or_default()
+                );
+                std::process::exit(1);
             }
-            println!("  {:<12} {}", name, desc);
-            count += 1;
-        }
-        if let Some(ref f) = filter {
-            println!("\n  {} animation(s) matching '{}'", count, f);
+            browse_entries = Some(entries);
+        } else {
+            print_list(&entries, filter.as_deref());
+            return Ok(());
         }
-        println!("\nRender modes: braille, half-block, ascii");
-        println!("Color modes: mono, ansi16, ansi256, true-color");
-        return Ok(());
     }
 
-    // Merge: CLI flags > config file > defaults
-    let anim_name = cli
-        .animation
-        .clone()
-        .or(cfg.animation)
-        .unwrap_or_else(|| "fire".to_string());
+    // Merge: CLI flags > --random > config file > defaults
+    let anim_name = match cli.animation.clone() {
+        Some(name) => name,
+        None if cli.random => {
+            let names = animations::ANIMATION_NAMES;
+            let name = names[cycle::random_pick(names.len(), cli.seed)];
+            if !(cli.clean || cfg.clean.unwrap_or(false)) {
+                eprintln!("termflix: playing {name}");
+            }
+            name.to_string()
+        }
+        None => cfg.animation.unwrap_or_else(|| "fire".to_string()),
+    };
     let unlimited = cli.unlimited || cfg.unlimited_fps.unwrap_or(false);
     let fps = cli.fps.or(cfg.fps).unwrap_or(24).clamp(1, 120);
-    let frame_dur = if unlimited {
-        Duration::ZERO
-    } else {
-        Duration::from_secs_f64(1.0 / fps as f64)
-    };
+    let frame_dur = Duration::from_secs_f64(1.0 / fps as f64);
 
     // Validate animation name before entering raw mode so errors print cleanly
     if !animations::ANIMATION_NAMES.contains(&anim_name.as_str()) {
@@ -303,43 +718,44 @@ fn main() -> io::Result<()> {
         std::process::exit(1);
     }
 
-    // Set up panic hook to restore terminal before printing panic info.
-    // Without this, a panic inside raw mode leaves the terminal unusable.
-    let default_hook = std::panic::take_hook();
-    std::panic::set_hook(Box::new(move |info| {
-        let _ = terminal::disable_raw_mode();
-        #[cfg(unix)]
-        {
-            use std::os::unix::io::AsRawFd;
-            let fd = io::stdout().as_raw_fd();
-            let restore = b"\x1b[?2026l\x1b[?25h\x1b[?1049l";
-            unsafe {
-                libc::write(fd, restore.as_ptr() as *const libc::c_void, restore.len());
-            }
-        }
-        #[cfg(not(unix))]
-        {
-            let mut stdout = io::stdout();
-            let _ = execute!(stdout, cursor::Show, terminal::LeaveAlternateScreen);
-        }
-        default_hook(info);
-    }));
-
-    terminal::enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    execute!(stdout, terminal::EnterAlternateScreen, cursor::Hide)?;
-    if cli.screensaver {
-        execute!(stdout, EnableFocusChange)?;
-    }
-
     // Merge remaining settings: CLI > config > defaults
-    let color_mode = cli
-        .color
-        .or(cfg.color.map(ColorMode::from))
-        .unwrap_or(ColorMode::TrueColor);
+    let explicit_color = cli.color.or(cfg.color.map(ColorMode::from));
+    // Read the terminal's palette for `--theme-from-terminal` (switching to
+    // it unless a color mode was chosen) or an explicit palette mode; no
+    // reply silently leaves colors as they were.
+    let theme_from_terminal = cli.theme_from_terminal || cfg.theme_from_terminal.unwrap_or(false);
+    let terminal_palette = if theme_from_terminal || explicit_color == Some(ColorMode::Palette) {
+        term_query::query_palette()
+    } else {
+        None
+    };
+    let color_mode = explicit_color.unwrap_or(if terminal_palette.is_some() {
+        ColorMode::Palette
+    } else {
+        ColorMode::TrueColor
+    });
     let scale = cli.scale.or(cfg.scale).unwrap_or(1.0).clamp(0.5, 2.0);
+    let cell_aspect = cli
+        .aspect
+        .or(cfg.aspect)
+        .unwrap_or(DEFAULT_CELL_ASPECT)
+        .clamp(0.5, 4.0);
+    // CLI > config; an unparseable config ratio is ignored.
+    let letterbox = cli.letterbox.or_else(|| {
+        cfg.letterbox
+            .as_deref()
+            .and_then(|s| render::letterbox::parse_ratio(s).ok())
+    });
     let cycle = cli.cycle.or(cfg.cycle).unwrap_or(0);
+    let shuffle = cli.shuffle || cfg.shuffle.unwrap_or(false);
     let clean = cli.clean || cfg.clean.unwrap_or(false);
+    // CLI > config > the classic bar; an unparseable config template is ignored.
+    let status_format = cli.status_format.clone().unwrap_or_else(|| {
+        cfg.status_format
+            .as_deref()
+            .and_then(|s| status::StatusFormat::parse(s).ok())
+            .unwrap_or_default()
+    });
     let color_quant = cfg.color_quant.unwrap_or(0);
     let render_override = cli.render.or(cfg.render.map(RenderMode::from));
 
@@ -365,6 +781,20 @@ fn main() -> io::Result<()> {
             .unwrap_or(0.0)
             .clamp(0.0, 1.0),
         scanlines: cli.scanlines || cfg.postproc.and_then(|p| p.scanlines).unwrap_or(false),
+        pixelate: cli
+            .pixelate
+            .or(cfg.postproc.and_then(|p| p.pixelate))
+            .unwrap_or(0)
+            .min(MAX_PIXELATE) as usize,
+        mirror: cli
+            .mirror
+            .or(cfg.postproc.and_then(|p| p.mirror).map(Mirror::from))
+            .unwrap_or_default(),
+        kaleidoscope: cli
+            .kaleidoscope
+            .or(cfg.postproc.and_then(|p| p.kaleidoscope))
+            .unwrap_or(0)
+            .min(MAX_KALEIDOSCOPE) as usize,
     };
 
     // Smoothing: live tau (0 = off) + the on-value the `s` key toggles to.
@@ -387,35 +817,382 @@ fn main() -> io::Result<()> {
     )
     .unwrap_or(ColorAssist::None);
     let dither = cli.dither || cfg.dither.unwrap_or(false);
+    let dim = cli.dim.or(cfg.dim).unwrap_or(1.0).clamp(0.0, 1.0);
+    let drift = cli.drift || cfg.drift.unwrap_or(false);
+    let beep = cli.beep || cfg.beep.unwrap_or(false);
+    // Shown on the top row at startup when capture can't start.
+    let audio_warning = if cli.audio || cli.audio_reactive {
+        termflix::audio::start().err()
+    } else {
+        None
+    };
+    let mono_threshold = cli
+        .mono_threshold
+        .or(cfg.mono_threshold)
+        .unwrap_or(0.0)
+        .clamp(0.0, 1.0);
+    // CLI > config; `Canvas::set_ascii_ramp` falls back on ramps too short to use.
+    let ascii_ramp = cli
+        .ascii_ramp
+        .or(cfg.ascii_ramp.take())
+        .unwrap_or_else(|| DEFAULT_ASCII_RAMP.to_string());
+    // Animation-specific CLI knobs seed the external-params state, so the
+    // control channel can override them later like any other param.
+    let mut initial_params = ExternalParams::default();
+    if let Some(params) = cfg.params.take() {
+        // Parsed like a control message so typed fields (`wind`, `gravity`,
+        // `emit_rate`, ...) land in their own slots; a mistyped one leaves
+        // everything as plain animation params.
+        let object = serde_json::Value::Object(params.clone().into_iter().collect());
+        match serde_json::from_value(object) {
+            Ok(parsed) => initial_params = parsed,
+            Err(_) => initial_params.extra.extend(params),
+        }
+    }
+    if let Some(speed) = cli.speed {
+        initial_params.speed = Some(speed);
+    }
+    if let Some(intensity) = cli.intensity {
+        initial_params.intensity = Some(intensity);
+    }
+    if let Some(shift) = cli.hue_shift {
+        initial_params.color_shift = Some(shift);
+    }
+    // `[animations.<name>]` tables, handed to each animation as it is created
+    let anim_settings = cfg.animations.take().unwrap_or_default();
+    if let Some(period) = cli.color_cycle_period {
+        initial_params
+            .extra
+            .insert("color_cycle_period".into(), serde_json::Value::from(period));
+    }
+    // Overrides `[params] hacker_lang` and `[animations.hackerman] language`.
+    if let Some(lang) = cli.hacker_lang {
+        initial_params
+            .extra
+            .insert("hacker_lang".into(), serde_json::Value::from(lang));
+    }
+    if let Some(palette) = cli.aurora_palette {
+        initial_params
+            .extra
+            .insert("aurora_palette".into(), serde_json::Value::from(palette));
+    }
+    if let Some(layers) = cli.aurora_layers {
+        initial_params
+            .extra
+            .insert("aurora_layers".into(), serde_json::Value::from(layers));
+    }
+    if let Some(font) = cli.banner_font {
+        initial_params
+            .extra
+            .insert("banner_font".into(), serde_json::Value::from(font));
+    }
+    if let Some(text) = cli.banner_text {
+        initial_params
+            .extra
+            .insert("banner_text".into(), serde_json::Value::from(text));
+    }
+    if let Some(palette) = cli.fire_palette {
+        initial_params
+            .extra
+            .insert("fire_palette".into(), serde_json::Value::from(palette));
+    }
+    if let Some(theme) = cli.lava_theme {
+        initial_params
+            .extra
+            .insert("lava_theme".into(), serde_json::Value::from(theme));
+    }
+    if let Some(threshold) = cli.lava_threshold {
+        initial_params
+            .extra
+            .insert("lava_threshold".into(), serde_json::Value::from(threshold));
+    }
+    if let Some(source) = cli.ripple_source {
+        initial_params
+            .extra
+            .insert("ripple_source".into(), serde_json::Value::from(source));
+    }
+    if let Some(color) = cli.smoke_color {
+        initial_params
+            .extra
+            .insert("smoke_color".into(), serde_json::Value::from(color));
+    }
+    if let Some(density) = cli.smoke_density {
+        initial_params
+            .extra
+            .insert("smoke_density".into(), serde_json::Value::from(density));
+    }
+    if let Some(arms) = cli.spiral_arms {
+        initial_params
+            .extra
+            .insert("spiral_arms".into(), serde_json::Value::from(arms));
+    }
+    if let Some(direction) = cli.spiral_direction {
+        initial_params.extra.insert(
+            "spiral_direction".into(),
+            serde_json::Value::from(direction),
+        );
+    }
+    if let Some(shape) = cli.spiral_shape {
+        initial_params
+            .extra
+            .insert("spiral_shape".into(), serde_json::Value::from(shape));
+    }
+    if let Some(palette) = cli.spiral_palette {
+        initial_params
+            .extra
+            .insert("spiral_palette".into(), serde_json::Value::from(palette));
+    }
+    if let Some(palette) = cli.wave_palette {
+        initial_params
+            .extra
+            .insert("wave_palette".into(), serde_json::Value::from(palette));
+    }
+    if let Some(speed) = cli.wave_speed {
+        initial_params
+            .extra
+            .insert("wave_speed".into(), serde_json::Value::from(speed));
+    }
+    if cli.life_graph {
+        initial_params
+            .extra
+            .insert("population_graph".into(), serde_json::Value::from(1.0));
+    }
+    if let Some(wrap) = cli.life_wrap {
+        initial_params.extra.insert(
+            "life_wrap".into(),
+            serde_json::Value::from(if wrap { 1.0 } else { 0.0 }),
+        );
+    }
+    if let Some(rule) = cli.life_rule {
+        initial_params
+            .extra
+            .insert("life_rule".into(), serde_json::Value::from(rule));
+    }
+    if let Some(pattern) = cli.life_pattern {
+        initial_params
+            .extra
+            .insert("life_pattern".into(), serde_json::Value::from(pattern));
+    }
+    if let Some(ants) = cli.langton_ants {
+        initial_params
+            .extra
+            .insert("langton_ants".into(), serde_json::Value::from(ants));
+    }
+    if let Some(rule) = cli.langton_rule {
+        initial_params
+            .extra
+            .insert("langton_rule".into(), serde_json::Value::from(rule));
+    }
+    if let Some(charset) = cli.matrix_charset {
+        initial_params
+            .extra
+            .insert("matrix_charset".into(), serde_json::Value::from(charset));
+    }
+    if let Some(message) = cli.matrix_message {
+        initial_params
+            .extra
+            .insert("matrix_message".into(), serde_json::Value::from(message));
+    }
+    if let Some((re, im)) = cli.mandel_center {
+        initial_params
+            .extra
+            .insert("mandel_re".into(), serde_json::Value::from(re));
+        initial_params
+            .extra
+            .insert("mandel_im".into(), serde_json::Value::from(im));
+    }
+    if let Some(zoom) = cli.mandel_zoom {
+        initial_params
+            .extra
+            .insert("mandel_zoom".into(), serde_json::Value::from(zoom));
+    }
+    if let Some((re, im)) = cli.mandel_julia {
+        initial_params
+            .extra
+            .insert("julia_re".into(), serde_json::Value::from(re));
+        initial_params
+            .extra
+            .insert("julia_im".into(), serde_json::Value::from(im));
+    }
+    if cli.boids_predator {
+        initial_params
+            .extra
+            .insert("boids_predator".into(), serde_json::Value::from(1.0));
+    }
+    if cli.boids_obstacles {
+        initial_params
+            .extra
+            .insert("boids_obstacles".into(), serde_json::Value::from(1.0));
+    }
+    if let Some(ai) = cli.snake_ai {
+        initial_params
+            .extra
+            .insert("snake_ai".into(), serde_json::Value::from(ai));
+    }
 
-    let result = run_loop(
-        &anim_name,
-        render_override,
-        color_mode,
-        color_quant,
-        unlimited,
-        frame_dur,
-        scale,
-        cycle,
-        clean,
-        cli.screensaver,
-        cli.screensaver_keys,
-        cli.record.as_deref(),
-        data_file,
-        postproc,
-        smoothing_tau,
-        default_smoothing_tau,
-        default_bloom,
-        assist,
-        dither,
-        &keybindings,
-        cli.profile,
-        cli.single_threaded,
-        cli.full_frames,
-    );
+    if cli.benchmark {
+        let names = match cli.animation {
+            Some(_) => animations::ANIMATION_NAMES
+                .iter()
+                .copied()
+                .filter(|&n| n == anim_name)
+                .collect(),
+            None => animations::ANIMATION_NAMES.to_vec(),
+        };
+        let (cols, rows) = cli.size.unwrap_or((80, 24));
+        let result = benchmark::run_benchmark(
+            &benchmark::BenchmarkConfig {
+                names,
+                frames: cli.frames,
+                fps,
+                scale,
+                seed: cli.seed,
+                cols: cols as usize,
+                rows: rows as usize,
+                render_mode: render_override,
+                cell_aspect,
+                settings: anim_settings,
+            },
+            &mut io::stdout().lock(),
+        );
+        // `termflix --benchmark | head` is fine
+        return match result {
+            Err(e) if e.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+            r => r,
+        };
+    }
+
+    let export_output = if let Some(dir) = cli.export_png {
+        Some(export::Output::PngDir(dir.into()))
+    } else if let Some(path) = cli.export_gif {
+        Some(export::Output::Gif(path.into()))
+    } else {
+        cli.record_headless
+            .map(|path| export::Output::Recording(path.into()))
+    };
+    if let Some(output) = export_output {
+        let (cols, rows) = cli.size.unwrap_or_else(|| match output {
+            // Never ask: it may be running under CI or on a server
+            export::Output::Recording(_) => (80, 24),
+            _ => terminal::size().unwrap_or((80, 25)),
+        });
+        let settings = anim_settings.get(&anim_name).cloned();
+        return export::run_export(&export::ExportConfig {
+            output,
+            name: anim_name,
+            frames: cli.frames,
+            fps,
+            scale,
+            seed: cli.seed,
+            cols: cols as usize,
+            rows: rows as usize,
+            render_mode: render_override,
+            color_mode,
+            color_quant,
+            dither,
+            mono_threshold,
+            ascii_ramp: ascii_ramp.clone(),
+            cell_aspect,
+            params: initial_params,
+            settings,
+            postproc,
+        });
+    }
 
-    // Restore terminal — disable raw mode first (doesn't write to stdout)
-    let _ = terminal::disable_raw_mode();
+    if cli.verbose {
+        print_diagnostics(&anim_name, render_override, color_mode, fps, unlimited);
+    }
+
+    // Set up panic hook to restore terminal before printing panic info.
+    // Without this, a panic inside raw mode leaves the terminal unusable.
+    let alt_screen = !cli.no_alt_screen;
+    let term_state = term_restore::TerminalState {
+        alt_screen,
+        graphics: cli.graphics.is_some(),
+        focus_events: cli.screensaver,
+    };
+    term_state.install_panic_hook();
+
+    terminal::enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    if alt_screen {
+        execute!(stdout, terminal::EnterAlternateScreen, cursor::Hide)?;
+    } else {
+        // Push whatever is on screen into the scrollback, so frames drawn
+        // from the home position overwrite blank lines instead of history
+        let (_, rows) = terminal::size()?;
+        execute!(stdout, Print("\n".repeat(rows as usize)), cursor::Hide)?;
+    }
+    if cli.screensaver {
+        execute!(stdout, EnableFocusChange)?;
+    }
+
+    let picked = match browse_entries {
+        Some(entries) => browser::run_browser(&browser::BrowserConfig {
+            entries,
+            render_override,
+            color_mode,
+            palette: terminal_palette,
+            scale,
+            cell_aspect,
+            frame_dur,
+            params: initial_params.clone(),
+            settings: anim_settings.clone(),
+        }),
+        None => Ok(Some(anim_name.as_str())),
+    };
+    let result = picked.and_then(|picked| match picked {
+        Some(anim_name) => run_loop(LoopConfig {
+            initial_anim: anim_name,
+            seed: cli.seed,
+            screensaver: cli.screensaver,
+            screensaver_keys: cli.screensaver_keys,
+            control,
+            initial_params,
+            anim_settings: &anim_settings,
+            keymap: &keymap,
+            beep,
+            audio_warning,
+            audio_reactive: cli.audio_reactive,
+            display: DisplayConfig {
+                explicit_render: render_override,
+                color_mode,
+                terminal_palette,
+                color_quant,
+                scale,
+                cell_aspect,
+                letterbox,
+                clean,
+                postproc,
+                smoothing_tau,
+                default_smoothing_tau,
+                default_bloom,
+                assist,
+                dither,
+                mono_threshold,
+                ascii_ramp: &ascii_ramp,
+                dim,
+                drift,
+                single_threaded: cli.single_threaded,
+                full_frames: cli.full_frames,
+                status_every_frame: cli.status_every_frame || cli.full_frames,
+                status_format: &status_format,
+                graphics: cli.graphics,
+                alt_screen,
+            },
+            pacing: PacingConfig {
+                unlimited,
+                frame_dur,
+                cycle,
+                shuffle,
+            },
+            recording: RecordingConfig {
+                path: cli.record.as_deref(),
+                profile: cli.profile,
+            },
+        }),
+        None => Ok(()),
+    });
 
     // Flush kernel PTY buffer
     #[cfg(unix)]
@@ -426,38 +1203,14 @@ fn main() -> io::Result<()> {
         }
     }
 
-    // Restore cursor and leave alt screen.
-    // \x1b[?2026l MUST come first: every frame starts with \x1b[?2026h (BSU begin
-    // synchronized output). If quit fires mid-write, the terminal has seen the begin
-    // marker but not the end marker, so it sits in sync mode buffering everything that
-    // follows — including the restore sequences — and appears frozen on the last frame.
-    // Sending \x1b[?2026l closes the pending sync block; it is a no-op if not in sync mode.
-    #[cfg(unix)]
-    {
-        use std::os::unix::io::AsRawFd;
-        let fd = io::stdout().as_raw_fd();
-        let restore = b"\x1b[?2026l\x1b[?25h\x1b[?1049l";
-        unsafe {
-            libc::write(fd, restore.as_ptr() as *const libc::c_void, restore.len());
-        }
-        // Explicitly disable focus-change reporting before exiting
-        if cli.screensaver {
-            let mut stdout = io::stdout();
-            let _ = execute!(stdout, DisableFocusChange);
-        }
-    }
-    #[cfg(not(unix))]
-    {
-        let mut stdout = io::stdout();
-        let _ = execute!(stdout, cursor::Show, terminal::LeaveAlternateScreen);
-        if cli.screensaver {
-            let _ = execute!(stdout, DisableFocusChange);
-        }
-    }
+    // Leave raw mode, close any pending sync block, restore the cursor, and
+    // leave the alt screen (see TerminalState::restore_sequence)
+    term_state.restore();
 
     // In tmux, tell tmux to discard buffered output and force a redraw.
     // Without this, tmux slowly drains queued animation frames row by row.
-    if std::env::var("TMUX").is_ok() {
+    // Skipped with --no-alt-screen, where the pane's history must survive.
+    if alt_screen && std::env::var("TMUX").is_ok() {
         // clear-history discards tmux's output buffer for this pane
         // refresh-client forces tmux to redraw from current state
         let _ = std::process::Command::new("tmux")
@@ -474,20 +1227,31 @@ fn main() -> io::Result<()> {
     result
 }
 
-const RENDER_MODES: [RenderMode; 3] = [
+const RENDER_MODES: [RenderMode; 4] = [
     RenderMode::Braille,
     RenderMode::HalfBlock,
+    RenderMode::Quadrant,
     RenderMode::Ascii,
 ];
-const COLOR_MODES: [ColorMode; 4] = [
+const COLOR_MODES: [ColorMode; 5] = [
     ColorMode::TrueColor,
     ColorMode::Ansi256,
     ColorMode::Ansi16,
+    ColorMode::Palette,
     ColorMode::Mono,
 ];
 
 const TRANSITION_FRAMES: u8 = 8;
 
+/// Smallest terminal (columns, rows) the loop builds a canvas for; below it
+/// every animation shows the "terminal too small" notice.
+const MIN_TERM_COLS: usize = 10;
+const MIN_TERM_ROWS: usize = 5;
+/// Largest `--pixelate` block width.
+const MAX_PIXELATE: u32 = 32;
+/// Largest `--kaleidoscope` segment count.
+const MAX_KALEIDOSCOPE: u32 = 12;
+
 struct FrameProfile {
     update_us: Vec<f64>,
     render_us: Vec<f64>,
@@ -585,6 +1349,61 @@ enum TransitionState {
     },
 }
 
+/// How long a run-time warning stays on the top row.
+const WARNING_DURATION: Duration = Duration::from_secs(3);
+
+/// How long `[SAVED file]` / `[COPIED]` stays in the status bar after a
+/// screenshot or clipboard copy.
+const SAVED_NOTICE_DURATION: Duration = Duration::from_secs(2);
+
+/// How long after a resize the loop waits for the size to settle before
+/// drawing (or recording) again.
+const RESIZE_SETTLE: Duration = Duration::from_millis(100);
+
+/// Write the frame on `canvas` to a timestamped screenshot file in the
+/// working directory. Returns the file name for the status bar, or the
+/// warning text to show instead.
+fn save_screenshot(canvas: &Canvas, format: screenshot::Format) -> Result<String, String> {
+    screenshot::save(Path::new("."), &canvas.render(), format)
+        .map(|path| {
+            path.file_name().map_or_else(
+                || path.display().to_string(),
+                |n| n.to_string_lossy().into(),
+            )
+        })
+        .map_err(|e| format!(" Screenshot failed: {e} "))
+}
+
+/// Swap `anim` for a fresh `name` sized to `canvas`. If it can't be built the
+/// current animation keeps running, resized to the canvas, and the returned
+/// text says why.
+fn replace_animation(
+    anim: &mut Box<dyn Animation>,
+    name: &str,
+    canvas: &Canvas,
+    scale: f64,
+    seed: Option<u64>,
+    settings: &HashMap<String, toml::Table>,
+) -> Option<String> {
+    match animations::create_for_canvas(
+        name,
+        canvas.width,
+        canvas.height,
+        scale,
+        seed,
+        settings.get(name),
+    ) {
+        Ok(new) => {
+            *anim = new;
+            None
+        }
+        Err(e) => {
+            anim.on_resize(canvas.width, canvas.height);
+            Some(format!(" {e}; still playing {} ", anim.name()))
+        }
+    }
+}
+
 fn start_transition(transition: &mut TransitionState, next_anim_index: usize) {
     *transition = TransitionState::FadingOut {
         next_anim_index,
@@ -592,35 +1411,131 @@ fn start_transition(transition: &mut TransitionState, next_anim_index: usize) {
     };
 }
 
-#[allow(clippy::too_many_arguments)]
-fn run_loop(
-    initial_anim: &str,
+/// Everything `run_loop` needs besides the terminal itself.
+struct LoopConfig<'a> {
+    initial_anim: &'a str,
+    seed: Option<u64>,
+    screensaver: bool,
+    screensaver_keys: bool,
+    control: Option<ParamsSource>,
+    initial_params: ExternalParams,
+    /// `[animations.<name>]` config tables
+    anim_settings: &'a HashMap<String, toml::Table>,
+    keymap: &'a keymap::KeyMap,
+    beep: bool,
+    audio_warning: Option<String>,
+    audio_reactive: bool,
+    display: DisplayConfig<'a>,
+    pacing: PacingConfig,
+    recording: RecordingConfig<'a>,
+}
+
+/// How frames are rendered, post-processed and written out.
+struct DisplayConfig<'a> {
     explicit_render: Option<RenderMode>,
-    mut color_mode: ColorMode,
+    color_mode: ColorMode,
+    /// Terminal palette for `ColorMode::Palette`, when it was read
+    terminal_palette: Option<[(u8, u8, u8); 16]>,
     color_quant: u8,
-    unlimited: bool,
-    frame_dur: Duration,
-    mut scale: f64,
-    cycle: u32,
+    scale: f64,
+    cell_aspect: f64,
+    letterbox: Option<f64>,
     clean: bool,
-    screensaver: bool,
-    screensaver_keys: bool,
-    record_path: Option<&str>,
-    data_file: Option<String>,
-    mut postproc: PostProcessConfig,
-    mut smoothing_tau: f64,
+    postproc: PostProcessConfig,
+    smoothing_tau: f64,
     default_smoothing_tau: f64,
     default_bloom: f64,
     assist: ColorAssist,
     dither: bool,
-    keybindings: &KeyBindings,
-    profile: bool,
+    mono_threshold: f64,
+    ascii_ramp: &'a str,
+    dim: f64,
+    drift: bool,
     single_threaded: bool,
     full_frames: bool,
-) -> io::Result<()> {
+    status_every_frame: bool,
+    status_format: &'a status::StatusFormat,
+    graphics: Option<Graphics>,
+    alt_screen: bool,
+}
+
+/// Frame rate and when to switch animations.
+struct PacingConfig {
+    unlimited: bool,
+    frame_dur: Duration,
+    cycle: u32,
+    shuffle: bool,
+}
+
+/// What the session writes out besides the terminal.
+struct RecordingConfig<'a> {
+    /// `--record` output file
+    path: Option<&'a str>,
+    /// Print per-stage frame timings on exit
+    profile: bool,
+}
+
+fn run_loop(config: LoopConfig<'_>) -> io::Result<()> {
+    let LoopConfig {
+        initial_anim,
+        seed,
+        screensaver,
+        screensaver_keys,
+        control,
+        initial_params,
+        anim_settings,
+        keymap,
+        beep,
+        audio_warning,
+        audio_reactive,
+        display:
+            DisplayConfig {
+                explicit_render,
+                mut color_mode,
+                terminal_palette,
+                color_quant,
+                mut scale,
+                cell_aspect,
+                letterbox,
+                clean,
+                mut postproc,
+                mut smoothing_tau,
+                default_smoothing_tau,
+                default_bloom,
+                assist,
+                dither,
+                mono_threshold,
+                ascii_ramp,
+                dim,
+                drift,
+                single_threaded,
+                full_frames,
+                status_every_frame,
+                status_format,
+                graphics,
+                alt_screen,
+            },
+        pacing:
+            PacingConfig {
+                mut unlimited,
+                frame_dur: capped_frame_dur,
+                cycle,
+                shuffle,
+            },
+        recording: RecordingConfig {
+            path: record_path,
+            profile,
+        },
+    } = config;
     let (mut cols, mut rows) = terminal::size()?;
     let is_tmux = std::env::var("TMUX").is_ok();
     let mut hide_status = clean;
+    // `u` toggles unlimited live; the FPS cap is kept so it can be restored.
+    let mut frame_dur = if unlimited {
+        Duration::ZERO
+    } else {
+        capped_frame_dur
+    };
     // Adaptive frame pacing — adjusts to actual terminal throughput
     let mut adaptive_frame_dur = frame_dur;
     let mut write_time_ema: f64 = 0.0; // exponential moving average of write time in secs
@@ -630,22 +1545,31 @@ fn run_loop(
     } else {
         (rows as usize).saturating_sub(1)
     };
-    let temp_canvas = Canvas::new(
-        cols as usize,
-        display_rows,
-        RenderMode::HalfBlock,
-        color_mode,
-    );
+    // Probe instance just to learn the preferred render mode.
+    let (probe_w, probe_h) = animations::PROBE_SIZE;
+    let invalid = |e| io::Error::new(io::ErrorKind::InvalidInput, e);
     let mut anim: Box<dyn Animation> =
-        animations::create(initial_anim, temp_canvas.width, temp_canvas.height, scale)
-            .expect("animation name validated before calling create");
+        animations::create(initial_anim, probe_w, probe_h, scale, seed).map_err(invalid)?;
     let mut render_mode = explicit_render.unwrap_or_else(|| anim.preferred_render());
-    let mut canvas = Canvas::new(cols as usize, display_rows, render_mode, color_mode);
+    let view = viewport(cols as usize, display_rows, letterbox, cell_aspect);
+    let mut canvas = Canvas::new(view.cols, view.rows, render_mode, color_mode);
     canvas.color_quant = color_quant;
     canvas.dither = dither;
-    anim = animations::create(initial_anim, canvas.width, canvas.height, scale)
-        .expect("animation name validated before calling create");
-    anim.on_resize(canvas.width, canvas.height);
+    canvas.mono_threshold = mono_threshold;
+    canvas.set_ascii_ramp(ascii_ramp);
+    if let Some(palette) = terminal_palette {
+        canvas.palette = palette;
+    }
+    canvas.set_cell_aspect(cell_aspect);
+    anim = animations::create_for_canvas(
+        initial_anim,
+        canvas.width,
+        canvas.height,
+        scale,
+        seed,
+        anim_settings.get(initial_anim),
+    )
+    .map_err(invalid)?;
 
     let mut anim_index = animations::ANIMATION_NAMES
         .iter()
@@ -653,29 +1577,51 @@ fn run_loop(
         .unwrap_or(0);
 
     let mut last_frame = Instant::now();
+    let mut schedule = pacing::FrameSchedule::new(last_frame);
     let mut cycle_start = Instant::now();
+    let mut cycle_order =
+        cycle::CycleOrder::new(animations::ANIMATION_NAMES.len(), anim_index, shuffle, seed);
+    // +1 walks forward through the cycle order, -1 backward (`,` flips it)
+    let mut cycle_dir: i32 = 1;
+    let drift_start = Instant::now();
     let mut frame_count: u64 = 0;
     let mut actual_fps: f64 = 0.0;
     let mut fps_update = Instant::now();
-    let mut recorder = record_path.map(|_| record::Recorder::new());
+    let mut recorder = record_path.map(|_| {
+        record::Recorder::with_metadata(record::Metadata {
+            anim: Some(initial_anim.to_string()),
+            size: Some((cols, rows)),
+            fps: Some((1.0 / capped_frame_dur.as_secs_f64()).round() as u32),
+        })
+    });
     let mut needs_rebuild = false;
     // Resize cooldown — skip frames after resize
     let mut resize_cooldown = Instant::now();
+    // The first frame after a rebuild is shown but not recorded
+    let mut just_rebuilt = false;
     // External control channel setup
-    let params_rx: Option<mpsc::Receiver<ExternalParams>> = {
-        if let Some(path) = data_file {
-            Some(spawn_reader(ParamsSource::File(path.into())))
-        } else if !std::io::stdin().is_terminal() {
-            Some(spawn_reader(ParamsSource::Stdin))
-        } else {
-            None
-        }
+    let params_rx: Option<mpsc::Receiver<ExternalParams>> = match control {
+        Some(source) => Some(spawn_reader(source)),
+        None if !std::io::stdin().is_terminal() => Some(spawn_reader(ParamsSource::Stdin)),
+        None => None,
     };
     let mut ext_state = CurrentState::default();
+    let mut reactive = termflix::audio::Reactive::new();
+    ext_state.merge(initial_params);
     let mut transition = TransitionState::None;
     let mut virtual_time: f64 = 0.0;
     let mut frame_profile = profile.then(|| FrameProfile::new(initial_anim));
     let quit = Arc::new(AtomicBool::new(false));
+    // SIGWINCH backstop: crossterm's Event::Resize can arrive late or not at
+    // all (stdin not the tty, some multiplexers), so the signal also sets a
+    // flag checked every iteration. signal-hook chains with crossterm's own
+    // handler rather than replacing it.
+    #[cfg(unix)]
+    let resize_signal = {
+        let flag = Arc::new(AtomicBool::new(false));
+        let _ = signal_hook::flag::register(signal_hook::consts::SIGWINCH, flag.clone());
+        flag
+    };
     #[cfg(unix)]
     let mut renderer: Option<render_sink::ThreadedRenderer> = if !single_threaded {
         use std::os::unix::io::AsRawFd;
@@ -690,11 +1636,35 @@ fn run_loop(
     // isn't flagged as an unused parameter.
     #[cfg(not(unix))]
     let _ = single_threaded;
-    use render::cell::CellGrid;
-    let mut prev_grid: Option<CellGrid> = None;
+    let mut delta = render::encoder::DeltaEncoder::default();
+    // Status text as last written; the row is only rewritten when it changes
+    // so it doesn't defeat dirty-cell rendering. `None` forces a rewrite.
+    let mut last_status: Option<String> = None;
+    // Transient warning drawn over the top row, e.g. for a control-channel
+    // request naming an animation that doesn't exist.
+    let mut warning: Option<(String, Instant)> = audio_warning.map(|w| (w, Instant::now()));
+    let kitty = graphics == Some(Graphics::Kitty);
+    if kitty && !render::kitty::likely_supported() {
+        warning = Some((
+            " --graphics kitty: terminal not recognized as kitty-compatible; drawing anyway "
+                .to_string(),
+            Instant::now(),
+        ));
+    }
+    let mut beeper = beep.then(sound::Beeper::new);
+    // Paused: the animation isn't updated and `last_grid` (the last rendered
+    // frame, before drift and letterboxing) is shown again until unpaused.
+    let mut paused = false;
+    let mut last_grid: Option<render::cell::CellGrid> = None;
+    // File name of the last screenshot, flashed in the status bar.
+    let mut saved: Option<(String, Instant)> = None;
+    // OSC 52 copy of the frame waiting to go out after the next frame, and
+    // when the last one did, for the status bar.
+    let mut clipboard: Option<String> = None;
+    let mut copied: Option<Instant> = None;
     let result: io::Result<()> = 'outer: loop {
         // Use event::poll as frame timer — properly yields to OS for signal handling
-        let time_to_next = adaptive_frame_dur.saturating_sub(last_frame.elapsed());
+        let time_to_next = schedule.until_next(Instant::now());
         if event::poll(time_to_next)? {
             // Drain all pending events
             loop {
@@ -720,26 +1690,55 @@ fn run_loop(
                             quit.store(true, Ordering::Release);
                             break 'outer Ok(());
                         }
-                        match code {
-                            kc if keybindings.quit.contains(&kc) => {
-                                if let (Some(rec), Some(path)) = (recorder.take(), record_path) {
+                        match keymap.action(code, modifiers) {
+                            Some(keymap::Action::Quit) => {
+                                if let (Some(mut rec), Some(path)) = (recorder.take(), record_path)
+                                {
+                                    // The size the frames were last drawn at, matching the .cast header
+                                    rec.metadata.size = Some((cols, rows));
                                     let mut stdout = io::stdout();
-                                    execute!(stdout, cursor::Show, terminal::LeaveAlternateScreen)?;
+                                    if alt_screen {
+                                        execute!(
+                                            stdout,
+                                            cursor::Show,
+                                            terminal::LeaveAlternateScreen
+                                        )?;
+                                    } else {
+                                        // Below the last frame, which stays on screen
+                                        execute!(
+                                            stdout,
+                                            Print(format!("\x1b[0m\x1b[{rows};1H\r\n"))
+                                        )?;
+                                    }
                                     terminal::disable_raw_mode()?;
-                                    rec.save(path)?;
-                                    println!("Saved {} frames to {}", rec.frame_count(), path);
-                                    terminal::enable_raw_mode()?;
-                                    execute!(stdout, terminal::EnterAlternateScreen, cursor::Hide)?;
+                                    rec.save_for_path(Path::new(path), cols, rows)?;
+                                    if alt_screen {
+                                        println!("Saved {} frames to {}", rec.frame_count(), path);
+                                        terminal::enable_raw_mode()?;
+                                        execute!(
+                                            stdout,
+                                            terminal::EnterAlternateScreen,
+                                            cursor::Hide
+                                        )?;
+                                    } else {
+                                        // The exit sequence adds the newline
+                                        let saved = format!(
+                                            "Saved {} frames to {}",
+                                            rec.frame_count(),
+                                            path
+                                        );
+                                        execute!(stdout, Print(saved))?;
+                                    }
                                 }
                                 quit.store(true, Ordering::Release);
                                 break 'outer Ok(());
                             }
-                            kc if keybindings.next.contains(&kc) => {
+                            Some(keymap::Action::Next) => {
                                 anim_index = (anim_index + 1) % animations::ANIMATION_NAMES.len();
                                 start_transition(&mut transition, anim_index);
                                 cycle_start = Instant::now();
                             }
-                            kc if keybindings.prev.contains(&kc) => {
+                            Some(keymap::Action::Prev) => {
                                 anim_index = if anim_index == 0 {
                                     animations::ANIMATION_NAMES.len() - 1
                                 } else {
@@ -748,7 +1747,31 @@ fn run_loop(
                                 start_transition(&mut transition, anim_index);
                                 cycle_start = Instant::now();
                             }
-                            kc if keybindings.render.contains(&kc) => {
+                            Some(keymap::Action::ReverseCycle) => {
+                                cycle_dir = -cycle_dir;
+                            }
+                            Some(action @ (keymap::Action::Faster | keymap::Action::Slower)) => {
+                                let faster = action == keymap::Action::Faster;
+                                ext_state.merge(ExternalParams {
+                                    speed: Some(external::step_speed(ext_state.speed(), faster)),
+                                    ..Default::default()
+                                });
+                            }
+                            Some(action @ (keymap::Action::HueDown | keymap::Action::HueUp)) => {
+                                let steps = if action == keymap::Action::HueUp {
+                                    1
+                                } else {
+                                    -1
+                                };
+                                ext_state.merge(ExternalParams {
+                                    color_shift: Some(external::nudge_hue(
+                                        ext_state.color_shift(),
+                                        steps,
+                                    )),
+                                    ..Default::default()
+                                });
+                            }
+                            Some(keymap::Action::CycleRender) => {
                                 let idx = RENDER_MODES
                                     .iter()
                                     .position(|&m| m == render_mode)
@@ -756,7 +1779,7 @@ fn run_loop(
                                 render_mode = RENDER_MODES[(idx + 1) % RENDER_MODES.len()];
                                 needs_rebuild = true;
                             }
-                            kc if keybindings.color.contains(&kc) => {
+                            Some(keymap::Action::CycleColor) => {
                                 let idx = COLOR_MODES
                                     .iter()
                                     .position(|&m| m == color_mode)
@@ -764,30 +1787,82 @@ fn run_loop(
                                 color_mode = COLOR_MODES[(idx + 1) % COLOR_MODES.len()];
                                 needs_rebuild = true;
                             }
-                            kc if keybindings.status.contains(&kc) => {
+                            Some(keymap::Action::ToggleStatus) => {
                                 hide_status = !hide_status;
                                 needs_rebuild = true;
                             }
-                            KeyCode::Char('b') => {
+                            Some(keymap::Action::ToggleBloom) => {
                                 postproc.bloom = if postproc.bloom > 0.0 {
                                     0.0
                                 } else {
                                     default_bloom
                                 };
                             }
-                            KeyCode::Char('s') => {
+                            Some(keymap::Action::ToggleSmoothing) => {
                                 smoothing_tau = if smoothing_tau > 0.0 {
                                     0.0
                                 } else {
                                     default_smoothing_tau
                                 };
                             }
-                            KeyCode::Char('d') => {
+                            Some(keymap::Action::ToggleDither) => {
                                 canvas.dither = !canvas.dither;
                             }
+                            // Written between frames, never inside a synchronized update.
+                            Some(
+                                action @ (keymap::Action::Screenshot
+                                | keymap::Action::ScreenshotText),
+                            ) => {
+                                let format = if action == keymap::Action::Screenshot {
+                                    screenshot::Format::Ansi
+                                } else {
+                                    screenshot::Format::Text
+                                };
+                                match save_screenshot(&canvas, format) {
+                                    Ok(name) => saved = Some((name, Instant::now())),
+                                    Err(text) => warning = Some((text, Instant::now())),
+                                }
+                            }
+                            Some(keymap::Action::CopyFrame) => {
+                                let text = screenshot::plain_text(&canvas.render());
+                                clipboard = Some(screenshot::osc52(&text));
+                                copied = Some(Instant::now());
+                            }
+                            Some(keymap::Action::ToggleUnlimited) => {
+                                unlimited = !unlimited;
+                                frame_dur = if unlimited {
+                                    Duration::ZERO
+                                } else {
+                                    capped_frame_dur
+                                };
+                                // Restart pacing from the new target; the EMA re-warms.
+                                adaptive_frame_dur = frame_dur;
+                                write_time_ema = 0.0;
+                            }
+                            Some(keymap::Action::Pause) => {
+                                paused = !paused;
+                            }
+                            Some(keymap::Action::Freeze) => {
+                                anim.toggle_freeze();
+                            }
+                            Some(keymap::Action::Reset) => {
+                                let name = animations::ANIMATION_NAMES[anim_index];
+                                if let Some(w) = replace_animation(
+                                    &mut anim,
+                                    name,
+                                    &canvas,
+                                    scale,
+                                    seed,
+                                    anim_settings,
+                                ) {
+                                    warning = Some((w, Instant::now()));
+                                }
+                                // Show the fresh animation even while paused
+                                last_grid = None;
+                            }
                             // Screensaver with keybindings active: any unbound key still dismisses.
                             // (Plain screensaver already exited above; reaching here means keys are on.)
-                            _ => {
+                            None => {
                                 if screensaver {
                                     quit.store(true, Ordering::Release);
                                     break 'outer Ok(());
@@ -808,8 +1883,14 @@ fn run_loop(
             }
         }
 
+        #[cfg(unix)]
+        if resize_signal.swap(false, Ordering::Relaxed) {
+            needs_rebuild = true;
+            resize_cooldown = Instant::now();
+        }
+
         // After resize, wait for things to settle before rendering
-        if resize_cooldown.elapsed() < Duration::from_millis(100) {
+        if resize_cooldown.elapsed() < RESIZE_SETTLE {
             needs_rebuild = true;
             continue;
         }
@@ -818,46 +1899,69 @@ fn run_loop(
         if needs_rebuild {
             // Get the CURRENT size (may have changed since event)
             let (cur_cols, cur_rows) = terminal::size()?;
-            if cur_cols >= 10 && cur_rows >= 5 {
-                cols = cur_cols;
-                rows = cur_rows;
+            cols = cur_cols;
+            rows = cur_rows;
+            // Below the floor keep the old canvas; the too-small notice covers it.
+            if cur_cols as usize >= MIN_TERM_COLS && cur_rows as usize >= MIN_TERM_ROWS {
                 let display_rows = if hide_status {
                     rows as usize
                 } else {
                     (rows as usize).saturating_sub(1)
                 };
-                canvas = Canvas::new(cols as usize, display_rows, render_mode, color_mode);
+                let view = viewport(cols as usize, display_rows, letterbox, cell_aspect);
+                canvas = Canvas::new(view.cols, view.rows, render_mode, color_mode);
                 canvas.color_quant = color_quant;
                 canvas.dither = dither;
-                anim = animations::create(
-                    animations::ANIMATION_NAMES[anim_index],
-                    canvas.width,
-                    canvas.height,
-                    scale,
-                )
-                .expect("animation name validated before calling create");
-                anim.on_resize(canvas.width, canvas.height);
+                canvas.mono_threshold = mono_threshold;
+                canvas.set_ascii_ramp(ascii_ramp);
+                if let Some(palette) = terminal_palette {
+                    canvas.palette = palette;
+                }
+                canvas.set_cell_aspect(cell_aspect);
+                let name = animations::ANIMATION_NAMES[anim_index];
+                if let Some(w) =
+                    replace_animation(&mut anim, name, &canvas, scale, seed, anim_settings)
+                {
+                    warning = Some((w, Instant::now()));
+                }
                 // No clear screen — next frame overwrites everything.
                 // Clearing here with a blocking flush can lock up in tmux
                 // when the output buffer is full from the previous frame.
             }
-            prev_grid = None;
+            delta.invalidate();
+            last_status = None;
+            last_grid = None;
             needs_rebuild = false;
+            just_rebuilt = true;
             last_frame = Instant::now();
+            schedule.reset(last_frame);
             continue; // Skip this frame, render fresh next iteration
         }
 
         // Auto-cycle
+        if paused {
+            cycle_start = Instant::now();
+        }
         if cycle > 0 && cycle_start.elapsed() >= Duration::from_secs(cycle as u64) {
-            anim_index = (anim_index + 1) % animations::ANIMATION_NAMES.len();
+            anim_index = cycle_order.step(anim_index, cycle_dir);
             start_transition(&mut transition, anim_index);
             cycle_start = Instant::now();
         }
 
         // Timing
         let now = Instant::now();
-        let dt = now.duration_since(last_frame).as_secs_f64().min(0.1); // Cap dt to avoid huge jumps
+        let gap = now.duration_since(last_frame);
+        let dt = if seed.is_some() {
+            // Seeded runs step whole frames so they replay identically.
+            capped_frame_dur.as_secs_f64()
+        } else {
+            if gap >= pacing::TIME_GAP {
+                anim.on_time_gap();
+            }
+            gap.as_secs_f64().min(0.1) // Cap dt to avoid huge jumps
+        };
         last_frame = now;
+        schedule.advance(now, adaptive_frame_dur);
 
         // Drain external params channel
         if let Some(rx) = &params_rx {
@@ -865,36 +1969,41 @@ fn run_loop(
                 ext_state.merge(p);
             }
         }
+        // Live audio overrides intensity and speed from the control channel.
+        if audio_reactive && let Some(pulse) = termflix::audio::pulse() {
+            ext_state.merge(reactive.update(pulse, dt));
+        }
 
         // Handle animation switch from external params
-        if let Some(name) = ext_state.take_animation_change()
-            && animations::ANIMATION_NAMES.contains(&name.as_str())
-        {
-            anim_index = animations::ANIMATION_NAMES
-                .iter()
-                .position(|&n| n == name.as_str())
-                .unwrap_or(anim_index);
-            start_transition(&mut transition, anim_index);
-            cycle_start = Instant::now();
+        if let Some(name) = ext_state.take_animation_change() {
+            match animations::ANIMATION_NAMES.iter().position(|&n| n == name) {
+                Some(index) => {
+                    anim_index = index;
+                    start_transition(&mut transition, anim_index);
+                    cycle_start = Instant::now();
+                }
+                None => {
+                    let e = animations::CreateError::UnknownAnimation(name);
+                    let text = format!(" {e}; still playing {} ", anim.name());
+                    warning = Some((text, Instant::now()));
+                }
+            }
         }
 
         // Handle scale change from external params
         if let Some(new_scale) = ext_state.take_scale_change() {
             scale = new_scale.clamp(0.5, 2.0);
-            anim = animations::create(
-                animations::ANIMATION_NAMES[anim_index],
-                canvas.width,
-                canvas.height,
-                scale,
-            )
-            .expect("animation name validated before calling create");
-            anim.on_resize(canvas.width, canvas.height);
-            prev_grid = None;
+            let name = animations::ANIMATION_NAMES[anim_index];
+            if let Some(w) = replace_animation(&mut anim, name, &canvas, scale, seed, anim_settings)
+            {
+                warning = Some((w, Instant::now()));
+            }
+            delta.invalidate();
         }
 
         // Handle render mode change from external params
         if let Some(render_name) = ext_state.take_render_change()
-            && let Some(new_mode) = parse_render_mode(&render_name)
+            && let Some(new_mode) = RenderMode::from_str(&render_name, true).ok()
         {
             render_mode = new_mode;
             needs_rebuild = true;
@@ -902,7 +2011,7 @@ fn run_loop(
 
         // Handle color mode change from external params
         if let Some(color_name) = ext_state.take_color_change()
-            && let Some(new_mode) = parse_color_mode(&color_name)
+            && let Some(new_mode) = ColorMode::from_str(&color_name, true).ok()
         {
             color_mode = new_mode;
             needs_rebuild = true;
@@ -914,103 +2023,143 @@ fn run_loop(
         }
 
         // Virtual time with speed multiplier
-        let speed = ext_state.speed().clamp(0.1, 5.0);
-        let effective_dt = (dt * speed).min(0.5);
+        let speed = ext_state.speed();
+        let effective_dt = if paused { 0.0 } else { (dt * speed).min(0.5) };
         virtual_time += effective_dt;
 
         // Per-animation semantic params
         anim.set_params(ext_state.params());
 
-        // Update animation
-        let update_start = Instant::now();
-        anim.update(&mut canvas, effective_dt, virtual_time);
-        let update_dur = update_start.elapsed();
-
-        // Temporal brightness smoothing (opt-in). Runs on raw animation output,
-        // before effects/post-process, so intentional changes stay responsive.
-        if smoothing_tau > 0.0 {
-            canvas.apply_smoothing(smoothing_alpha(effective_dt, smoothing_tau));
-        }
+        // Terminal too small for this animation: explain instead of drawing a
+        // blank or stale, oversized frame.
+        let area_rows = if hide_status {
+            rows as usize
+        } else {
+            (rows as usize).saturating_sub(1)
+        };
+        let (min_cols, min_rows) = export::min_cells(anim.as_ref(), render_mode);
+        let need = (min_cols.max(MIN_TERM_COLS), min_rows.max(MIN_TERM_ROWS));
+        let view = viewport(cols as usize, area_rows, letterbox, cell_aspect);
+        let too_small = view.cols < need.0 || view.rows < need.1;
+
+        let mut events = Vec::new();
+        // Mid-transition the fade keeps running even while paused.
+        let frozen = last_grid
+            .as_ref()
+            .filter(|_| paused && matches!(transition, TransitionState::None));
+        let (frame, update_dur, render_dur) = if too_small {
+            delta.invalidate();
+            let mut notice = too_small_notice(cols as usize, area_rows, need, color_mode);
+            if kitty {
+                notice.insert_str(0, render::kitty::DELETE_ALL);
+            }
+            (notice, Duration::ZERO, Duration::ZERO)
+        } else if let Some(grid) = frozen {
+            let render_start = Instant::now();
+            let frame = encode_view(
+                &mut delta,
+                grid.clone(),
+                drift.then(|| drift_start.elapsed()),
+                (cols as usize, area_rows),
+                &view,
+                !full_frames && recorder.is_none(),
+                (render_mode, color_mode),
+            );
+            (frame, Duration::ZERO, render_start.elapsed())
+        } else {
+            // Update animation
+            let update_start = Instant::now();
+            anim.update(&mut canvas, effective_dt, virtual_time);
+            let update_dur = update_start.elapsed();
+            events = anim.take_events();
+
+            // Temporal brightness smoothing (opt-in). Runs on raw animation output,
+            // before effects/post-process, so intentional changes stay responsive.
+            if smoothing_tau > 0.0 {
+                canvas.apply_smoothing(smoothing_alpha(effective_dt, smoothing_tau));
+            }
 
-        // Transition fade processing
-        let transition_factor = match &mut transition {
-            TransitionState::None => 1.0,
-            TransitionState::FadingOut {
-                next_anim_index,
-                remaining,
-            } => {
-                let factor = *remaining as f64 / TRANSITION_FRAMES as f64;
-                if *remaining == 0 {
-                    anim = animations::create(
-                        animations::ANIMATION_NAMES[*next_anim_index],
-                        canvas.width,
-                        canvas.height,
-                        scale,
-                    )
-                    .expect("animation name validated before calling create");
-                    anim.on_resize(canvas.width, canvas.height);
-                    if explicit_render.is_none() {
-                        render_mode = anim.preferred_render();
-                        needs_rebuild = true;
+            // Transition fade processing
+            let transition_factor = match &mut transition {
+                TransitionState::None => 1.0,
+                TransitionState::FadingOut {
+                    next_anim_index,
+                    remaining,
+                } => {
+                    let factor = *remaining as f64 / TRANSITION_FRAMES as f64;
+                    if *remaining == 0 {
+                        let name = animations::ANIMATION_NAMES[*next_anim_index];
+                        if let Some(w) =
+                            replace_animation(&mut anim, name, &canvas, scale, seed, anim_settings)
+                        {
+                            warning = Some((w, Instant::now()));
+                            anim_index = animations::ANIMATION_NAMES
+                                .iter()
+                                .position(|&n| n == anim.name())
+                                .unwrap_or(anim_index);
+                        }
+                        if explicit_render.is_none() {
+                            render_mode = anim.preferred_render();
+                            needs_rebuild = true;
+                        }
+                        delta.invalidate();
+                        transition = TransitionState::FadingIn {
+                            remaining: TRANSITION_FRAMES,
+                        };
+                        0.0
+                    } else {
+                        *remaining -= 1;
+                        factor
                     }
-                    prev_grid = None;
-                    transition = TransitionState::FadingIn {
-                        remaining: TRANSITION_FRAMES,
-                    };
-                    0.0
-                } else {
-                    *remaining -= 1;
-                    factor
                 }
-            }
-            TransitionState::FadingIn { remaining } => {
-                let factor = 1.0 - *remaining as f64 / TRANSITION_FRAMES as f64;
-                if *remaining == 0 {
-                    transition = TransitionState::None;
-                    1.0
-                } else {
-                    *remaining -= 1;
-                    factor
+                TransitionState::FadingIn { remaining } => {
+                    let factor = 1.0 - *remaining as f64 / TRANSITION_FRAMES as f64;
+                    if *remaining == 0 {
+                        transition = TransitionState::None;
+                        1.0
+                    } else {
+                        *remaining -= 1;
+                        factor
+                    }
                 }
-            }
-        };
+            };
 
-        if needs_rebuild {
-            continue;
-        }
+            if needs_rebuild {
+                continue;
+            }
 
-        // Post-process canvas with intensity and hue shift
-        let intensity = ext_state.intensity().clamp(0.0, 2.0) * transition_factor;
-        let hue = ext_state.color_shift().clamp(0.0, 1.0);
-        canvas.apply_effects(intensity, hue);
-        canvas.apply_color_assist(&assist);
-        canvas.post_process(&postproc);
-
-        // Render to string
-        let render_start = Instant::now();
-        let always_reset_row_end = !matches!(render_mode, RenderMode::HalfBlock);
-        let grid = canvas.render_cells();
-        let frame = match &prev_grid {
-            Some(p)
-                if p.cols == grid.cols
-                    && p.rows == grid.rows
-                    && !full_frames
-                    && recorder.is_none()
-                    && !render::encoder::grid_has_wide(&grid)
-                    && render::encoder::dirty_ratio(p, &grid)
-                        <= render::encoder::FULL_REDRAW_THRESHOLD =>
-            {
-                render::encoder::encode_diff(p, &grid)
+            // Post-process canvas with intensity and hue shift
+            let intensity = ext_state.intensity().clamp(0.0, 2.0) * transition_factor * dim;
+            let hue = ext_state.color_shift().clamp(0.0, 1.0);
+            canvas.apply_effects(intensity, hue);
+            canvas.apply_color_assist(&assist);
+            canvas.post_process(&postproc);
+
+            // Render to string
+            let render_start = Instant::now();
+            // --graphics: blank cells clear the margins and any old text,
+            // then the image covers the view
+            let grid = if kitty {
+                render::cell::CellGrid::new(view.cols, view.rows)
+            } else {
+                canvas.render_cells()
+            };
+            let mut frame = encode_view(
+                &mut delta,
+                grid.clone(),
+                drift.then(|| drift_start.elapsed()),
+                (cols as usize, area_rows),
+                &view,
+                !full_frames && recorder.is_none(),
+                (render_mode, color_mode),
+            );
+            if kitty {
+                frame.push_str(&render::kitty::encode_image(&canvas, &view));
             }
-            _ => render::encoder::encode_full(&grid, always_reset_row_end),
+            last_grid = Some(grid);
+            let render_dur = render_start.elapsed();
+            (frame, update_dur, render_dur)
         };
-        prev_grid = Some(grid);
-        let render_dur = render_start.elapsed();
-
-        // Record if active
-        if let Some(ref mut rec) = recorder {
-            rec.capture(&frame);
-        }
 
         // Build frame buffer with synchronized output
         let mut frame_buf: Vec<u8> = Vec::with_capacity(256 * 1024);
@@ -1028,36 +2177,59 @@ fn run_loop(
             fps_update = Instant::now();
         }
         if !hide_status {
-            let rec_indicator = if recorder.is_some() { " [REC]" } else { "" };
-            let fps_str = if unlimited {
-                "∞ fps".to_string()
-            } else {
-                format!("{:.0} fps", actual_fps)
-            };
-            let bloom_str = if postproc.bloom > 0.0 { "ON" } else { "off" };
-            let smooth_str = if smoothing_tau > 0.0 { "ON" } else { "off" };
-            let dither_str = if canvas.dither { "ON" } else { "off" };
-            let assist_str = match &assist {
-                ColorAssist::None => String::new(),
-                ColorAssist::Remap(p) => format!(" | pal:{}", p.name()),
-                ColorAssist::Daltonize(d) => format!(" | cb:{}", d.name()),
-            };
-            let status = format!(
-                " {} | {:?} | {:?} | {}{} | bloom:{} | smooth:{} | dither:{}{assist_str} | [←/→] anim  [b] bloom  [s] smooth  [d] dither  [r] render  [c] color  [h] hide  [q] quit ",
-                anim.name(),
-                render_mode,
-                color_mode,
-                fps_str,
-                rec_indicator,
-                bloom_str,
-                smooth_str,
-                dither_str,
-            );
+            let status = status_format.render(&status::StatusValues {
+                name: anim.name(),
+                render: format!("{render_mode:?}"),
+                color: format!("{color_mode:?}"),
+                fps: if unlimited {
+                    "∞ fps".to_string()
+                } else {
+                    format!("{:.0} fps", actual_fps)
+                },
+                recording: recorder.is_some(),
+                paused,
+                speed: ext_state.speed(),
+                saved: match &saved {
+                    Some((name, since)) if since.elapsed() < SAVED_NOTICE_DURATION => Some(name),
+                    _ => None,
+                },
+                copied: copied.is_some_and(|since| since.elapsed() < SAVED_NOTICE_DURATION),
+                scale,
+                bloom: postproc.bloom > 0.0,
+                smooth: smoothing_tau > 0.0,
+                dither: canvas.dither,
+                keys: keymap.hints(),
+                assist: match &assist {
+                    ColorAssist::None => String::new(),
+                    ColorAssist::Remap(p) => format!(" | pal:{}", p.name()),
+                    ColorAssist::Daltonize(d) => format!(" | cb:{}", d.name()),
+                },
+            });
             let w = cols as usize;
             let truncated: String = status.chars().take(w).collect();
             let padded = format!("{:<width$}", truncated, width = w);
-            frame_buf
-                .extend_from_slice(format!("\x1b[{};1H\x1b[7m{}\x1b[0m", rows, padded).as_bytes());
+            if status_every_frame || last_status.as_ref() != Some(&padded) {
+                frame_buf.extend_from_slice(
+                    format!("\x1b[{};1H\x1b[7m{}\x1b[0m", rows, padded).as_bytes(),
+                );
+                last_status = Some(padded);
+            }
+        }
+
+        if let Some((text, since)) = &warning {
+            if since.elapsed() < WARNING_DURATION {
+                let line: String = text.chars().take(cols as usize).collect();
+                frame_buf
+                    .extend_from_slice(format!("\x1b[1;1H\x1b[30;43m{line}\x1b[0m").as_bytes());
+            } else {
+                // Diffs don't know about the overlay; repaint what it covered.
+                warning = None;
+                delta.invalidate();
+            }
+        }
+
+        if let Some(beeper) = &mut beeper {
+            beeper.play(&events, Instant::now(), &mut frame_buf);
         }
 
         // Final size check — if terminal changed since we started rendering, discard frame
@@ -1070,8 +2242,23 @@ fn run_loop(
             continue; // Discard frame_buf, don't write anything
         }
 
+        // Record if active, leaving out frames that may be a transient size
+        let settling =
+            std::mem::take(&mut just_rebuilt) || resize_cooldown.elapsed() < RESIZE_SETTLE;
+        if let Some(ref mut rec) = recorder
+            && !too_small
+            && !paused
+            && !settling
+        {
+            rec.capture(&frame, (cols, rows));
+        }
+
         // End synchronized update
         frame_buf.extend_from_slice(b"\x1b[?2026l");
+        // After the sync block, through the same writer, so it can't interleave
+        if let Some(seq) = clipboard.take() {
+            frame_buf.extend_from_slice(seq.as_bytes());
+        }
 
         // Write frame — on Unix, write in chunks with quit checks between each
         // so 'q' is responsive even when tmux's buffer is full.
@@ -1080,7 +2267,7 @@ fn run_loop(
         {
             let outcome = if let Some(ref mut r) = renderer {
                 // Threaded: hand the owned frame buffer to the writer thread.
-                match r.submit(frame_buf, &quit, &keybindings.quit) {
+                match r.submit(frame_buf, &quit, keymap.quit_keys()) {
                     Ok(render_sink::SubmitResult::Ok) => render_sink::WriteOutcome::Complete,
                     Ok(render_sink::SubmitResult::Quit) => render_sink::WriteOutcome::QuitSignaled,
                     Ok(render_sink::SubmitResult::WriterDied) => break 'outer Ok(()),
@@ -1098,7 +2285,7 @@ fn run_loop(
                             modifiers,
                             ..
                         }) = event::read()?
-                        && render_sink::is_quit_key(code, modifiers, &keybindings.quit)
+                        && render_sink::is_quit_key(code, modifiers, keymap.quit_keys())
                     {
                         quit.store(true, Ordering::Release);
                         return Ok(true);
@@ -1168,6 +2355,233 @@ fn run_loop(
     result
 }
 
+/// Place a rendered grid on screen — shifted by burn-in drift after
+/// `drift_elapsed`, then letterboxed into the `area` (cols, rows) at `view`
+/// — and encode it against the previous frame, in full when `modes` changed.
+fn encode_view(
+    delta: &mut render::encoder::DeltaEncoder,
+    mut grid: render::cell::CellGrid,
+    drift_elapsed: Option<Duration>,
+    area: (usize, usize),
+    view: &render::letterbox::Viewport,
+    allow_diff: bool,
+    (render_mode, color_mode): (RenderMode, ColorMode),
+) -> String {
+    delta.set_modes(render_mode, color_mode);
+    if let Some(elapsed) = drift_elapsed {
+        let (dx, dy) = drift::offset(elapsed);
+        if dx > 0 || dy > 0 {
+            grid = grid.shifted(dx, dy);
+        }
+    }
+    // Letterboxed: blank margins are part of the frame, so diffs
+    // stay aligned with the screen.
+    if grid.cols < area.0 || grid.rows < area.1 {
+        grid = grid.placed(area.0, area.1, view.col, view.row);
+    }
+    let always_reset_row_end = !matches!(render_mode, RenderMode::HalfBlock);
+    delta.encode(grid, allow_diff, always_reset_row_end)
+}
+
+/// Full-screen frame telling the user the terminal area (`cols` x `rows`)
+/// is smaller than the current animation `need`s.
+fn too_small_notice(
+    cols: usize,
+    rows: usize,
+    need: (usize, usize),
+    color_mode: ColorMode,
+) -> String {
+    let mut canvas = Canvas::new(cols.max(1), rows.max(1), RenderMode::Ascii, color_mode);
+    let title = if cols >= 18 {
+        "Terminal too small"
+    } else {
+        "Too small"
+    };
+    let lines = [
+        title.to_string(),
+        format!("need {}x{}", need.0, need.1),
+        format!("have {}x{}", cols, rows),
+    ];
+    let top = rows.saturating_sub(lines.len()) / 2;
+    for (i, line) in lines.iter().enumerate() {
+        let x = cols.saturating_sub(line.chars().count()) / 2;
+        canvas.draw_text(x, top + i, line, 255, 200, 80);
+    }
+    canvas.render()
+}
+
+/// `--verbose`: report what termflix detected and chose, for debugging
+/// "why does it look wrong in my terminal" reports.
+fn print_diagnostics(
+    anim_name: &str,
+    render_override: Option<RenderMode>,
+    color_mode: ColorMode,
+    fps: u32,
+    unlimited: bool,
+) {
+    use clap::ValueEnum;
+    let mode_name = |v: Option<clap::builder::PossibleValue>| {
+        v.map(|v| v.get_name().to_string()).unwrap_or_default()
+    };
+    let env = |key: &str| std::env::var(key).unwrap_or_else(|_| "(unset)".to_string());
+
+    match terminal::size() {
+        Ok((cols, rows)) => eprintln!("terminal size:  {}x{}", cols, rows),
+        Err(e) => eprintln!("terminal size:  unknown ({})", e),
+    }
+    eprintln!("TERM:           {}", env("TERM"));
+    eprintln!("COLORTERM:      {}", env("COLORTERM"));
+    eprintln!(
+        "tmux:           {}",
+        if std::env::var("TMUX").is_ok() {
+            "yes"
+        } else {
+            "no"
+        }
+    );
+    eprintln!("sync output:    {}", probe_sync_output());
+    eprintln!("animation:      {}", anim_name);
+    let render = match render_override {
+        Some(mode) => mode_name(mode.to_possible_value()),
+        None => animations::create(
+            anim_name,
+            animations::PROBE_SIZE.0,
+            animations::PROBE_SIZE.1,
+            1.0,
+            None,
+        )
+        .map(|a| {
+            format!(
+                "{} (animation default)",
+                mode_name(a.preferred_render().to_possible_value())
+            )
+        })
+        .unwrap_or_default(),
+    };
+    eprintln!("render mode:    {}", render);
+    eprintln!(
+        "color mode:     {}",
+        mode_name(color_mode.to_possible_value())
+    );
+    if unlimited {
+        eprintln!("fps target:     unlimited");
+    } else {
+        eprintln!("fps target:     {}", fps);
+    }
+}
+
+/// Ask the terminal whether it supports synchronized output (DEC mode 2026)
+/// with a DECRQM query, waiting briefly for the reply.
+fn probe_sync_output() -> String {
+    // Reply: ESC [ ? 2026 ; <state> $ y
+    let reply = match term_query::query(b"\x1b[?2026$p", Duration::from_millis(200), |r| {
+        r.ends_with(b"$y") || r.len() >= 32
+    }) {
+        Ok(reply) => reply,
+        Err(term_query::QueryError::NotATerminal) => return "unknown (not a terminal)".to_string(),
+        Err(term_query::QueryError::NoRawMode) => {
+            return "unknown (raw mode unavailable)".to_string();
+        }
+        Err(term_query::QueryError::Unsupported) => {
+            return "unknown (probe not supported on this platform)".to_string();
+        }
+    };
+    let reply = String::from_utf8_lossy(&reply);
+    match reply
+        .split_once("2026;")
+        .and_then(|(_, rest)| rest.chars().next())
+    {
+        Some('1' | '2') => "supported".to_string(),
+        Some('3') => "supported (always on)".to_string(),
+        Some('4') => "not supported (permanently off)".to_string(),
+        Some('0') => "not supported".to_string(),
+        _ if std::env::var("TMUX").is_ok() => "no reply (tmux strips the markers)".to_string(),
+        _ => "no reply (assuming unsupported; markers are sent anyway)".to_string(),
+    }
+}
+
+/// Animations whose name or description contains `filter` (case-insensitive).
+fn filter_animations(filter: Option<&str>) -> Vec<(&'static str, &'static str)> {
+    let filter = filter.map(str::to_lowercase);
+    animations::ANIMATIONS
+        .iter()
+        .filter(|(name, desc)| {
+            filter
+                .as_ref()
+                .is_none_or(|f| name.to_lowercase().contains(f) || desc.to_lowercase().contains(f))
+        })
+        .copied()
+        .collect()
+}
+
+fn print_list(entries: &[(&str, &str)], filter: Option<&str>) {
+    println!("Available animations:");
+    for &(name, desc) in entries {
+        println!("  {:<12} {}", name, desc);
+    }
+    if let Some(f) = filter {
+        println!(
+            "\n  {} animation(s) matching '{}'",
+            entries.len(),
+            f.to_lowercase()
+        );
+    }
+    println!("\nRender modes: {}", value_names::<RenderMode>());
+    println!("Color modes: {}", value_names::<ColorMode>());
+}
+
+/// The CLI spellings of every `T` variant, comma-separated, so the listing
+/// can't fall behind the enum.
+fn value_names<T: ValueEnum>() -> String {
+    T::value_variants()
+        .iter()
+        .filter_map(|v| v.to_possible_value())
+        .map(|v| v.get_name().to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// `--list-json` entry.
+#[derive(serde::Serialize)]
+struct CatalogEntry<'a> {
+    name: &'a str,
+    description: &'a str,
+    /// The render mode the animation picks when none is given
+    preferred_render: String,
+}
+
+/// `--list-json`: one object per animation, in `--list` order. Each is built
+/// once at the probe size to ask for its preferred render mode.
+fn print_list_json(entries: &[(&str, &str)]) -> io::Result<()> {
+    let (probe_w, probe_h) = animations::PROBE_SIZE;
+    let catalog: Vec<CatalogEntry> = entries
+        .iter()
+        .map(|&(name, description)| {
+            let render = animations::create(name, probe_w, probe_h, 1.0, Some(0))
+                .map(|a| a.preferred_render())
+                .unwrap_or(RenderMode::Braille);
+            CatalogEntry {
+                name,
+                description,
+                preferred_render: render
+                    .to_possible_value()
+                    .map(|v| v.get_name().to_string())
+                    .unwrap_or_default(),
+            }
+        })
+        .collect();
+    use std::io::Write;
+    let mut out = io::stdout().lock();
+    let result = serde_json::to_writer(&mut out, &catalog)
+        .map_err(io::Error::from)
+        .and_then(|()| writeln!(out));
+    // `termflix --list-json | head -c 100` is fine
+    match result {
+        Err(e) if e.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+        r => r,
+    }
+}
+
 fn detect_recording_size(frames: &[record::Frame]) -> (usize, usize) {
     let mut max_row = 24usize;
     let mut max_col = 80usize;
@@ -1200,112 +2614,3 @@ fn detect_recording_size(frames: &[record::Frame]) -> (usize, usize) {
     }
     (max_col, max_row)
 }
-
-fn parse_render_mode(s: &str) -> Option<RenderMode> {
-    match s {
-        "braille" => Some(RenderMode::Braille),
-        "half-block" | "halfblock" => Some(RenderMode::HalfBlock),
-        "ascii" => Some(RenderMode::Ascii),
-        _ => None,
-    }
-}
-
-fn parse_color_mode(s: &str) -> Option<ColorMode> {
-    match s {
-        "mono" => Some(ColorMode::Mono),
-        "ansi16" => Some(ColorMode::Ansi16),
-        "ansi256" => Some(ColorMode::Ansi256),
-        "true-color" | "truecolor" => Some(ColorMode::TrueColor),
-        _ => None,
-    }
-}
-
-fn parse_key_binding(s: &str) -> Option<(KeyCode, KeyModifiers)> {
-    let s = s.trim();
-    if let Some((mods, key)) = s.split_once('+') {
-        let key_code = parse_key_code(key.trim())?;
-        let modifiers = match mods.trim().to_ascii_lowercase().as_str() {
-            "ctrl" => KeyModifiers::CONTROL,
-            "alt" => KeyModifiers::ALT,
-            "shift" => KeyModifiers::SHIFT,
-            _ => return None,
-        };
-        return Some((key_code, modifiers));
-    }
-    let key_code = parse_key_code(s)?;
-    Some((key_code, KeyModifiers::NONE))
-}
-
-fn parse_key_code(s: &str) -> Option<KeyCode> {
-    match s {
-        "Left" => Some(KeyCode::Left),
-        "Right" => Some(KeyCode::Right),
-        "Up" => Some(KeyCode::Up),
-        "Down" => Some(KeyCode::Down),
-        "Esc" => Some(KeyCode::Esc),
-        "Enter" => Some(KeyCode::Enter),
-        "Space" => Some(KeyCode::Char(' ')),
-        "Tab" => Some(KeyCode::Tab),
-        s if s.len() == 1 => Some(KeyCode::Char(s.chars().next().unwrap())),
-        _ => None,
-    }
-}
-
-struct KeyBindings {
-    next: Vec<KeyCode>,
-    prev: Vec<KeyCode>,
-    quit: Vec<KeyCode>,
-    render: Vec<KeyCode>,
-    color: Vec<KeyCode>,
-    status: Vec<KeyCode>,
-}
-
-impl KeyBindings {
-    fn defaults() -> Self {
-        KeyBindings {
-            next: vec![KeyCode::Right, KeyCode::Char('n')],
-            prev: vec![KeyCode::Left, KeyCode::Char('p')],
-            quit: vec![KeyCode::Char('q'), KeyCode::Esc],
-            render: vec![KeyCode::Char('r')],
-            color: vec![KeyCode::Char('c')],
-            status: vec![KeyCode::Char('h')],
-        }
-    }
-}
-
-fn build_keybindings(cfg: &config::Config) -> KeyBindings {
-    let kb = cfg.keybindings.as_ref();
-    let defaults = KeyBindings::defaults();
-    KeyBindings {
-        next: kb
-            .and_then(|m| m.get("next"))
-            .and_then(|s| parse_key_binding(s))
-            .map(|(c, _)| vec![c])
-            .unwrap_or(defaults.next),
-        prev: kb
-            .and_then(|m| m.get("prev"))
-            .and_then(|s| parse_key_binding(s))
-            .map(|(c, _)| vec![c])
-            .unwrap_or(defaults.prev),
-        quit: kb
-            .and_then(|m| m.get("quit"))
-            .and_then(|s| parse_key_binding(s))
-            .map(|(c, _)| vec![c])
-            .unwrap_or(defaults.quit),
-        render: kb
-            .and_then(|m| m.get("render"))
-            .and_then(|s| parse_key_binding(s))
-            .map(|(c, _)| vec![c])
-            .unwrap_or(defaults.render),
-        color: kb
-            .and_then(|m| m.get("color"))
-            .and_then(|s| parse_key_binding(s))
-            .map(|(c, _)| vec![c])
-            .unwrap_or(defaults.color),
-        status: kb
-            .and_then(|m| m.get("status"))
-            .and_then(|s| parse_key_binding(s))
-            .map(|(c, _)| vec![c])
-            .unwrap_or(defaults.status),
-    }
-}
@@ -0,0 +1,103 @@
+use std::time::{Duration, Instant};
+
+/// How many frames the schedule may fall behind before it gives up catching
+/// up. Within the window late frames run back to back until the loop is on
+/// time again; past it (a stall, a suspended process) the schedule restarts
+/// from the current instant instead of bursting through the backlog.
+const MAX_LAG_FRAMES: u32 = 2;
+
+/// A wall-clock gap between frames this long (a suspended laptop, a
+/// stopped process) is treated as a break in time rather than a slow frame;
+/// `run_loop` calls `Animation::on_time_gap` before the next update.
+pub const TIME_GAP: Duration = Duration::from_secs(1);
+
+/// Frame deadlines on a fixed wall-clock grid (`next += frame_dur`) rather
+/// than `frame_dur` after whenever the previous frame started, so a slow
+/// frame borrows from the next one instead of pushing every later frame back,
+/// and the average rate matches the target.
+pub struct FrameSchedule {
+    next: Instant,
+}
+
+impl FrameSchedule {
+    pub fn new(now: Instant) -> Self {
+        FrameSchedule { next: now }
+    }
+
+    /// Time left until the next frame is due; zero when it is already late.
+    pub fn until_next(&self, now: Instant) -> Duration {
+        self.next.saturating_duration_since(now)
+    }
+
+    /// Book the frame after the one starting at `now`. A frame that started
+    /// early (woken by input) or too far behind restarts the grid at `now`.
+    pub fn advance(&mut self, now: Instant, frame_dur: Duration) {
+        let next = self.next + frame_dur;
+        let early = next > now + frame_dur;
+        let stalled = now.saturating_duration_since(next) > frame_dur * MAX_LAG_FRAMES;
+        self.next = if early || stalled {
+            now + frame_dur
+        } else {
+            next
+        };
+    }
+
+    /// Restart the grid at `now`, e.g. after a canvas rebuild.
+    pub fn reset(&mut self, now: Instant) {
+        self.next = now;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FRAME: Duration = Duration::from_millis(40);
+
+    /// Run `frames` frames whose work takes `work`, waiting out the schedule
+    /// between them, and return the simulated time taken.
+    fn simulate(frames: u32, work: impl Fn(u32) -> Duration) -> Duration {
+        let start = Instant::now();
+        let mut now = start;
+        let mut schedule = FrameSchedule::new(now);
+        for i in 0..frames {
+            now += schedule.until_next(now);
+            schedule.advance(now, FRAME);
+            now += work(i);
+        }
+        now += schedule.until_next(now);
+        now - start
+    }
+
+    #[test]
+    fn slow_frames_do_not_accumulate_drift() {
+        // Every fifth frame overruns by half a frame; the rest are quick.
+        let elapsed = simulate(100, |i| if i % 5 == 0 { FRAME * 3 / 2 } else { FRAME / 4 });
+        assert_eq!(elapsed, FRAME * 100);
+    }
+
+    #[test]
+    fn long_stall_restarts_the_grid() {
+        let start = Instant::now();
+        let mut schedule = FrameSchedule::new(start);
+        schedule.advance(start, FRAME);
+        let after_stall = start + FRAME * 10;
+        assert_eq!(schedule.until_next(after_stall), Duration::ZERO);
+        schedule.advance(after_stall, FRAME);
+        // Only the stalled frame runs late; the next one is a full frame away.
+        assert_eq!(schedule.until_next(after_stall), FRAME);
+    }
+
+    #[test]
+    fn early_frames_do_not_push_the_schedule_out() {
+        let start = Instant::now();
+        let mut schedule = FrameSchedule::new(start);
+        schedule.advance(start, FRAME);
+        // A burst of input-driven frames before the deadline.
+        for ms in 1..=5 {
+            schedule.advance(start + Duration::from_millis(ms), FRAME);
+        }
+        let last = start + Duration::from_millis(5);
+        assert_eq!(schedule.until_next(last), FRAME);
+    }
+}
@@ -0,0 +1,143 @@
+//! Named heat gradients shared by the fire-like animations (`fire`,
+//! `campfire`, `lava`, `smoke`), chosen with `--fire-palette` or the
+//! `fire_palette` param.
+
+use crate::generators::{ColorGradient, ColorStop, named_param};
+
+/// A heat color ramp, from cold (t = 0) to the hottest core (t = 1).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Palette {
+    /// The original red/orange/yellow fire. Animations other than `fire`
+    /// keep their own built-in colors under it.
+    #[default]
+    Classic,
+    BlueFire,
+    GreenFire,
+    Ice,
+    Toxic,
+}
+
+named_param!(Palette {
+    Classic => "classic",
+    BlueFire => "blue-fire",
+    GreenFire => "green-fire",
+    Ice => "ice",
+    Toxic => "toxic",
+});
+
+impl Palette {
+    /// Heat gradient sampled by heat in 0..=1.
+    pub fn gradient(self) -> ColorGradient {
+        let stops: &[(f64, u8, u8, u8)] = match self {
+            Palette::Classic => &[
+                (0.0, 0, 0, 0),
+                (0.3, 100, 0, 0),
+                (0.6, 255, 0, 0),
+                (0.85, 255, 200, 0),
+                (1.0, 255, 255, 200),
+            ],
+            Palette::BlueFire => &[
+                (0.0, 0, 0, 0),
+                (0.3, 0, 0, 100),
+                (0.6, 0, 60, 255),
+                (0.85, 80, 200, 255),
+                (1.0, 220, 245, 255),
+            ],
+            Palette::GreenFire => &[
+                (0.0, 0, 0, 0),
+                (0.3, 0, 70, 0),
+                (0.6, 20, 200, 0),
+                (0.85, 150, 255, 40),
+                (1.0, 230, 255, 180),
+            ],
+            Palette::Ice => &[
+                (0.0, 0, 0, 0),
+                (0.3, 20, 40, 90),
+                (0.6, 90, 150, 220),
+                (0.85, 180, 225, 255),
+                (1.0, 255, 255, 255),
+            ],
+            Palette::Toxic => &[
+                (0.0, 0, 0, 0),
+                (0.3, 40, 0, 60),
+                (0.6, 110, 200, 0),
+                (0.85, 190, 255, 30),
+                (1.0, 240, 255, 160),
+            ],
+        };
+        ColorGradient::new(
+            stops
+                .iter()
+                .map(|&(t, r, g, b)| ColorStop { t, r, g, b })
+                .collect(),
+        )
+    }
+
+    /// Gradient for particles colored by age (embers, smoke puffs): born at
+    /// the hottest color and cooling to a dim glow, never to black.
+    pub fn cooling(self) -> ColorGradient {
+        let heat = self.gradient();
+        ColorGradient::new(
+            (0..=4)
+                .map(|i| {
+                    let t = i as f64 / 4.0;
+                    let (r, g, b) = heat.sample(1.0 - 0.7 * t);
+                    ColorStop { t, r, g, b }
+                })
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::external::ExternalParams;
+    use crate::generators::NamedParam;
+
+    /// The hand-written ramp `fire` used before `classic`.
+    fn original_fire_color(v: f64) -> (u8, u8, u8) {
+        if v > 0.85 {
+            let t = (v - 0.85) / 0.15;
+            (255, (200.0 + 55.0 * t) as u8, (t * 200.0) as u8)
+        } else if v > 0.6 {
+            let t = (v - 0.6) / 0.25;
+            (255, (t * 200.0) as u8, 0)
+        } else if v > 0.3 {
+            let t = (v - 0.3) / 0.3;
+            ((100.0 + 155.0 * t) as u8, 0, 0)
+        } else {
+            let t = v / 0.3;
+            ((t * 100.0) as u8, 0, 0)
+        }
+    }
+
+    #[test]
+    fn classic_matches_original_fire_ramp() {
+        let gradient = Palette::Classic.gradient();
+        for i in 0..=200 {
+            let v = i as f64 / 200.0;
+            let (a, b) = (gradient.sample(v), original_fire_color(v));
+            let close = |x: u8, y: u8| x.abs_diff(y) <= 1;
+            assert!(
+                close(a.0, b.0) && close(a.1, b.1) && close(a.2, b.2),
+                "v={v}: {a:?} vs {b:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn param_accepts_name_or_index() {
+        let params = |json: &str| serde_json::from_str::<ExternalParams>(json).unwrap();
+        let from = |json| Palette::from_params(&params(json), "fire_palette");
+        assert_eq!(
+            from(r#"{"fire_palette": "blue-fire"}"#),
+            Some(Palette::BlueFire)
+        );
+        assert_eq!(from(r#"{"fire_palette": 4}"#), Some(Palette::Toxic));
+        assert_eq!(from(r#"{"fire_palette": "purple"}"#), None);
+        assert_eq!(from("{}"), None);
+        // Cooling starts at the palette's hottest color
+        assert_eq!(Palette::Ice.cooling().sample(0.0), (255, 255, 255));
+    }
+}
@@ -1,67 +1,271 @@
 use std::fs::File;
-use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
 use std::path::Path;
 use std::time::{Duration, Instant};
 
 use crossterm::{cursor, execute, terminal};
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use termflix::render::base64;
 
 /// A single recorded frame with its timestamp.
 pub struct Frame {
     pub timestamp_ms: u64,
     pub content: String,
+    /// Terminal `(cols, rows)` it was drawn for; `None` in older files
+    pub size: Option<(u16, u16)>,
+}
+
+/// Parse a `<cols>x<rows>` size, as in the `SIZE` header and frame stamps.
+fn parse_size(s: &str) -> Option<(u16, u16)> {
+    let (c, r) = s.split_once('x')?;
+    Some((c.parse().ok()?, r.parse().ok()?))
+}
+
+/// Size stamp appended to a frame's `T` line, or nothing if unknown.
+fn size_suffix(size: Option<(u16, u16)>) -> String {
+    size.map(|(c, r)| format!(" {c}x{r}")).unwrap_or_default()
+}
+
+/// Drop frames whose size stamp differs from both neighbours': a lone
+/// frame drawn at a transient size mid-resize. A lasting resize keeps its
+/// frames, since they agree with each other.
+fn drop_size_discontinuities(frames: Vec<Frame>) -> Vec<Frame> {
+    let sizes: Vec<_> = frames.iter().map(|f| f.size).collect();
+    frames
+        .into_iter()
+        .enumerate()
+        .filter(|&(i, ref f)| {
+            let (Some(size), Some(&Some(prev)), Some(&Some(next))) = (
+                f.size,
+                i.checked_sub(1).and_then(|j| sizes.get(j)),
+                sizes.get(i + 1),
+            ) else {
+                return true;
+            };
+            size == prev || size == next
+        })
+        .map(|(_, f)| f)
+        .collect()
+}
+
+/// What was captured, from the optional `ANIM`, `SIZE`, and `FPS` header
+/// lines. Older files and unparseable values leave fields `None`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Metadata {
+    pub anim: Option<String>,
+    /// Terminal `(cols, rows)` at capture time
+    pub size: Option<(u16, u16)>,
+    /// Target frame rate
+    pub fps: Option<u32>,
+}
+
+impl Metadata {
+    /// Header lines for the fields that are set, each ending in a newline.
+    fn header_lines(&self) -> String {
+        let mut lines = String::new();
+        if let Some(ref anim) = self.anim {
+            lines += &format!("ANIM {anim}\n");
+        }
+        if let Some((cols, rows)) = self.size {
+            lines += &format!("SIZE {cols}x{rows}\n");
+        }
+        if let Some(fps) = self.fps {
+            lines += &format!("FPS {fps}\n");
+        }
+        lines
+    }
+
+    /// Take one header line; unknown keys and bad values are ignored.
+    fn parse_line(&mut self, line: &str) {
+        let Some((key, value)) = line.split_once(' ') else {
+            return;
+        };
+        match key {
+            "ANIM" if !value.is_empty() => self.anim = Some(value.to_string()),
+            "SIZE" => self.size = parse_size(value),
+            "FPS" => self.fps = value.parse().ok(),
+            _ => {}
+        }
+    }
+
+    /// One-line summary for `--play`, e.g. `fire · 80x24 · 24 fps`, or
+    /// `None` for a file without metadata.
+    pub fn summary(&self) -> Option<String> {
+        let parts: Vec<String> = [
+            self.anim.clone(),
+            self.size.map(|(c, r)| format!("{c}x{r}")),
+            self.fps.map(|f| format!("{f} fps")),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        (!parts.is_empty()).then(|| parts.join(" · "))
+    }
 }
 
 /// Captures rendered frames with timestamps for later playback.
 pub struct Recorder {
     frames: Vec<Frame>,
     start: Instant,
+    /// Written into the `.asciianim` header by [`Recorder::save`]
+    pub metadata: Metadata,
 }
 
 impl Recorder {
-    /// Create a new Recorder.
+    /// Create a new Recorder with no header metadata.
+    #[allow(dead_code)]
     pub fn new() -> Self {
+        Self::with_metadata(Metadata::default())
+    }
+
+    /// Create a new Recorder that saves `metadata` in its header.
+    pub fn with_metadata(metadata: Metadata) -> Self {
         Recorder {
             frames: Vec::new(),
             start: Instant::now(),
+            metadata,
         }
     }
 
-    /// Record a rendered frame.
-    pub fn capture(&mut self, content: &str) {
-        let timestamp_ms = self.start.elapsed().as_millis() as u64;
+    /// Record a rendered frame drawn for a `size` (`cols`, `rows`) terminal.
+    pub fn capture(&mut self, content: &str, size: (u16, u16)) {
+        self.capture_at(content, self.start.elapsed().as_millis() as u64, size);
+    }
+
+    /// Record a rendered frame at an explicit timestamp, for offscreen
+    /// capture that steps a fixed `dt` instead of following wall time.
+    pub fn capture_at(&mut self, content: &str, timestamp_ms: u64, size: (u16, u16)) {
         self.frames.push(Frame {
             timestamp_ms,
             content: content.to_string(),
+            size: Some(size),
         });
     }
 
-    /// Save recorded frames to a .asciianim file.
+    /// Save as asciinema v2 when `path` ends in `.cast`, otherwise as a
+    /// compressed `.asciianim`. `cols`×`rows` is only used by the former.
+    pub fn save_for_path(&self, path: &Path, cols: u16, rows: u16) -> io::Result<()> {
+        if path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("cast"))
+        {
+            self.save_asciicast(path, cols, rows)
+        } else {
+            self.save(path, true)
+        }
+    }
+
+    /// Save recorded frames to a .asciianim file, gzip-compressed (v2) or
+    /// base64 text (v1).
+    ///
+    /// Both versions may carry `ANIM <name>`, `SIZE <cols>x<rows>`, and
+    /// `FPS <n>` lines between the version and frame-count lines, for
+    /// whichever [`Metadata`] fields are set. Each `T` line ends with the
+    /// frame's `<cols>x<rows>` when known, so playback can spot frames drawn
+    /// at a transient size.
     ///
-    /// Format:
+    /// v1:
     /// ```text
     /// ASCIIANIM v1
     /// FRAMES <count>
     /// ---
-    /// T <timestamp_ms>
+    /// T <timestamp_ms> [<cols>x<rows>]
     /// <frame content (base64 encoded)>
     /// ---
     /// ...
     /// ```
-    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
-        use std::io::Write as _;
+    ///
+    /// v2 indexes each frame by byte offset and length into the
+    /// concatenated frame contents, which follow `DATA` as one gzip stream
+    /// running to the end of the file:
+    /// ```text
+    /// ASCIIANIM v2
+    /// FRAMES <count>
+    /// T <timestamp_ms> <offset> <len> [<cols>x<rows>]
+    /// ...
+    /// DATA
+    /// <gzip bytes>
+    /// ```
+    pub fn save<P: AsRef<Path>>(&self, path: P, compressed: bool) -> io::Result<()> {
         let file = File::create(path)?;
         let mut writer = BufWriter::new(file);
+        if compressed {
+            self.write_v2(&mut writer)?;
+        } else {
+            self.write_v1(&mut writer)?;
+        }
+        writer.flush()
+    }
 
+    fn write_v1(&self, writer: &mut impl Write) -> io::Result<()> {
         writeln!(writer, "ASCIIANIM v1")?;
+        write!(writer, "{}", self.metadata.header_lines())?;
         writeln!(writer, "FRAMES {}", self.frames.len())?;
 
         for frame in &self.frames {
             writeln!(writer, "---")?;
-            writeln!(writer, "T {}", frame.timestamp_ms)?;
+            writeln!(
+                writer,
+                "T {}{}",
+                frame.timestamp_ms,
+                size_suffix(frame.size)
+            )?;
             // Base64 encode frame content to avoid delimiter conflicts
-            let encoded = base64_encode(frame.content.as_bytes());
+            let encoded = base64::encode(frame.content.as_bytes());
             writeln!(writer, "{}", encoded)?;
         }
+        Ok(())
+    }
+
+    fn write_v2(&self, writer: &mut impl Write) -> io::Result<()> {
+        writeln!(writer, "ASCIIANIM v2")?;
+        write!(writer, "{}", self.metadata.header_lines())?;
+        writeln!(writer, "FRAMES {}", self.frames.len())?;
+        let mut offset = 0;
+        for frame in &self.frames {
+            let len = frame.content.len();
+            writeln!(
+                writer,
+                "T {} {} {}{}",
+                frame.timestamp_ms,
+                offset,
+                len,
+                size_suffix(frame.size)
+            )?;
+            offset += len;
+        }
+        writeln!(writer, "DATA")?;
+
+        let mut gz = GzEncoder::new(writer, Compression::default());
+        for frame in &self.frames {
+            gz.write_all(frame.content.as_bytes())?;
+        }
+        gz.finish()?;
+        Ok(())
+    }
+
+    /// Save recorded frames as an asciinema v2 `.cast` file for a
+    /// `cols`×`rows` terminal: a JSON header line, then one
+    /// `[seconds, "o", data]` output event per frame. Each frame is prefixed
+    /// with a cursor-home escape (the first also clears the screen), since
+    /// frames are drawn from the top-left corner.
+    pub fn save_asciicast<P: AsRef<Path>>(&self, path: P, cols: u16, rows: u16) -> io::Result<()> {
+        let file = File::create(path)?;
+        let mut writer = BufWriter::new(file);
+
+        let header = serde_json::json!({ "version": 2, "width": cols, "height": rows });
+        writeln!(writer, "{header}")?;
+        for (i, frame) in self.frames.iter().enumerate() {
+            let home = if i == 0 { "\x1b[2J\x1b[H" } else { "\x1b[H" };
+            let event = serde_json::json!([
+                frame.timestamp_ms as f64 / 1000.0,
+                "o",
+                format!("{home}{}", frame.content),
+            ]);
+            writeln!(writer, "{event}")?;
+        }
 
         writer.flush()?;
         Ok(())
@@ -79,76 +283,102 @@ impl Recorder {
     }
 }
 
+/// Slowest and fastest playback speed multipliers.
+pub const MIN_SPEED: f64 = 0.1;
+pub const MAX_SPEED: f64 = 10.0;
+
+/// Factor one `+`/`-` press changes the playback speed by.
+const SPEED_STEP: f64 = 1.25;
+
+/// Shortest hold between `--loop` passes when the header has no `FPS`.
+const MIN_PASS_GAP: Duration = Duration::from_millis(100);
+
 /// Plays back a recorded .asciianim file.
 pub struct Player {
     frames: Vec<Frame>,
+    /// Header metadata; all `None` for files written before it existed
+    pub metadata: Metadata,
+    /// Playback speed multiplier (2.0 = twice as fast).
+    speed: f64,
 }
 
-impl Player {
-    /// Load a .asciianim file for playback.
-    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
-        let file = File::open(path)?;
-        let reader = BufReader::new(file);
-        let mut lines = reader.lines();
+/// Position in recording time, advancing with wall time scaled by the
+/// playback speed. Rebased whenever the speed changes so the position
+/// stays continuous.
+struct Playhead {
+    base_pos: Duration,
+    base_real: Instant,
+}
 
-        // Parse header
-        let header = lines
-            .next()
-            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "Missing header"))??;
-        if !header.starts_with("ASCIIANIM v1") {
-            return Err(io::Error::new(
-                io::ErrorKind::InvalidData,
-                format!("Invalid header: {}", header),
-            ));
+impl Playhead {
+    fn new() -> Self {
+        Playhead {
+            base_pos: Duration::ZERO,
+            base_real: Instant::now(),
         }
+    }
 
-        let frame_count_line = lines
-            .next()
-            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "Missing frame count"))??;
-        let _frame_count: usize = frame_count_line
-            .strip_prefix("FRAMES ")
-            .and_then(|s| s.parse().ok())
-            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "Invalid frame count"))?;
+    fn position(&self, speed: f64) -> Duration {
+        self.base_pos + self.base_real.elapsed().mul_f64(speed)
+    }
 
-        let mut frames = Vec::new();
+    fn rebase(&mut self, speed: f64) {
+        self.base_pos = self.position(speed);
+        self.base_real = Instant::now();
+    }
+}
 
-        while let Some(line) = lines.next() {
-            let line = line?;
-            if line != "---" {
-                continue;
+impl Player {
+    /// Load a .asciianim file (v1 or v2) for playback.
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let file = File::open(path)?;
+        let mut reader = BufReader::new(file);
+
+        let header = read_line(&mut reader)?
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "Missing header"))?;
+        // Metadata lines, if any, come before the frame count
+        let mut metadata = Metadata::default();
+        let frame_count_line = loop {
+            let line = read_line(&mut reader)?
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "Missing frame count"))?;
+            if line.starts_with("FRAMES ") {
+                break line;
             }
+            metadata.parse_line(&line);
+        };
+        let frame_count: usize = frame_count_line
+            .strip_prefix("FRAMES ")
+            .and_then(|s| s.parse().ok())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "Invalid frame count"))?;
 
-            // Read timestamp
-            let t_line = lines.next().ok_or_else(|| {
-                io::Error::new(io::ErrorKind::UnexpectedEof, "Missing timestamp")
-            })??;
-            let timestamp_ms: u64 = t_line
-                .strip_prefix("T ")
-                .and_then(|s| s.parse().ok())
-                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "Invalid timestamp"))?;
-
-            // Read base64 encoded content
-            let encoded = lines.next().ok_or_else(|| {
-                io::Error::new(io::ErrorKind::UnexpectedEof, "Missing frame content")
-            })??;
-
-            let content_bytes = base64_decode(&encoded).map_err(|e| {
-                io::Error::new(
+        let frames = match header.as_str() {
+            h if h.starts_with("ASCIIANIM v1") => load_v1(reader)?,
+            h if h.starts_with("ASCIIANIM v2") => load_v2(reader, frame_count)?,
+            _ => {
+                return Err(io::Error::new(
                     io::ErrorKind::InvalidData,
-                    format!("Base64 decode error: {}", e),
-                )
-            })?;
-            let content = String::from_utf8(content_bytes).map_err(|e| {
-                io::Error::new(io::ErrorKind::InvalidData, format!("UTF-8 error: {}", e))
-            })?;
-
-            frames.push(Frame {
-                timestamp_ms,
-                content,
-            });
+                    format!("Invalid header: {}", header),
+                ));
+            }
+        };
+        // Playback waits for each timestamp in turn, so one that goes
+        // backwards (hand-edited or spliced files) can't be played
+        if let Some(i) = frames
+            .windows(2)
+            .position(|w| w[1].timestamp_ms < w[0].timestamp_ms)
+        {
+            return Err(invalid(format!(
+                "Frame {} timestamp goes backwards ({} ms after {} ms)",
+                i + 2,
+                frames[i + 1].timestamp_ms,
+                frames[i].timestamp_ms
+            )));
         }
-
-        Ok(Player { frames })
+        Ok(Player {
+            frames: drop_size_discontinuities(frames),
+            metadata,
+            speed: 1.0,
+        })
     }
 
     /// Access the recorded frames.
@@ -156,117 +386,235 @@ impl Player {
         &self.frames
     }
 
-    /// Play back the recording to the terminal.
-    pub fn play(&self) -> io::Result<()> {
-        if self.frames.is_empty() {
+    /// Set the playback speed multiplier, clamped to [`MIN_SPEED`]..=[`MAX_SPEED`]
+    /// (NaN keeps the recorded speed).
+    pub fn set_speed(&mut self, speed: f64) {
+        if !speed.is_nan() {
+            self.speed = speed.clamp(MIN_SPEED, MAX_SPEED);
+        }
+    }
+
+    /// Recorded time from the first frame to the last.
+    fn span(&self) -> Duration {
+        match (self.frames.first(), self.frames.last()) {
+            (Some(first), Some(last)) => {
+                Duration::from_millis(last.timestamp_ms - first.timestamp_ms)
+            }
+            _ => Duration::ZERO,
+        }
+    }
+
+    /// How long the last frame holds between passes: one average frame
+    /// interval, but never less than one `FPS` frame (or [`MIN_PASS_GAP`]),
+    /// so a recording whose frames share a timestamp still paces its loop.
+    fn pass_gap(&self) -> Duration {
+        let floor = self
+            .metadata
+            .fps
+            .filter(|&fps| fps > 0)
+            .map_or(MIN_PASS_GAP, |fps| Duration::from_secs(1) / fps);
+        (self.span() / (self.frames.len() as u32).saturating_sub(1).max(1)).max(floor)
+    }
+
+    /// Play back the recording to the terminal `passes` times, or until
+    /// quit if `passes` is 0. Each pass restarts the clock at its first
+    /// frame, so timing never drifts however long it loops. `+`/`-` change
+    /// the speed while playing.
+    pub fn play(&self, passes: u32) -> io::Result<()> {
+        let Some(first) = self.frames.first() else {
             println!("No frames to play.");
             return Ok(());
-        }
+        };
+        let span = self.span();
+        let gap = self.pass_gap();
 
         terminal::enable_raw_mode()?;
         let mut stdout = io::stdout();
         execute!(stdout, terminal::EnterAlternateScreen, cursor::Hide)?;
 
-        let start = Instant::now();
-
-        for frame in &self.frames {
-            // Wait until the correct time
-            let target = Duration::from_millis(frame.timestamp_ms);
-            let elapsed = start.elapsed();
-            if target > elapsed {
-                std::thread::sleep(target - elapsed);
+        let mut speed = self.speed;
+        let mut played = 0;
+        let mut size = first.size;
+        'playback: loop {
+            let mut playhead = Playhead::new();
+            for frame in &self.frames {
+                // Wait until the correct time, handling keys as they arrive
+                let target = Duration::from_millis(frame.timestamp_ms - first.timestamp_ms);
+                if !wait_until(&mut playhead, &mut speed, target)? {
+                    break 'playback;
+                }
+
+                // A smaller frame wouldn't cover everything the last one drew
+                if frame.size != size {
+                    size = frame.size;
+                    execute!(stdout, terminal::Clear(terminal::ClearType::All))?;
+                }
+                execute!(stdout, cursor::MoveTo(0, 0))?;
+                stdout.write_all(frame.content.as_bytes())?;
+                stdout.flush()?;
             }
-
-            // Check for quit
-            if crossterm::event::poll(Duration::ZERO)?
-                && let crossterm::event::Event::Key(key) = crossterm::event::read()?
-                && matches!(
-                    key.code,
-                    crossterm::event::KeyCode::Char('q') | crossterm::event::KeyCode::Esc
-                )
-            {
+            played += 1;
+            if played == passes || !wait_until(&mut playhead, &mut speed, span + gap)? {
                 break;
             }
-
-            execute!(stdout, cursor::MoveTo(0, 0))?;
-            stdout.write_all(frame.content.as_bytes())?;
-            stdout.flush()?;
         }
 
         execute!(stdout, cursor::Show, terminal::LeaveAlternateScreen)?;
         terminal::disable_raw_mode()?;
 
+        let repeats = if played > 1 {
+            format!(", {played} passes")
+        } else {
+            String::new()
+        };
         println!(
-            "Playback complete: {} frames, {:.1}s",
+            "Playback complete: {} frames, {:.1}s{repeats}",
             self.frames.len(),
-            self.frames.last().map_or(0, |f| f.timestamp_ms) as f64 / 1000.0
+            span.as_secs_f64()
         );
 
         Ok(())
     }
 }
 
-// Simple base64 encoder/decoder (no external dependency needed)
-
-const B64_CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
-
-fn base64_encode(data: &[u8]) -> String {
-    let mut result = String::with_capacity(data.len().div_ceil(3) * 4);
-    for chunk in data.chunks(3) {
-        let b0 = chunk[0] as u32;
-        let b1 = if chunk.len() > 1 { chunk[1] as u32 } else { 0 };
-        let b2 = if chunk.len() > 2 { chunk[2] as u32 } else { 0 };
-        let triple = (b0 << 16) | (b1 << 8) | b2;
-
-        result.push(B64_CHARS[((triple >> 18) & 0x3F) as usize] as char);
-        result.push(B64_CHARS[((triple >> 12) & 0x3F) as usize] as char);
-        if chunk.len() > 1 {
-            result.push(B64_CHARS[((triple >> 6) & 0x3F) as usize] as char);
-        } else {
-            result.push('=');
+/// Handle playback keys until `playhead` reaches `target`; `false` if the
+/// user quit. Keys are polled at least once even when `target` has already
+/// passed, so quit works however tightly the frames are packed.
+fn wait_until(playhead: &mut Playhead, speed: &mut f64, target: Duration) -> io::Result<bool> {
+    use crossterm::event::{self, Event, KeyCode, KeyModifiers};
+
+    loop {
+        let wait = target
+            .saturating_sub(playhead.position(*speed))
+            .div_f64(*speed);
+        if !event::poll(wait)? {
+            if wait.is_zero() {
+                return Ok(true);
+            }
+            continue;
         }
-        if chunk.len() > 2 {
-            result.push(B64_CHARS[(triple & 0x3F) as usize] as char);
-        } else {
-            result.push('=');
+        if let Event::Key(key) = event::read()? {
+            let step = match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(false),
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    return Ok(false);
+                }
+                KeyCode::Char('+') | KeyCode::Char('=') => SPEED_STEP,
+                KeyCode::Char('-') | KeyCode::Char('_') => 1.0 / SPEED_STEP,
+                _ => continue,
+            };
+            playhead.rebase(*speed);
+            *speed = (*speed * step).clamp(MIN_SPEED, MAX_SPEED);
         }
     }
-    result
 }
 
-fn base64_decode(data: &str) -> Result<Vec<u8>, String> {
-    let data: Vec<u8> = data.bytes().filter(|&b| b != b'\n' && b != b'\r').collect();
-    if !data.len().is_multiple_of(4) {
-        return Err("Invalid base64 length".to_string());
-    }
-
-    let mut result = Vec::with_capacity(data.len() / 4 * 3);
-
-    for chunk in data.chunks(4) {
-        let mut vals = [0u32; 4];
-        for (i, &byte) in chunk.iter().enumerate() {
-            vals[i] = match byte {
-                b'A'..=b'Z' => (byte - b'A') as u32,
-                b'a'..=b'z' => (byte - b'a' + 26) as u32,
-                b'0'..=b'9' => (byte - b'0' + 52) as u32,
-                b'+' => 62,
-                b'/' => 63,
-                b'=' => 0,
-                _ => return Err(format!("Invalid base64 character: {}", byte as char)),
-            };
-        }
+/// Next line without its newline, or `None` at end of file.
+fn read_line(reader: &mut impl BufRead) -> io::Result<Option<String>> {
+    let mut line = String::new();
+    if reader.read_line(&mut line)? == 0 {
+        return Ok(None);
+    }
+    line.truncate(line.trim_end_matches(['\r', '\n']).len());
+    Ok(Some(line))
+}
 
-        let triple = (vals[0] << 18) | (vals[1] << 12) | (vals[2] << 6) | vals[3];
-        result.push(((triple >> 16) & 0xFF) as u8);
-        if chunk[2] != b'=' {
-            result.push(((triple >> 8) & 0xFF) as u8);
-        }
-        if chunk[3] != b'=' {
-            result.push((triple & 0xFF) as u8);
+fn invalid(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+/// Frames of a v1 file, after the header and frame-count lines.
+fn load_v1(reader: impl BufRead) -> io::Result<Vec<Frame>> {
+    let mut lines = reader.lines();
+    let mut frames = Vec::new();
+
+    while let Some(line) = lines.next() {
+        let line = line?;
+        if line != "---" {
+            continue;
         }
+
+        // Read timestamp
+        let t_line = lines
+            .next()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::UnexpectedEof, "Missing timestamp"))??;
+        let (timestamp_ms, size) = t_line
+            .strip_prefix("T ")
+            .and_then(|s| {
+                let (ts, size) = match s.split_once(' ') {
+                    Some((ts, size)) => (ts, Some(parse_size(size)?)),
+                    None => (s, None),
+                };
+                Some((ts.parse::<u64>().ok()?, size))
+            })
+            .ok_or_else(|| invalid("Invalid timestamp".into()))?;
+
+        // Read base64 encoded content
+        let encoded = lines.next().ok_or_else(|| {
+            io::Error::new(io::ErrorKind::UnexpectedEof, "Missing frame content")
+        })??;
+
+        let content_bytes =
+            base64::decode(&encoded).map_err(|e| invalid(format!("Base64 decode error: {}", e)))?;
+        let content =
+            String::from_utf8(content_bytes).map_err(|e| invalid(format!("UTF-8 error: {}", e)))?;
+
+        frames.push(Frame {
+            timestamp_ms,
+            content,
+            size,
+        });
+    }
+
+    Ok(frames)
+}
+
+/// Frames of a v2 file, after the header and frame-count lines: the
+/// `frame_count` index lines, `DATA`, then the gzip stream.
+fn load_v2(mut reader: impl BufRead, frame_count: usize) -> io::Result<Vec<Frame>> {
+    // Timestamp, offset, length, and size stamp of each frame
+    type IndexEntry = (u64, usize, usize, Option<(u16, u16)>);
+    let mut index = Vec::with_capacity(frame_count);
+    for _ in 0..frame_count {
+        let line = read_line(&mut reader)?
+            .ok_or_else(|| io::Error::new(io::ErrorKind::UnexpectedEof, "Missing frame index"))?;
+        let parse = |line: &str| -> Option<IndexEntry> {
+            let mut fields = line.strip_prefix("T ")?.split(' ');
+            let entry = (
+                fields.next()?.parse().ok()?,
+                fields.next()?.parse().ok()?,
+                fields.next()?.parse().ok()?,
+                match fields.next() {
+                    Some(size) => Some(parse_size(size)?),
+                    None => None,
+                },
+            );
+            fields.next().is_none().then_some(entry)
+        };
+        index.push(parse(&line).ok_or_else(|| invalid(format!("Invalid frame index: {}", line)))?);
+    }
+    if read_line(&mut reader)?.as_deref() != Some("DATA") {
+        return Err(invalid("Missing DATA marker".into()));
     }
 
-    Ok(result)
+    let mut data = Vec::new();
+    GzDecoder::new(reader).read_to_end(&mut data)?;
+    index
+        .into_iter()
+        .map(|(timestamp_ms, offset, len, size)| {
+            let bytes = offset
+                .checked_add(len)
+                .and_then(|end| data.get(offset..end))
+                .ok_or_else(|| invalid("Frame index past end of data".into()))?;
+            let content = String::from_utf8(bytes.to_vec())
+                .map_err(|e| invalid(format!("UTF-8 error: {}", e)))?;
+            Ok(Frame {
+                timestamp_ms,
+                content,
+                size,
+            })
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -274,26 +622,228 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_base64_roundtrip_empty() {
-        let input: &[u8] = b"";
-        let encoded = base64_encode(input);
-        let decoded = base64_decode(&encoded).unwrap();
-        assert_eq!(decoded, input);
+    fn test_asciicast_header_and_events() {
+        let mut rec = Recorder::new();
+        for (ms, content) in [(0, "\x1b[31mA"), (1500, "B\"")] {
+            rec.frames.push(Frame {
+                timestamp_ms: ms,
+                content: content.into(),
+                size: None,
+            });
+        }
+        let path = std::env::temp_dir().join(format!("termflix-{}.cast", std::process::id()));
+        rec.save_asciicast(&path, 80, 24).unwrap();
+        let text = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        let lines: Vec<serde_json::Value> = text
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(
+            lines[0],
+            serde_json::json!({ "version": 2, "width": 80, "height": 24 })
+        );
+        assert_eq!(
+            lines[1],
+            serde_json::json!([0.0, "o", "\x1b[2J\x1b[H\x1b[31mA"])
+        );
+        assert_eq!(lines[2], serde_json::json!([1.5, "o", "\x1b[HB\""]));
+        assert_eq!(lines.len(), 3);
+    }
+
+    fn sample_recorder() -> Recorder {
+        let mut rec = Recorder::new();
+        for (ms, content, size) in [
+            (0, "\x1b[31m▀▀\x1b[0m\n---", Some((80, 24))),
+            (40, "", None),
+            (83, "frame three", Some((80, 24))),
+        ] {
+            rec.frames.push(Frame {
+                timestamp_ms: ms,
+                content: content.into(),
+                size,
+            });
+        }
+        rec
+    }
+
+    type FrameParts = (u64, String, Option<(u16, u16)>);
+
+    fn parts(frames: &[Frame]) -> Vec<FrameParts> {
+        frames
+            .iter()
+            .map(|f| (f.timestamp_ms, f.content.clone(), f.size))
+            .collect()
+    }
+
+    fn round_trip(compressed: bool) -> Vec<FrameParts> {
+        let path = std::env::temp_dir().join(format!(
+            "termflix-{}-{compressed}.asciianim",
+            std::process::id()
+        ));
+        sample_recorder().save(&path, compressed).unwrap();
+        let player = Player::load(&path);
+        let _ = std::fs::remove_file(&path);
+        parts(player.unwrap().frames())
+    }
+
+    #[test]
+    fn test_v1_and_v2_round_trip() {
+        let expected = parts(sample_recorder().frames());
+        assert_eq!(round_trip(false), expected);
+        assert_eq!(round_trip(true), expected);
+    }
+
+    #[test]
+    fn test_load_rejects_timestamps_that_go_backwards() {
+        let path = std::env::temp_dir().join(format!(
+            "termflix-backwards-{}.asciianim",
+            std::process::id()
+        ));
+        let mut file = String::from("ASCIIANIM v1\nFRAMES 2\n");
+        for ms in [500, 100] {
+            file += &format!("---\nT {ms}\n{}\n", base64::encode(b"frame"));
+        }
+        std::fs::write(&path, file).unwrap();
+        let player = Player::load(&path);
+        let _ = std::fs::remove_file(&path);
+        let err = player.err().expect("out-of-order timestamps load");
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.to_string().contains("Frame 2"), "{err}");
+    }
+
+    #[test]
+    fn test_lone_transient_size_frames_are_dropped() {
+        let kept = |sizes: &[Option<(u16, u16)>]| -> Vec<String> {
+            let frames = sizes
+                .iter()
+                .enumerate()
+                .map(|(i, &size)| Frame {
+                    timestamp_ms: i as u64 * 40,
+                    content: i.to_string(),
+                    size,
+                })
+                .collect();
+            drop_size_discontinuities(frames)
+                .into_iter()
+                .map(|f| f.content)
+                .collect()
+        };
+        let (a, b, c) = (Some((80, 24)), Some((100, 30)), Some((60, 20)));
+        // A one-frame blip mid-resize goes; the settled new size stays
+        assert_eq!(kept(&[a, a, c, b, b]), ["0", "1", "3", "4"]);
+        // Unstamped frames and the first and last frame are never dropped
+        assert_eq!(kept(&[a, None, b]), ["0", "1", "2"]);
+        assert_eq!(kept(&[a, b]), ["0", "1"]);
     }
 
     #[test]
-    fn test_base64_roundtrip_hello() {
-        let input = b"hello";
-        let encoded = base64_encode(input);
-        let decoded = base64_decode(&encoded).unwrap();
-        assert_eq!(decoded, input);
+    fn test_metadata_round_trip_and_lenient_parse() {
+        let metadata = Metadata {
+            anim: Some("fire".into()),
+            size: Some((120, 40)),
+            fps: Some(30),
+        };
+        for compressed in [false, true] {
+            let path = std::env::temp_dir().join(format!(
+                "termflix-meta-{}-{compressed}.asciianim",
+                std::process::id()
+            ));
+            let mut rec = sample_recorder();
+            rec.metadata = metadata.clone();
+            rec.save(&path, compressed).unwrap();
+            let player = Player::load(&path);
+            let _ = std::fs::remove_file(&path);
+            let player = player.unwrap();
+            assert_eq!(player.metadata, metadata);
+            assert_eq!(player.frames().len(), 3);
+        }
+        assert_eq!(
+            metadata.summary().as_deref(),
+            Some("fire · 120x40 · 30 fps")
+        );
+        assert_eq!(Metadata::default().summary(), None);
+
+        let mut parsed = Metadata::default();
+        for line in ["SIZE wide", "FPS 24", "TITLE something new", "ANIM "] {
+            parsed.parse_line(line);
+        }
+        assert_eq!(
+            parsed,
+            Metadata {
+                fps: Some(24),
+                ..Metadata::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_v2_compresses_repetitive_frames() {
+        let mut rec = Recorder::new();
+        let frame = "\x1b[38;2;200;40;40m▀".repeat(2000);
+        for i in 0..50 {
+            rec.frames.push(Frame {
+                timestamp_ms: i * 40,
+                content: frame.clone(),
+                size: Some((80, 24)),
+            });
+        }
+        let (mut v1, mut v2) = (Vec::new(), Vec::new());
+        rec.write_v1(&mut v1).unwrap();
+        rec.write_v2(&mut v2).unwrap();
+        assert!(v2.len() * 100 < v1.len(), "v1 {} v2 {}", v1.len(), v2.len());
+    }
+
+    #[test]
+    fn test_play_speed_is_clamped() {
+        let mut player = Player {
+            frames: Vec::new(),
+            metadata: Metadata::default(),
+            speed: 1.0,
+        };
+        player.set_speed(f64::NAN);
+        assert_eq!(player.speed, 1.0);
+        player.set_speed(50.0);
+        assert_eq!(player.speed, MAX_SPEED);
+        player.set_speed(0.0);
+        assert_eq!(player.speed, MIN_SPEED);
+    }
+
+    #[test]
+    fn test_single_frame_loop_still_paces() {
+        let mut player = Player {
+            frames: vec![Frame {
+                timestamp_ms: 0,
+                content: "only".into(),
+                size: None,
+            }],
+            metadata: Metadata::default(),
+            speed: 1.0,
+        };
+        assert_eq!(player.span(), Duration::ZERO);
+        assert_eq!(player.pass_gap(), MIN_PASS_GAP);
+        player.metadata.fps = Some(25);
+        assert_eq!(player.pass_gap(), Duration::from_millis(40));
+        // Spread-out frames keep their average interval
+        player.frames.push(Frame {
+            timestamp_ms: 500,
+            content: "two".into(),
+            size: None,
+        });
+        assert_eq!(player.pass_gap(), Duration::from_millis(500));
     }
 
     #[test]
-    fn test_base64_roundtrip_all_bytes() {
-        let input: Vec<u8> = (0u8..=255u8).collect();
-        let encoded = base64_encode(&input);
-        let decoded = base64_decode(&encoded).unwrap();
-        assert_eq!(decoded, input);
+    fn test_playhead_rebase_keeps_position() {
+        let mut playhead = Playhead {
+            base_pos: Duration::from_secs(3),
+            base_real: Instant::now() - Duration::from_secs(1),
+        };
+        let before = playhead.position(2.0);
+        playhead.rebase(2.0);
+        assert!(playhead.base_pos >= before);
+        assert!(playhead.base_pos - before < Duration::from_millis(100));
+        assert!(before >= Duration::from_secs(5));
     }
 }
@@ -0,0 +1,103 @@
+//! Standard base64 (RFC 4648) for v1 recording frames, kitty graphics
+//! payloads and OSC 52 clipboard text.
+
+const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Standard base64 with padding.
+pub fn encode(data: &[u8]) -> String {
+    let mut result = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let b0 = chunk[0] as u32;
+        let b1 = if chunk.len() > 1 { chunk[1] as u32 } else { 0 };
+        let b2 = if chunk.len() > 2 { chunk[2] as u32 } else { 0 };
+        let triple = (b0 << 16) | (b1 << 8) | b2;
+
+        result.push(ALPHABET[((triple >> 18) & 0x3F) as usize] as char);
+        result.push(ALPHABET[((triple >> 12) & 0x3F) as usize] as char);
+        if chunk.len() > 1 {
+            result.push(ALPHABET[((triple >> 6) & 0x3F) as usize] as char);
+        } else {
+            result.push('=');
+        }
+        if chunk.len() > 2 {
+            result.push(ALPHABET[(triple & 0x3F) as usize] as char);
+        } else {
+            result.push('=');
+        }
+    }
+    result
+}
+
+/// Decode standard padded base64; line breaks are skipped.
+pub fn decode(data: &str) -> Result<Vec<u8>, String> {
+    let data: Vec<u8> = data.bytes().filter(|&b| b != b'\n' && b != b'\r').collect();
+    if !data.len().is_multiple_of(4) {
+        return Err("Invalid base64 length".to_string());
+    }
+
+    let mut result = Vec::with_capacity(data.len() / 4 * 3);
+
+    for chunk in data.chunks(4) {
+        let mut vals = [0u32; 4];
+        for (i, &byte) in chunk.iter().enumerate() {
+            vals[i] = match byte {
+                b'A'..=b'Z' => (byte - b'A') as u32,
+                b'a'..=b'z' => (byte - b'a' + 26) as u32,
+                b'0'..=b'9' => (byte - b'0' + 52) as u32,
+                b'+' => 62,
+                b'/' => 63,
+                b'=' => 0,
+                _ => return Err(format!("Invalid base64 character: {}", byte as char)),
+            };
+        }
+
+        let triple = (vals[0] << 18) | (vals[1] << 12) | (vals[2] << 6) | vals[3];
+        result.push(((triple >> 16) & 0xFF) as u8);
+        if chunk[2] != b'=' {
+            result.push(((triple >> 8) & 0xFF) as u8);
+        }
+        if chunk[3] != b'=' {
+            result.push((triple & 0xFF) as u8);
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_rfc4648_vectors() {
+        assert_eq!(encode(b""), "");
+        assert_eq!(encode(b"f"), "Zg==");
+        assert_eq!(encode(b"fo"), "Zm8=");
+        assert_eq!(encode(b"foo"), "Zm9v");
+        assert_eq!(encode(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[test]
+    fn roundtrip_empty() {
+        let input: &[u8] = b"";
+        let encoded = encode(input);
+        let decoded = decode(&encoded).unwrap();
+        assert_eq!(decoded, input);
+    }
+
+    #[test]
+    fn roundtrip_hello() {
+        let input = b"hello";
+        let encoded = encode(input);
+        let decoded = decode(&encoded).unwrap();
+        assert_eq!(decoded, input);
+    }
+
+    #[test]
+    fn roundtrip_all_bytes() {
+        let input: Vec<u8> = (0u8..=255u8).collect();
+        let encoded = encode(&input);
+        let decoded = decode(&encoded).unwrap();
+        assert_eq!(decoded, input);
+    }
+}
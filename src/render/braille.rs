@@ -23,59 +23,111 @@ const DOT_MAP: [(usize, usize, u32); 8] = [
 /// Calibrated so mid-intensity animations fill ~50% of dots.
 const BRIGHTNESS_THRESHOLD: f64 = 0.3;
 
-pub fn render(canvas: &Canvas) -> String {
-    super::encoder::encode_full(&build_grid(canvas), true)
-}
-
 pub fn build_grid(canvas: &Canvas) -> CellGrid {
     let cols = canvas.width / 2;
     let rows = canvas.height / 4;
     let use_color = canvas.color_mode != ColorMode::Mono;
+    let threshold = canvas.lit_threshold(BRIGHTNESS_THRESHOLD);
     let mut cells = Vec::with_capacity(cols * rows);
     for row in 0..rows {
         for col in 0..cols {
             let px = col * 2;
             let py = row * 4;
             let mut bits: u32 = 0;
-            let mut total_r: u32 = 0;
-            let mut total_g: u32 = 0;
-            let mut total_b: u32 = 0;
-            let mut lit_count: u32 = 0;
+            // Brightness-weighted RGB sums of the lit dots; a cell can carry only
+            // one foreground color, so mixed-color cells blend instead of bleeding.
+            let mut total_r = 0.0;
+            let mut total_g = 0.0;
+            let mut total_b = 0.0;
+            let mut total_w = 0.0;
             for &(dx, dy, bit) in &DOT_MAP {
                 let x = px + dx;
                 let y = py + dy;
                 if x < canvas.width && y < canvas.height {
                     let idx = y * canvas.width + x;
-                    if canvas.pixels[idx] > BRIGHTNESS_THRESHOLD {
+                    let v = canvas.pixels[idx];
+                    if v > threshold {
                         bits |= bit;
                         let (r, g, b) = canvas.colors[idx];
-                        total_r += r as u32;
-                        total_g += g as u32;
-                        total_b += b as u32;
-                        lit_count += 1;
+                        let w = v.min(1.0);
+                        total_r += r as f64 * w;
+                        total_g += g as f64 * w;
+                        total_b += b as f64 * w;
+                        total_w += w;
                     }
                 }
             }
             debug_assert!(bits <= 0xFF);
             let ch = char::from_u32(BRAILLE_OFFSET + bits).expect("valid braille");
-            let cell = if use_color && lit_count > 0 {
-                let r = (total_r / lit_count) as u8;
-                let g = (total_g / lit_count) as u8;
-                let b = (total_b / lit_count) as u8;
-                Cell {
-                    ch,
-                    fg: Some(canvas.map_color(col, row, r, g, b)),
-                    bg: None,
-                }
-            } else {
-                Cell {
-                    ch,
-                    fg: None,
-                    bg: None,
-                }
-            };
-            cells.push(cell);
+            let fg = (use_color && total_w > 0.0).then(|| {
+                let r = (total_r / total_w) as u8;
+                let g = (total_g / total_w) as u8;
+                let b = (total_b / total_w) as u8;
+                canvas.map_color(col, row, r, g, b)
+            });
+            let bg = canvas.cell_bg(col, row, 2, 4);
+            cells.push(Cell {
+                ch,
+                fg,
+                bg,
+                attrs: 0,
+            });
         }
     }
     CellGrid { cols, rows, cells }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::RenderMode;
+    use crossterm::style::Color;
+
+    #[test]
+    fn two_colored_dots_blend_weighted_by_brightness() {
+        let mut c = Canvas::new(1, 1, RenderMode::Braille, ColorMode::TrueColor);
+        c.set_colored(0, 0, 1.0, 255, 0, 0);
+        c.set_colored(1, 3, 0.5, 0, 0, 255);
+        let cell = build_grid(&c).get(0, 0);
+        assert_eq!(
+            cell.ch,
+            char::from_u32(BRAILLE_OFFSET + 0x01 + 0x80).unwrap()
+        );
+        assert_eq!(
+            cell.fg,
+            Some(Color::Rgb {
+                r: 170,
+                g: 0,
+                b: 85
+            })
+        );
+    }
+
+    #[test]
+    fn equal_brightness_dots_average_evenly() {
+        let mut c = Canvas::new(1, 1, RenderMode::Braille, ColorMode::TrueColor);
+        c.set_colored(0, 0, 0.8, 200, 0, 0);
+        c.set_colored(1, 0, 0.8, 0, 100, 0);
+        let cell = build_grid(&c).get(0, 0);
+        assert_eq!(
+            cell.fg,
+            Some(Color::Rgb {
+                r: 100,
+                g: 50,
+                b: 0
+            })
+        );
+    }
+
+    #[test]
+    fn mono_threshold_drops_faint_dots_only_in_mono() {
+        let mut c = Canvas::new(1, 1, RenderMode::Braille, ColorMode::Mono);
+        c.set(0, 0, 1.0);
+        c.set(1, 0, 0.4);
+        c.mono_threshold = 0.5;
+        let lit = |c: &Canvas| build_grid(c).get(0, 0).ch as u32 - BRAILLE_OFFSET;
+        assert_eq!(lit(&c), 0x01);
+        c.color_mode = ColorMode::TrueColor;
+        assert_eq!(lit(&c), 0x01 | 0x08);
+    }
+}
@@ -1,17 +1,75 @@
 use super::cell::{Cell, CellGrid};
 use super::color_assist::{ColorAssist, daltonize, luminance};
+use super::font::Font;
 use crossterm::style::Color;
 
+/// Overlay text cell: character plus its RGB color.
+pub type OverlayChar = (char, (u8, u8, u8));
+
+/// Default terminal cell height:width ratio (typical monospace fonts are ~2:1).
+pub const DEFAULT_CELL_ASPECT: f64 = 2.0;
+
+/// ASCII mode characters from darkest to brightest.
+pub const DEFAULT_ASCII_RAMP: &str = " .:-=+*#%@";
+
 /// 4×4 Bayer ordered-dither thresholds (values 0..=15).
 const BAYER_4X4: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
 
+/// Dither amplitude for ANSI-16: roughly the gap between xterm's channel
+/// levels (0, 127–205, 255), as 51 is the gap between ANSI-256 cube levels.
+const ANSI16_DITHER_STEP: f64 = 128.0;
+
+/// xterm's default 16 ANSI colors: the reference `ColorMode::Ansi16` matches
+/// against, and `ColorMode::Palette`'s until the terminal's own is read.
+pub const XTERM_PALETTE: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// The named colors at each `XTERM_PALETTE` index.
+const ANSI16_COLORS: [Color; 16] = [
+    Color::Black,
+    Color::DarkRed,
+    Color::DarkGreen,
+    Color::DarkYellow,
+    Color::DarkBlue,
+    Color::DarkMagenta,
+    Color::DarkCyan,
+    Color::Grey,
+    Color::DarkGrey,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::White,
+];
+
 /// How to render sub-cell pixels to terminal characters
 #[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
 pub enum RenderMode {
     /// Unicode braille characters (2x4 per cell = highest resolution)
     Braille,
     /// Half-block characters ▀▄█ (1x2 per cell)
+    #[value(alias = "halfblock")]
     HalfBlock,
+    /// Quadrant block characters ▘▝▖▗▚▞… (2x2 per cell, one color per cell)
+    Quadrant,
     /// Plain ASCII characters with density mapping
     Ascii,
 }
@@ -26,15 +84,44 @@ pub enum ColorMode {
     /// 256-color palette
     Ansi256,
     /// 24-bit true color (RGB)
+    #[value(alias = "truecolor")]
     TrueColor,
+    /// The terminal's own 16-color palette, read with `--theme-from-terminal`
+    /// (standard xterm colors until then)
+    Palette,
+}
+
+/// Which axes [`Canvas::flip`] mirrors the picture across.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum Mirror {
+    #[default]
+    None,
+    /// Left-right
+    H,
+    /// Upside down
+    V,
+    /// Both (a 180° rotation)
+    Both,
 }
 
+/// `char_override` glyphs that point the other way when mirrored left-right.
+const H_MIRRORED: [(char, char); 5] = [('/', '\\'), ('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+
+/// `char_override` glyphs that point the other way when mirrored upside down.
+const V_MIRRORED: [(char, char); 1] = [('/', '\\')];
+
 #[derive(Debug, Clone, Copy, Default)]
 pub struct PostProcessConfig {
     pub bloom: f64,
     pub bloom_threshold: f64,
     pub vignette: f64,
     pub scanlines: bool,
+    /// Block width in pixels for [`Canvas::pixelate`]; 0 or 1 is off.
+    pub pixelate: usize,
+    /// Mirror the frame with [`Canvas::flip`] before the other effects.
+    pub mirror: Mirror,
+    /// Fold count for [`Canvas::kaleidoscope`]; 0 or 1 is off.
+    pub kaleidoscope: usize,
 }
 
 /// A pixel-level canvas that gets rendered to terminal characters.
@@ -48,20 +135,41 @@ pub struct Canvas {
     pub pixels: Vec<f64>,
     /// Per-pixel color (optional — used when color mode != Mono)
     pub colors: Vec<(u8, u8, u8)>,
+    /// Per-pixel background color, shown behind the unlit part of a cell.
+    /// Black (the default) means none: the terminal background shows through.
+    pub bg_colors: Vec<(u8, u8, u8)>,
     pub render_mode: RenderMode,
     pub color_mode: ColorMode,
     /// Optional per-cell character override (ASCII mode only).
     /// When set (non-\0), this char is used instead of brightness-mapped ASCII.
     pub char_override: Vec<char>,
+    /// Per-pixel SGR attributes (`ATTR_BOLD`/`ATTR_DIM`), ASCII mode only.
+    pub attrs: Vec<u8>,
     /// Color quantization step (0 = off, 4/8/16 = round RGB to nearest N).
     /// Higher values = fewer unique colors = better dedup = less output.
     pub color_quant: u8,
     /// Apply 4×4 Bayer ordered dithering when quantizing to ANSI-256.
     pub dither: bool,
+    /// Mono mode only: pixels below this brightness render unlit in every
+    /// render mode (0 = each renderer's own cutoff).
+    pub mono_threshold: f64,
     /// Previous-frame brightness, used by temporal smoothing.
     /// NOT touched by `clear()` — persists across the per-frame wipe.
     /// `None` until first use; resets to `None` on `Canvas::new()`.
     pub prev_pixels: Option<Vec<f64>>,
+    /// On-screen height of one pixel relative to its width (vertical stretch).
+    /// Derived from the terminal cell aspect and the render mode's sub-cell grid:
+    /// on a 2:1 cell, Braille (2×4) and HalfBlock (1×2) pixels are square (1.0)
+    /// while Quadrant (2×2) and ASCII (1×1) pixels are twice as tall (2.0).
+    pub aspect: f64,
+    /// Per-terminal-cell text overlay `(char, rgb)` drawn on top of the rendered
+    /// cells in every render mode (see `draw_text`). Reset by `clear()`.
+    pub text_overlay: Vec<Option<OverlayChar>>,
+    /// The 16 ANSI colors `ColorMode::Palette` picks from.
+    pub palette: [(u8, u8, u8); 16],
+    /// Characters ASCII mode maps brightness onto, darkest first (see
+    /// `set_ascii_ramp`).
+    pub ascii_ramp: String,
 }
 
 impl Canvas {
@@ -71,41 +179,119 @@ impl Canvas {
         render_mode: RenderMode,
         color_mode: ColorMode,
     ) -> Self {
-        let (px_w, px_h) = match render_mode {
-            RenderMode::Braille => (term_cols * 2, term_rows * 4),
-            RenderMode::HalfBlock => (term_cols, term_rows * 2),
-            RenderMode::Ascii => (term_cols, term_rows),
-        };
+        let (px_per_col, px_per_row) = subcell_grid(render_mode);
+        let (px_w, px_h) = (term_cols * px_per_col, term_rows * px_per_row);
         let size = px_w * px_h;
         Canvas {
             width: px_w,
             height: px_h,
             pixels: vec![0.0; size],
             colors: vec![(255, 255, 255); size],
+            bg_colors: vec![(0, 0, 0); size],
             char_override: vec!['\0'; size],
+            attrs: vec![0; size],
             render_mode,
             color_mode,
             color_quant: 0,
             dither: false,
+            mono_threshold: 0.0,
             prev_pixels: None,
+            aspect: pixel_aspect(render_mode, DEFAULT_CELL_ASPECT),
+            text_overlay: vec![None; term_cols * term_rows],
+            palette: XTERM_PALETTE,
+            ascii_ramp: DEFAULT_ASCII_RAMP.to_string(),
         }
     }
 
+    /// Use `ramp` for ASCII mode, or the default when it has fewer than two
+    /// characters to spread brightness across.
+    pub fn set_ascii_ramp(&mut self, ramp: &str) {
+        let ramp = if ramp.chars().count() >= 2 {
+            ramp
+        } else {
+            DEFAULT_ASCII_RAMP
+        };
+        self.ascii_ramp = ramp.to_string();
+    }
+
+    /// Set the terminal cell height:width ratio and recompute `aspect` for the
+    /// current render mode. Use for fonts with unusual cell proportions.
+    pub fn set_cell_aspect(&mut self, cell_aspect: f64) {
+        self.aspect = pixel_aspect(self.render_mode, cell_aspect);
+    }
+
     pub fn clear(&mut self) {
         self.pixels.fill(0.0);
         self.colors.fill((255, 255, 255));
+        self.bg_colors.fill((0, 0, 0));
         self.char_override.fill('\0');
+        self.attrs.fill(0);
+        self.text_overlay.fill(None);
+    }
+
+    /// Draw `text` left-to-right starting at the terminal cell containing pixel
+    /// `(x, y)`. Works in every render mode: each char replaces a whole cell,
+    /// so it reads as a label over braille/half-block graphics. Clipped at the
+    /// right edge.
+    pub fn draw_text(&mut self, x: usize, y: usize, text: &str, r: u8, g: u8, b: u8) {
+        let (cols, rows) = self.term_size();
+        let (px_per_col, px_per_row) = subcell_grid(self.render_mode);
+        let (col, row) = (x / px_per_col, y / px_per_row);
+        if row >= rows {
+            return;
+        }
+        for (i, ch) in text.chars().enumerate() {
+            let c = col + i;
+            if c >= cols {
+                break;
+            }
+            self.text_overlay[row * cols + c] = Some((ch, (r, g, b)));
+        }
     }
 
     /// Set a character directly at terminal-cell coordinates (ASCII mode).
     /// The character will be rendered as-is with the given color.
     #[inline]
     pub fn set_char(&mut self, x: usize, y: usize, ch: char, r: u8, g: u8, b: u8) {
+        self.set_char_alpha(x, y, ch, 1.0, r, g, b);
+    }
+
+    /// `set_char` with SGR attributes (`ATTR_BOLD`/`ATTR_DIM` bits), which
+    /// only the ASCII renderer emits.
+    #[inline]
+    #[allow(clippy::too_many_arguments)]
+    pub fn set_char_attr(&mut self, x: usize, y: usize, ch: char, attrs: u8, r: u8, g: u8, b: u8) {
+        self.set_char(x, y, ch, r, g, b);
+        if x < self.width && y < self.height {
+            self.attrs[y * self.width + x] = attrs;
+        }
+    }
+
+    /// `set_char` at partial opacity: brightness becomes `alpha` and the color
+    /// is blended over what is already drawn there (its color scaled by its
+    /// brightness), so text can be dimmed, ghosted, or faded in.
+    #[inline]
+    #[allow(clippy::too_many_arguments)]
+    pub fn set_char_alpha(
+        &mut self,
+        x: usize,
+        y: usize,
+        ch: char,
+        alpha: f64,
+        r: u8,
+        g: u8,
+        b: u8,
+    ) {
         if x < self.width && y < self.height {
             let idx = y * self.width + x;
+            let a = alpha.clamp(0.0, 1.0);
+            let under = self.pixels[idx].clamp(0.0, 1.0);
+            let (ur, ug, ub) = self.colors[idx];
+            let mix = |top: u8, base: u8| (top as f64 * a + base as f64 * under * (1.0 - a)) as u8;
             self.char_override[idx] = ch;
-            self.pixels[idx] = 1.0;
-            self.colors[idx] = (r, g, b);
+            self.attrs[idx] = 0;
+            self.pixels[idx] = a;
+            self.colors[idx] = (mix(r, ur), mix(g, ug), mix(b, ub));
         }
     }
 
@@ -128,39 +314,350 @@ impl Canvas {
         }
     }
 
+    /// Write whole rows at once: `shade(y, pixels, colors)` fills row `y`'s
+    /// brightness and color slices (`width` long) in place. Rows run in
+    /// parallel with the `rayon` feature, so `shade` must not depend on the
+    /// order they are visited.
+    pub fn fill_rows<F>(&mut self, shade: F)
+    where
+        F: Fn(usize, &mut [f64], &mut [(u8, u8, u8)]) + Send + Sync,
+    {
+        let w = self.width.max(1);
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+            self.pixels
+                .par_chunks_mut(w)
+                .zip(self.colors.par_chunks_mut(w))
+                .enumerate()
+                .for_each(|(y, (pixels, colors))| shade(y, pixels, colors));
+        }
+        #[cfg(not(feature = "rayon"))]
+        for (y, (pixels, colors)) in self
+            .pixels
+            .chunks_mut(w)
+            .zip(self.colors.chunks_mut(w))
+            .enumerate()
+        {
+            shade(y, pixels, colors);
+        }
+    }
+
+    /// Additive counterpart to `set_colored`: brightness accumulates (capped
+    /// at 1.0) and the color moves toward `(r, g, b)` by the incoming share
+    /// of the total, so overlapping particles glow instead of overwriting.
+    #[inline]
+    pub fn add_colored(&mut self, x: usize, y: usize, brightness: f64, r: u8, g: u8, b: u8) {
+        if x < self.width && y < self.height && brightness > 0.0 {
+            let idx = y * self.width + x;
+            let old = self.pixels[idx].max(0.0);
+            let w = brightness / (old + brightness);
+            let (or, og, ob) = self.colors[idx];
+            let mix =
+                |from: u8, to: u8| (from as f64 + (to as f64 - from as f64) * w).round() as u8;
+            self.pixels[idx] = (old + brightness).min(1.0);
+            self.colors[idx] = (mix(or, r), mix(og, g), mix(ob, b));
+        }
+    }
+
+    /// `set_colored` plus a background color for the pixel. In half-block
+    /// mode the pixel shows `fg` scaled by brightness over `bg`; the other
+    /// renderers color each cell's background with the mean of its pixels'.
+    pub fn set_colored_bg(
+        &mut self,
+        x: usize,
+        y: usize,
+        brightness: f64,
+        fg: (u8, u8, u8),
+        bg: (u8, u8, u8),
+    ) {
+        if x < self.width && y < self.height {
+            let idx = y * self.width + x;
+            self.pixels[idx] = brightness;
+            self.colors[idx] = fg;
+            self.bg_colors[idx] = bg;
+        }
+    }
+
+    /// Background for the terminal cell `(col, row)` covering `w`×`h` pixels:
+    /// the mean of its pixels' non-black backgrounds, or `None` if it has
+    /// none (and always in mono mode).
+    pub(crate) fn cell_bg(&self, col: usize, row: usize, w: usize, h: usize) -> Option<Color> {
+        if self.color_mode == ColorMode::Mono {
+            return None;
+        }
+        let (mut r, mut g, mut b, mut n) = (0u32, 0u32, 0u32, 0u32);
+        for y in row * h..((row + 1) * h).min(self.height) {
+            for x in col * w..((col + 1) * w).min(self.width) {
+                let bg = self.bg_colors[y * self.width + x];
+                if bg != (0, 0, 0) {
+                    r += bg.0 as u32;
+                    g += bg.1 as u32;
+                    b += bg.2 as u32;
+                    n += 1;
+                }
+            }
+        }
+        (n > 0).then(|| self.map_color(col, row, (r / n) as u8, (g / n) as u8, (b / n) as u8))
+    }
+
+    /// Fill a disc centered at `(cx, cy)` whose horizontal radius is `radius` pixels.
+    /// The vertical radius is `radius / aspect`, so the disc is round on screen in
+    /// every render mode. The rim is antialiased: pixels the edge passes through
+    /// blend toward the disc by how much of them it covers.
+    #[allow(clippy::too_many_arguments)]
+    pub fn fill_circle(
+        &mut self,
+        cx: f64,
+        cy: f64,
+        radius: f64,
+        brightness: f64,
+        r: u8,
+        g: u8,
+        b: u8,
+    ) {
+        if radius <= 0.0 {
+            return;
+        }
+        self.shade_circle(
+            cx,
+            cy,
+            radius + 0.5,
+            |dist| radius + 0.5 - dist,
+            brightness,
+            (r, g, b),
+        );
+    }
+
+    /// Draw the outline of the circle `fill_circle` would fill, about one
+    /// pixel thick. Where pixels are taller than wide (`aspect` > 1) the ring
+    /// thickens to match, so its top and bottom have no gaps.
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_circle(
+        &mut self,
+        cx: f64,
+        cy: f64,
+        radius: f64,
+        brightness: f64,
+        r: u8,
+        g: u8,
+        b: u8,
+    ) {
+        if radius <= 0.0 {
+            return;
+        }
+        let half = 0.5 * self.aspect.max(1.0) + 0.5;
+        self.shade_circle(
+            cx,
+            cy,
+            radius + half,
+            |dist| half - (dist - radius).abs(),
+            brightness,
+            (r, g, b),
+        );
+    }
+
+    /// Visit the pixels within `extent` (horizontal pixels, aspect-corrected)
+    /// of `(cx, cy)` and blend each toward `brightness`/`color` by
+    /// `coverage(dist)`, clamped to 0..=1.
+    fn shade_circle(
+        &mut self,
+        cx: f64,
+        cy: f64,
+        extent: f64,
+        coverage: impl Fn(f64) -> f64,
+        brightness: f64,
+        (r, g, b): (u8, u8, u8),
+    ) {
+        let ry = extent / self.aspect;
+        let min_x = (cx - extent).ceil().max(0.0) as usize;
+        let max_x = (cx + extent + 1.0).min(self.width as f64).max(0.0) as usize;
+        let min_y = (cy - ry).ceil().max(0.0) as usize;
+        let max_y = (cy + ry + 1.0).min(self.height as f64).max(0.0) as usize;
+        for py in min_y..max_y {
+            let dy = (py as f64 - cy) * self.aspect;
+            for px in min_x..max_x {
+                let dist = (px as f64 - cx).hypot(dy);
+                let cover = coverage(dist).clamp(0.0, 1.0);
+                if cover >= 1.0 {
+                    self.set_colored(px, py, brightness, r, g, b);
+                } else if cover > 0.0 {
+                    self.blend_colored(px, py, cover, brightness, (r, g, b));
+                }
+            }
+        }
+    }
+
+    /// Move an in-bounds pixel `cover` of the way toward `brightness`, its
+    /// color weighted by each side's share of the result.
+    fn blend_colored(
+        &mut self,
+        x: usize,
+        y: usize,
+        cover: f64,
+        brightness: f64,
+        rgb: (u8, u8, u8),
+    ) {
+        let idx = y * self.width + x;
+        let old = self.pixels[idx].max(0.0);
+        let (new, kept) = (brightness * cover, old * (1.0 - cover));
+        self.pixels[idx] = new + kept;
+        if new + kept > 0.0 {
+            self.colors[idx] = lerp_rgb(self.colors[idx], rgb, new / (new + kept));
+        }
+    }
+
+    /// Paint every pixel at `brightness`, blending from `top` on the first row
+    /// to `bottom` on the last. Meant as a backdrop drawn right after `clear`.
+    pub fn fill_vertical_gradient(
+        &mut self,
+        brightness: f64,
+        top: (u8, u8, u8),
+        bottom: (u8, u8, u8),
+    ) {
+        let span = self.height.saturating_sub(1).max(1) as f64;
+        for y in 0..self.height {
+            let color = lerp_rgb(top, bottom, y as f64 / span);
+            let row = y * self.width..(y + 1) * self.width;
+            self.pixels[row.clone()].fill(brightness);
+            self.colors[row].fill(color);
+        }
+    }
+
+    /// Paint every pixel at `brightness`, blending from `inner` at `(cx, cy)`
+    /// to `outer` at `radius` pixels and beyond. Distance is measured like
+    /// `fill_circle`, so the rings are round on screen in every render mode.
+    pub fn fill_radial_gradient(
+        &mut self,
+        cx: f64,
+        cy: f64,
+        radius: f64,
+        brightness: f64,
+        inner: (u8, u8, u8),
+        outer: (u8, u8, u8),
+    ) {
+        let radius = radius.max(f64::EPSILON);
+        for y in 0..self.height {
+            let dy = (y as f64 - cy) * self.aspect;
+            for x in 0..self.width {
+                let dx = x as f64 - cx;
+                let t = (dx * dx + dy * dy).sqrt() / radius;
+                let idx = y * self.width + x;
+                self.pixels[idx] = brightness;
+                self.colors[idx] = lerp_rgb(inner, outer, t);
+            }
+        }
+    }
+
+    /// Draw a DDA-stepped line segment (sub-cell coordinates). Points
+    /// outside the canvas are skipped.
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_line(
+        &mut self,
+        x0: f64,
+        y0: f64,
+        x1: f64,
+        y1: f64,
+        brightness: f64,
+        r: u8,
+        g: u8,
+        b: u8,
+    ) {
+        let dx = x1 - x0;
+        let dy = y1 - y0;
+        let steps = dx.abs().max(dy.abs()).ceil() as usize;
+        for s in 0..=steps {
+            let t = if steps == 0 {
+                0.0
+            } else {
+                s as f64 / steps as f64
+            };
+            let x = x0 + dx * t;
+            let y = y0 + dy * t;
+            if x >= 0.0 && y >= 0.0 {
+                self.set_colored(x as usize, y as usize, brightness, r, g, b);
+            }
+        }
+    }
+
+    /// Draw one bitmap-font glyph with its top-left corner at pixel (x, y),
+    /// each font pixel `scale` canvas pixels square. Off-canvas pixels are
+    /// skipped, so text can scroll in from any edge.
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_glyph(
+        &mut self,
+        x: isize,
+        y: isize,
+        ch: char,
+        font: &Font,
+        scale: usize,
+        r: u8,
+        g: u8,
+        b: u8,
+    ) {
+        for (row, bits) in font.glyph(ch).iter().enumerate() {
+            for col in 0..font.width {
+                if bits & (1 << (font.width - 1 - col)) == 0 {
+                    continue;
+                }
+                for dy in 0..scale {
+                    for dx in 0..scale {
+                        let px = x + (col * scale + dx) as isize;
+                        let py = y + (row * scale + dy) as isize;
+                        if px >= 0 && py >= 0 {
+                            self.set_colored(px as usize, py as usize, 1.0, r, g, b);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
     /// Terminal dimensions needed for this canvas
     pub fn term_size(&self) -> (usize, usize) {
         match self.render_mode {
             RenderMode::Braille => (self.width / 2, self.height / 4),
             RenderMode::HalfBlock => (self.width, self.height / 2),
+            RenderMode::Quadrant => (self.width / 2, self.height / 2),
             RenderMode::Ascii => (self.width, self.height),
         }
     }
 
     /// Render the canvas to a string buffer for output
     pub fn render(&self) -> String {
-        match self.render_mode {
-            RenderMode::Braille => super::braille::render(self),
-            RenderMode::HalfBlock => super::halfblock::render(self),
-            RenderMode::Ascii => super::encoder::encode_full(&self.ascii_build_grid(), true),
+        // Braille/ASCII reset colors at every row end; half-block only when active.
+        let always_reset_row_end = !matches!(self.render_mode, RenderMode::HalfBlock);
+        super::encoder::encode_full(&self.build_grid(), always_reset_row_end)
+    }
+
+    /// Lit/unlit cutoff for a renderer whose own threshold is `base`; in mono
+    /// mode `mono_threshold` can raise it.
+    pub fn lit_threshold(&self, base: f64) -> f64 {
+        if self.color_mode == ColorMode::Mono {
+            base.max(self.mono_threshold)
+        } else {
+            base
         }
     }
 
     pub fn ascii_build_grid(&self) -> CellGrid {
-        const CHARS: &[u8] = b" .:-=+*#%@";
+        let ramp: Vec<char> = self.ascii_ramp.chars().collect();
+        let top = ramp.len().saturating_sub(1) as f64;
         let cols = self.width;
         let rows = self.height;
         let use_color = self.color_mode != ColorMode::Mono;
+        let threshold = self.lit_threshold(0.0);
         let mut cells = Vec::with_capacity(cols * rows);
         for row in 0..rows {
             for col in 0..cols {
                 let idx = row * self.width + col;
                 let v = self.pixels[idx].clamp(0.0, 1.0);
+                let v = if v < threshold { 0.0 } else { v };
                 let co = self.char_override[idx];
                 let ch = if co != '\0' {
                     co
                 } else {
-                    CHARS[(v * (CHARS.len() - 1) as f64) as usize] as char
+                    ramp.get((v * top) as usize).copied().unwrap_or(' ')
                 };
                 let fg = if use_color {
                     let (r, g, b) = self.colors[idx];
@@ -168,7 +665,9 @@ impl Canvas {
                 } else {
                     None
                 };
-                cells.push(Cell { ch, fg, bg: None });
+                let bg = self.cell_bg(col, row, 1, 1);
+                let attrs = self.attrs[idx];
+                cells.push(Cell { ch, fg, bg, attrs });
             }
         }
         CellGrid { cols, rows, cells }
@@ -176,10 +675,31 @@ impl Canvas {
 
     /// Build the terminal-cell grid for the current render mode.
     pub fn build_grid(&self) -> CellGrid {
-        match self.render_mode {
+        let mut grid = match self.render_mode {
             RenderMode::Braille => super::braille::build_grid(self),
             RenderMode::HalfBlock => super::halfblock::build_grid(self),
+            RenderMode::Quadrant => super::quadrant::build_grid(self),
             RenderMode::Ascii => self.ascii_build_grid(),
+        };
+        self.apply_text_overlay(&mut grid);
+        grid
+    }
+
+    fn apply_text_overlay(&self, grid: &mut CellGrid) {
+        if self.text_overlay.len() != grid.cells.len() {
+            return;
+        }
+        let use_color = self.color_mode != ColorMode::Mono;
+        for (i, overlay) in self.text_overlay.iter().enumerate() {
+            if let Some((ch, (r, g, b))) = *overlay {
+                let fg = use_color.then(|| self.map_color(i % grid.cols, i / grid.cols, r, g, b));
+                grid.cells[i] = Cell {
+                    ch,
+                    fg,
+                    bg: grid.cells[i].bg,
+                    attrs: 0,
+                };
+            }
         }
     }
 
@@ -223,10 +743,13 @@ impl Canvas {
                 for i in 0..self.pixels.len() {
                     let t = (self.pixels[i] * luminance(self.colors[i])).clamp(0.0, 1.0);
                     self.colors[i] = p.sample(t);
+                    if self.bg_colors[i] != (0, 0, 0) {
+                        self.bg_colors[i] = p.sample(luminance(self.bg_colors[i]));
+                    }
                 }
             }
             ColorAssist::Daltonize(d) => {
-                for c in self.colors.iter_mut() {
+                for c in self.colors.iter_mut().chain(self.bg_colors.iter_mut()) {
                     *c = daltonize(*c, d);
                 }
             }
@@ -241,9 +764,13 @@ impl Canvas {
             for p in &mut self.pixels {
                 *p = (*p * intensity).clamp(0.0, 1.0);
             }
+            let scale = |c: u8| (c as f64 * intensity).clamp(0.0, 255.0) as u8;
+            for c in &mut self.bg_colors {
+                *c = (scale(c.0), scale(c.1), scale(c.2));
+            }
         }
         if hue_shift.abs() > 1e-10 {
-            for c in &mut self.colors {
+            for c in self.colors.iter_mut().chain(self.bg_colors.iter_mut()) {
                 *c = rotate_hue(*c, hue_shift);
             }
         }
@@ -251,6 +778,19 @@ impl Canvas {
 
     /// Apply post-processing effects to the canvas.
     pub fn post_process(&mut self, config: &PostProcessConfig) {
+        let (h, v) = match config.mirror {
+            Mirror::None => (false, false),
+            Mirror::H => (true, false),
+            Mirror::V => (false, true),
+            Mirror::Both => (true, true),
+        };
+        self.flip(h, v);
+        if config.kaleidoscope > 1 {
+            self.kaleidoscope(config.kaleidoscope);
+        }
+        if config.pixelate > 1 {
+            self.pixelate(config.pixelate);
+        }
         if config.bloom > 0.0 {
             self.apply_bloom(config.bloom, config.bloom_threshold);
         }
@@ -262,6 +802,137 @@ impl Canvas {
         }
     }
 
+    /// Mirror the frame in place: left-right when `h`, upside down when `v`.
+    /// Moves brightness, colors, backgrounds, attributes, and character
+    /// overrides together; overrides that have a mirror image (`/` and `\`,
+    /// brackets, `<` and `>`) are swapped for it. Text overlays stay as drawn
+    /// so labels remain readable.
+    pub fn flip(&mut self, h: bool, v: bool) {
+        if !h && !v {
+            return;
+        }
+        let (w, ht) = (self.width, self.height);
+        let mirrored = |i: usize| {
+            let (x, y) = (i % w, i / w);
+            let x = if h { w - 1 - x } else { x };
+            let y = if v { ht - 1 - y } else { y };
+            y * w + x
+        };
+        // Each pixel trades places with its mirror image once
+        for i in 0..w * ht {
+            let j = mirrored(i);
+            if i < j {
+                self.pixels.swap(i, j);
+                self.colors.swap(i, j);
+                self.bg_colors.swap(i, j);
+                self.attrs.swap(i, j);
+                self.char_override.swap(i, j);
+            }
+        }
+        let swap_glyph = |ch: char, pairs: &[(char, char)]| {
+            pairs
+                .iter()
+                .find_map(|&(a, b)| (ch == a).then_some(b).or((ch == b).then_some(a)))
+        };
+        for ch in &mut self.char_override {
+            if h && let Some(m) = swap_glyph(*ch, &H_MIRRORED) {
+                *ch = m;
+            }
+            if v && let Some(m) = swap_glyph(*ch, &V_MIRRORED) {
+                *ch = m;
+            }
+        }
+    }
+
+    /// Rebuild the frame from one wedge around the center, reflected into `n`
+    /// symmetric segments (`n` clamped to 2..=12). Angles are folded into the
+    /// wedge `0..π/n` by alternating reflections, so odd `n` has no seam.
+    /// Distances are measured on screen (vertical offsets scaled by
+    /// `aspect`) so the pattern is round in every render mode. The center is
+    /// `((w - 1) / 2, (h - 1) / 2)`, between pixels on even sizes; sample
+    /// points outside the canvas clamp to its edge.
+    pub fn kaleidoscope(&mut self, n: usize) {
+        let n = n.clamp(2, 12);
+        let (w, h) = (self.width, self.height);
+        if w == 0 || h == 0 {
+            return;
+        }
+        let wedge = std::f64::consts::PI / n as f64;
+        let (cx, cy) = ((w - 1) as f64 / 2.0, (h - 1) as f64 / 2.0);
+        let pixels = self.pixels.clone();
+        let colors = self.colors.clone();
+        let bg_colors = self.bg_colors.clone();
+        let chars = self.char_override.clone();
+        let attrs = self.attrs.clone();
+        for y in 0..h {
+            for x in 0..w {
+                let dx = x as f64 - cx;
+                let dy = (y as f64 - cy) * self.aspect;
+                let r = dx.hypot(dy);
+                let mut a = dy.atan2(dx).rem_euclid(2.0 * wedge);
+                if a > wedge {
+                    a = 2.0 * wedge - a;
+                }
+                let sx = (cx + r * a.cos()).round().clamp(0.0, (w - 1) as f64) as usize;
+                let sy = (cy + r * a.sin() / self.aspect)
+                    .round()
+                    .clamp(0.0, (h - 1) as f64) as usize;
+                let (dst, src) = (y * w + x, sy * w + sx);
+                self.pixels[dst] = pixels[src];
+                self.colors[dst] = colors[src];
+                self.bg_colors[dst] = bg_colors[src];
+                self.char_override[dst] = chars[src];
+                self.attrs[dst] = attrs[src];
+            }
+        }
+    }
+
+    /// Replace each block of pixels with its average for a chunky low-res
+    /// look. Blocks are `n` pixels wide and as many rows tall as makes them
+    /// square on screen, so the effect looks the same in every render mode.
+    /// Partial blocks at the right and bottom edges average only the pixels
+    /// they cover. Colors are brightness-weighted so unlit pixels don't wash
+    /// them out; character overrides and text overlays are left as drawn.
+    pub fn pixelate(&mut self, n: usize) {
+        if n <= 1 {
+            return;
+        }
+        let bw = n;
+        let bh = ((n as f64 / self.aspect).round() as usize).max(1);
+        for by in (0..self.height).step_by(bh) {
+            for bx in (0..self.width).step_by(bw) {
+                let ys = by..(by + bh).min(self.height);
+                let xs = bx..(bx + bw).min(self.width);
+                let count = (ys.len() * xs.len()) as f64;
+                let (mut sum, mut r, mut g, mut b) = (0.0, 0.0, 0.0, 0.0);
+                for y in ys.clone() {
+                    for x in xs.clone() {
+                        let idx = y * self.width + x;
+                        let v = self.pixels[idx];
+                        let (cr, cg, cb) = self.colors[idx];
+                        sum += v;
+                        r += cr as f64 * v;
+                        g += cg as f64 * v;
+                        b += cb as f64 * v;
+                    }
+                }
+                let avg = sum / count;
+                let color = if sum > 0.0 {
+                    ((r / sum) as u8, (g / sum) as u8, (b / sum) as u8)
+                } else {
+                    self.colors[by * self.width + bx]
+                };
+                for y in ys {
+                    for x in xs.clone() {
+                        let idx = y * self.width + x;
+                        self.pixels[idx] = avg;
+                        self.colors[idx] = color;
+                    }
+                }
+            }
+        }
+    }
+
     fn apply_bloom(&mut self, strength: f64, threshold: f64) {
         let w = self.width;
         let h = self.height;
@@ -351,39 +1022,57 @@ impl Canvas {
                     Color::AnsiValue(idx as u8)
                 }
             }
+            ColorMode::Palette => Color::AnsiValue(nearest_palette_index(&self.palette, (r, g, b))),
             ColorMode::Ansi16 => {
-                // Simple mapping to basic colors
-                let brightness = (r as u16 + g as u16 + b as u16) / 3;
-                if brightness < 64 {
-                    Color::Black
-                } else if r > g && r > b {
-                    if brightness > 180 {
-                        Color::Red
-                    } else {
-                        Color::DarkRed
-                    }
-                } else if g > r && g > b {
-                    if brightness > 180 {
-                        Color::Green
-                    } else {
-                        Color::DarkGreen
-                    }
-                } else if b > r && b > g {
-                    if brightness > 180 {
-                        Color::Blue
-                    } else {
-                        Color::DarkBlue
-                    }
-                } else if brightness > 180 {
-                    Color::White
+                let rgb = if self.dither {
+                    // Same position-keyed bias as ANSI-256, so regions between
+                    // two xterm colors mix them in a stable pattern.
+                    let thr = BAYER_4X4[y % 4][x % 4] as f64 / 16.0;
+                    let bias = (thr - 0.5) * ANSI16_DITHER_STEP;
+                    let shift = |c: u8| (c as f64 + bias).round().clamp(0.0, 255.0) as u8;
+                    (shift(r), shift(g), shift(b))
                 } else {
-                    Color::Grey
-                }
+                    (r, g, b)
+                };
+                ANSI16_COLORS[nearest_palette_index(&XTERM_PALETTE, rgb) as usize]
             }
         }
     }
 }
 
+/// Index of the `palette` entry closest to `rgb`, by squared distance
+/// weighted toward green, which the eye separates best.
+fn nearest_palette_index(palette: &[(u8, u8, u8); 16], rgb: (u8, u8, u8)) -> u8 {
+    let dist = |c: &(u8, u8, u8)| {
+        let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2);
+        2 * d(c.0, rgb.0) + 4 * d(c.1, rgb.1) + 3 * d(c.2, rgb.2)
+    };
+    (0..16).min_by_key(|&i| dist(&palette[i])).unwrap_or(0) as u8
+}
+
+/// Pixel height:width ratio for `mode` on a terminal cell of `cell_aspect` (height:width).
+fn pixel_aspect(mode: RenderMode, cell_aspect: f64) -> f64 {
+    let (px_per_col, px_per_row) = subcell_grid(mode);
+    cell_aspect * px_per_col as f64 / px_per_row as f64
+}
+
+/// Pixels per terminal cell `(columns, rows)` for a render mode.
+pub fn subcell_grid(mode: RenderMode) -> (usize, usize) {
+    match mode {
+        RenderMode::Braille => (2, 4),
+        RenderMode::HalfBlock => (1, 2),
+        RenderMode::Quadrant => (2, 2),
+        RenderMode::Ascii => (1, 1),
+    }
+}
+
+/// Linear blend from `a` (t = 0) to `b` (t = 1); `t` is clamped.
+fn lerp_rgb(a: (u8, u8, u8), b: (u8, u8, u8), t: f64) -> (u8, u8, u8) {
+    let t = t.clamp(0.0, 1.0);
+    let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+    (mix(a.0, b.0), mix(a.1, b.1), mix(a.2, b.2))
+}
+
 fn rotate_hue(rgb: (u8, u8, u8), shift: f64) -> (u8, u8, u8) {
     let (r, g, b) = rgb;
     let r = r as f64 / 255.0;
@@ -463,6 +1152,52 @@ pub fn smoothing_alpha(dt: f64, tau: f64) -> f64 {
     1.0 - (-dt / tau).exp()
 }
 
+/// Test helper: the characters `canvas` renders to, one `|`-framed string per
+/// terminal row; colors are dropped.
+#[cfg(test)]
+pub(crate) fn char_rows(canvas: &Canvas) -> Vec<String> {
+    let grid = canvas.build_grid();
+    (0..grid.rows)
+        .map(|row| {
+            let line: String = (0..grid.cols).map(|col| grid.get(row, col).ch).collect();
+            format!("|{line}|")
+        })
+        .collect()
+}
+
+/// Test helper: assert that `canvas` renders to `expected`, given as
+/// `|`-framed rows like [`char_rows`]. Whitespace around the frames and blank
+/// lines are ignored so the art can be indented in source. On mismatch,
+/// panics listing both grids with differing rows marked.
+#[cfg(test)]
+pub(crate) fn assert_canvas_chars(canvas: &Canvas, expected: &str) {
+    let expected: Vec<&str> = expected
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .collect();
+    let actual = char_rows(canvas);
+    if actual
+        .iter()
+        .map(String::as_str)
+        .eq(expected.iter().copied())
+    {
+        return;
+    }
+    let mut report = format!(
+        "canvas does not match: expected {} rows, got {}\n",
+        expected.len(),
+        actual.len()
+    );
+    for i in 0..expected.len().max(actual.len()) {
+        let e = expected.get(i).copied().unwrap_or("");
+        let a = actual.get(i).map_or("", String::as_str);
+        let mark = if e == a { ' ' } else { '>' };
+        report.push_str(&format!("{mark} {i:>3} expected {e}\n      actual   {a}\n"));
+    }
+    panic!("{report}");
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -471,6 +1206,19 @@ mod tests {
         Canvas::new(10, 10, RenderMode::HalfBlock, ColorMode::TrueColor)
     }
 
+    #[test]
+    fn mode_names_parse_case_insensitively_with_aliases() {
+        use clap::ValueEnum;
+        for name in ["half-block", "halfblock", "Half-Block"] {
+            assert_eq!(RenderMode::from_str(name, true), Ok(RenderMode::HalfBlock));
+        }
+        for name in ["true-color", "truecolor", "TrueColor"] {
+            assert_eq!(ColorMode::from_str(name, true), Ok(ColorMode::TrueColor));
+        }
+        assert_eq!(RenderMode::from_str("ASCII", true), Ok(RenderMode::Ascii));
+        assert!(ColorMode::from_str("sepia", true).is_err());
+    }
+
     #[test]
     fn test_set_and_get_pixel() {
         let mut c = test_canvas();
@@ -486,6 +1234,38 @@ mod tests {
         c.set(usize::MAX, usize::MAX, 1.0);
     }
 
+    #[test]
+    fn test_fill_rows_passes_each_row_its_index() {
+        let mut c = test_canvas();
+        c.fill_rows(|y, pixels, colors| {
+            for (x, (p, col)) in pixels.iter_mut().zip(colors).enumerate() {
+                *p = (y * 100 + x) as f64;
+                *col = (y as u8, x as u8, 0);
+            }
+        });
+        let w = c.width;
+        assert_eq!(c.pixels[3 * w + 7], 307.0);
+        assert_eq!(c.colors[3 * w + 7], (3, 7, 0));
+        assert_eq!(
+            c.pixels.last().copied(),
+            Some(((c.height - 1) * 100 + w - 1) as f64)
+        );
+    }
+
+    #[test]
+    fn test_add_colored_accumulates_and_blends() {
+        let mut c = test_canvas();
+        c.clear();
+        c.add_colored(2, 2, 0.5, 255, 0, 0);
+        assert_eq!(c.colors[22], (255, 0, 0));
+        c.add_colored(2, 2, 0.5, 0, 0, 255);
+        assert!((c.pixels[22] - 1.0).abs() < f64::EPSILON);
+        assert_eq!(c.colors[22], (128, 0, 128));
+        c.add_colored(2, 2, 0.5, 0, 255, 0);
+        assert!((c.pixels[22] - 1.0).abs() < f64::EPSILON, "capped at 1.0");
+        c.add_colored(100, 100, 1.0, 0, 0, 0);
+    }
+
     #[test]
     fn test_clear_zeroes_all_pixels() {
         let mut c = test_canvas();
@@ -514,6 +1294,9 @@ mod tests {
             bloom_threshold: 0.6,
             vignette: 0.0,
             scanlines: false,
+            pixelate: 0,
+            mirror: Mirror::None,
+            kaleidoscope: 0,
         };
         c.post_process(&cfg);
         for dy in -1i32..=1 {
@@ -541,6 +1324,9 @@ mod tests {
             bloom_threshold: 0.6,
             vignette: 0.8,
             scanlines: false,
+            pixelate: 0,
+            mirror: Mirror::None,
+            kaleidoscope: 0,
         };
         c.post_process(&cfg);
         let center = c.pixels[5 * c.width + 5];
@@ -548,6 +1334,28 @@ mod tests {
         assert!(corner < center);
     }
 
+    #[test]
+    fn pixelate_averages_blocks_and_partial_edges() {
+        // Half-block pixels are square, so blocks are 3x3; a 5x4 canvas leaves
+        // partial blocks along the right and bottom edges.
+        let mut c = Canvas::new(5, 2, RenderMode::HalfBlock, ColorMode::TrueColor);
+        c.set_colored(0, 0, 1.0, 200, 0, 0);
+        c.set_colored(1, 1, 0.2, 0, 0, 200);
+        c.set_colored(4, 0, 0.6, 10, 20, 30);
+        c.set_colored(4, 3, 0.8, 0, 255, 0);
+        c.pixelate(3);
+
+        let at = |x: usize, y: usize| (c.pixels[y * c.width + x], c.colors[y * c.width + x]);
+        let (v, rgb) = at(2, 2);
+        assert!((v - 1.2 / 9.0).abs() < 1e-9);
+        assert_eq!(rgb, (166, 0, 33));
+        assert_eq!(at(0, 0), at(2, 2));
+        assert!((at(3, 1).0 - 0.1).abs() < 1e-9);
+        assert_eq!(at(3, 1).1, (10, 20, 30));
+        assert_eq!(at(3, 3), (0.4, (0, 255, 0)));
+        assert_eq!(at(1, 3).0, 0.0);
+    }
+
     #[test]
     fn test_scanlines_darkens_even_rows() {
         let mut c = test_canvas();
@@ -559,6 +1367,9 @@ mod tests {
             bloom_threshold: 0.6,
             vignette: 0.0,
             scanlines: true,
+            pixelate: 0,
+            mirror: Mirror::None,
+            kaleidoscope: 0,
         };
         c.post_process(&cfg);
         let even_val = c.pixels[0];
@@ -642,6 +1453,85 @@ mod tests {
         assert!(last > 0.99, "converges toward the target");
     }
 
+    #[test]
+    fn aspect_matches_render_mode_subcell_grid() {
+        let braille = Canvas::new(4, 2, RenderMode::Braille, ColorMode::TrueColor);
+        let half = Canvas::new(4, 2, RenderMode::HalfBlock, ColorMode::TrueColor);
+        let mut ascii = Canvas::new(4, 2, RenderMode::Ascii, ColorMode::TrueColor);
+        assert!((braille.aspect - 1.0).abs() < 1e-12);
+        assert!((half.aspect - 1.0).abs() < 1e-12);
+        assert!((ascii.aspect - 2.0).abs() < 1e-12);
+        ascii.set_cell_aspect(2.4);
+        assert!((ascii.aspect - 2.4).abs() < 1e-12);
+    }
+
+    #[test]
+    fn fill_circle_compresses_vertically_by_aspect() {
+        let mut c = Canvas::new(21, 21, RenderMode::Ascii, ColorMode::TrueColor);
+        c.fill_circle(10.0, 10.0, 6.0, 1.0, 255, 0, 0);
+        let lit_in_row = |y: usize| {
+            (0..c.width)
+                .filter(|&x| c.pixels[y * c.width + x] >= 1.0)
+                .count()
+        };
+        let lit_in_col = |x: usize| {
+            (0..c.height)
+                .filter(|&y| c.pixels[y * c.width + x] >= 1.0)
+                .count()
+        };
+        assert_eq!(lit_in_row(10), 11);
+        assert_eq!(lit_in_col(10), 5, "aspect 2.0 halves the vertical extent");
+        assert_eq!(c.colors[10 * c.width + 10], (255, 0, 0));
+    }
+
+    #[test]
+    fn circles_antialias_their_edges() {
+        let mut c = Canvas::new(21, 21, RenderMode::HalfBlock, ColorMode::TrueColor);
+        c.fill_circle(10.0, 10.0, 5.0, 1.0, 255, 0, 0);
+        let px = |c: &Canvas, x: usize, y: usize| c.pixels[y * c.width + x];
+        assert_eq!(px(&c, 14, 10), 1.0);
+        // The edge runs through x = 15: half covered, half lit
+        assert!((px(&c, 15, 10) - 0.5).abs() < 1e-9);
+        assert_eq!(px(&c, 16, 10), 0.0);
+        // Blending over a lit background keeps its color in the mix
+        c.clear();
+        c.set_colored(15, 10, 1.0, 0, 0, 255);
+        c.fill_circle(10.0, 10.0, 5.0, 1.0, 255, 0, 0);
+        assert_eq!(c.colors[10 * c.width + 15], (128, 0, 128));
+
+        // A ring lights every angle around it and leaves the middle dark, in
+        // square and tall pixels alike
+        for mode in [RenderMode::HalfBlock, RenderMode::Ascii] {
+            let mut c = Canvas::new(41, 41, mode, ColorMode::TrueColor);
+            c.draw_circle(20.0, 20.0, 12.0, 1.0, 0, 255, 0);
+            for i in 0..64 {
+                let a = i as f64 / 64.0 * std::f64::consts::TAU;
+                let x = (20.0 + 12.0 * a.cos()).round() as usize;
+                let y = (20.0 + 12.0 * a.sin() / c.aspect).round() as usize;
+                let near = (0..9).map(|n| px(&c, x + n % 3 - 1, y + n / 3 - 1));
+                assert!(near.fold(0.0, f64::max) >= 0.6, "{mode:?} gap at {x},{y}");
+            }
+            assert_eq!(px(&c, 20, 20), 0.0);
+        }
+    }
+
+    #[test]
+    fn draw_text_overlays_cells_in_braille_mode() {
+        let mut c = Canvas::new(6, 2, RenderMode::Braille, ColorMode::TrueColor);
+        // Pixel (4, 5) lives in cell (col 2, row 1).
+        c.draw_text(4, 5, "AB1234", 0, 255, 0);
+        let g = c.build_grid();
+        assert_eq!(g.get(1, 2).ch, 'A');
+        assert_eq!(g.get(1, 5).ch, '2', "clipped at the right edge");
+        assert_eq!(g.get(1, 2).fg, Some(Color::Rgb { r: 0, g: 255, b: 0 }));
+        c.clear();
+        assert_eq!(
+            c.build_grid().get(1, 2).ch,
+            '\u{2800}',
+            "clear() drops the overlay"
+        );
+    }
+
     #[test]
     fn new_initializes_dither_false() {
         let c = Canvas::new(4, 2, RenderMode::HalfBlock, ColorMode::Ansi256);
@@ -683,6 +1573,168 @@ mod tests {
         );
     }
 
+    #[test]
+    fn background_shows_behind_unlit_pixels() {
+        let mut c = Canvas::new(1, 1, RenderMode::HalfBlock, ColorMode::TrueColor);
+        c.set_colored_bg(0, 1, 0.5, (200, 0, 0), (0, 0, 100));
+        let cell = c.build_grid().get(0, 0);
+        assert_eq!(cell.fg, Some(Color::Rgb { r: 0, g: 0, b: 0 }));
+        assert_eq!(
+            cell.bg,
+            Some(Color::Rgb {
+                r: 100,
+                g: 0,
+                b: 50
+            })
+        );
+
+        let mut c = Canvas::new(1, 1, RenderMode::Braille, ColorMode::TrueColor);
+        c.set_colored_bg(0, 0, 0.0, (0, 0, 0), (0, 0, 80));
+        c.set_colored_bg(1, 0, 0.0, (0, 0, 0), (0, 0, 40));
+        let cell = c.build_grid().get(0, 0);
+        assert_eq!((cell.ch, cell.fg), ('\u{2800}', None));
+        assert_eq!(cell.bg, Some(Color::Rgb { r: 0, g: 0, b: 60 }));
+        c.color_mode = ColorMode::Mono;
+        assert_eq!(c.build_grid().get(0, 0).bg, None);
+    }
+
+    #[test]
+    fn flip_mirrors_every_buffer_and_directional_glyphs() {
+        let mut c = Canvas::new(3, 2, RenderMode::Ascii, ColorMode::TrueColor);
+        c.set_colored(0, 0, 0.9, 10, 20, 30);
+        c.char_override[0] = '/';
+        c.char_override[4] = '(';
+        c.set_colored_bg(2, 1, 0.0, (0, 0, 0), (0, 0, 70));
+
+        c.flip(true, false);
+        assert_eq!((c.pixels[2], c.colors[2]), (0.9, (10, 20, 30)));
+        assert_eq!(c.pixels[0], 0.0);
+        assert_eq!(c.char_override[2], '\\');
+        assert_eq!(c.char_override[4], ')', "the middle column stays put");
+        assert_eq!(c.bg_colors[3], (0, 0, 70));
+
+        c.flip(false, true);
+        assert_eq!(c.pixels[3 + 2], 0.9);
+        assert_eq!(c.char_override[3 + 2], '/');
+        assert_eq!(c.char_override[1], ')', "brackets only turn left-right");
+
+        // Both axes twice is the identity
+        let before = (c.pixels.clone(), c.char_override.clone());
+        c.flip(true, true);
+        c.flip(true, true);
+        assert_eq!((c.pixels.clone(), c.char_override.clone()), before);
+    }
+
+    #[test]
+    fn kaleidoscope_repeats_the_wedge_around_the_center() {
+        let mut c = Canvas::new(41, 41, RenderMode::Ascii, ColorMode::TrueColor);
+        c.set_cell_aspect(1.0);
+        // A blob inside the 6-fold source wedge (0..30°), centered at 15°
+        for y in 21..=25 {
+            for x in 30..=34 {
+                c.set_colored(x, y, 1.0, 200, 0, 0);
+            }
+        }
+        c.kaleidoscope(6);
+        let at = |deg: f64| {
+            let (sin, cos) = deg.to_radians().sin_cos();
+            let (x, y) = ((20.0 + 12.0 * cos).round(), (20.0 + 12.0 * sin).round());
+            let i = y as usize * c.width + x as usize;
+            (c.pixels[i], c.colors[i])
+        };
+        for k in 0..6 {
+            let base = 60.0 * k as f64;
+            let red = (1.0, (200, 0, 0));
+            assert_eq!(at(base + 15.0), red, "rotated copy at {}°", base + 15.0);
+            assert_eq!(at(base - 15.0), red, "reflected copy at {}°", base - 15.0);
+            assert_eq!(at(base + 30.0).0, 0.0, "gap at {}°", base + 30.0);
+        }
+
+        // Odd counts and the 1x1 center pixel are handled without panicking
+        let mut odd = Canvas::new(1, 1, RenderMode::Ascii, ColorMode::TrueColor);
+        odd.set(0, 0, 0.5);
+        odd.kaleidoscope(5);
+        assert_eq!(odd.pixels[0], 0.5);
+    }
+
+    #[test]
+    fn mono_frames_carry_no_color_sequences() {
+        use crate::render::encoder::{encode_diff, encode_full};
+        for mode in [
+            RenderMode::HalfBlock,
+            RenderMode::Braille,
+            RenderMode::Quadrant,
+            RenderMode::Ascii,
+        ] {
+            let mut c = Canvas::new(6, 3, mode, ColorMode::Mono);
+            for (i, p) in c.pixels.iter_mut().enumerate() {
+                *p = (i % 5) as f64 / 4.0;
+            }
+            c.colors.fill((200, 40, 90));
+            c.set_colored_bg(0, 0, 0.5, (10, 200, 10), (0, 0, 120));
+            c.draw_text(0, c.height - 1, "hi", 255, 0, 0);
+            let grid = c.build_grid();
+            assert!(
+                grid.cells
+                    .iter()
+                    .all(|cell| cell.fg.is_none() && cell.bg.is_none())
+            );
+            let out = encode_full(&grid, true);
+            // Brightness is carried by the glyphs alone: no fg/bg SGR of any kind
+            for seq in out.split("\x1b[").skip(1) {
+                let end = seq.find(|ch: char| ch.is_ascii_alphabetic()).unwrap();
+                let (params, fin) = (&seq[..end], &seq[end..=end]);
+                assert!(
+                    fin != "m" || params.split(';').all(|p| matches!(p, "" | "0")),
+                    "{mode:?} emitted \\x1b[{params}m"
+                );
+            }
+
+            // Recoloring without changing brightness leaves nothing to redraw
+            let before = c.build_grid();
+            c.colors.fill((0, 90, 255));
+            c.bg_colors.fill((60, 0, 0));
+            assert_eq!(encode_diff(&before, &c.build_grid()), "", "{mode:?}");
+        }
+    }
+
+    #[test]
+    fn map_color_ansi16_picks_nearest_xterm_color() {
+        let c = Canvas::new(4, 2, RenderMode::HalfBlock, ColorMode::Ansi16);
+        // Aurora's teal and plasma's magenta and orange keep their hue.
+        assert_eq!(c.map_color(0, 0, 20, 200, 170), Color::DarkCyan);
+        assert_eq!(c.map_color(0, 0, 230, 40, 220), Color::DarkMagenta);
+        assert_eq!(c.map_color(0, 0, 240, 160, 20), Color::DarkYellow);
+        assert_eq!(c.map_color(0, 0, 30, 30, 40), Color::Black);
+        assert_eq!(c.map_color(0, 0, 120, 120, 120), Color::DarkGrey);
+    }
+
+    #[test]
+    fn map_color_ansi16_dither_mixes_neighbouring_colors() {
+        let mut c = Canvas::new(4, 4, RenderMode::HalfBlock, ColorMode::Ansi16);
+        let block = |c: &Canvas| -> Vec<Color> {
+            (0..16)
+                .map(|i| c.map_color(i % 4, i / 4, 50, 50, 50))
+                .collect()
+        };
+        assert!(block(&c).iter().all(|&col| col == Color::Black));
+        c.dither = true;
+        let dithered = block(&c);
+        assert!(dithered.contains(&Color::Black));
+        assert!(dithered.contains(&Color::DarkGrey));
+        // Stable per position, frame to frame
+        assert_eq!(dithered, block(&c));
+    }
+
+    #[test]
+    fn map_color_palette_picks_nearest_theme_entry() {
+        let mut c = Canvas::new(4, 2, RenderMode::HalfBlock, ColorMode::Palette);
+        assert_eq!(c.map_color(0, 0, 250, 10, 10), Color::AnsiValue(9));
+        // A theme whose "blue" slot is orange wins for orange input
+        c.palette[4] = (255, 140, 0);
+        assert_eq!(c.map_color(0, 0, 250, 150, 10), Color::AnsiValue(4));
+    }
+
     #[test]
     fn apply_color_assist_remap_by_luminance() {
         use crate::render::color_assist::{ColorAssist, Palette};
@@ -738,6 +1790,70 @@ mod tests {
         );
     }
 
+    #[test]
+    fn char_attrs_reach_ascii_cells_until_overwritten() {
+        use crate::render::cell::ATTR_BOLD;
+        let mut c = Canvas::new(2, 1, RenderMode::Ascii, ColorMode::TrueColor);
+        c.set_char_attr(0, 0, 'x', ATTR_BOLD, 255, 255, 255);
+        c.set_char_attr(1, 0, 'y', ATTR_BOLD, 0, 255, 0);
+        c.set_char(1, 0, 'z', 0, 255, 0);
+        let grid = c.build_grid();
+        assert_eq!(grid.get(0, 0).attrs, ATTR_BOLD);
+        assert_eq!(grid.get(0, 1).attrs, 0);
+        c.clear();
+        assert_eq!(c.build_grid().get(0, 0).attrs, 0);
+    }
+
+    #[test]
+    fn set_char_alpha_blends_over_existing_pixel() {
+        let mut c = Canvas::new(2, 1, RenderMode::Ascii, ColorMode::TrueColor);
+        c.set_colored(0, 0, 1.0, 200, 0, 0);
+        c.set_char_alpha(0, 0, 'x', 0.25, 0, 0, 200);
+        assert_eq!(c.char_override[0], 'x');
+        assert_eq!(c.pixels[0], 0.25);
+        assert_eq!(c.colors[0], (150, 0, 50));
+
+        // Opaque is plain set_char; an empty pixel contributes no color
+        c.set_char(1, 0, 'y', 10, 20, 30);
+        assert_eq!((c.pixels[1], c.colors[1]), (1.0, (10, 20, 30)));
+        c.clear();
+        c.set_char_alpha(1, 0, 'z', 0.5, 100, 100, 100);
+        assert_eq!(c.colors[1], (50, 50, 50));
+    }
+
+    #[test]
+    fn ascii_ramp_indexes_by_char_and_rejects_short_ramps() {
+        let mut c = Canvas::new(3, 1, RenderMode::Ascii, ColorMode::Mono);
+        c.set_ascii_ramp(" ░▒▓█");
+        for (x, v) in [0.1, 0.6, 1.0].into_iter().enumerate() {
+            c.set(x, 0, v);
+        }
+        let glyphs: String = c.build_grid().cells.iter().map(|cell| cell.ch).collect();
+        assert_eq!(glyphs, " ▒█");
+
+        c.set_ascii_ramp("#");
+        assert_eq!(c.ascii_ramp, DEFAULT_ASCII_RAMP);
+    }
+
+    #[test]
+    fn gradient_fills_cover_the_buffer() {
+        let mut c = Canvas::new(4, 5, RenderMode::Ascii, ColorMode::TrueColor);
+        c.fill_vertical_gradient(0.2, (0, 0, 100), (100, 0, 0));
+        assert!(c.pixels.iter().all(|&p| p == 0.2));
+        assert_eq!(c.colors[0], (0, 0, 100));
+        assert_eq!(c.colors[2 * 4 + 3], (50, 0, 50));
+        assert_eq!(c.colors[4 * 4], (100, 0, 0));
+
+        // ASCII pixels are twice as tall as wide, so one row down is 2 units out.
+        c.set_cell_aspect(2.0);
+        c.fill_radial_gradient(0.0, 0.0, 4.0, 1.0, (200, 200, 200), (0, 0, 0));
+        assert_eq!(c.colors[0], (200, 200, 200));
+        assert_eq!(c.colors[1], (150, 150, 150));
+        assert_eq!(c.colors[2], (100, 100, 100));
+        assert_eq!(c.colors[4], c.colors[2]);
+        assert_eq!(c.colors[4 * 4 + 3], (0, 0, 0));
+    }
+
     /// Deterministic canvases exercising each render mode × color mode + a bloom variant.
     /// KEEP STABLE — their rendered bytes are the golden snapshots.
     fn snapshot_fixtures() -> Vec<(String, Canvas)> {
@@ -745,6 +1861,7 @@ mod tests {
         for mode in [
             RenderMode::HalfBlock,
             RenderMode::Braille,
+            RenderMode::Quadrant,
             RenderMode::Ascii,
         ] {
             for cm in [ColorMode::Mono, ColorMode::TrueColor, ColorMode::Ansi256] {
@@ -764,6 +1881,7 @@ mod tests {
         for mode in [
             RenderMode::HalfBlock,
             RenderMode::Braille,
+            RenderMode::Quadrant,
             RenderMode::Ascii,
         ] {
             let name = format!("{mode:?}-ansi256-dither")
@@ -795,6 +1913,9 @@ mod tests {
             bloom_threshold: 0.6,
             vignette: 0.4,
             scanlines: false,
+            pixelate: 0,
+            mirror: Mirror::None,
+            kaleidoscope: 0,
         });
         v.push(("halfblock-truecolor-bloom".to_string(), c));
         v
@@ -1,4 +1,10 @@
 use crossterm::style::Color;
+use unicode_width::UnicodeWidthChar;
+
+/// `Cell::attrs` bit for SGR bold (`1`).
+pub const ATTR_BOLD: u8 = 1;
+/// `Cell::attrs` bit for SGR faint (`2`).
+pub const ATTR_DIM: u8 = 2;
 
 /// One terminal cell's rendered content, mode-agnostic.
 /// `fg`/`bg` of `None` mean "default/dark" (no SGR; a reset is emitted when leaving a colored cell).
@@ -7,9 +13,12 @@ pub struct Cell {
     pub ch: char,
     pub fg: Option<Color>,
     pub bg: Option<Color>,
+    /// `ATTR_*` bits; 0 for plain text.
+    pub attrs: u8,
 }
 
 /// Row-major grid of terminal cells — the single source of truth for a frame's content.
+#[derive(Clone)]
 pub struct CellGrid {
     pub cols: usize,
     pub rows: usize,
@@ -25,7 +34,8 @@ impl CellGrid {
                 Cell {
                     ch: ' ',
                     fg: None,
-                    bg: None
+                    bg: None,
+                    attrs: 0
                 };
                 cols * rows
             ],
@@ -35,4 +45,28 @@ impl CellGrid {
     pub fn get(&self, row: usize, col: usize) -> Cell {
         self.cells[row * self.cols + col]
     }
+
+    /// Same-size copy with the content moved `dx` columns right and `dy` rows
+    /// down. Vacated cells are blank and whatever crosses the right or bottom
+    /// edge is dropped.
+    pub fn shifted(&self, dx: usize, dy: usize) -> CellGrid {
+        self.placed(self.cols, self.rows, dx, dy)
+    }
+
+    /// This grid drawn at `(col, row)` on a blank `cols` x `rows` grid.
+    /// Anything past the right or bottom edge is dropped, including a wide
+    /// glyph that would straddle it.
+    pub fn placed(&self, cols: usize, rows: usize, col: usize, row: usize) -> CellGrid {
+        let mut out = CellGrid::new(cols, rows);
+        for r in 0..self.rows.min(rows.saturating_sub(row)) {
+            for c in 0..self.cols.min(cols.saturating_sub(col)) {
+                let cell = self.get(r, c);
+                let wide = UnicodeWidthChar::width(cell.ch).unwrap_or(1) >= 2;
+                if !(wide && col + c + 1 == cols) {
+                    out.cells[(row + r) * cols + col + c] = cell;
+                }
+            }
+        }
+        out
+    }
 }
@@ -1,5 +1,5 @@
-use crate::render::canvas::color_to_fg;
-use crate::render::cell::{Cell, CellGrid};
+use crate::render::canvas::{ColorMode, RenderMode, color_to_fg};
+use crate::render::cell::{ATTR_BOLD, ATTR_DIM, Cell, CellGrid};
 use crossterm::style::Color;
 use unicode_width::UnicodeWidthChar;
 
@@ -30,50 +30,68 @@ fn color_to_bg(color: Color) -> String {
     }
 }
 
-/// Emit the SGR transition to `cell`'s colors, updating tracked state. Shared by both encoders.
-fn write_color_transition(
-    out: &mut String,
-    cell: Cell,
-    last_fg: &mut Option<Color>,
-    last_bg: &mut Option<Color>,
-) {
-    let fg_changed = cell.fg != *last_fg;
-    let bg_changed = cell.bg != *last_bg;
-    if !(fg_changed || bg_changed) {
+/// SGR state last written to the terminal: colors plus `ATTR_*` bits.
+#[derive(Clone, Copy, Default, PartialEq)]
+struct Sgr {
+    fg: Option<Color>,
+    bg: Option<Color>,
+    attrs: u8,
+}
+
+impl Sgr {
+    fn is_set(&self) -> bool {
+        self.fg.is_some() || self.bg.is_some() || self.attrs != 0
+    }
+}
+
+/// Emit the SGR transition to `cell`'s colors and attributes, updating tracked state.
+/// Shared by both encoders.
+fn write_color_transition(out: &mut String, cell: Cell, last: &mut Sgr) {
+    let next = Sgr {
+        fg: cell.fg,
+        bg: cell.bg,
+        attrs: cell.attrs,
+    };
+    if next == *last {
         return;
     }
-    match (cell.fg, cell.bg) {
-        (None, None) => out.push_str("\x1b[0m"),
-        (Some(f), None) => {
-            out.push_str("\x1b[");
-            out.push_str(&color_to_fg(f));
-            out.push('m');
-        }
-        (None, Some(b)) => {
-            out.push_str("\x1b[");
-            out.push_str(&color_to_bg(b));
-            out.push('m');
-        }
-        (Some(f), Some(b)) => {
-            if fg_changed && bg_changed {
-                out.push_str("\x1b[");
-                out.push_str(&color_to_fg(f));
-                out.push(';');
-                out.push_str(&color_to_bg(b));
-                out.push('m');
-            } else if fg_changed {
-                out.push_str("\x1b[");
-                out.push_str(&color_to_fg(f));
-                out.push('m');
-            } else {
-                out.push_str("\x1b[");
-                out.push_str(&color_to_bg(b));
-                out.push('m');
-            }
-        }
+    // Dropping a color or attribute needs a reset first; SGR has no "unset".
+    let reset = (next.fg.is_none() && last.fg.is_some())
+        || (next.bg.is_none() && last.bg.is_some())
+        || last.attrs & !next.attrs != 0;
+    let (attrs, fg, bg) = if reset {
+        (next.attrs, next.fg, next.bg)
+    } else {
+        (
+            next.attrs & !last.attrs,
+            next.fg.filter(|_| next.fg != last.fg),
+            next.bg.filter(|_| next.bg != last.bg),
+        )
+    };
+    out.push_str("\x1b[");
+    let mut sep = "";
+    let mut param = |out: &mut String, p: &str| {
+        out.push_str(sep);
+        out.push_str(p);
+        sep = ";";
+    };
+    if reset {
+        param(out, "0");
+    }
+    if attrs & ATTR_BOLD != 0 {
+        param(out, "1");
+    }
+    if attrs & ATTR_DIM != 0 {
+        param(out, "2");
     }
-    *last_fg = cell.fg;
-    *last_bg = cell.bg;
+    if let Some(f) = fg {
+        param(out, &color_to_fg(f));
+    }
+    if let Some(b) = bg {
+        param(out, &color_to_bg(b));
+    }
+    out.push('m');
+    *last = next;
 }
 
 /// Full-frame encode, byte-identical to today's per-mode `render()`.
@@ -81,13 +99,12 @@ fn write_color_transition(
 /// halfblock emits it only when a color is active in the row.
 pub fn encode_full(grid: &CellGrid, always_reset_row_end: bool) -> String {
     let mut out = String::with_capacity(grid.cols * grid.rows * 10);
-    let mut last_fg: Option<Color> = None;
-    let mut last_bg: Option<Color> = None;
+    let mut last = Sgr::default();
     for row in 0..grid.rows {
         let mut col = 0;
         while col < grid.cols {
             let cell = grid.cells[row * grid.cols + col];
-            write_color_transition(&mut out, cell, &mut last_fg, &mut last_bg);
+            write_color_transition(&mut out, cell, &mut last);
             out.push(cell.ch);
             // Advance by display width: a wide (2-column) glyph absorbs the next grid
             // cell, so skip it to keep later cells aligned to their true columns.
@@ -97,11 +114,9 @@ pub fn encode_full(grid: &CellGrid, always_reset_row_end: bool) -> String {
                 1
             };
         }
-        let active = last_fg.is_some() || last_bg.is_some();
-        if always_reset_row_end || active {
+        if always_reset_row_end || last.is_set() {
             out.push_str("\x1b[0m");
-            last_fg = None;
-            last_bg = None;
+            last = Sgr::default();
         }
         out.push_str("\x1b[");
         out.push_str(&(row + 2).to_string());
@@ -137,8 +152,7 @@ pub fn dirty_ratio(prev: &CellGrid, grid: &CellGrid) -> f64 {
 /// default color (every prior frame ends with a reset). Ends with `\x1b[0m` if a color was emitted.
 pub fn encode_diff(prev: &CellGrid, grid: &CellGrid) -> String {
     let mut out = String::new();
-    let mut last_fg: Option<Color> = None;
-    let mut last_bg: Option<Color> = None;
+    let mut last = Sgr::default();
     let mut prev_col: Option<usize> = None;
     let mut prev_row: usize = 0;
     for row in 0..grid.rows {
@@ -156,18 +170,70 @@ pub fn encode_diff(prev: &CellGrid, grid: &CellGrid) -> String {
                 out.push_str(&(col + 1).to_string());
                 out.push('H');
             }
-            write_color_transition(&mut out, cell, &mut last_fg, &mut last_bg);
+            write_color_transition(&mut out, cell, &mut last);
             out.push(cell.ch);
             prev_col = Some(col);
             prev_row = row;
         }
     }
-    if last_fg.is_some() || last_bg.is_some() {
+    if last.is_set() {
         out.push_str("\x1b[0m");
     }
     out
 }
 
+/// Frame-to-frame encoder state: the last grid written, so the next frame can
+/// go out as an [`encode_diff`] against it. Render and color mode changes are
+/// caught by [`set_modes`](DeltaEncoder::set_modes); anything else that changes
+/// what a grid means on screen (e.g. a resize) must call
+/// [`invalidate`](DeltaEncoder::invalidate) so the following frame is a full redraw.
+#[derive(Default)]
+pub struct DeltaEncoder {
+    prev: Option<CellGrid>,
+    /// Modes the previous frame was rendered in
+    modes: Option<(RenderMode, ColorMode)>,
+}
+
+impl DeltaEncoder {
+    /// Forget the previous frame; the next [`encode`](DeltaEncoder::encode) is full.
+    pub fn invalidate(&mut self) {
+        self.prev = None;
+    }
+
+    /// Record the modes the next grid was rendered in. A change from the
+    /// previous frame's invalidates, since its cells no longer compare.
+    pub fn set_modes(&mut self, render: RenderMode, color: ColorMode) {
+        if self.modes.replace((render, color)) != Some((render, color)) {
+            self.invalidate();
+        }
+    }
+
+    /// Encode `grid` as a diff against the previous frame when that is safe
+    /// and cheaper, otherwise in full. `allow_diff = false` forces a full
+    /// frame (e.g. `--full-frames`, or while recording).
+    pub fn encode(
+        &mut self,
+        grid: CellGrid,
+        allow_diff: bool,
+        always_reset_row_end: bool,
+    ) -> String {
+        let frame = match &self.prev {
+            Some(p)
+                if allow_diff
+                    && p.cols == grid.cols
+                    && p.rows == grid.rows
+                    && !grid_has_wide(&grid)
+                    && dirty_ratio(p, &grid) <= FULL_REDRAW_THRESHOLD =>
+            {
+                encode_diff(p, &grid)
+            }
+            _ => encode_full(&grid, always_reset_row_end),
+        };
+        self.prev = Some(grid);
+        frame
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -188,16 +254,19 @@ mod tests {
                 ch: wide,
                 fg: None,
                 bg: None,
+                attrs: 0,
             },
             Cell {
                 ch: 'X',
                 fg: None,
                 bg: None,
+                attrs: 0,
             },
             Cell {
                 ch: 'Z',
                 fg: None,
                 bg: None,
+                attrs: 0,
             },
         ];
         let grid = g(cells, 3, 1);
@@ -210,6 +279,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn dropping_background_resets_it() {
+        let red = Some(Color::Rgb { r: 255, g: 0, b: 0 });
+        let blue = Some(Color::Rgb { r: 0, g: 0, b: 255 });
+        let cells = vec![
+            Cell {
+                ch: 'a',
+                fg: red,
+                bg: blue,
+                attrs: 0,
+            },
+            Cell {
+                ch: 'b',
+                fg: red,
+                bg: None,
+                attrs: 0,
+            },
+        ];
+        let out = encode_full(&g(cells, 2, 1), true);
+        assert!(
+            out.starts_with("\x1b[38;2;255;0;0;48;2;0;0;255ma\x1b[0;38;2;255;0;0mb"),
+            "{out:?}"
+        );
+    }
+
+    #[test]
+    fn attributes_are_set_once_and_reset_when_dropped() {
+        let cell = |ch, fg, attrs| Cell {
+            ch,
+            fg,
+            bg: None,
+            attrs,
+        };
+        let red = Some(Color::Red);
+        let cells = vec![
+            cell('a', red, ATTR_BOLD),
+            cell('b', red, ATTR_BOLD),
+            cell('c', red, 0),
+            cell('d', None, ATTR_DIM),
+        ];
+        let out = encode_full(&g(cells, 4, 1), false);
+        assert_eq!(out, "\x1b[1;91mab\x1b[0;91mc\x1b[0;2md\x1b[0m\x1b[2;1H");
+    }
+
     #[test]
     fn encode_full_narrow_grid_is_unchanged() {
         // A width-1-only grid must produce identical output to the pre-change behavior.
@@ -218,22 +331,48 @@ mod tests {
                 ch: 'a',
                 fg: None,
                 bg: None,
+                attrs: 0,
             },
             Cell {
                 ch: 'b',
                 fg: None,
                 bg: None,
+                attrs: 0,
             },
             Cell {
                 ch: 'c',
                 fg: None,
                 bg: None,
+                attrs: 0,
             },
         ];
         let grid = g(cells, 3, 1);
         assert_eq!(encode_full(&grid, false), "abc\x1b[2;1H");
     }
 
+    #[test]
+    fn delta_encoder_redraws_in_full_after_color_mode_change() {
+        use crate::render::{Canvas, ColorMode, RenderMode};
+        let frame = |mode| {
+            let mut canvas = Canvas::new(8, 2, RenderMode::HalfBlock, mode);
+            for x in 0..canvas.width {
+                canvas.set_colored(x, 0, 1.0, 200, 40, 40);
+            }
+            canvas.render_cells()
+        };
+        let mut delta = DeltaEncoder::default();
+        let mut encode = |mode| {
+            delta.set_modes(RenderMode::HalfBlock, mode);
+            delta.encode(frame(mode), true, false)
+        };
+        encode(ColorMode::TrueColor);
+        assert_eq!(encode(ColorMode::TrueColor), "");
+
+        let full = encode_full(&frame(ColorMode::Ansi256), false);
+        assert_eq!(encode(ColorMode::Ansi256), full);
+        assert_eq!(encode(ColorMode::Ansi256), "");
+    }
+
     #[test]
     fn dirty_ratio_extremes() {
         let a = g(
@@ -241,7 +380,8 @@ mod tests {
                 Cell {
                     ch: 'a',
                     fg: None,
-                    bg: None
+                    bg: None,
+                    attrs: 0
                 };
                 4
             ],
@@ -254,7 +394,8 @@ mod tests {
                 Cell {
                     ch: 'b',
                     fg: None,
-                    bg: None
+                    bg: None,
+                    attrs: 0
                 };
                 4
             ],
@@ -272,21 +413,25 @@ mod tests {
                     ch: 'a',
                     fg: None,
                     bg: None,
+                    attrs: 0,
                 },
                 Cell {
                     ch: 'b',
                     fg: None,
                     bg: None,
+                    attrs: 0,
                 },
                 Cell {
                     ch: 'c',
                     fg: None,
                     bg: None,
+                    attrs: 0,
                 },
                 Cell {
                     ch: 'd',
                     fg: None,
                     bg: None,
+                    attrs: 0,
                 },
             ],
             2,
@@ -297,11 +442,13 @@ mod tests {
             ch: 'X',
             fg: None,
             bg: None,
+            attrs: 0,
         };
         bc[2] = Cell {
             ch: 'Y',
             fg: None,
             bg: None,
+            attrs: 0,
         };
         let out = encode_diff(&a, &g(bc, 2, 2));
         assert!(
@@ -322,7 +469,8 @@ mod tests {
                 Cell {
                     ch: 'a',
                     fg: None,
-                    bg: None
+                    bg: None,
+                    attrs: 0
                 };
                 3
             ],
@@ -334,11 +482,13 @@ mod tests {
             ch: 'X',
             fg: None,
             bg: None,
+            attrs: 0,
         };
         bc[1] = Cell {
             ch: 'Y',
             fg: None,
             bg: None,
+            attrs: 0,
         };
         let out = encode_diff(&a, &g(bc, 3, 1));
         assert_eq!(
@@ -518,7 +668,7 @@ mod tests {
             ColorMode::TrueColor,
         );
         let mut anim =
-            animations::create("matrix", canvas.width, canvas.height, 1.0).expect("anim");
+            animations::create("matrix", canvas.width, canvas.height, 1.0, None).expect("anim");
         anim.on_resize(canvas.width, canvas.height);
         let mut term = Term::new(cols, rows);
         let mut prev: Option<CellGrid> = None;
@@ -600,7 +750,7 @@ mod tests {
         for &name in &names {
             let mut canvas = Canvas::new(cols, rows, RenderMode::HalfBlock, ColorMode::TrueColor);
             let mut anim =
-                animations::create(name, canvas.width, canvas.height, 1.0).expect("anim");
+                animations::create(name, canvas.width, canvas.height, 1.0, None).expect("anim");
             anim.on_resize(canvas.width, canvas.height);
             let mut prev: Option<CellGrid> = None;
             let (mut fb, mut db, mut ds, mut dn) = (0u64, 0u64, 0f64, 0u64);
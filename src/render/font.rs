@@ -0,0 +1,781 @@
+//! Embedded bitmap fonts for large on-canvas text (banners, clocks).
+//!
+//! Glyphs are drawn in canvas pixels by [`Canvas::draw_glyph`], so they work
+//! in every render mode and scale up by integer factors.
+//!
+//! [`Canvas::draw_glyph`]: super::Canvas::draw_glyph
+
+/// A fixed-width bitmap font. Each glyph is `height` rows of `width` bits,
+/// most significant bit leftmost.
+pub struct Font {
+    pub name: &'static str,
+    pub width: usize,
+    pub height: usize,
+    glyphs: &'static [(char, &'static [u8])],
+}
+
+/// Classic 5x7 dot-matrix font.
+pub static COMPACT: Font = Font {
+    name: "compact",
+    width: 5,
+    height: 7,
+    glyphs: COMPACT_GLYPHS,
+};
+
+/// The compact font with doubled vertical strokes; chunkier at a distance.
+pub static BOLD: Font = Font {
+    name: "bold",
+    width: 6,
+    height: 7,
+    glyphs: BOLD_GLYPHS,
+};
+
+/// 3x5 font for small terminals.
+pub static TINY: Font = Font {
+    name: "tiny",
+    width: 3,
+    height: 5,
+    glyphs: TINY_GLYPHS,
+};
+
+pub const FONTS: &[&Font] = &[&COMPACT, &BOLD, &TINY];
+
+/// Font names in `FONTS` order, for CLI value lists.
+pub const FONT_NAMES: &[&str] = &["compact", "bold", "tiny"];
+
+impl Font {
+    pub fn from_name(name: &str) -> Option<&'static Font> {
+        FONTS.iter().copied().find(|f| f.name == name)
+    }
+
+    /// Rows for `ch`. Letters are uppercase-only; anything without a glyph
+    /// draws as `?`.
+    pub fn glyph(&self, ch: char) -> &'static [u8] {
+        let ch = ch.to_ascii_uppercase();
+        let find = |c: char| self.glyphs.iter().find(|&&(g, _)| g == c);
+        find(ch)
+            .or_else(|| find('?'))
+            .map(|&(_, rows)| rows)
+            .expect("every font has a '?' glyph")
+    }
+
+    /// Pixels from one glyph origin to the next at `scale` (one blank column
+    /// between glyphs).
+    pub fn advance(&self, scale: usize) -> usize {
+        (self.width + 1) * scale
+    }
+
+    /// Width in pixels of `text` at `scale`, without trailing spacing.
+    pub fn text_width(&self, text: &str, scale: usize) -> usize {
+        let n = text.chars().count();
+        (n * self.advance(scale)).saturating_sub(scale)
+    }
+}
+
+const COMPACT_GLYPHS: &[(char, &[u8])] = &[
+    (
+        'A',
+        &[
+            0b01110, 0b10001, 0b10001, 0b11111, 0b10001, 0b10001, 0b10001,
+        ],
+    ),
+    (
+        'B',
+        &[
+            0b11110, 0b10001, 0b10001, 0b11110, 0b10001, 0b10001, 0b11110,
+        ],
+    ),
+    (
+        'C',
+        &[
+            0b01110, 0b10001, 0b10000, 0b10000, 0b10000, 0b10001, 0b01110,
+        ],
+    ),
+    (
+        'D',
+        &[
+            0b11110, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b11110,
+        ],
+    ),
+    (
+        'E',
+        &[
+            0b11111, 0b10000, 0b10000, 0b11110, 0b10000, 0b10000, 0b11111,
+        ],
+    ),
+    (
+        'F',
+        &[
+            0b11111, 0b10000, 0b10000, 0b11110, 0b10000, 0b10000, 0b10000,
+        ],
+    ),
+    (
+        'G',
+        &[
+            0b01110, 0b10001, 0b10000, 0b10111, 0b10001, 0b10001, 0b01111,
+        ],
+    ),
+    (
+        'H',
+        &[
+            0b10001, 0b10001, 0b10001, 0b11111, 0b10001, 0b10001, 0b10001,
+        ],
+    ),
+    (
+        'I',
+        &[
+            0b01110, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110,
+        ],
+    ),
+    (
+        'J',
+        &[
+            0b00111, 0b00010, 0b00010, 0b00010, 0b00010, 0b10010, 0b01100,
+        ],
+    ),
+    (
+        'K',
+        &[
+            0b10001, 0b10010, 0b10100, 0b11000, 0b10100, 0b10010, 0b10001,
+        ],
+    ),
+    (
+        'L',
+        &[
+            0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b11111,
+        ],
+    ),
+    (
+        'M',
+        &[
+            0b10001, 0b11011, 0b10101, 0b10101, 0b10001, 0b10001, 0b10001,
+        ],
+    ),
+    (
+        'N',
+        &[
+            0b10001, 0b10001, 0b11001, 0b10101, 0b10011, 0b10001, 0b10001,
+        ],
+    ),
+    (
+        'O',
+        &[
+            0b01110, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01110,
+        ],
+    ),
+    (
+        'P',
+        &[
+            0b11110, 0b10001, 0b10001, 0b11110, 0b10000, 0b10000, 0b10000,
+        ],
+    ),
+    (
+        'Q',
+        &[
+            0b01110, 0b10001, 0b10001, 0b10001, 0b10101, 0b10010, 0b01101,
+        ],
+    ),
+    (
+        'R',
+        &[
+            0b11110, 0b10001, 0b10001, 0b11110, 0b10100, 0b10010, 0b10001,
+        ],
+    ),
+    (
+        'S',
+        &[
+            0b01111, 0b10000, 0b10000, 0b01110, 0b00001, 0b00001, 0b11110,
+        ],
+    ),
+    (
+        'T',
+        &[
+            0b11111, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100,
+        ],
+    ),
+    (
+        'U',
+        &[
+            0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01110,
+        ],
+    ),
+    (
+        'V',
+        &[
+            0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01010, 0b00100,
+        ],
+    ),
+    (
+        'W',
+        &[
+            0b10001, 0b10001, 0b10001, 0b10101, 0b10101, 0b10101, 0b01010,
+        ],
+    ),
+    (
+        'X',
+        &[
+            0b10001, 0b10001, 0b01010, 0b00100, 0b01010, 0b10001, 0b10001,
+        ],
+    ),
+    (
+        'Y',
+        &[
+            0b10001, 0b10001, 0b01010, 0b00100, 0b00100, 0b00100, 0b00100,
+        ],
+    ),
+    (
+        'Z',
+        &[
+            0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b10000, 0b11111,
+        ],
+    ),
+    (
+        '0',
+        &[
+            0b01110, 0b10001, 0b10011, 0b10101, 0b11001, 0b10001, 0b01110,
+        ],
+    ),
+    (
+        '1',
+        &[
+            0b00100, 0b01100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110,
+        ],
+    ),
+    (
+        '2',
+        &[
+            0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b01000, 0b11111,
+        ],
+    ),
+    (
+        '3',
+        &[
+            0b11111, 0b00010, 0b00100, 0b00010, 0b00001, 0b10001, 0b01110,
+        ],
+    ),
+    (
+        '4',
+        &[
+            0b00010, 0b00110, 0b01010, 0b10010, 0b11111, 0b00010, 0b00010,
+        ],
+    ),
+    (
+        '5',
+        &[
+            0b11111, 0b10000, 0b11110, 0b00001, 0b00001, 0b10001, 0b01110,
+        ],
+    ),
+    (
+        '6',
+        &[
+            0b00110, 0b01000, 0b10000, 0b11110, 0b10001, 0b10001, 0b01110,
+        ],
+    ),
+    (
+        '7',
+        &[
+            0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b01000, 0b01000,
+        ],
+    ),
+    (
+        '8',
+        &[
+            0b01110, 0b10001, 0b10001, 0b01110, 0b10001, 0b10001, 0b01110,
+        ],
+    ),
+    (
+        '9',
+        &[
+            0b01110, 0b10001, 0b10001, 0b01111, 0b00001, 0b00010, 0b01100,
+        ],
+    ),
+    (
+        ' ',
+        &[
+            0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b00000,
+        ],
+    ),
+    (
+        '.',
+        &[
+            0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b01100, 0b01100,
+        ],
+    ),
+    (
+        ',',
+        &[
+            0b00000, 0b00000, 0b00000, 0b00000, 0b01100, 0b00100, 0b01000,
+        ],
+    ),
+    (
+        ':',
+        &[
+            0b00000, 0b01100, 0b01100, 0b00000, 0b01100, 0b01100, 0b00000,
+        ],
+    ),
+    (
+        '!',
+        &[
+            0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b00000, 0b00100,
+        ],
+    ),
+    (
+        '?',
+        &[
+            0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b00000, 0b00100,
+        ],
+    ),
+    (
+        '-',
+        &[
+            0b00000, 0b00000, 0b00000, 0b11111, 0b00000, 0b00000, 0b00000,
+        ],
+    ),
+    (
+        '+',
+        &[
+            0b00000, 0b00100, 0b00100, 0b11111, 0b00100, 0b00100, 0b00000,
+        ],
+    ),
+    (
+        '/',
+        &[
+            0b00000, 0b00001, 0b00010, 0b00100, 0b01000, 0b10000, 0b00000,
+        ],
+    ),
+    (
+        '\'',
+        &[
+            0b00100, 0b00100, 0b01000, 0b00000, 0b00000, 0b00000, 0b00000,
+        ],
+    ),
+    (
+        '(',
+        &[
+            0b00010, 0b00100, 0b01000, 0b01000, 0b01000, 0b00100, 0b00010,
+        ],
+    ),
+    (
+        ')',
+        &[
+            0b01000, 0b00100, 0b00010, 0b00010, 0b00010, 0b00100, 0b01000,
+        ],
+    ),
+    (
+        '=',
+        &[
+            0b00000, 0b00000, 0b11111, 0b00000, 0b11111, 0b00000, 0b00000,
+        ],
+    ),
+    (
+        '#',
+        &[
+            0b01010, 0b01010, 0b11111, 0b01010, 0b11111, 0b01010, 0b01010,
+        ],
+    ),
+    (
+        '*',
+        &[
+            0b00000, 0b00100, 0b10101, 0b01110, 0b10101, 0b00100, 0b00000,
+        ],
+    ),
+];
+
+const BOLD_GLYPHS: &[(char, &[u8])] = &[
+    (
+        'A',
+        &[
+            0b011110, 0b110011, 0b110011, 0b111111, 0b110011, 0b110011, 0b110011,
+        ],
+    ),
+    (
+        'B',
+        &[
+            0b111110, 0b110011, 0b110011, 0b111110, 0b110011, 0b110011, 0b111110,
+        ],
+    ),
+    (
+        'C',
+        &[
+            0b011110, 0b110011, 0b110000, 0b110000, 0b110000, 0b110011, 0b011110,
+        ],
+    ),
+    (
+        'D',
+        &[
+            0b111110, 0b110011, 0b110011, 0b110011, 0b110011, 0b110011, 0b111110,
+        ],
+    ),
+    (
+        'E',
+        &[
+            0b111111, 0b110000, 0b110000, 0b111110, 0b110000, 0b110000, 0b111111,
+        ],
+    ),
+    (
+        'F',
+        &[
+            0b111111, 0b110000, 0b110000, 0b111110, 0b110000, 0b110000, 0b110000,
+        ],
+    ),
+    (
+        'G',
+        &[
+            0b011110, 0b110011, 0b110000, 0b111111, 0b110011, 0b110011, 0b011111,
+        ],
+    ),
+    (
+        'H',
+        &[
+            0b110011, 0b110011, 0b110011, 0b111111, 0b110011, 0b110011, 0b110011,
+        ],
+    ),
+    (
+        'I',
+        &[
+            0b011110, 0b001100, 0b001100, 0b001100, 0b001100, 0b001100, 0b011110,
+        ],
+    ),
+    (
+        'J',
+        &[
+            0b001111, 0b000110, 0b000110, 0b000110, 0b000110, 0b110110, 0b011100,
+        ],
+    ),
+    (
+        'K',
+        &[
+            0b110011, 0b110110, 0b111100, 0b111000, 0b111100, 0b110110, 0b110011,
+        ],
+    ),
+    (
+        'L',
+        &[
+            0b110000, 0b110000, 0b110000, 0b110000, 0b110000, 0b110000, 0b111111,
+        ],
+    ),
+    (
+        'M',
+        &[
+            0b110011, 0b111111, 0b111111, 0b111111, 0b110011, 0b110011, 0b110011,
+        ],
+    ),
+    (
+        'N',
+        &[
+            0b110011, 0b110011, 0b111011, 0b111111, 0b110111, 0b110011, 0b110011,
+        ],
+    ),
+    (
+        'O',
+        &[
+            0b011110, 0b110011, 0b110011, 0b110011, 0b110011, 0b110011, 0b011110,
+        ],
+    ),
+    (
+        'P',
+        &[
+            0b111110, 0b110011, 0b110011, 0b111110, 0b110000, 0b110000, 0b110000,
+        ],
+    ),
+    (
+        'Q',
+        &[
+            0b011110, 0b110011, 0b110011, 0b110011, 0b111111, 0b110110, 0b011111,
+        ],
+    ),
+    (
+        'R',
+        &[
+            0b111110, 0b110011, 0b110011, 0b111110, 0b111100, 0b110110, 0b110011,
+        ],
+    ),
+    (
+        'S',
+        &[
+            0b011111, 0b110000, 0b110000, 0b011110, 0b000011, 0b000011, 0b111110,
+        ],
+    ),
+    (
+        'T',
+        &[
+            0b111111, 0b001100, 0b001100, 0b001100, 0b001100, 0b001100, 0b001100,
+        ],
+    ),
+    (
+        'U',
+        &[
+            0b110011, 0b110011, 0b110011, 0b110011, 0b110011, 0b110011, 0b011110,
+        ],
+    ),
+    (
+        'V',
+        &[
+            0b110011, 0b110011, 0b110011, 0b110011, 0b110011, 0b011110, 0b001100,
+        ],
+    ),
+    (
+        'W',
+        &[
+            0b110011, 0b110011, 0b110011, 0b111111, 0b111111, 0b111111, 0b011110,
+        ],
+    ),
+    (
+        'X',
+        &[
+            0b110011, 0b110011, 0b011110, 0b001100, 0b011110, 0b110011, 0b110011,
+        ],
+    ),
+    (
+        'Y',
+        &[
+            0b110011, 0b110011, 0b011110, 0b001100, 0b001100, 0b001100, 0b001100,
+        ],
+    ),
+    (
+        'Z',
+        &[
+            0b111111, 0b000011, 0b000110, 0b001100, 0b011000, 0b110000, 0b111111,
+        ],
+    ),
+    (
+        '0',
+        &[
+            0b011110, 0b110011, 0b110111, 0b111111, 0b111011, 0b110011, 0b011110,
+        ],
+    ),
+    (
+        '1',
+        &[
+            0b001100, 0b011100, 0b001100, 0b001100, 0b001100, 0b001100, 0b011110,
+        ],
+    ),
+    (
+        '2',
+        &[
+            0b011110, 0b110011, 0b000011, 0b000110, 0b001100, 0b011000, 0b111111,
+        ],
+    ),
+    (
+        '3',
+        &[
+            0b111111, 0b000110, 0b001100, 0b000110, 0b000011, 0b110011, 0b011110,
+        ],
+    ),
+    (
+        '4',
+        &[
+            0b000110, 0b001110, 0b011110, 0b110110, 0b111111, 0b000110, 0b000110,
+        ],
+    ),
+    (
+        '5',
+        &[
+            0b111111, 0b110000, 0b111110, 0b000011, 0b000011, 0b110011, 0b011110,
+        ],
+    ),
+    (
+        '6',
+        &[
+            0b001110, 0b011000, 0b110000, 0b111110, 0b110011, 0b110011, 0b011110,
+        ],
+    ),
+    (
+        '7',
+        &[
+            0b111111, 0b000011, 0b000110, 0b001100, 0b011000, 0b011000, 0b011000,
+        ],
+    ),
+    (
+        '8',
+        &[
+            0b011110, 0b110011, 0b110011, 0b011110, 0b110011, 0b110011, 0b011110,
+        ],
+    ),
+    (
+        '9',
+        &[
+            0b011110, 0b110011, 0b110011, 0b011111, 0b000011, 0b000110, 0b011100,
+        ],
+    ),
+    (
+        ' ',
+        &[
+            0b000000, 0b000000, 0b000000, 0b000000, 0b000000, 0b000000, 0b000000,
+        ],
+    ),
+    (
+        '.',
+        &[
+            0b000000, 0b000000, 0b000000, 0b000000, 0b000000, 0b011100, 0b011100,
+        ],
+    ),
+    (
+        ',',
+        &[
+            0b000000, 0b000000, 0b000000, 0b000000, 0b011100, 0b001100, 0b011000,
+        ],
+    ),
+    (
+        ':',
+        &[
+            0b000000, 0b011100, 0b011100, 0b000000, 0b011100, 0b011100, 0b000000,
+        ],
+    ),
+    (
+        '!',
+        &[
+            0b001100, 0b001100, 0b001100, 0b001100, 0b001100, 0b000000, 0b001100,
+        ],
+    ),
+    (
+        '?',
+        &[
+            0b011110, 0b110011, 0b000011, 0b000110, 0b001100, 0b000000, 0b001100,
+        ],
+    ),
+    (
+        '-',
+        &[
+            0b000000, 0b000000, 0b000000, 0b111111, 0b000000, 0b000000, 0b000000,
+        ],
+    ),
+    (
+        '+',
+        &[
+            0b000000, 0b001100, 0b001100, 0b111111, 0b001100, 0b001100, 0b000000,
+        ],
+    ),
+    (
+        '/',
+        &[
+            0b000000, 0b000011, 0b000110, 0b001100, 0b011000, 0b110000, 0b000000,
+        ],
+    ),
+    (
+        '\'',
+        &[
+            0b001100, 0b001100, 0b011000, 0b000000, 0b000000, 0b000000, 0b000000,
+        ],
+    ),
+    (
+        '(',
+        &[
+            0b000110, 0b001100, 0b011000, 0b011000, 0b011000, 0b001100, 0b000110,
+        ],
+    ),
+    (
+        ')',
+        &[
+            0b011000, 0b001100, 0b000110, 0b000110, 0b000110, 0b001100, 0b011000,
+        ],
+    ),
+    (
+        '=',
+        &[
+            0b000000, 0b000000, 0b111111, 0b000000, 0b111111, 0b000000, 0b000000,
+        ],
+    ),
+    (
+        '#',
+        &[
+            0b011110, 0b011110, 0b111111, 0b011110, 0b111111, 0b011110, 0b011110,
+        ],
+    ),
+    (
+        '*',
+        &[
+            0b000000, 0b001100, 0b111111, 0b011110, 0b111111, 0b001100, 0b000000,
+        ],
+    ),
+];
+
+const TINY_GLYPHS: &[(char, &[u8])] = &[
+    ('A', &[0b010, 0b101, 0b111, 0b101, 0b101]),
+    ('B', &[0b110, 0b101, 0b110, 0b101, 0b110]),
+    ('C', &[0b011, 0b100, 0b100, 0b100, 0b011]),
+    ('D', &[0b110, 0b101, 0b101, 0b101, 0b110]),
+    ('E', &[0b111, 0b100, 0b110, 0b100, 0b111]),
+    ('F', &[0b111, 0b100, 0b110, 0b100, 0b100]),
+    ('G', &[0b011, 0b100, 0b101, 0b101, 0b011]),
+    ('H', &[0b101, 0b101, 0b111, 0b101, 0b101]),
+    ('I', &[0b111, 0b010, 0b010, 0b010, 0b111]),
+    ('J', &[0b001, 0b001, 0b001, 0b101, 0b010]),
+    ('K', &[0b101, 0b101, 0b110, 0b101, 0b101]),
+    ('L', &[0b100, 0b100, 0b100, 0b100, 0b111]),
+    ('M', &[0b101, 0b111, 0b111, 0b101, 0b101]),
+    ('N', &[0b110, 0b101, 0b101, 0b101, 0b101]),
+    ('O', &[0b010, 0b101, 0b101, 0b101, 0b010]),
+    ('P', &[0b110, 0b101, 0b110, 0b100, 0b100]),
+    ('Q', &[0b010, 0b101, 0b101, 0b110, 0b011]),
+    ('R', &[0b110, 0b101, 0b110, 0b101, 0b101]),
+    ('S', &[0b011, 0b100, 0b010, 0b001, 0b110]),
+    ('T', &[0b111, 0b010, 0b010, 0b010, 0b010]),
+    ('U', &[0b101, 0b101, 0b101, 0b101, 0b111]),
+    ('V', &[0b101, 0b101, 0b101, 0b101, 0b010]),
+    ('W', &[0b101, 0b101, 0b111, 0b111, 0b101]),
+    ('X', &[0b101, 0b101, 0b010, 0b101, 0b101]),
+    ('Y', &[0b101, 0b101, 0b010, 0b010, 0b010]),
+    ('Z', &[0b111, 0b001, 0b010, 0b100, 0b111]),
+    ('0', &[0b111, 0b101, 0b101, 0b101, 0b111]),
+    ('1', &[0b010, 0b110, 0b010, 0b010, 0b111]),
+    ('2', &[0b110, 0b001, 0b010, 0b100, 0b111]),
+    ('3', &[0b110, 0b001, 0b010, 0b001, 0b110]),
+    ('4', &[0b101, 0b101, 0b111, 0b001, 0b001]),
+    ('5', &[0b111, 0b100, 0b110, 0b001, 0b110]),
+    ('6', &[0b011, 0b100, 0b111, 0b101, 0b111]),
+    ('7', &[0b111, 0b001, 0b010, 0b010, 0b010]),
+    ('8', &[0b111, 0b101, 0b111, 0b101, 0b111]),
+    ('9', &[0b111, 0b101, 0b111, 0b001, 0b110]),
+    (' ', &[0b000, 0b000, 0b000, 0b000, 0b000]),
+    ('.', &[0b000, 0b000, 0b000, 0b000, 0b010]),
+    (',', &[0b000, 0b000, 0b000, 0b010, 0b100]),
+    (':', &[0b000, 0b010, 0b000, 0b010, 0b000]),
+    ('!', &[0b010, 0b010, 0b010, 0b000, 0b010]),
+    ('?', &[0b110, 0b001, 0b010, 0b000, 0b010]),
+    ('-', &[0b000, 0b000, 0b111, 0b000, 0b000]),
+    ('+', &[0b000, 0b010, 0b111, 0b010, 0b000]),
+    ('/', &[0b001, 0b001, 0b010, 0b100, 0b100]),
+    ('\'', &[0b010, 0b010, 0b000, 0b000, 0b000]),
+    ('(', &[0b010, 0b100, 0b100, 0b100, 0b010]),
+    (')', &[0b010, 0b001, 0b001, 0b001, 0b010]),
+    ('=', &[0b000, 0b111, 0b000, 0b111, 0b000]),
+    ('#', &[0b101, 0b111, 0b101, 0b111, 0b101]),
+    ('*', &[0b000, 0b101, 0b010, 0b101, 0b000]),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glyphs_fit_font_cell() {
+        for font in FONTS {
+            for &(ch, rows) in font.glyphs {
+                assert_eq!(rows.len(), font.height, "{} {ch:?}", font.name);
+                assert!(
+                    rows.iter().all(|&r| (r as usize) < 1 << font.width),
+                    "{} {ch:?} wider than {}",
+                    font.name,
+                    font.width
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn every_font_covers_the_same_characters() {
+        let chars = |f: &Font| f.glyphs.iter().map(|&(c, _)| c).collect::<Vec<_>>();
+        for font in FONTS {
+            assert_eq!(chars(font), chars(&COMPACT), "{}", font.name);
+        }
+    }
+
+    #[test]
+    fn lowercase_and_unknown_characters_fall_back() {
+        assert_eq!(COMPACT.glyph('a'), COMPACT.glyph('A'));
+        assert_eq!(COMPACT.glyph('~'), COMPACT.glyph('?'));
+        assert_eq!(FONT_NAMES.len(), FONTS.len());
+        assert!(Font::from_name("tiny").is_some());
+    }
+}
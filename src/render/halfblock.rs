@@ -7,13 +7,10 @@ use super::cell::{Cell, CellGrid};
 /// background rather than being clipped. A low threshold preserves this detail.
 const DARK_THRESHOLD: f64 = 0.02;
 
-pub fn render(canvas: &Canvas) -> String {
-    super::encoder::encode_full(&build_grid(canvas), false)
-}
-
 pub fn build_grid(canvas: &Canvas) -> CellGrid {
     let cols = canvas.width;
     let rows = canvas.height / 2;
+    let dark_threshold = canvas.lit_threshold(DARK_THRESHOLD);
     let mut cells = Vec::with_capacity(cols * rows);
     for row in 0..rows {
         for col in 0..cols {
@@ -21,8 +18,8 @@ pub fn build_grid(canvas: &Canvas) -> CellGrid {
             let bot_idx = (row * 2 + 1) * canvas.width + col;
             let top_v = canvas.pixels[top_idx];
             let bot_v = canvas.pixels[bot_idx];
-            let top_dark = top_v < DARK_THRESHOLD;
-            let bot_dark = bot_v < DARK_THRESHOLD;
+            let top_dark = top_v < dark_threshold;
+            let bot_dark = bot_v < dark_threshold;
             let cell = if canvas.color_mode == ColorMode::Mono {
                 let ch = match (!top_dark, !bot_dark) {
                     (true, true) => '█',
@@ -34,35 +31,36 @@ pub fn build_grid(canvas: &Canvas) -> CellGrid {
                     ch,
                     fg: None,
                     bg: None,
+                    attrs: 0,
                 }
-            } else if top_dark && bot_dark {
+            } else if top_dark
+                && bot_dark
+                && canvas.bg_colors[top_idx] == (0, 0, 0)
+                && canvas.bg_colors[bot_idx] == (0, 0, 0)
+            {
                 Cell {
                     ch: ' ',
                     fg: None,
                     bg: None,
+                    attrs: 0,
                 }
             } else {
-                let (tr, tg, tb) = canvas.colors[top_idx];
-                let (br, bgc, bb) = canvas.colors[bot_idx];
-                let scale = |c: u8, v: f64| -> u8 { (c as f64 * v.clamp(0.0, 1.0)) as u8 };
-                let top = canvas.map_color(
-                    col,
-                    row,
-                    scale(tr, top_v),
-                    scale(tg, top_v),
-                    scale(tb, top_v),
-                );
-                let bot = canvas.map_color(
-                    col,
-                    row,
-                    scale(br, bot_v),
-                    scale(bgc, bot_v),
-                    scale(bb, bot_v),
-                );
+                // Each pixel shows its color scaled by brightness over its
+                // background (black unless set with `set_colored_bg`).
+                let shade = |idx: usize, v: f64| {
+                    let v = v.clamp(0.0, 1.0);
+                    let (fr, fg, fb) = canvas.colors[idx];
+                    let (br, bg, bb) = canvas.bg_colors[idx];
+                    let mix = |f: u8, b: u8| (f as f64 * v + b as f64 * (1.0 - v)) as u8;
+                    canvas.map_color(col, row, mix(fr, br), mix(fg, bg), mix(fb, bb))
+                };
+                let top = shade(top_idx, top_v);
+                let bot = shade(bot_idx, bot_v);
                 Cell {
                     ch: '▀',
                     fg: Some(top),
                     bg: Some(bot),
+                    attrs: 0,
                 }
             };
             cells.push(cell);
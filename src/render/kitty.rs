@@ -0,0 +1,130 @@
+//! `--graphics kitty`: send each frame as one true-color image using the
+//! kitty graphics protocol (kitty, WezTerm, Ghostty) instead of glyphs, at the
+//! canvas's sub-pixel resolution, scaled by the terminal to cover the view.
+
+use super::Canvas;
+use super::base64;
+use super::letterbox::Viewport;
+use flate2::Compression;
+use flate2::write::ZlibEncoder;
+use std::io::Write;
+
+/// Every frame replaces this image and its one placement, so nothing piles
+/// up in the terminal's image store.
+const IMAGE_ID: u32 = 1;
+
+/// Base64 payload bytes per escape sequence; the protocol's limit.
+const CHUNK: usize = 4096;
+
+/// Removes every image this program placed (sent on exit and before text
+/// that must not be covered, such as the too-small notice).
+pub const DELETE_ALL: &str = "\x1b_Ga=d,d=A,q=2\x1b\\";
+
+/// Best guess, from the environment, that the terminal speaks the protocol.
+/// Only used to warn; the flag is trusted either way.
+pub fn likely_supported() -> bool {
+    let env = |k: &str| std::env::var(k).unwrap_or_default();
+    std::env::var_os("KITTY_WINDOW_ID").is_some()
+        || env("TERM").contains("kitty")
+        || env("TERM").contains("ghostty")
+        || matches!(env("TERM_PROGRAM").as_str(), "WezTerm" | "ghostty")
+}
+
+/// Escape sequences that transmit `canvas` as a zlib-compressed RGBA image
+/// and display it stretched over `view`, leaving the cursor where it was.
+pub fn encode_image(canvas: &Canvas, view: &Viewport) -> String {
+    let mut zlib = ZlibEncoder::new(Vec::new(), Compression::fast());
+    // Writing into a Vec can't fail
+    zlib.write_all(&canvas_rgba(canvas)).unwrap();
+    let payload = base64::encode(&zlib.finish().unwrap());
+
+    let mut out = format!("\x1b[{};{}H", view.row + 1, view.col + 1);
+    let chunks: Vec<&[u8]> = payload.as_bytes().chunks(CHUNK).collect();
+    for (i, chunk) in chunks.iter().enumerate() {
+        let more = u8::from(i + 1 < chunks.len());
+        if i == 0 {
+            out.push_str(&format!(
+                "\x1b_Ga=T,f=32,o=z,s={},v={},c={},r={},i={IMAGE_ID},p=1,C=1,q=2,m={more};",
+                canvas.width, canvas.height, view.cols, view.rows
+            ));
+        } else {
+            out.push_str(&format!("\x1b_Gm={more};"));
+        }
+        // Base64 is ASCII
+        out.push_str(std::str::from_utf8(chunk).unwrap());
+        out.push_str("\x1b\\");
+    }
+    out
+}
+
+/// One opaque RGBA pixel per canvas pixel: the color at its brightness over
+/// the pixel's background, like a half-block cell.
+fn canvas_rgba(canvas: &Canvas) -> Vec<u8> {
+    let mut rgba = Vec::with_capacity(canvas.pixels.len() * 4);
+    for ((&v, fg), bg) in canvas
+        .pixels
+        .iter()
+        .zip(&canvas.colors)
+        .zip(&canvas.bg_colors)
+    {
+        let v = v.clamp(0.0, 1.0);
+        let mix = |f: u8, b: u8| (f as f64 * v + b as f64 * (1.0 - v)).round() as u8;
+        rgba.extend_from_slice(&[mix(fg.0, bg.0), mix(fg.1, bg.1), mix(fg.2, bg.2), 255]);
+    }
+    rgba
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::{ColorMode, RenderMode};
+    use flate2::read::ZlibDecoder;
+    use std::io::Read;
+
+    #[test]
+    fn image_is_chunked_and_decodes_to_the_canvas() {
+        let mut c = Canvas::new(40, 20, RenderMode::Braille, ColorMode::TrueColor);
+        // Noisy pixels so the compressed payload spans several chunks
+        for (i, p) in c.pixels.iter_mut().enumerate() {
+            *p = (i * 7919 % 101) as f64 / 100.0;
+        }
+        for (i, col) in c.colors.iter_mut().enumerate() {
+            *col = (i as u8, (i * 3) as u8, (i * 13) as u8);
+        }
+        let view = Viewport {
+            col: 2,
+            row: 1,
+            cols: 40,
+            rows: 20,
+        };
+        let out = encode_image(&c, &view);
+        assert!(out.starts_with("\x1b[2;3H\x1b_Ga=T,f=32,o=z,s=80,v=80,c=40,r=20,i=1,p=1,"));
+
+        let mut payload = String::new();
+        let mut last_more = None;
+        for seq in out.split("\x1b_G").skip(1) {
+            let (keys, rest) = seq.split_once(';').unwrap();
+            let data = rest.strip_suffix("\x1b\\").unwrap();
+            assert!(data.len() <= CHUNK);
+            last_more = keys.split(',').find_map(|k| k.strip_prefix("m="));
+            payload.push_str(data);
+        }
+        assert_eq!(last_more, Some("0"));
+        assert!(payload.len() > CHUNK, "test frame should need chunking");
+
+        let compressed = base64::decode(&payload).unwrap();
+        let mut rgba = Vec::new();
+        ZlibDecoder::new(&compressed[..])
+            .read_to_end(&mut rgba)
+            .unwrap();
+        assert_eq!(rgba, canvas_rgba(&c));
+        assert_eq!(rgba.len(), 80 * 80 * 4);
+    }
+
+    #[test]
+    fn pixels_blend_over_their_background() {
+        let mut c = Canvas::new(1, 1, RenderMode::HalfBlock, ColorMode::TrueColor);
+        c.set_colored_bg(0, 0, 0.5, (200, 100, 0), (0, 0, 100));
+        assert_eq!(&canvas_rgba(&c)[..4], &[100, 50, 50, 255]);
+    }
+}
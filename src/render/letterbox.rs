@@ -0,0 +1,101 @@
+//! `--letterbox`: a centered sub-rectangle of the terminal with a fixed
+//! on-screen aspect ratio, so round and 3D animations keep their proportions
+//! on very wide or very tall terminals.
+
+/// Terminal-cell rectangle the canvas is drawn into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Viewport {
+    pub col: usize,
+    pub row: usize,
+    pub cols: usize,
+    pub rows: usize,
+}
+
+/// Parse a width:height ratio written as `16:9`, `4/3`, or `1.5`.
+pub fn parse_ratio(s: &str) -> Result<f64, String> {
+    let ratio = match s.split_once([':', '/']) {
+        Some((w, h)) => {
+            let w: f64 = w
+                .trim()
+                .parse()
+                .map_err(|_| format!("bad width in {s:?}"))?;
+            let h: f64 = h
+                .trim()
+                .parse()
+                .map_err(|_| format!("bad height in {s:?}"))?;
+            w / h
+        }
+        None => s
+            .trim()
+            .parse()
+            .map_err(|_| format!("expected W:H or a number, got {s:?}"))?,
+    };
+    if ratio.is_finite() && ratio > 0.0 {
+        Ok(ratio)
+    } else {
+        Err(format!("ratio must be positive, got {s:?}"))
+    }
+}
+
+/// Largest `ratio` (on-screen width ÷ height) rectangle centered in a
+/// `cols` x `rows` area whose cells are `cell_aspect` times taller than
+/// wide. Without a ratio the whole area.
+pub fn viewport(cols: usize, rows: usize, ratio: Option<f64>, cell_aspect: f64) -> Viewport {
+    let full = Viewport {
+        col: 0,
+        row: 0,
+        cols,
+        rows,
+    };
+    let Some(ratio) = ratio else {
+        return full;
+    };
+    let area = cols as f64 / (rows as f64 * cell_aspect);
+    let (w, h) = if area > ratio {
+        let w = (rows as f64 * cell_aspect * ratio).round() as usize;
+        (w.clamp(1, cols), rows)
+    } else {
+        let h = (cols as f64 / (ratio * cell_aspect)).round() as usize;
+        (cols, h.clamp(1, rows))
+    };
+    Viewport {
+        col: (cols - w) / 2,
+        row: (rows - h) / 2,
+        cols: w,
+        rows: h,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_ratio_forms() {
+        assert_eq!(parse_ratio("16:9"), Ok(16.0 / 9.0));
+        assert_eq!(parse_ratio("4/3"), Ok(4.0 / 3.0));
+        assert_eq!(parse_ratio(" 1.5 "), Ok(1.5));
+        assert!(parse_ratio("wide").is_err());
+        assert!(parse_ratio("1:0").is_err());
+        assert!(parse_ratio("-2").is_err());
+    }
+
+    #[test]
+    fn viewport_is_centered_at_the_target_ratio() {
+        // 200x50 cells at 2:1 is 200:100 on screen; a square keeps 100 columns.
+        let v = viewport(200, 50, Some(1.0), 2.0);
+        assert_eq!(
+            v,
+            Viewport {
+                col: 50,
+                row: 0,
+                cols: 100,
+                rows: 50
+            }
+        );
+        // A tall area keeps its width and loses rows above and below.
+        let v = viewport(40, 60, Some(1.0), 2.0);
+        assert_eq!((v.col, v.row, v.cols, v.rows), (0, 20, 40, 20));
+        assert_eq!(viewport(80, 24, None, 2.0).cols, 80);
+    }
+}
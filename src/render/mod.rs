@@ -1,9 +1,17 @@
+pub mod base64;
 pub mod braille;
 pub mod canvas;
 pub mod cell;
 pub mod color_assist;
 pub mod encoder;
+pub mod font;
 pub mod halfblock;
+pub mod kitty;
+pub mod letterbox;
+pub mod quadrant;
 
-pub use canvas::{Canvas, ColorMode, PostProcessConfig, RenderMode, smoothing_alpha};
+pub use canvas::{
+    Canvas, ColorMode, DEFAULT_ASCII_RAMP, DEFAULT_CELL_ASPECT, Mirror, PostProcessConfig,
+    RenderMode, smoothing_alpha, subcell_grid,
+};
 pub use color_assist::ColorAssist;
@@ -0,0 +1,97 @@
+use super::canvas::{Canvas, ColorMode};
+use super::cell::{Cell, CellGrid};
+
+/// Quadrant glyphs indexed by lit sub-pixels within a 2x2 cell:
+/// bit 0 upper-left, bit 1 upper-right, bit 2 lower-left, bit 3 lower-right.
+const QUADRANTS: [char; 16] = [
+    ' ', '▘', '▝', '▀', '▖', '▌', '▞', '▛', '▗', '▚', '▐', '▜', '▄', '▙', '▟', '█',
+];
+
+/// Minimum pixel brightness [0.0..=1.0] to fill a quadrant. Lower than the
+/// braille threshold (0.3): lit quadrants are colored by brightness, so dim
+/// pixels already read as dark blocks instead of full-strength dots.
+const BRIGHTNESS_THRESHOLD: f64 = 0.15;
+
+pub fn build_grid(canvas: &Canvas) -> CellGrid {
+    let cols = canvas.width / 2;
+    let rows = canvas.height / 2;
+    let use_color = canvas.color_mode != ColorMode::Mono;
+    let threshold = canvas.lit_threshold(BRIGHTNESS_THRESHOLD);
+    let mut cells = Vec::with_capacity(cols * rows);
+    for row in 0..rows {
+        for col in 0..cols {
+            let mut bits = 0;
+            // A cell carries one foreground color: the mean of its lit
+            // quadrants, each scaled by brightness like half-block output.
+            let mut total = (0.0, 0.0, 0.0);
+            let mut lit = 0.0;
+            for (bit, (dx, dy)) in [(0, 0), (1, 0), (0, 1), (1, 1)].into_iter().enumerate() {
+                let idx = (row * 2 + dy) * canvas.width + col * 2 + dx;
+                let v = canvas.pixels[idx];
+                if v > threshold {
+                    bits |= 1 << bit;
+                    let (r, g, b) = canvas.colors[idx];
+                    let v = v.min(1.0);
+                    total.0 += r as f64 * v;
+                    total.1 += g as f64 * v;
+                    total.2 += b as f64 * v;
+                    lit += 1.0;
+                }
+            }
+            let ch = QUADRANTS[bits];
+            let fg = (use_color && lit > 0.0).then(|| {
+                canvas.map_color(
+                    col,
+                    row,
+                    (total.0 / lit) as u8,
+                    (total.1 / lit) as u8,
+                    (total.2 / lit) as u8,
+                )
+            });
+            let bg = canvas.cell_bg(col, row, 2, 2);
+            cells.push(Cell {
+                ch,
+                fg,
+                bg,
+                attrs: 0,
+            });
+        }
+    }
+    CellGrid { cols, rows, cells }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::RenderMode;
+    use crossterm::style::Color;
+
+    #[test]
+    fn lit_quadrants_pick_glyph_and_average_color() {
+        let mut c = Canvas::new(1, 1, RenderMode::Quadrant, ColorMode::TrueColor);
+        c.set_colored(1, 0, 1.0, 200, 0, 0);
+        c.set_colored(0, 1, 0.5, 0, 0, 200);
+        c.set_colored(1, 1, 0.1, 0, 255, 0);
+        let cell = build_grid(&c).get(0, 0);
+        assert_eq!(cell.ch, '▞');
+        assert_eq!(
+            cell.fg,
+            Some(Color::Rgb {
+                r: 100,
+                g: 0,
+                b: 50
+            })
+        );
+    }
+
+    #[test]
+    fn mono_cells_fill_or_clear_whole_cell() {
+        let mut c = Canvas::new(1, 1, RenderMode::Quadrant, ColorMode::Mono);
+        for (x, y) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+            c.set(x, y, 1.0);
+        }
+        assert_eq!(build_grid(&c).get(0, 0).ch, '█');
+        c.clear();
+        assert_eq!(build_grid(&c).get(0, 0).ch, ' ');
+    }
+}
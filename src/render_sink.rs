@@ -1,3 +1,4 @@
+use crate::keymap::{self, KeyBinding};
 use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
 use std::io;
 use std::os::unix::io::RawFd;
@@ -11,6 +12,9 @@ use std::time::{Duration, Instant};
 const SUBMIT_POLL_TIMEOUT: Duration = Duration::from_millis(2);
 /// How long `shutdown` waits for the writer to finish before giving up.
 const SHUTDOWN_JOIN_TIMEOUT: Duration = Duration::from_millis(1000);
+/// How long one chunk waits for the fd to accept output before re-checking for
+/// quit. Bounds the time a flooded terminal can hold the write loop hostage.
+const WRITE_READY_TIMEOUT_MS: i32 = 50;
 
 /// Outcome of a chunked write.
 pub enum WriteOutcome {
@@ -24,6 +28,13 @@ pub enum WriteOutcome {
 /// and after each successful chunk. Returns `QuitSignaled` if `should_quit`
 /// returned `Ok(true)` before the buffer was fully written.
 ///
+/// Each chunk first waits (bounded) for `fd` to become writable, so a full
+/// terminal buffer re-checks `should_quit` every `WRITE_READY_TIMEOUT_MS`
+/// instead of parking inside `libc::write()` until the terminal drains.
+/// Writability only promises some free space, so `fd` is non-blocking for
+/// the duration: a chunk bigger than that space writes partially or fails
+/// with `WouldBlock`, and the loop goes back to waiting.
+///
 /// Shared by the inline (main-thread) write path and the threaded writer so the
 /// on-wire bytes stay identical between `--single-threaded` and threaded mode.
 pub fn write_chunked(
@@ -31,11 +42,15 @@ pub fn write_chunked(
     buf: &[u8],
     mut should_quit: impl FnMut() -> io::Result<bool>,
 ) -> io::Result<WriteOutcome> {
+    let _nonblocking = NonBlocking::set(fd)?;
     let mut written = 0;
     while written < buf.len() {
         if should_quit()? {
             return Ok(WriteOutcome::QuitSignaled);
         }
+        if !wait_writable(fd, WRITE_READY_TIMEOUT_MS)? {
+            continue;
+        }
         let chunk_end = (written + 16384).min(buf.len());
         let n = unsafe {
             libc::write(
@@ -51,7 +66,10 @@ pub fn write_chunked(
             }
         } else if n < 0 {
             let err = io::Error::last_os_error();
-            if err.kind() == io::ErrorKind::Interrupted {
+            if matches!(
+                err.kind(),
+                io::ErrorKind::Interrupted | io::ErrorKind::WouldBlock
+            ) {
                 continue;
             }
             return Err(err);
@@ -60,10 +78,55 @@ pub fn write_chunked(
     Ok(WriteOutcome::Complete)
 }
 
+/// `O_NONBLOCK` on an fd until dropped, which restores the original flags.
+/// The flag lives on the shared open file (the terminal, usually also
+/// stderr), so it must not outlive the write.
+struct NonBlocking {
+    fd: RawFd,
+    flags: libc::c_int,
+}
+
+impl NonBlocking {
+    fn set(fd: RawFd) -> io::Result<NonBlocking> {
+        let flags = unsafe { libc::fcntl(fd, libc::F_GETFL) };
+        if flags < 0 || unsafe { libc::fcntl(fd, libc::F_SETFL, flags | libc::O_NONBLOCK) } < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(NonBlocking { fd, flags })
+    }
+}
+
+impl Drop for NonBlocking {
+    fn drop(&mut self) {
+        unsafe {
+            libc::fcntl(self.fd, libc::F_SETFL, self.flags);
+        }
+    }
+}
+
+/// Wait up to `timeout_ms` for `fd` to accept output. Returns `Ok(false)` on
+/// timeout or signal interruption so the caller can re-check for quit.
+fn wait_writable(fd: RawFd, timeout_ms: i32) -> io::Result<bool> {
+    let mut pfd = libc::pollfd {
+        fd,
+        events: libc::POLLOUT,
+        revents: 0,
+    };
+    let n = unsafe { libc::poll(&mut pfd, 1, timeout_ms) };
+    if n < 0 {
+        let err = io::Error::last_os_error();
+        if err.kind() == io::ErrorKind::Interrupted {
+            return Ok(false);
+        }
+        return Err(err);
+    }
+    Ok(n > 0)
+}
+
 /// Returns true if the given key event is a quit gesture (a configured quit key
 /// or Ctrl+C). Shared by the inline write closure and the threaded submit loop.
-pub fn is_quit_key(code: KeyCode, modifiers: KeyModifiers, quit_keys: &[KeyCode]) -> bool {
-    quit_keys.contains(&code)
+pub fn is_quit_key(code: KeyCode, modifiers: KeyModifiers, quit_keys: &[KeyBinding]) -> bool {
+    quit_keys.contains(&keymap::normalize(code, modifiers))
         || (code == KeyCode::Char('c') && modifiers.contains(KeyModifiers::CONTROL))
 }
 
@@ -118,7 +181,7 @@ impl ThreadedRenderer {
         &mut self,
         mut buf: Vec<u8>,
         quit: &AtomicBool,
-        quit_keys: &[KeyCode],
+        quit_keys: &[KeyBinding],
     ) -> io::Result<SubmitResult> {
         let tx = match &self.tx {
             Some(tx) => tx,
@@ -168,7 +231,7 @@ impl ThreadedRenderer {
 
 /// Read one pending input event (event::poll already confirmed one is ready) and
 /// return true if it is a quit gesture, setting `quit` when it is.
-fn poll_quit(quit: &AtomicBool, quit_keys: &[KeyCode]) -> io::Result<bool> {
+fn poll_quit(quit: &AtomicBool, quit_keys: &[KeyBinding]) -> io::Result<bool> {
     if let Event::Key(KeyEvent {
         code,
         kind: KeyEventKind::Press,
@@ -238,6 +301,65 @@ mod tests {
         );
     }
 
+    #[test]
+    fn write_chunked_rechecks_quit_while_fd_is_full() {
+        let (_read_end, write_end) = make_pipe();
+        let fd = write_end.as_raw_fd();
+        // Fill the pipe so the next write would block indefinitely.
+        unsafe {
+            let flags = libc::fcntl(fd, libc::F_GETFL);
+            libc::fcntl(fd, libc::F_SETFL, flags | libc::O_NONBLOCK);
+            let junk = [0u8; 4096];
+            while libc::write(fd, junk.as_ptr() as *const libc::c_void, junk.len()) > 0 {}
+            libc::fcntl(fd, libc::F_SETFL, flags);
+        }
+        let start = Instant::now();
+        let mut calls = 0;
+        let outcome = write_chunked(fd, b"frame", || {
+            calls += 1;
+            Ok(calls >= 3)
+        })
+        .unwrap();
+        assert!(matches!(outcome, WriteOutcome::QuitSignaled));
+        assert!(
+            start.elapsed() < Duration::from_secs(2),
+            "quit took {:?} with a full fd",
+            start.elapsed()
+        );
+    }
+
+    #[test]
+    fn write_chunked_never_blocks_on_a_partly_free_fd() {
+        let (mut read_end, write_end) = make_pipe();
+        let fd = write_end.as_raw_fd();
+        // Fill the pipe, then free one page: writable, but a 16KB chunk
+        // would block in a blocking write() with nobody reading.
+        unsafe {
+            let flags = libc::fcntl(fd, libc::F_GETFL);
+            libc::fcntl(fd, libc::F_SETFL, flags | libc::O_NONBLOCK);
+            let junk = [0u8; 4096];
+            while libc::write(fd, junk.as_ptr() as *const libc::c_void, junk.len()) > 0 {}
+            libc::fcntl(fd, libc::F_SETFL, flags);
+        }
+        read_end.read_exact(&mut [0u8; 4096]).unwrap();
+        let start = Instant::now();
+        let mut calls = 0;
+        let outcome = write_chunked(fd, &[b'x'; 40_000], || {
+            calls += 1;
+            Ok(calls >= 6)
+        })
+        .unwrap();
+        assert!(matches!(outcome, WriteOutcome::QuitSignaled));
+        assert!(
+            start.elapsed() < Duration::from_secs(2),
+            "quit took {:?} with a partly free fd",
+            start.elapsed()
+        );
+        // The original (blocking) flags are back
+        let flags = unsafe { libc::fcntl(fd, libc::F_GETFL) };
+        assert_eq!(flags & libc::O_NONBLOCK, 0);
+    }
+
     #[test]
     fn threaded_renderer_writes_submitted_frame() {
         let (read_end, write_end) = make_pipe();
@@ -246,7 +368,7 @@ mod tests {
         let mut renderer = ThreadedRenderer::new(quit.clone(), fd);
         let frame = b"\x1b[?2026hframe1\x1b[?2026l".to_vec();
         let res = renderer
-            .submit(frame, &quit, &[KeyCode::Char('q')])
+            .submit(frame, &quit, &[(KeyCode::Char('q'), KeyModifiers::NONE)])
             .unwrap();
         assert!(matches!(res, SubmitResult::Ok));
         // shutdown joins the writer, guaranteeing the frame was written before we read.
@@ -282,7 +404,11 @@ mod tests {
         // Drop the sender to simulate the writer having exited.
         renderer.tx.take();
         let res = renderer
-            .submit(vec![b'x'; 10], &quit, &[KeyCode::Char('q')])
+            .submit(
+                vec![b'x'; 10],
+                &quit,
+                &[(KeyCode::Char('q'), KeyModifiers::NONE)],
+            )
             .unwrap();
         assert!(matches!(res, SubmitResult::WriterDied));
     }
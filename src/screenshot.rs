@@ -0,0 +1,172 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Which file a screenshot key writes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// The frame's escape codes as drawn, after a clear; `cat` replays it
+    Ansi,
+    /// Plain text with SGR stripped, one line per terminal row
+    Text,
+}
+
+impl Format {
+    fn extension(self) -> &'static str {
+        match self {
+            Format::Ansi => "ans",
+            Format::Text => "txt",
+        }
+    }
+}
+
+/// Write `frame` (an encoded full frame, e.g. `Canvas::render()`) to
+/// `termflix-YYYYMMDD-HHMMSS.<ext>` in `dir`, adding `-2`, `-3`, ... if
+/// that name is taken. Returns the path written.
+pub fn save(dir: &Path, frame: &str, format: Format) -> io::Result<PathBuf> {
+    let stem = format!("termflix-{}", timestamp(SystemTime::now()));
+    let ext = format.extension();
+    let mut path = dir.join(format!("{stem}.{ext}"));
+    let mut n = 2;
+    while path.exists() {
+        path = dir.join(format!("{stem}-{n}.{ext}"));
+        n += 1;
+    }
+    let contents = match format {
+        Format::Ansi => format!("\x1b[2J\x1b[H{frame}\x1b[0m\n"),
+        Format::Text => plain_text(frame),
+    };
+    fs::write(&path, contents)?;
+    Ok(path)
+}
+
+/// Drop every CSI escape from an encoded frame, turning the cursor moves
+/// between rows into newlines. Trailing spaces are trimmed from each line.
+pub fn plain_text(frame: &str) -> String {
+    let mut lines = vec![String::new()];
+    let mut started = false;
+    let mut chars = frame.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '\x1b' {
+            lines.last_mut().unwrap().push(ch);
+            started = true;
+            continue;
+        }
+        if chars.next() != Some('[') {
+            continue;
+        }
+        // Parameters and intermediates, then one final byte in @..~
+        let fin = chars.by_ref().find(|c| ('@'..='~').contains(c));
+        if fin == Some('H') && started {
+            lines.push(String::new());
+        }
+    }
+    let text: Vec<&str> = lines.iter().map(|l| l.trim_end_matches(' ')).collect();
+    // Blank rows at the bottom carry nothing worth keeping
+    let mut out = text.join("\n").trim_end_matches('\n').to_string();
+    out.push('\n');
+    out
+}
+
+/// OSC 52 sequence that puts `text` on the system clipboard. The terminal
+/// does the copy, so it works over SSH with no clipboard tool on the host.
+pub fn osc52(text: &str) -> String {
+    format!(
+        "\x1b]52;c;{}\x07",
+        termflix::render::base64::encode(text.as_bytes())
+    )
+}
+
+/// `YYYYMMDD-HHMMSS` for `time`, in local time where the platform tells us
+/// the offset, otherwise UTC.
+fn timestamp(time: SystemTime) -> String {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
+    let local = secs + utc_offset(secs);
+    let (days, day_secs) = (local.div_euclid(86_400), local.rem_euclid(86_400));
+    let (y, m, d) = civil_from_days(days);
+    format!(
+        "{y:04}{m:02}{d:02}-{:02}{:02}{:02}",
+        day_secs / 3600,
+        day_secs / 60 % 60,
+        day_secs % 60
+    )
+}
+
+#[cfg(unix)]
+fn utc_offset(secs: i64) -> i64 {
+    let t = secs as libc::time_t;
+    // SAFETY: `localtime_r` only reads `t` and writes into `tm`, which we own.
+    unsafe {
+        let mut tm: libc::tm = std::mem::zeroed();
+        if libc::localtime_r(&t, &mut tm).is_null() {
+            0
+        } else {
+            tm.tm_gmtoff as i64
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn utc_offset(_secs: i64) -> i64 {
+    0
+}
+
+/// Proleptic Gregorian `(year, month, day)` for days since 1970-01-01
+/// (Howard Hinnant's `civil_from_days`).
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let y = yoe + era * 400 + i64::from(m <= 2);
+    (y, m, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn civil_dates_from_day_counts() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        assert_eq!(civil_from_days(11_016), (2000, 2, 29));
+        assert_eq!(civil_from_days(20_743), (2026, 10, 17));
+    }
+
+    #[test]
+    fn plain_text_strips_sgr_and_splits_rows() {
+        let frame = "\x1b[38;2;255;0;0mab \x1b[0m \x1b[2;1H\x1b[1mc\x1b[0m\x1b[3;1H   \x1b[4;1H";
+        assert_eq!(plain_text(frame), "ab\nc\n");
+    }
+
+    #[test]
+    fn osc52_wraps_base64_text() {
+        assert_eq!(osc52("hi\n"), "\x1b]52;c;aGkK\x07");
+    }
+
+    #[test]
+    fn save_never_overwrites() {
+        let dir = std::env::temp_dir().join(format!("termflix-shot-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let first = save(&dir, "\x1b[1mhi\x1b[0m", Format::Text).unwrap();
+        let second = save(&dir, "\x1b[1mhi\x1b[0m", Format::Ansi).unwrap();
+        let third = save(&dir, "yo", Format::Text).unwrap();
+        assert_ne!(first, third);
+        assert_eq!(fs::read_to_string(&first).unwrap(), "hi\n");
+        assert!(
+            fs::read_to_string(&second)
+                .unwrap()
+                .starts_with("\x1b[2J\x1b[H\x1b[1mhi")
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,120 @@
+//! `--beep`: audible cues for the game animations' [`GameEvent`]s.
+
+use crate::animations::GameEvent;
+use std::time::{Duration, Instant};
+
+/// Shortest gap between terminal bells. `invaders` fires a few times a
+/// second, and most terminals ding or flash on every BEL.
+const MIN_BELL_GAP: Duration = Duration::from_millis(300);
+
+/// Turns game events into sound: short tones when built with the `sound`
+/// feature and an audio output opens, otherwise the terminal bell.
+pub struct Beeper {
+    last_bell: Option<Instant>,
+    #[cfg(feature = "sound")]
+    tones: Option<tones::Tones>,
+}
+
+impl Beeper {
+    pub fn new() -> Self {
+        Beeper {
+            last_bell: None,
+            #[cfg(feature = "sound")]
+            tones: tones::Tones::open(),
+        }
+    }
+
+    /// Sound one frame's `events`. A bell is appended to `out`, the frame
+    /// buffer, so it goes out with the frame; several events in one frame,
+    /// or within `MIN_BELL_GAP` of the last bell, ring once.
+    pub fn play(&mut self, events: &[GameEvent], now: Instant, out: &mut Vec<u8>) {
+        if events.is_empty() {
+            return;
+        }
+        #[cfg(feature = "sound")]
+        if let Some(tones) = &self.tones {
+            for &event in events {
+                tones.play(event);
+            }
+            return;
+        }
+        if self
+            .last_bell
+            .is_none_or(|last| now.duration_since(last) >= MIN_BELL_GAP)
+        {
+            out.push(0x07);
+            self.last_bell = Some(now);
+        }
+    }
+}
+
+#[cfg(feature = "sound")]
+mod tones {
+    use super::GameEvent;
+    use rodio::source::{SineWave, Source};
+    use rodio::{OutputStream, OutputStreamBuilder};
+    use std::time::Duration;
+
+    /// Tone volume; sine waves at full scale are harsh.
+    const VOLUME: f32 = 0.15;
+
+    /// The default audio output, kept open for the whole run.
+    pub struct Tones {
+        stream: OutputStream,
+    }
+
+    impl Tones {
+        /// `None` when there is no usable output device.
+        pub fn open() -> Option<Tones> {
+            let mut stream = OutputStreamBuilder::open_default_stream().ok()?;
+            // Dropping the stream at exit would otherwise print to stderr.
+            stream.log_on_drop(false);
+            Some(Tones { stream })
+        }
+
+        pub fn play(&self, event: GameEvent) {
+            let (freq, ms) = tone(event);
+            self.stream.mixer().add(
+                SineWave::new(freq)
+                    .take_duration(Duration::from_millis(ms))
+                    .amplify(VOLUME),
+            );
+        }
+    }
+
+    /// Pitch in Hz and length in milliseconds for each event.
+    fn tone(event: GameEvent) -> (f32, u64) {
+        match event {
+            GameEvent::Shot => (1320.0, 30),
+            GameEvent::Kill => (220.0, 60),
+            GameEvent::Eat => (880.0, 50),
+            GameEvent::Score => (440.0, 150),
+            GameEvent::Die => (110.0, 400),
+            GameEvent::Complete => (660.0, 250),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bell_rings_once_per_gap() {
+        let mut beeper = Beeper {
+            last_bell: None,
+            #[cfg(feature = "sound")]
+            tones: None,
+        };
+        let start = Instant::now();
+        let mut out = Vec::new();
+        beeper.play(&[], start, &mut out);
+        assert!(out.is_empty());
+        beeper.play(&[GameEvent::Shot, GameEvent::Kill], start, &mut out);
+        assert_eq!(out, b"\x07");
+        beeper.play(&[GameEvent::Shot], start + MIN_BELL_GAP / 2, &mut out);
+        assert_eq!(out, b"\x07");
+        beeper.play(&[GameEvent::Shot], start + MIN_BELL_GAP, &mut out);
+        assert_eq!(out, b"\x07\x07");
+    }
+}
@@ -0,0 +1,247 @@
+/// Status bar text used when no `--status-format` / `status_format` is given;
+/// it renders the bar exactly as earlier versions drew it.
+pub const DEFAULT_STATUS_FORMAT: &str = " {name} | {render} | {color} | {fps}{rec}{paused}{speed}{saved} | bloom:{bloom} | smooth:{smooth} | dither:{dither}{assist} | {keys} ";
+
+/// A value the status bar can show.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Name,
+    Render,
+    Color,
+    Fps,
+    Rec,
+    Paused,
+    Speed,
+    Saved,
+    Scale,
+    Bloom,
+    Smooth,
+    Dither,
+    Assist,
+    Keys,
+}
+
+impl Field {
+    const ALL: [(&'static str, Field); 14] = [
+        ("name", Field::Name),
+        ("render", Field::Render),
+        ("color", Field::Color),
+        ("fps", Field::Fps),
+        ("rec", Field::Rec),
+        ("paused", Field::Paused),
+        ("speed", Field::Speed),
+        ("saved", Field::Saved),
+        ("scale", Field::Scale),
+        ("bloom", Field::Bloom),
+        ("smooth", Field::Smooth),
+        ("dither", Field::Dither),
+        ("assist", Field::Assist),
+        ("keys", Field::Keys),
+    ];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Text(String),
+    Field(Field),
+}
+
+/// A parsed status bar template: literal text with `{placeholder}`s, where
+/// `{{` and `}}` stand for literal braces. Parsed once at startup, filled in
+/// every frame by [`StatusFormat::render`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatusFormat {
+    segments: Vec<Segment>,
+}
+
+impl Default for StatusFormat {
+    fn default() -> Self {
+        StatusFormat::parse(DEFAULT_STATUS_FORMAT).expect("default status format parses")
+    }
+}
+
+/// Per-frame values for the placeholders.
+pub struct StatusValues<'a> {
+    pub name: &'a str,
+    /// e.g. `HalfBlock`
+    pub render: String,
+    /// e.g. `TrueColor`
+    pub color: String,
+    /// `24 fps`, or `∞ fps` when uncapped
+    pub fps: String,
+    pub recording: bool,
+    pub paused: bool,
+    /// Global speed multiplier; shown only when not 1.0
+    pub speed: f64,
+    /// File name of a screenshot saved moments ago
+    pub saved: Option<&'a str>,
+    /// The frame was copied to the clipboard moments ago
+    pub copied: bool,
+    pub scale: f64,
+    pub bloom: bool,
+    pub smooth: bool,
+    pub dither: bool,
+    /// ` | pal:viridis`, ` | cb:protanopia`, or empty
+    pub assist: String,
+    /// Hints for the active key bindings ([`KeyMap::hints`](crate::keymap::KeyMap::hints))
+    pub keys: &'a str,
+}
+
+impl StatusFormat {
+    /// Parse `template`, rejecting unknown placeholders and unbalanced braces.
+    pub fn parse(template: &str) -> Result<StatusFormat, String> {
+        let mut segments = Vec::new();
+        let mut text = String::new();
+        let mut chars = template.chars().peekable();
+        while let Some(ch) = chars.next() {
+            match ch {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    text.push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    text.push('}');
+                }
+                '{' => {
+                    let mut key = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) => key.push(c),
+                            None => return Err(format!("unclosed '{{{key}'")),
+                        }
+                    }
+                    let field = Field::ALL
+                        .iter()
+                        .find(|(name, _)| *name == key)
+                        .map(|&(_, f)| f)
+                        .ok_or_else(|| {
+                            let names: Vec<&str> = Field::ALL.iter().map(|(n, _)| *n).collect();
+                            format!(
+                                "unknown placeholder '{{{key}}}' (expected one of {{{}}})",
+                                names.join("}, {")
+                            )
+                        })?;
+                    if !text.is_empty() {
+                        segments.push(Segment::Text(std::mem::take(&mut text)));
+                    }
+                    segments.push(Segment::Field(field));
+                }
+                '}' => return Err("unmatched '}' (write '}}' for a literal brace)".into()),
+                _ => text.push(ch),
+            }
+        }
+        if !text.is_empty() {
+            segments.push(Segment::Text(text));
+        }
+        Ok(StatusFormat { segments })
+    }
+
+    /// The status bar text for this frame.
+    pub fn render(&self, v: &StatusValues) -> String {
+        let on_off = |on: bool| if on { "ON" } else { "off" };
+        let mut out = String::new();
+        for segment in &self.segments {
+            match segment {
+                Segment::Text(t) => out.push_str(t),
+                Segment::Field(f) => match f {
+                    Field::Name => out.push_str(v.name),
+                    Field::Render => out.push_str(&v.render),
+                    Field::Color => out.push_str(&v.color),
+                    Field::Fps => out.push_str(&v.fps),
+                    Field::Rec if v.recording => out.push_str(" [REC]"),
+                    Field::Paused if v.paused => out.push_str(" [PAUSED]"),
+                    Field::Saved => {
+                        if let Some(name) = v.saved {
+                            out.push_str(&format!(" [SAVED {name}]"));
+                        }
+                        if v.copied {
+                            out.push_str(" [COPIED]");
+                        }
+                    }
+                    Field::Speed if v.speed != 1.0 => out.push_str(&format!(" [{:.2}x]", v.speed)),
+                    Field::Rec | Field::Paused | Field::Speed => {}
+                    Field::Scale => out.push_str(&format!("{:.2}x", v.scale)),
+                    Field::Bloom => out.push_str(on_off(v.bloom)),
+                    Field::Smooth => out.push_str(on_off(v.smooth)),
+                    Field::Dither => out.push_str(on_off(v.dither)),
+                    Field::Assist => out.push_str(&v.assist),
+                    Field::Keys => out.push_str(v.keys),
+                },
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values() -> StatusValues<'static> {
+        StatusValues {
+            name: "fire",
+            render: "HalfBlock".into(),
+            color: "TrueColor".into(),
+            fps: "24 fps".into(),
+            recording: true,
+            paused: false,
+            speed: 1.0,
+            saved: None,
+            copied: false,
+            scale: 1.5,
+            bloom: false,
+            smooth: true,
+            dither: false,
+            assist: String::new(),
+            keys: "[q] quit",
+        }
+    }
+
+    #[test]
+    fn default_format_matches_the_classic_bar() {
+        assert_eq!(
+            StatusFormat::default().render(&values()),
+            " fire | HalfBlock | TrueColor | 24 fps [REC] | bloom:off | smooth:ON | dither:off | [q] quit "
+        );
+    }
+
+    #[test]
+    fn speed_shows_only_when_changed() {
+        let f = StatusFormat::parse("{fps}{speed}").unwrap();
+        assert_eq!(f.render(&values()), "24 fps");
+        let slow = StatusValues {
+            speed: 0.5,
+            ..values()
+        };
+        assert_eq!(f.render(&slow), "24 fps [0.50x]");
+    }
+
+    #[test]
+    fn saved_placeholder_also_flags_a_copy() {
+        let f = StatusFormat::parse("{saved}").unwrap();
+        let copied = StatusValues {
+            copied: true,
+            ..values()
+        };
+        assert_eq!(f.render(&copied), " [COPIED]");
+    }
+
+    #[test]
+    fn custom_format_substitutes_placeholders_and_braces() {
+        let f = StatusFormat::parse("{name} | {fps} {{x{scale}}}").unwrap();
+        assert_eq!(f.render(&values()), "fire | 24 fps {x1.50x}");
+    }
+
+    #[test]
+    fn rejects_unknown_placeholders_and_stray_braces() {
+        assert!(
+            StatusFormat::parse("{nam}")
+                .unwrap_err()
+                .contains("'{nam}'")
+        );
+        assert!(StatusFormat::parse("fps}").is_err());
+        assert!(StatusFormat::parse("{fps").is_err());
+    }
+}
@@ -0,0 +1,177 @@
+//! Escape-sequence queries the terminal answers on stdin: the DECRQM probe
+//! for synchronized output and OSC 4 palette reads for `--theme-from-terminal`.
+
+use std::time::Duration;
+
+/// An RGB triple.
+pub type Rgb = (u8, u8, u8);
+
+/// How long to wait for all sixteen palette replies.
+const PALETTE_TIMEOUT: Duration = Duration::from_millis(300);
+
+/// Why a query could not be sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueryError {
+    NotATerminal,
+    NoRawMode,
+    /// No query support on this platform (non-Unix)
+    #[cfg_attr(unix, allow(dead_code))]
+    Unsupported,
+}
+
+/// Write `request` in raw mode and read the reply until `done` accepts it or
+/// `timeout` passes. A terminal that doesn't answer yields whatever arrived,
+/// often nothing.
+#[cfg(unix)]
+pub fn query(
+    request: &[u8],
+    timeout: Duration,
+    done: impl Fn(&[u8]) -> bool,
+) -> Result<Vec<u8>, QueryError> {
+    use crossterm::terminal;
+    use std::io::{self, IsTerminal, Write};
+    use std::os::unix::io::AsRawFd;
+    use std::time::Instant;
+
+    if !io::stdin().is_terminal() || !io::stdout().is_terminal() {
+        return Err(QueryError::NotATerminal);
+    }
+    if terminal::enable_raw_mode().is_err() {
+        return Err(QueryError::NoRawMode);
+    }
+    let mut stdout = io::stdout();
+    let _ = stdout.write_all(request);
+    let _ = stdout.flush();
+
+    let fd = io::stdin().as_raw_fd();
+    let deadline = Instant::now() + timeout;
+    let mut reply = Vec::new();
+    while !done(&reply) {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        let mut pfd = libc::pollfd {
+            fd,
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        if remaining.is_zero()
+            || unsafe { libc::poll(&mut pfd, 1, remaining.as_millis() as i32) } <= 0
+        {
+            break;
+        }
+        let mut byte = 0u8;
+        if unsafe { libc::read(fd, &mut byte as *mut u8 as *mut libc::c_void, 1) } != 1 {
+            break;
+        }
+        reply.push(byte);
+    }
+    let _ = terminal::disable_raw_mode();
+    Ok(reply)
+}
+
+#[cfg(not(unix))]
+pub fn query(
+    _request: &[u8],
+    _timeout: Duration,
+    _done: impl Fn(&[u8]) -> bool,
+) -> Result<Vec<u8>, QueryError> {
+    Err(QueryError::Unsupported)
+}
+
+/// The terminal's 16 ANSI colors, read with OSC 4 queries. A primary device
+/// attributes request (DA1) follows them; every terminal answers that, so
+/// its reply ends the wait early when OSC 4 goes unanswered. `None` unless
+/// all 16 colors came back.
+pub fn query_palette() -> Option<[Rgb; 16]> {
+    let mut request: Vec<u8> = (0..16)
+        .flat_map(|i| format!("\x1b]4;{i};?\x1b\\").into_bytes())
+        .collect();
+    request.extend_from_slice(b"\x1b[c");
+    let reply = query(&request, PALETTE_TIMEOUT, |r| {
+        r.ends_with(b"c") && r.windows(3).any(|w| w == b"\x1b[?")
+    })
+    .ok()?;
+    parse_palette(&reply)
+}
+
+/// Colors from OSC 4 replies (`ESC ] 4 ; N ; rgb:R/G/B` ended by BEL or ST,
+/// 1-4 hex digits per channel), or `None` if any of the 16 is missing.
+pub fn parse_palette(reply: &[u8]) -> Option<[Rgb; 16]> {
+    let reply = String::from_utf8_lossy(reply);
+    let mut colors = [None; 16];
+    for entry in reply.split("\x1b]4;").skip(1) {
+        let end = entry.find(['\x07', '\x1b']).unwrap_or(entry.len());
+        let Some((index, spec)) = entry[..end].split_once(';') else {
+            continue;
+        };
+        let (Ok(index), Some(rgb)) = (index.parse::<usize>(), parse_rgb_spec(spec)) else {
+            continue;
+        };
+        if let Some(slot) = colors.get_mut(index) {
+            *slot = Some(rgb);
+        }
+    }
+    let mut palette = [(0, 0, 0); 16];
+    for (slot, color) in palette.iter_mut().zip(colors) {
+        *slot = color?;
+    }
+    Some(palette)
+}
+
+/// `rgb:RRRR/GGGG/BBBB` with 1-4 hex digits per channel, scaled to 8 bits.
+fn parse_rgb_spec(spec: &str) -> Option<Rgb> {
+    let mut channels = spec.strip_prefix("rgb:")?.split('/').map(|c| {
+        if !(1..=4).contains(&c.len()) {
+            return None;
+        }
+        let max = 16u32.pow(c.len() as u32) - 1;
+        let v = u32::from_str_radix(c, 16).ok()?;
+        Some(((v * 255 + max / 2) / max) as u8)
+    });
+    let rgb = (channels.next()??, channels.next()??, channels.next()??);
+    channels.next().is_none().then_some(rgb)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reply(colors: &[(usize, &str)], terminator: &str) -> Vec<u8> {
+        let mut out: String = colors
+            .iter()
+            .map(|(i, spec)| format!("\x1b]4;{i};{spec}{terminator}"))
+            .collect();
+        out.push_str("\x1b[?62;22c");
+        out.into_bytes()
+    }
+
+    #[test]
+    fn parses_all_sixteen_replies_in_any_order() {
+        let specs: Vec<(usize, String)> = (0..16)
+            .rev()
+            .map(|i| (i, format!("rgb:{:04x}/0000/ffff", i * 0x1111)))
+            .collect();
+        let specs: Vec<(usize, &str)> = specs.iter().map(|(i, s)| (*i, s.as_str())).collect();
+        let palette = parse_palette(&reply(&specs, "\x1b\\")).unwrap();
+        assert_eq!(palette[0], (0, 0, 255));
+        assert_eq!(palette[15], (255, 0, 255));
+        assert_eq!(palette[1], (17, 0, 255));
+    }
+
+    #[test]
+    fn scales_short_channels_and_accepts_bel() {
+        let mut specs = vec![(0, "rgb:f/80/abc")];
+        specs.extend((1..16).map(|i| (i, "rgb:00/00/00")));
+        let palette = parse_palette(&reply(&specs, "\x07")).unwrap();
+        assert_eq!(palette[0], (255, 128, 171));
+    }
+
+    #[test]
+    fn missing_or_malformed_colors_give_none() {
+        let specs: Vec<(usize, &str)> = (0..15).map(|i| (i, "rgb:00/00/00")).collect();
+        assert_eq!(parse_palette(&reply(&specs, "\x07")), None);
+        assert_eq!(parse_palette(b"\x1b[?62;22c"), None);
+        assert_eq!(parse_rgb_spec("rgb:12/34"), None);
+        assert_eq!(parse_rgb_spec("rgb:12345/0/0"), None);
+        assert_eq!(parse_rgb_spec("cmy:0/0/0"), None);
+    }
+}
@@ -0,0 +1,158 @@
+//! Handing the terminal back to the shell, on a normal exit and from the
+//! panic hook, so a panicking animation never leaves raw mode behind.
+
+use std::io;
+
+use crossterm::terminal;
+
+/// How the session left the terminal, i.e. what there is to undo.
+#[derive(Debug, Clone, Copy)]
+pub struct TerminalState {
+    /// In the alternate screen (not `--no-alt-screen`)
+    pub alt_screen: bool,
+    /// Drawing with `--graphics` images
+    pub graphics: bool,
+    /// Focus-change reporting was enabled (`--screensaver`)
+    pub focus_events: bool,
+}
+
+impl TerminalState {
+    /// Escape sequences that restore the terminal, for a screen `rows` tall.
+    ///
+    /// `\x1b[?2026l` must come before the rest: every frame starts with
+    /// `\x1b[?2026h` (begin synchronized output), and if the last write
+    /// stopped part-way the terminal is still buffering everything that
+    /// follows — including the restore — and appears frozen on the last
+    /// frame. Closing the block is a no-op outside sync mode. With
+    /// `--graphics` the write may also have stopped inside an image escape:
+    /// ST ends it, then the image is deleted (kept with `--no-alt-screen`,
+    /// as the last frame).
+    pub fn restore_sequence(&self, rows: u16) -> String {
+        let graphics = match (self.graphics, self.alt_screen) {
+            (true, true) => format!("\x1b\\{}", termflix::render::kitty::DELETE_ALL),
+            (true, false) => "\x1b\\".to_string(),
+            (false, _) => String::new(),
+        };
+        // --no-alt-screen leaves the last frame in place and puts the prompt
+        // on the line below it
+        let leave_screen = if self.alt_screen {
+            "\x1b[?1049l".to_string()
+        } else {
+            format!("\x1b[0m\x1b[{rows};1H\r\n")
+        };
+        let focus = if self.focus_events { "\x1b[?1004l" } else { "" };
+        format!("{graphics}\x1b[?2026l\x1b[?25h{leave_screen}{focus}")
+    }
+
+    /// Leave raw mode and write [`TerminalState::restore_sequence`] for the
+    /// current size.
+    pub fn restore(&self) {
+        // Disable raw mode first; it doesn't write to stdout
+        let _ = terminal::disable_raw_mode();
+        let rows = terminal::size().map_or(1, |(_, rows)| rows);
+        write_stdout(self.restore_sequence(rows).as_bytes());
+    }
+
+    /// Restore the terminal before the default panic message prints, from
+    /// whichever thread panics.
+    pub fn install_panic_hook(self) {
+        let default_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            self.restore();
+            default_hook(info);
+        }));
+    }
+}
+
+/// Write straight to the stdout fd, bypassing `Stdout`'s lock and buffer:
+/// the writer thread may hold the lock or have a half-written frame queued.
+#[cfg(unix)]
+fn write_stdout(bytes: &[u8]) {
+    use std::os::unix::io::AsRawFd;
+    let fd = io::stdout().as_raw_fd();
+    unsafe {
+        libc::write(fd, bytes.as_ptr() as *const libc::c_void, bytes.len());
+    }
+}
+
+#[cfg(not(unix))]
+fn write_stdout(bytes: &[u8]) {
+    use std::io::Write;
+    let mut stdout = io::stdout();
+    let _ = stdout.write_all(bytes);
+    let _ = stdout.flush();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use termflix::{Animation, Canvas, ColorMode, RenderMode};
+
+    const STATE: TerminalState = TerminalState {
+        alt_screen: true,
+        graphics: false,
+        focus_events: false,
+    };
+
+    #[test]
+    fn sync_close_comes_first() {
+        assert_eq!(
+            STATE.restore_sequence(24),
+            "\x1b[?2026l\x1b[?25h\x1b[?1049l"
+        );
+        let inline = TerminalState {
+            alt_screen: false,
+            graphics: true,
+            focus_events: true,
+        };
+        assert_eq!(
+            inline.restore_sequence(30),
+            "\x1b\\\x1b[?2026l\x1b[?25h\x1b[0m\x1b[30;1H\r\n\x1b[?1004l"
+        );
+    }
+
+    struct Panicking;
+
+    impl Animation for Panicking {
+        fn name(&self) -> &str {
+            "panicking"
+        }
+
+        fn update(&mut self, _canvas: &mut Canvas, _dt: f64, _time: f64) {
+            panic!("bad frame");
+        }
+    }
+
+    /// Runs in a child copy of the test binary (see below): installs the
+    /// hook and panics inside an animation's update.
+    #[test]
+    fn panic_hook_child() {
+        if std::env::var_os("TERMFLIX_PANIC_HOOK_CHILD").is_none() {
+            return;
+        }
+        STATE.install_panic_hook();
+        let mut canvas = Canvas::new(10, 5, RenderMode::HalfBlock, ColorMode::TrueColor);
+        Panicking.update(&mut canvas, 0.1, 0.0);
+    }
+
+    #[test]
+    fn panic_in_animation_restores_terminal() {
+        let output = std::process::Command::new(std::env::current_exe().unwrap())
+            .args([
+                "--exact",
+                "term_restore::tests::panic_hook_child",
+                "--nocapture",
+            ])
+            .env("TERMFLIX_PANIC_HOOK_CHILD", "1")
+            .output()
+            .unwrap();
+        assert!(!output.status.success());
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(
+            stdout.contains(&STATE.restore_sequence(1)),
+            "no restore sequence in {stdout:?}"
+        );
+        // The default hook still reports the panic afterwards
+        assert!(String::from_utf8_lossy(&output.stderr).contains("bad frame"));
+    }
+}